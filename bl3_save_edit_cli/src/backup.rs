@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use bl3_save_edit_core::backup::{profile_backup_name, save_backup_name};
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+/// Writes a backup of the save as it was loaded, using the same naming as the editor.
+pub fn backup_save(backup_dir: &Path, existing_save: &Bl3Save) -> Result<PathBuf> {
+    let (existing_save_output, _) = existing_save.as_bytes()?;
+
+    write_backup(
+        backup_dir,
+        save_backup_name(existing_save),
+        &existing_save_output,
+    )
+}
+
+/// Writes a backup of the profile as it was loaded, using the same naming as the editor.
+pub fn backup_profile(backup_dir: &Path, existing_profile: &Bl3Profile) -> Result<PathBuf> {
+    let (existing_profile_output, _) = existing_profile.as_bytes()?;
+
+    write_backup(
+        backup_dir,
+        profile_backup_name(existing_profile),
+        &existing_profile_output,
+    )
+}
+
+fn write_backup(backup_dir: &Path, backup_name: String, data: &[u8]) -> Result<PathBuf> {
+    if !backup_dir.exists() {
+        std::fs::create_dir_all(backup_dir).with_context(|| {
+            format!(
+                "failed to create backup directory: {}",
+                backup_dir.display()
+            )
+        })?;
+    }
+
+    let backup_path = backup_dir.join(backup_name);
+
+    std::fs::write(&backup_path, data)
+        .with_context(|| format!("failed to write backup: {}", backup_path.display()))?;
+
+    Ok(backup_path)
+}
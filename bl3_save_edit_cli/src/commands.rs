@@ -0,0 +1,364 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
+use bl3_save_edit_core::bl3_save::util::REQUIRED_XP_LIST;
+use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::parser::HeaderType;
+
+use crate::backup;
+
+#[derive(Debug)]
+pub enum Command {
+    Info,
+    SetLevel(usize),
+    SetMoney(i32),
+    SetEridium(i32),
+    UnlockSdu,
+    ImportItem(String),
+    Convert(ConvertTarget),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ConvertTarget {
+    Pc,
+    Ps4,
+}
+
+impl FromStr for ConvertTarget {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "pc" => Ok(ConvertTarget::Pc),
+            "ps4" => Ok(ConvertTarget::Ps4),
+            _ => bail!("unknown conversion target: '{}', expected 'pc' or 'ps4'", s),
+        }
+    }
+}
+
+pub fn run(command: Command, input: &Path, output: Option<&Path>, backup_dir: &Path) -> Result<()> {
+    let data = std::fs::read(input)
+        .with_context(|| format!("failed to read file: {}", input.display()))?;
+
+    let file = Bl3FileType::from_unknown_data(input, &data)
+        .with_context(|| format!("failed to load file: {}", input.display()))?;
+
+    if let Command::Info = command {
+        print_info(&file);
+
+        return Ok(());
+    }
+
+    let output = output.unwrap_or(input);
+
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
+            let mut new_save = save.clone();
+
+            edit_save(command, &mut new_save)?;
+
+            let (output_data, new_save) = new_save.as_bytes()?;
+
+            let backup_path = backup::backup_save(backup_dir, &save)?;
+
+            println!("Made a backup of existing save: {}", backup_path.display());
+
+            write_output(output, &output_data)?;
+
+            println!(
+                "Saved file: {} ({})",
+                output.display(),
+                new_save.header_type
+            );
+        }
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            let mut new_profile = profile.clone();
+
+            edit_profile(command, &mut new_profile)?;
+
+            let (output_data, new_profile) = new_profile.as_bytes()?;
+
+            let backup_path = backup::backup_profile(backup_dir, &profile)?;
+
+            println!(
+                "Made a backup of existing profile: {}",
+                backup_path.display()
+            );
+
+            write_output(output, &output_data)?;
+
+            println!(
+                "Saved profile: {} ({})",
+                output.display(),
+                new_profile.header_type
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn print_info(file: &Bl3FileType) {
+    println!("{}", file);
+
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => print!("{}", save),
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            print!("{}", profile)
+        }
+    }
+}
+
+fn edit_save(command: Command, save: &mut Bl3Save) -> Result<()> {
+    let character_data = &mut save.character_data;
+
+    match command {
+        Command::Info => (),
+        Command::SetLevel(level) => {
+            if !(1..=MAX_CHARACTER_LEVEL).contains(&level) {
+                bail!(
+                    "level must be between 1 and {}, got: {}",
+                    MAX_CHARACTER_LEVEL,
+                    level
+                );
+            }
+
+            character_data.set_player_level(REQUIRED_XP_LIST[level - 1][0])?;
+        }
+        Command::SetMoney(amount) => character_data.set_money(amount)?,
+        Command::SetEridium(amount) => character_data.set_eridium(amount)?,
+        Command::UnlockSdu => {
+            for sdu_slot in SaveSduSlot::iter() {
+                let maximum = sdu_slot.maximum();
+
+                character_data.set_sdu_slot(&sdu_slot, maximum);
+            }
+        }
+        Command::ImportItem(serial) => {
+            let item = Bl3Item::from_serial_base64(serial.trim())
+                .with_context(|| format!("failed to import item from serial: {}", serial))?;
+
+            let pickup_order_index = character_data.inventory_items().len() as i32;
+
+            character_data.add_inventory_item(pickup_order_index, &item)?;
+        }
+        Command::Convert(target) => {
+            save.header_type = match target {
+                ConvertTarget::Pc => HeaderType::PcSave,
+                ConvertTarget::Ps4 => HeaderType::Ps4Save,
+            };
+        }
+    }
+
+    Ok(())
+}
+
+fn edit_profile(command: Command, profile: &mut Bl3Profile) -> Result<()> {
+    let profile_data = &mut profile.profile_data;
+
+    match command {
+        Command::Info => (),
+        Command::SetLevel(_) | Command::SetMoney(_) | Command::SetEridium(_) => {
+            bail!("{:?} can only be used on a save, not a profile", command)
+        }
+        Command::UnlockSdu => {
+            for sdu_slot in ProfileSduSlot::iter() {
+                let maximum = sdu_slot.maximum();
+
+                profile_data.set_sdu_slot(&sdu_slot, maximum);
+            }
+        }
+        Command::ImportItem(serial) => {
+            let item = Bl3Item::from_serial_base64(serial.trim())
+                .with_context(|| format!("failed to import item from serial: {}", serial))?;
+
+            profile_data.add_bank_item(&item)?;
+        }
+        Command::Convert(target) => {
+            profile.header_type = match target {
+                ConvertTarget::Pc => HeaderType::PcProfile,
+                ConvertTarget::Ps4 => HeaderType::Ps4Profile,
+            };
+        }
+    }
+
+    Ok(())
+}
+
+fn write_output(output: &Path, data: &[u8]) -> Result<()> {
+    std::fs::write(output, data)
+        .with_context(|| format!("failed to write file: {}", output.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use bl3_save_edit_core::backup::parse_backup_name;
+
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("bl3_save_edit_cli_{}_{}", name, std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+
+        fs::create_dir_all(&dir).expect("failed to create test directory");
+
+        dir
+    }
+
+    fn read_file(path: &Path) -> Bl3FileType {
+        let data = fs::read(path).expect("failed to read test_file");
+
+        Bl3FileType::from_unknown_data(path, &data).expect("failed to read test file")
+    }
+
+    fn backups_in(backup_dir: &Path) -> Vec<String> {
+        let mut backups = fs::read_dir(backup_dir)
+            .expect("failed to read backup directory")
+            .filter_map(|e| parse_backup_name(&e.ok()?.path()))
+            .map(|(group, _)| group)
+            .collect::<Vec<_>>();
+
+        backups.sort();
+
+        backups
+    }
+
+    #[test]
+    fn test_set_level() {
+        let dir = test_dir("set_level");
+        let input = Path::new("../bl3_save_edit_core/test_files/19.sav");
+        let output = dir.join("19.sav");
+        let backup_dir = dir.join("backups");
+
+        run(Command::SetLevel(50), input, Some(&output), &backup_dir).expect("failed to set level");
+
+        match read_file(&output) {
+            Bl3FileType::PcSave(save) => assert_eq!(save.character_data.player_level(), 50),
+            file => panic!("expected a pc save, got: {}", file),
+        }
+
+        let backups = backups_in(&backup_dir);
+        assert_eq!(backups.len(), 1);
+        assert!(backups[0].starts_with("19_"));
+
+        assert!(run(
+            Command::SetLevel(MAX_CHARACTER_LEVEL + 1),
+            input,
+            Some(&output),
+            &backup_dir
+        )
+        .is_err());
+
+        fs::remove_dir_all(&dir).expect("failed to remove test directory");
+    }
+
+    #[test]
+    fn test_convert_save() {
+        let dir = test_dir("convert_save");
+        let input = Path::new("../bl3_save_edit_core/test_files/19.sav");
+        let ps4_output = dir.join("19_ps4.sav");
+        let pc_output = dir.join("19_pc.sav");
+        let backup_dir = dir.join("backups");
+
+        let original = match read_file(input) {
+            Bl3FileType::PcSave(save) => save,
+            file => panic!("expected a pc save, got: {}", file),
+        };
+
+        run(
+            Command::Convert(ConvertTarget::Ps4),
+            input,
+            Some(&ps4_output),
+            &backup_dir,
+        )
+        .expect("failed to convert save to ps4");
+
+        match read_file(&ps4_output) {
+            Bl3FileType::Ps4Save(save) => assert_eq!(
+                save.character_data.character,
+                original.character_data.character
+            ),
+            file => panic!("expected a ps4 save, got: {}", file),
+        }
+
+        run(
+            Command::Convert(ConvertTarget::Pc),
+            &ps4_output,
+            Some(&pc_output),
+            &backup_dir,
+        )
+        .expect("failed to convert save to pc");
+
+        match read_file(&pc_output) {
+            Bl3FileType::PcSave(save) => assert_eq!(
+                save.character_data.character,
+                original.character_data.character
+            ),
+            file => panic!("expected a pc save, got: {}", file),
+        }
+
+        fs::remove_dir_all(&dir).expect("failed to remove test directory");
+    }
+
+    #[test]
+    fn test_convert_profile() {
+        let dir = test_dir("convert_profile");
+        let input = Path::new("../bl3_save_edit_core/test_files/profile.sav");
+        let ps4_output = dir.join("profile_ps4.sav");
+        let pc_output = dir.join("profile_pc.sav");
+        let backup_dir = dir.join("backups");
+
+        let original = match read_file(input) {
+            Bl3FileType::PcProfile(profile) => profile,
+            file => panic!("expected a pc profile, got: {}", file),
+        };
+
+        run(
+            Command::Convert(ConvertTarget::Ps4),
+            input,
+            Some(&ps4_output),
+            &backup_dir,
+        )
+        .expect("failed to convert profile to ps4");
+
+        match read_file(&ps4_output) {
+            Bl3FileType::Ps4Profile(profile) => {
+                assert_eq!(profile.profile_data.profile, original.profile_data.profile)
+            }
+            file => panic!("expected a ps4 profile, got: {}", file),
+        }
+
+        run(
+            Command::Convert(ConvertTarget::Pc),
+            &ps4_output,
+            Some(&pc_output),
+            &backup_dir,
+        )
+        .expect("failed to convert profile to pc");
+
+        match read_file(&pc_output) {
+            Bl3FileType::PcProfile(profile) => {
+                assert_eq!(profile.profile_data.profile, original.profile_data.profile)
+            }
+            file => panic!("expected a pc profile, got: {}", file),
+        }
+
+        assert_eq!(backups_in(&backup_dir), vec!["profile", "profile_ps4"]);
+
+        fs::remove_dir_all(&dir).expect("failed to remove test directory");
+    }
+}
@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+
+use crate::commands::Command;
+
+mod backup;
+mod commands;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const CONFIG_DIR: &str = "bl3_save_editor";
+const BACKUP_DIR: &str = "backups";
+
+const HELP: &str = "\
+bl3_save_edit_cli
+
+USAGE:
+  bl3_save_edit_cli <COMMAND> <FILE> [ARGS] [OPTIONS]
+
+COMMANDS:
+  info                          Print information about a save or profile
+  set-level <LEVEL>             Set the level of a save
+  set-money <AMOUNT>            Set the money of a save
+  set-eridium <AMOUNT>          Set the eridium of a save
+  unlock-sdu                    Max out all SDUs of a save or profile
+  import-item <SERIAL>          Add an item (BL3(...) serial) to a save's inventory or a profile's bank
+  convert --to <ps4|pc>         Convert a save or profile between PC and PS4 formats
+
+OPTIONS:
+  --output <FILE>               Write the modified file here instead of overwriting <FILE>
+  --backup-dir <DIR>            Directory to store backups in (defaults to the editor's backup directory)
+  -h, --help                    Print this help message
+  -V, --version                 Print the version
+";
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {:#}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let mut pargs = pico_args::Arguments::from_env();
+
+    if pargs.contains(["-h", "--help"]) {
+        print!("{}", HELP);
+        return Ok(());
+    }
+
+    if pargs.contains(["-V", "--version"]) {
+        println!("bl3_save_edit_cli {}", VERSION);
+        return Ok(());
+    }
+
+    let output: Option<PathBuf> = pargs.opt_value_from_str("--output")?;
+
+    let backup_dir: PathBuf = pargs
+        .opt_value_from_str("--backup-dir")?
+        .unwrap_or_else(default_backup_dir);
+
+    let convert_to: Option<String> = pargs.opt_value_from_str("--to")?;
+
+    let subcommand = match pargs.subcommand()? {
+        Some(s) => s,
+        None => {
+            print!("{}", HELP);
+            return Ok(());
+        }
+    };
+
+    let input: PathBuf = pargs
+        .free_from_str()
+        .context("no input file was specified")?;
+
+    let command = match subcommand.as_str() {
+        "info" => Command::Info,
+        "set-level" => Command::SetLevel(
+            pargs
+                .free_from_str()
+                .context("set-level requires a level")?,
+        ),
+        "set-money" => Command::SetMoney(
+            pargs
+                .free_from_str()
+                .context("set-money requires an amount")?,
+        ),
+        "set-eridium" => Command::SetEridium(
+            pargs
+                .free_from_str()
+                .context("set-eridium requires an amount")?,
+        ),
+        "unlock-sdu" => Command::UnlockSdu,
+        "import-item" => Command::ImportItem(
+            pargs
+                .free_from_str()
+                .context("import-item requires an item serial")?,
+        ),
+        "convert" => {
+            let convert_to = convert_to.context("convert requires --to <ps4|pc>")?;
+
+            Command::Convert(convert_to.parse()?)
+        }
+        _ => bail!(
+            "unknown command: '{}', run with --help to see the available commands",
+            subcommand
+        ),
+    };
+
+    let remaining = pargs.finish();
+
+    if !remaining.is_empty() {
+        bail!("unexpected arguments: {:?}", remaining);
+    }
+
+    commands::run(command, &input, output.as_deref(), &backup_dir)
+}
+
+fn default_backup_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_default()
+        .join(CONFIG_DIR)
+        .join(BACKUP_DIR)
+}
@@ -23,6 +23,7 @@ fn main() {
     let game_data_inputs_kv = vec![
         "game_data/FAST_TRAVEL.csv",
         "game_data/MISSION.csv",
+        "game_data/ECHO_LOGS.csv",
         "game_data/PROFILE_ROOM_DECORATIONS.csv",
         "game_data/PROFILE_WEAPON_SKINS.csv",
         "game_data/PROFILE_WEAPON_TRINKETS.csv",
@@ -51,6 +52,7 @@ fn main() {
         "game_data/VEHICLE_SKINS_TECHNICAL.csv",
         "game_data/VEHICLE_SKINS_CYCLONE.csv",
         "game_data/VEHICLE_SKINS_JETBEAST.csv",
+        "game_data/FAST_TRAVEL_STATIONS.csv",
     ];
 
     let lootlemon_items = "resources/LOOTLEMON_BL3_ITEMS.csv";
@@ -87,6 +89,7 @@ fn main() {
         .inputs(proto_inputs)
         .customize(Customize {
             gen_mod_rs: Some(true),
+            serde_derive: Some(true),
             ..Default::default()
         })
         .run()
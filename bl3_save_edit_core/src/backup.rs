@@ -0,0 +1,93 @@
+use std::ffi::OsStr;
+use std::path::Path;
+
+use chrono::{Local, NaiveDateTime};
+
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::Bl3Save;
+
+const BACKUP_TIME_FORMAT: &str = "%d-%m-%Y_%H.%M.%S";
+const BACKUP_TIME_LEN: usize = "01-01-2021_00.00.00".len();
+
+/// The save file name is part of the backup name so characters which share a class and name
+/// in different save slots are kept apart.
+pub fn save_backup_name(save: &Bl3Save) -> String {
+    let backup_name = format!(
+        "{}_{}_{}-{}.sav",
+        save.file_name.replace(".sav", ""),
+        save.character_data.player_class(),
+        save.character_data.character.preferred_character_name,
+        Local::now().format(BACKUP_TIME_FORMAT)
+    );
+
+    sanitize_filename::sanitize(backup_name)
+}
+
+pub fn profile_backup_name(profile: &Bl3Profile) -> String {
+    let backup_name = format!(
+        "{}-{}.sav",
+        profile.file_name.replace(".sav", ""),
+        Local::now().format(BACKUP_TIME_FORMAT)
+    );
+
+    sanitize_filename::sanitize(backup_name)
+}
+
+/// Splits a backup file name into the save (or profile) it belongs to and the time it was made.
+/// Files which were not created by the editor return `None`.
+pub fn parse_backup_name(path: &Path) -> Option<(String, NaiveDateTime)> {
+    if path.extension().and_then(OsStr::to_str) != Some("sav") {
+        return None;
+    }
+
+    let stem = path.file_stem().and_then(OsStr::to_str)?;
+
+    let split = stem.len().checked_sub(BACKUP_TIME_LEN)?;
+
+    let group = stem.get(..split)?.strip_suffix('-')?;
+    let created = NaiveDateTime::parse_from_str(stem.get(split..)?, BACKUP_TIME_FORMAT).ok()?;
+
+    Some((group.to_owned(), created))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_backup_names() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let (group, _) = parse_backup_name(Path::new(&save_backup_name(&bl3_save)))
+            .expect("failed to parse save backup name");
+
+        assert!(group.starts_with("19_"));
+        assert!(group.ends_with(&bl3_save.character_data.character.preferred_character_name));
+
+        let filename = Path::new("./test_files/profile.sav");
+
+        let profile_file_data = fs::read(filename).expect("failed to read test_file");
+
+        let bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let (group, _) = parse_backup_name(Path::new(&profile_backup_name(&bl3_profile)))
+            .expect("failed to parse profile backup name");
+
+        assert_eq!(group, "profile");
+
+        assert!(parse_backup_name(Path::new("19.sav")).is_none());
+        assert!(parse_backup_name(Path::new("profile-01-01-2021_00.00.00.json")).is_none());
+    }
+}
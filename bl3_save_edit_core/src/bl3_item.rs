@@ -12,11 +12,13 @@ use strum::{Display, EnumString};
 use tracing::warn;
 
 use crate::arbitrary_bits::{ArbitraryBitVec, ArbitraryBits};
-use crate::game_data::{BALANCE_NAME_MAPPING, BALANCE_TO_INV_KEY};
+use crate::bl3_save::character_data::MAX_CHARACTER_LEVEL;
 use crate::parser::read_be_signed_int;
+use crate::protos::oak_save::OakInventoryItemSaveGameData;
+use crate::resources::PROFILE_WEAPON_SKINS;
 use crate::resources::{
-    INVENTORY_INV_DATA_PARTS, INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB,
-    INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
+    ResourceItem, BALANCE_NAME_MAPPING, BALANCE_TO_INV_KEY, INVENTORY_INV_DATA_PARTS,
+    INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
 };
 
 pub const MAX_BL3_ITEM_PARTS: usize = 63;
@@ -49,6 +51,19 @@ pub struct Bl3Item {
     pub item_parts: Option<Bl3ItemParts>,
     pub item_type: ItemType,
     pub flags: Option<ItemFlags>,
+    weapon_skin_path: Option<String>,
+    customization_parts: Vec<String>,
+    opaque_data: Option<OpaqueItemData>,
+}
+
+/// An item which this parser can't fully read, usually because the game added it after the
+/// bundled serial db. It is kept exactly as it was loaded so it can be written back unchanged.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct OpaqueItemData {
+    pub serial: Vec<u8>,
+    pub unparsed_bits: BitVec<Lsb0, u8>,
+    /// Why the serial couldn't be decrypted at all, these items only keep their raw serial.
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
@@ -216,6 +231,69 @@ pub enum WeaponType {
     Heavy,
 }
 
+/// A reason the game may reject or delete an item when the save is loaded.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ItemIssue {
+    /// Items never drop above the character level cap so the same bounds as character levels apply.
+    LevelOutOfRange {
+        level: usize,
+    },
+    TooManyParts {
+        count: usize,
+    },
+    TooManyAnointments {
+        count: usize,
+    },
+    MissingDependency {
+        part: String,
+        dependencies: Vec<String>,
+    },
+    ExcludedPart {
+        part: String,
+        excluded_by: String,
+    },
+    CategoryCount {
+        category: String,
+        count: usize,
+        min_parts: u8,
+        max_parts: u8,
+    },
+}
+
+impl std::fmt::Display for ItemIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemIssue::LevelOutOfRange { level } => {
+                write!(f, "等级 {} 不在 1 到 {} 之间", level, MAX_CHARACTER_LEVEL)
+            }
+            ItemIssue::TooManyParts { count } => {
+                write!(f, "部件数量 {} 超过了上限 {}", count, MAX_BL3_ITEM_PARTS)
+            }
+            ItemIssue::TooManyAnointments { count } => write!(
+                f,
+                "赋能数量 {} 超过了上限 {}",
+                count, MAX_BL3_ITEM_ANOINTMENTS
+            ),
+            ItemIssue::MissingDependency { part, dependencies } => {
+                write!(f, "{} 需要以下部件之一: {}", part, dependencies.join(", "))
+            }
+            ItemIssue::ExcludedPart { part, excluded_by } => {
+                write!(f, "{} 不能与 {} 同时使用", part, excluded_by)
+            }
+            ItemIssue::CategoryCount {
+                category,
+                count,
+                min_parts,
+                max_parts,
+            } => write!(
+                f,
+                "{} 有 {} 个部件, 但需要 {} 到 {} 个",
+                category, count, min_parts, max_parts
+            ),
+        }
+    }
+}
+
 impl Bl3Item {
     pub fn from_serial_bytes(serial: &[u8], flags: Option<ItemFlags>) -> Result<Self> {
         let serial = serial;
@@ -234,6 +312,8 @@ impl Bl3Item {
 
         let orig_seed = read_be_signed_int(&serial[1..5])?.1;
 
+        let orig_serial = serial;
+
         let mut serial = serial.to_vec();
 
         let decrypted_serial = Self::bogodecrypt(&mut serial[5..], orig_seed);
@@ -273,8 +353,24 @@ impl Bl3Item {
 
         let data_version = bits.eat(7)?;
 
+        let opaque_item = |unparsed_bits: &BitSlice<Lsb0, u8>| Self {
+            serial_version,
+            orig_seed,
+            decrypted_serial: decrypted_serial.to_vec(),
+            data_version,
+            flags,
+            opaque_data: Some(OpaqueItemData {
+                serial: orig_serial.to_vec(),
+                unparsed_bits: unparsed_bits.to_bitvec(),
+                error: None,
+            }),
+            ..Self::default()
+        };
+
         if data_version > INVENTORY_SERIAL_DB.max_version {
-            bail!("Cannot parse item as it is newer than the version of this item parser, expected: {}, found: {}", INVENTORY_SERIAL_DB.max_version, data_version);
+            warn!("Item is newer than the version of this item parser, it will be kept as is, expected: {}, found: {}", INVENTORY_SERIAL_DB.max_version, data_version);
+
+            return Ok(opaque_item(bits.bitslice()));
         }
 
         let (balance, balance_bits, balance_idx) =
@@ -306,7 +402,7 @@ impl Bl3Item {
         let balance_eng_name = BALANCE_NAME_MAPPING
             .par_iter()
             .find_first(|gd| {
-                balance_short_name_lower == gd.ident.rsplit('/').next().unwrap_or(gd.ident)
+                balance_short_name_lower == gd.ident.rsplit('/').next().unwrap_or(&gd.ident)
             })
             .map(|gd| gd.name.to_owned());
 
@@ -371,7 +467,9 @@ impl Bl3Item {
             }
 
             if bits.bitslice().count_ones() > 0 {
-                bail!("Could not fully parse the item data, there was unexpected data left.")
+                warn!("Could not fully parse the item data, there was unexpected data left, it will be kept as is.");
+
+                return Ok(opaque_item(bits.bitslice()));
             }
 
             let rarity = item_part_info
@@ -433,9 +531,49 @@ impl Bl3Item {
             item_parts,
             item_type,
             flags,
+            weapon_skin_path: None,
+            customization_parts: Vec::new(),
+            opaque_data: None,
         })
     }
 
+    /// Reads an item from a save's inventory, this keeps the weapon skin and customization parts
+    /// which are stored next to the serial.
+    /// Keeps the place of an item whose serial can't be read so the lists it is in keep the same
+    /// indexes as the file, the serial is written back unchanged.
+    pub(crate) fn unreadable(
+        serial: &[u8],
+        flags: Option<ItemFlags>,
+        error: &anyhow::Error,
+    ) -> Self {
+        Self {
+            flags,
+            opaque_data: Some(OpaqueItemData {
+                serial: serial.to_vec(),
+                unparsed_bits: BitVec::new(),
+                error: Some(format!("{:#}", error)),
+            }),
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn from_oak_inventory_item(oak_item: &OakInventoryItemSaveGameData) -> Result<Self> {
+        let mut item = Self::from_serial_bytes(
+            &oak_item.item_serial_number,
+            ItemFlags::from_bits(oak_item.flags),
+        )?;
+
+        if !oak_item.weapon_skin_path.is_empty() {
+            item.weapon_skin_path = Some(oak_item.weapon_skin_path.clone());
+        }
+
+        if let Some(development_save_data) = oak_item.development_save_data.as_ref() {
+            item.customization_parts = development_save_data.customization_part_list.to_vec();
+        }
+
+        Ok(item)
+    }
+
     pub fn from_serial_base64(serial: &str) -> Result<Self> {
         if serial.len() < 5 {
             bail!("Serial length must be longer than 4 characters.");
@@ -478,6 +616,13 @@ impl Bl3Item {
     }
 
     pub fn get_serial_number(&self, orig_seed: bool) -> Result<Vec<u8>> {
+        if let Some(opaque_data) = &self.opaque_data {
+            // Without a decrypted serial the original is the only serial there is
+            if orig_seed || opaque_data.error.is_some() {
+                return Ok(opaque_data.serial.clone());
+            }
+        }
+
         let seed = if orig_seed { self.orig_seed } else { 0 };
 
         self.encrypt_serial(seed)
@@ -497,6 +642,27 @@ impl Bl3Item {
         Ok(res)
     }
 
+    /// Items which couldn't be fully read can't be edited, only moved or removed.
+    pub fn is_opaque(&self) -> bool {
+        self.opaque_data.is_some()
+    }
+
+    pub fn opaque_data(&self) -> Option<&OpaqueItemData> {
+        self.opaque_data.as_ref()
+    }
+
+    fn ensure_editable(&self) -> Result<()> {
+        if let Some(opaque_data) = &self.opaque_data {
+            bail!(
+                "this item (version {}, {} unparsed bits) can't be edited as it couldn't be fully read",
+                self.data_version,
+                opaque_data.unparsed_bits.len()
+            );
+        }
+
+        Ok(())
+    }
+
     pub fn balance_part(&self) -> &BalancePart {
         &self.balance_part
     }
@@ -510,6 +676,8 @@ impl Bl3Item {
     }
 
     pub fn set_balance(&mut self, balance_part: BalancePart) -> Result<()> {
+        self.ensure_editable()?;
+
         let balance_ident_lower = balance_part.ident.to_lowercase();
 
         match BALANCE_TO_INV_KEY
@@ -579,6 +747,8 @@ impl Bl3Item {
     }
 
     pub fn set_inv_data(&mut self, inv_data_part: InvDataPart) -> Result<()> {
+        self.ensure_editable()?;
+
         self.inv_data_part = inv_data_part;
 
         self.update_weapon_serial()?;
@@ -587,6 +757,8 @@ impl Bl3Item {
     }
 
     pub fn set_manufacturer(&mut self, manufacturer_part: ManufacturerPart) -> Result<()> {
+        self.ensure_editable()?;
+
         self.manufacturer_part = manufacturer_part;
 
         self.update_weapon_serial()?;
@@ -594,11 +766,46 @@ impl Bl3Item {
         Ok(())
     }
 
+    pub fn weapon_skin_path(&self) -> Option<&str> {
+        self.weapon_skin_path.as_deref()
+    }
+
+    pub fn set_weapon_skin_path(&mut self, weapon_skin_path: Option<&str>) -> Result<()> {
+        if let Some(weapon_skin_path) = weapon_skin_path {
+            if self.item_type != ItemType::Weapon {
+                bail!("weapon skins can only be applied to weapons");
+            }
+
+            if !PROFILE_WEAPON_SKINS
+                .iter()
+                .any(|s| s.ident == weapon_skin_path)
+            {
+                bail!("unknown weapon skin: {}", weapon_skin_path);
+            }
+        }
+
+        self.weapon_skin_path = weapon_skin_path.map(|s| s.to_owned());
+
+        Ok(())
+    }
+
+    pub fn customization_parts(&self) -> &[String] {
+        &self.customization_parts
+    }
+
+    /// Weapon skins and customization parts are only stored with inventory items, the bank and
+    /// lost loot only store the item serial so these are lost when the item is moved there.
+    pub fn has_cosmetics(&self) -> bool {
+        self.weapon_skin_path.is_some() || !self.customization_parts.is_empty()
+    }
+
     pub fn level(&self) -> usize {
         self.level
     }
 
     pub fn set_level(&mut self, new_level: usize) -> Result<()> {
+        self.ensure_editable()?;
+
         self.level = new_level;
 
         self.update_weapon_serial()?;
@@ -756,7 +963,116 @@ impl Bl3Item {
         Ok(())
     }
 
+    /// Checks the item against the part rules for its balance as well as the serial limits.
+    ///
+    /// Part rules are only checked when the balance is found in `INVENTORY_PARTS_ALL_CATEGORIZED`.
+    pub fn validate(&self) -> Vec<ItemIssue> {
+        let resource_item = self
+            .balance_part
+            .short_ident
+            .as_ref()
+            .and_then(|bs| INVENTORY_PARTS_ALL_CATEGORIZED.get(bs));
+
+        self.validate_with(resource_item)
+    }
+
+    fn validate_with(&self, resource_item: Option<&ResourceItem>) -> Vec<ItemIssue> {
+        let mut issues = Vec::new();
+
+        // There is nothing to check as items which couldn't be fully read are never edited
+        if self.is_opaque() {
+            return issues;
+        }
+
+        if !(1..=MAX_CHARACTER_LEVEL).contains(&self.level) {
+            issues.push(ItemIssue::LevelOutOfRange { level: self.level });
+        }
+
+        let item_parts = match &self.item_parts {
+            Some(item_parts) => item_parts,
+            None => return issues,
+        };
+
+        if item_parts.parts.len() > MAX_BL3_ITEM_PARTS {
+            issues.push(ItemIssue::TooManyParts {
+                count: item_parts.parts.len(),
+            });
+        }
+
+        if item_parts.generic_parts.len() > MAX_BL3_ITEM_ANOINTMENTS {
+            issues.push(ItemIssue::TooManyAnointments {
+                count: item_parts.generic_parts.len(),
+            });
+        }
+
+        let resource_item = match resource_item {
+            Some(resource_item) => resource_item,
+            None => return issues,
+        };
+
+        let part_names = item_parts
+            .parts
+            .iter()
+            .filter_map(|p| p.short_ident.as_deref())
+            .collect::<Vec<_>>();
+
+        let has_part = |name: &str| part_names.iter().any(|p| p.eq_ignore_ascii_case(name));
+
+        for category in &resource_item.inventory_categorized_parts {
+            let mut count = 0;
+
+            for resource_part in &category.parts {
+                let part_count = part_names
+                    .iter()
+                    .filter(|p| p.eq_ignore_ascii_case(&resource_part.name))
+                    .count();
+
+                if part_count == 0 {
+                    continue;
+                }
+
+                count += part_count;
+
+                if let Some(dependencies) = &resource_part.dependencies {
+                    if !dependencies.is_empty() && !dependencies.iter().any(|d| has_part(d)) {
+                        issues.push(ItemIssue::MissingDependency {
+                            part: resource_part.name.clone(),
+                            dependencies: dependencies.clone(),
+                        });
+                    }
+                }
+
+                if let Some(excluders) = &resource_part.excluders {
+                    for excluder in excluders.iter().filter(|e| has_part(e)) {
+                        issues.push(ItemIssue::ExcludedPart {
+                            part: resource_part.name.clone(),
+                            excluded_by: excluder.clone(),
+                        });
+                    }
+                }
+            }
+
+            // Every part in a category carries the same limits
+            if let Some(first_part) = category.parts.first() {
+                let (min_parts, max_parts) = (first_part.min_parts, first_part.max_parts);
+
+                if count < min_parts as usize || count > max_parts as usize {
+                    issues.push(ItemIssue::CategoryCount {
+                        category: category.category.clone(),
+                        count,
+                        min_parts,
+                        max_parts,
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+
     pub fn update_weapon_serial(&mut self) -> Result<()> {
+        self.ensure_editable()?;
+
         let serial_db = &*INVENTORY_SERIAL_DB;
 
         self.data_version = serial_db.max_version;
@@ -907,6 +1223,8 @@ impl Bl3Item {
 
 #[cfg(test)]
 mod tests {
+    use crate::resources::{ResourceCategorizedParts, ResourcePart};
+
     use super::*;
 
     #[test]
@@ -976,9 +1294,138 @@ mod tests {
         assert_eq!(decrypted, encrypted_from_base64);
     }
 
+    #[test]
+    fn test_opaque_item_passthrough() {
+        let item =
+            Bl3Item::from_serial_base64("BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)").unwrap();
+
+        assert!(!item.is_opaque());
+
+        // An item from a newer version of the game
+        let newer_serial_base64 = "BL3(AwAAAAAKG4D/I9xAEzwShMJVX8nPYwsAAA==)";
+
+        let mut newer_item = Bl3Item::from_serial_base64(newer_serial_base64).unwrap();
+
+        assert!(newer_item.is_opaque());
+        assert_eq!(newer_item.data_version, 127);
+        assert!(newer_item.validate().is_empty());
+        assert!(newer_item.set_level(10).is_err());
+        assert_eq!(
+            newer_item.get_serial_number_base64(true).unwrap(),
+            newer_serial_base64
+        );
+        assert_eq!(
+            newer_item.get_serial_number_base64(false).unwrap(),
+            newer_serial_base64
+        );
+
+        // An item with data after its parts
+        let mut trailing_item = item.clone();
+        trailing_item.decrypted_serial.push(0xFF);
+
+        let trailing_serial = trailing_item.encrypt_serial(1234).unwrap();
+
+        let trailing_item = Bl3Item::from_serial_bytes(&trailing_serial, None).unwrap();
+
+        assert!(trailing_item.is_opaque());
+        assert_eq!(trailing_item.data_version, item.data_version);
+        assert!(
+            trailing_item
+                .opaque_data()
+                .unwrap()
+                .unparsed_bits
+                .count_ones()
+                > 0
+        );
+        assert_eq!(
+            trailing_item.get_serial_number(true).unwrap(),
+            trailing_serial
+        );
+    }
+
     #[test]
     fn test_decrypt_base64() {
         Bl3Item::from_serial_base64("bl3(BMo1YGLGQ0MGYsI1/FbX0bJzzEAlJV/zmj/7qVR3P7k=)").unwrap();
         Bl3Item::from_serial_base64("bl3(BDcRFWih0RoFBasjJ57Z1Zlf1975cgf2ns3n+pGwL9wo0iSoqfEvpNLcQBqq+kyitN3iuNu36Njp0sLClYQHFp550i9NgKN5J6xn8H2YeH1Ugoqv)").unwrap();
     }
+
+    #[test]
+    fn test_validate_parts() {
+        let item =
+            Bl3Item::from_serial_base64("BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)").unwrap();
+
+        assert!(item.validate_with(None).is_empty());
+
+        let resource_part = |name: &str, min_parts, max_parts| ResourcePart {
+            name: name.to_owned(),
+            min_parts,
+            max_parts,
+            ..ResourcePart::default()
+        };
+
+        let resource_item = ResourceItem {
+            inventory_categorized_parts: vec![
+                ResourceCategorizedParts {
+                    category: "Body".to_owned(),
+                    parts: vec![resource_part("Shield_Part_Body_03_Hyperion", 1, 1)],
+                },
+                ResourceCategorizedParts {
+                    category: "Augment".to_owned(),
+                    parts: vec![
+                        ResourcePart {
+                            dependencies: Some(vec!["Part_Shield_Aug_Missing".to_owned()]),
+                            ..resource_part("Part_Shield_Aug_RechargeRate", 0, 1)
+                        },
+                        ResourcePart {
+                            excluders: Some(vec!["Part_Shield_Aug_RechargeRate".to_owned()]),
+                            ..resource_part("Part_Shield_Aug_Spike", 0, 1)
+                        },
+                    ],
+                },
+                ResourceCategorizedParts {
+                    category: "Element".to_owned(),
+                    parts: vec![resource_part("Shield_Part_Element_Shock", 1, 1)],
+                },
+            ],
+            ..ResourceItem::default()
+        };
+
+        let issues = item.validate_with(Some(&resource_item));
+
+        assert_eq!(
+            issues,
+            vec![
+                ItemIssue::MissingDependency {
+                    part: "Part_Shield_Aug_RechargeRate".to_owned(),
+                    dependencies: vec!["Part_Shield_Aug_Missing".to_owned()],
+                },
+                ItemIssue::ExcludedPart {
+                    part: "Part_Shield_Aug_Spike".to_owned(),
+                    excluded_by: "Part_Shield_Aug_RechargeRate".to_owned(),
+                },
+                ItemIssue::CategoryCount {
+                    category: "Augment".to_owned(),
+                    count: 2,
+                    min_parts: 0,
+                    max_parts: 1,
+                },
+                ItemIssue::CategoryCount {
+                    category: "Element".to_owned(),
+                    count: 0,
+                    min_parts: 1,
+                    max_parts: 1,
+                },
+            ]
+        );
+
+        let mut item = item;
+        item.level = MAX_CHARACTER_LEVEL + 1;
+
+        assert_eq!(
+            item.validate_with(None),
+            vec![ItemIssue::LevelOutOfRange {
+                level: MAX_CHARACTER_LEVEL + 1
+            }]
+        );
+    }
 }
@@ -0,0 +1,647 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::protos::oak_profile::{
+    PlayerInputBinding_Axis, PlayerInputBinding_Axis_Key, PlayerInputBinding_Button,
+    PlayerInputBinding_Category, PlayerInputBindings, Profile,
+};
+use crate::protos::oak_shared::Vec3;
+
+#[derive(
+    Debug, Display, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GameSettingSection {
+    Input,
+    Audio,
+    Video,
+    Interface,
+}
+
+/// A setting value as written to a preset, integers are accepted for float settings.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GameSettingValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+}
+
+impl std::fmt::Display for GameSettingValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(v) => write!(f, "{}", v),
+            Self::Int(v) => write!(f, "{}", v),
+            Self::Float(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameSetting {
+    pub section: GameSettingSection,
+    pub name: &'static str,
+    pub value: GameSettingValue,
+}
+
+#[derive(Copy, Clone)]
+enum GameSettingField {
+    Bool(fn(&Profile) -> bool, fn(&mut Profile) -> &mut bool),
+    I32(fn(&Profile) -> i32, fn(&mut Profile) -> &mut i32),
+    U32(fn(&Profile) -> u32, fn(&mut Profile) -> &mut u32),
+    F32(fn(&Profile) -> f32, fn(&mut Profile) -> &mut f32),
+}
+
+struct GameSettingDefinition {
+    section: GameSettingSection,
+    name: &'static str,
+    field: GameSettingField,
+}
+
+const fn setting(
+    section: GameSettingSection,
+    name: &'static str,
+    field: GameSettingField,
+) -> GameSettingDefinition {
+    GameSettingDefinition {
+        section,
+        name,
+        field,
+    }
+}
+
+use GameSettingField::{Bool, F32, I32, U32};
+use GameSettingSection::{Audio, Input, Interface, Video};
+
+// Settings that are safe to copy between machines, progression and online data is left out
+const GAME_SETTINGS: &[GameSettingDefinition] = &[
+    setting(
+        Input,
+        "mouse_scale",
+        F32(|p| p.mouse_scale, |p| &mut p.mouse_scale),
+    ),
+    setting(
+        Input,
+        "mouse_ads_scale",
+        F32(|p| p.mouse_ads_scale, |p| &mut p.mouse_ads_scale),
+    ),
+    setting(
+        Input,
+        "mouse_vehicle_scale",
+        F32(|p| p.mouse_vehicle_scale, |p| &mut p.mouse_vehicle_scale),
+    ),
+    setting(
+        Input,
+        "invert_mouse_pitch",
+        Bool(|p| p.invert_mouse_pitch, |p| &mut p.invert_mouse_pitch),
+    ),
+    setting(
+        Input,
+        "enable_mouse_smoothing",
+        Bool(
+            |p| p.enable_mouse_smoothing,
+            |p| &mut p.enable_mouse_smoothing,
+        ),
+    ),
+    setting(
+        Input,
+        "enable_mouse_acceleration",
+        Bool(
+            |p| p.enable_mouse_acceleration,
+            |p| &mut p.enable_mouse_acceleration,
+        ),
+    ),
+    setting(
+        Input,
+        "mouse_ironsight_aim_assist",
+        Bool(
+            |p| p.mouse_ironsight_aim_assist,
+            |p| &mut p.mouse_ironsight_aim_assist,
+        ),
+    ),
+    setting(
+        Input,
+        "enable_aim_assist",
+        Bool(|p| p.enable_aim_assist, |p| &mut p.enable_aim_assist),
+    ),
+    setting(
+        Input,
+        "ironsight_aim_assist",
+        Bool(|p| p.ironsight_aim_assist, |p| &mut p.ironsight_aim_assist),
+    ),
+    setting(
+        Input,
+        "enable_vibration",
+        Bool(|p| p.enable_vibration, |p| &mut p.enable_vibration),
+    ),
+    setting(
+        Input,
+        "gamepad_invert_look",
+        Bool(|p| p.gamepad_invert_look, |p| &mut p.gamepad_invert_look),
+    ),
+    setting(
+        Input,
+        "gamepad_invert_turn",
+        Bool(|p| p.gamepad_invert_turn, |p| &mut p.gamepad_invert_turn),
+    ),
+    setting(
+        Input,
+        "gamepad_invert_move",
+        Bool(|p| p.gamepad_invert_move, |p| &mut p.gamepad_invert_move),
+    ),
+    setting(
+        Input,
+        "gamepad_invert_strafe",
+        Bool(
+            |p| p.gamepad_invert_strafe,
+            |p| &mut p.gamepad_invert_strafe,
+        ),
+    ),
+    setting(
+        Input,
+        "gamepad_hip_sensitivity_level",
+        I32(
+            |p| p.gamepad_hip_sensitivity_level,
+            |p| &mut p.gamepad_hip_sensitivity_level,
+        ),
+    ),
+    setting(
+        Input,
+        "gamepad_zoomed_sensitivity_level",
+        I32(
+            |p| p.gamepad_zoomed_sensitivity_level,
+            |p| &mut p.gamepad_zoomed_sensitivity_level,
+        ),
+    ),
+    setting(
+        Input,
+        "gamepad_vehicle_sensitivity_level",
+        I32(
+            |p| p.gamepad_vehicle_sensitivity_level,
+            |p| &mut p.gamepad_vehicle_sensitivity_level,
+        ),
+    ),
+    setting(
+        Input,
+        "gamepad_left_dead_zone_inner",
+        F32(
+            |p| p.gamepad_left_dead_zone_inner,
+            |p| &mut p.gamepad_left_dead_zone_inner,
+        ),
+    ),
+    setting(
+        Input,
+        "gamepad_left_dead_zone_outer",
+        F32(
+            |p| p.gamepad_left_dead_zone_outer,
+            |p| &mut p.gamepad_left_dead_zone_outer,
+        ),
+    ),
+    setting(
+        Input,
+        "gamepad_right_dead_zone_inner",
+        F32(
+            |p| p.gamepad_right_dead_zone_inner,
+            |p| &mut p.gamepad_right_dead_zone_inner,
+        ),
+    ),
+    setting(
+        Input,
+        "gamepad_right_dead_zone_outer",
+        F32(
+            |p| p.gamepad_right_dead_zone_outer,
+            |p| &mut p.gamepad_right_dead_zone_outer,
+        ),
+    ),
+    setting(
+        Input,
+        "toggle_sprint",
+        Bool(|p| p.toggle_sprint, |p| &mut p.toggle_sprint),
+    ),
+    setting(
+        Input,
+        "toggle_crouch",
+        Bool(|p| p.toggle_crouch, |p| &mut p.toggle_crouch),
+    ),
+    setting(
+        Input,
+        "weapon_aim_toggle",
+        Bool(|p| p.weapon_aim_toggle, |p| &mut p.weapon_aim_toggle),
+    ),
+    setting(
+        Input,
+        "mantle_requires_button",
+        Bool(
+            |p| p.mantle_requires_button,
+            |p| &mut p.mantle_requires_button,
+        ),
+    ),
+    setting(
+        Input,
+        "swap_dual_wield_controls",
+        Bool(
+            |p| p.swap_dual_wield_controls,
+            |p| &mut p.swap_dual_wield_controls,
+        ),
+    ),
+    setting(
+        Audio,
+        "master_volume",
+        F32(|p| p.master_volume, |p| &mut p.master_volume),
+    ),
+    setting(
+        Audio,
+        "music_volume",
+        F32(|p| p.music_volume, |p| &mut p.music_volume),
+    ),
+    setting(
+        Audio,
+        "sound_effects_volume",
+        F32(|p| p.sound_effects_volume, |p| &mut p.sound_effects_volume),
+    ),
+    setting(
+        Audio,
+        "vo_volume",
+        F32(|p| p.vo_volume, |p| &mut p.vo_volume),
+    ),
+    setting(
+        Audio,
+        "voice_volume",
+        F32(|p| p.voice_volume, |p| &mut p.voice_volume),
+    ),
+    setting(
+        Audio,
+        "enable_optional_vo",
+        Bool(|p| p.enable_optional_vo, |p| &mut p.enable_optional_vo),
+    ),
+    setting(
+        Audio,
+        "push_to_talk",
+        Bool(|p| p.push_to_talk, |p| &mut p.push_to_talk),
+    ),
+    setting(
+        Audio,
+        "enable_controller_audio",
+        Bool(
+            |p| p.enable_controller_audio,
+            |p| &mut p.enable_controller_audio,
+        ),
+    ),
+    setting(
+        Audio,
+        "mute_audio_on_focus_loss",
+        Bool(
+            |p| p.mute_audio_on_focus_loss,
+            |p| &mut p.mute_audio_on_focus_loss,
+        ),
+    ),
+    setting(Video, "base_fov", F32(|p| p.base_fov, |p| &mut p.base_fov)),
+    setting(
+        Video,
+        "base_vehicle_fov",
+        F32(|p| p.base_vehicle_fov, |p| &mut p.base_vehicle_fov),
+    ),
+    setting(
+        Video,
+        "head_bob_scale",
+        F32(|p| p.head_bob_scale, |p| &mut p.head_bob_scale),
+    ),
+    setting(
+        Video,
+        "frame_rate_limit",
+        U32(|p| p.frame_rate_limit, |p| &mut p.frame_rate_limit),
+    ),
+    setting(
+        Video,
+        "object_motion_blur",
+        Bool(|p| p.object_motion_blur, |p| &mut p.object_motion_blur),
+    ),
+    setting(
+        Video,
+        "lens_flare",
+        Bool(|p| p.lens_flare, |p| &mut p.lens_flare),
+    ),
+    setting(
+        Interface,
+        "show_damage_numbers",
+        Bool(|p| p.show_damage_numbers, |p| &mut p.show_damage_numbers),
+    ),
+    setting(
+        Interface,
+        "show_damage_number_icons",
+        Bool(
+            |p| p.show_damage_number_icons,
+            |p| &mut p.show_damage_number_icons,
+        ),
+    ),
+    setting(
+        Interface,
+        "center_crosshair",
+        Bool(|p| p.center_crosshair, |p| &mut p.center_crosshair),
+    ),
+    setting(
+        Interface,
+        "crosshair_neutral_color_frame",
+        U32(
+            |p| p.crosshair_neutral_color_frame,
+            |p| &mut p.crosshair_neutral_color_frame,
+        ),
+    ),
+    setting(
+        Interface,
+        "crosshair_enemy_color_frame",
+        U32(
+            |p| p.crosshair_enemy_color_frame,
+            |p| &mut p.crosshair_enemy_color_frame,
+        ),
+    ),
+    setting(
+        Interface,
+        "crosshair_ally_color_frame",
+        U32(
+            |p| p.crosshair_ally_color_frame,
+            |p| &mut p.crosshair_ally_color_frame,
+        ),
+    ),
+    setting(
+        Interface,
+        "hud_scale_multiplier",
+        F32(|p| p.hud_scale_multiplier, |p| &mut p.hud_scale_multiplier),
+    ),
+    setting(
+        Interface,
+        "enable_subtitles",
+        Bool(|p| p.enable_subtitles, |p| &mut p.enable_subtitles),
+    ),
+    setting(
+        Interface,
+        "enable_closed_captions",
+        Bool(
+            |p| p.enable_closed_captions,
+            |p| &mut p.enable_closed_captions,
+        ),
+    ),
+    setting(
+        Interface,
+        "show_text_chat",
+        Bool(|p| p.show_text_chat, |p| &mut p.show_text_chat),
+    ),
+    setting(
+        Interface,
+        "enable_training_messages",
+        Bool(
+            |p| p.enable_training_messages,
+            |p| &mut p.enable_training_messages,
+        ),
+    ),
+];
+
+impl GameSettingField {
+    fn value(&self, profile: &Profile) -> GameSettingValue {
+        match self {
+            Bool(get, _) => GameSettingValue::Bool(get(profile)),
+            I32(get, _) => GameSettingValue::Int(get(profile) as i64),
+            U32(get, _) => GameSettingValue::Int(get(profile) as i64),
+            // Go through the string form so that 0.3 isn't written as 0.30000001192092896
+            F32(get, _) => {
+                GameSettingValue::Float(get(profile).to_string().parse().unwrap_or_default())
+            }
+        }
+    }
+
+    fn set_value(&self, profile: &mut Profile, value: GameSettingValue) -> Result<()> {
+        match (self, value) {
+            (Bool(_, field), GameSettingValue::Bool(v)) => *field(profile) = v,
+            (I32(_, field), GameSettingValue::Int(v)) => *field(profile) = i32::try_from(v)?,
+            (U32(_, field), GameSettingValue::Int(v)) => *field(profile) = u32::try_from(v)?,
+            (F32(_, field), GameSettingValue::Int(v)) => *field(profile) = v as f32,
+            (F32(_, field), GameSettingValue::Float(v)) => *field(profile) = v as f32,
+            (_, v) => bail!("{} is not a valid value", v),
+        }
+
+        Ok(())
+    }
+}
+
+fn find_game_setting(
+    section: GameSettingSection,
+    name: &str,
+) -> Result<&'static GameSettingDefinition> {
+    GAME_SETTINGS
+        .iter()
+        .find(|s| s.section == section && s.name == name)
+        .with_context(|| format!("unknown game setting: {}.{}", section, name))
+}
+
+pub fn game_settings(profile: &Profile) -> Vec<GameSetting> {
+    GAME_SETTINGS
+        .iter()
+        .map(|s| GameSetting {
+            section: s.section,
+            name: s.name,
+            value: s.field.value(profile),
+        })
+        .collect()
+}
+
+pub fn set_game_setting(
+    profile: &mut Profile,
+    section: GameSettingSection,
+    name: &str,
+    value: GameSettingValue,
+) -> Result<()> {
+    find_game_setting(section, name)?
+        .field
+        .set_value(profile, value)
+        .with_context(|| format!("failed to set game setting {}.{}", section, name))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyBindingCategory {
+    pub category: String,
+    pub context: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub buttons: Vec<ButtonBinding>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub axes: Vec<AxisBinding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ButtonBinding {
+    pub action: String,
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisBinding {
+    pub action: String,
+    pub keys: Vec<AxisKeyBinding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisKeyBinding {
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<[f32; 3]>,
+}
+
+pub fn key_bindings(profile: &Profile) -> Option<Vec<KeyBindingCategory>> {
+    let bindings = profile.player_input_bindings.as_ref()?;
+
+    let categories = bindings
+        .categories
+        .iter()
+        .map(|c| KeyBindingCategory {
+            category: c.category_data_path.clone(),
+            context: c.context_data_path.clone(),
+            buttons: c
+                .button_bindings
+                .iter()
+                .map(|b| ButtonBinding {
+                    action: b.rebind_data_path.clone(),
+                    keys: b.key_names.to_vec(),
+                })
+                .collect(),
+            axes: c
+                .axis_bindings
+                .iter()
+                .map(|a| AxisBinding {
+                    action: a.rebind_data_path.clone(),
+                    keys: a
+                        .keys
+                        .iter()
+                        .map(|k| AxisKeyBinding {
+                            key: k.key_name.clone(),
+                            scale: k.scale_3d.as_ref().map(|s| [s.x, s.y, s.z]),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    Some(categories)
+}
+
+pub fn set_key_bindings(profile: &mut Profile, categories: &[KeyBindingCategory]) {
+    let categories = categories
+        .iter()
+        .map(|c| PlayerInputBinding_Category {
+            category_data_path: c.category.clone(),
+            context_data_path: c.context.clone(),
+            button_bindings: c
+                .buttons
+                .iter()
+                .map(|b| PlayerInputBinding_Button {
+                    rebind_data_path: b.action.clone(),
+                    key_names: b.keys.clone().into(),
+                    ..Default::default()
+                })
+                .collect(),
+            axis_bindings: c
+                .axes
+                .iter()
+                .map(|a| PlayerInputBinding_Axis {
+                    rebind_data_path: a.action.clone(),
+                    keys: a
+                        .keys
+                        .iter()
+                        .map(|k| PlayerInputBinding_Axis_Key {
+                            key_name: k.key.clone(),
+                            scale_3d: k
+                                .scale
+                                .map(|[x, y, z]| Vec3 {
+                                    x,
+                                    y,
+                                    z,
+                                    ..Default::default()
+                                })
+                                .into(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        })
+        .collect();
+
+    profile.player_input_bindings = Some(PlayerInputBindings {
+        categories,
+        ..Default::default()
+    })
+    .into();
+}
+
+/// The portable part of a profile, settings left out of a preset are not changed when it is applied.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSettingsPreset {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub input: BTreeMap<String, GameSettingValue>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub audio: BTreeMap<String, GameSettingValue>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub video: BTreeMap<String, GameSettingValue>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub interface: BTreeMap<String, GameSettingValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_bindings: Option<Vec<KeyBindingCategory>>,
+}
+
+impl GameSettingsPreset {
+    pub fn from_profile(profile: &Profile) -> Self {
+        let mut preset = Self {
+            key_bindings: key_bindings(profile),
+            ..Default::default()
+        };
+
+        for setting in game_settings(profile) {
+            preset
+                .section_mut(setting.section)
+                .insert(setting.name.to_owned(), setting.value);
+        }
+
+        preset
+    }
+
+    fn section_mut(
+        &mut self,
+        section: GameSettingSection,
+    ) -> &mut BTreeMap<String, GameSettingValue> {
+        match section {
+            Input => &mut self.input,
+            Audio => &mut self.audio,
+            Video => &mut self.video,
+            Interface => &mut self.interface,
+        }
+    }
+
+    /// Applies the preset to `profile`, nothing is changed if any setting in the preset is invalid.
+    pub fn apply_to_profile(&self, profile: &mut Profile) -> Result<()> {
+        let mut updated_profile = profile.clone();
+
+        for (section, settings) in [
+            (Input, &self.input),
+            (Audio, &self.audio),
+            (Video, &self.video),
+            (Interface, &self.interface),
+        ] {
+            for (name, value) in settings {
+                set_game_setting(&mut updated_profile, section, name, *value)?;
+            }
+        }
+
+        if let Some(key_bindings) = &self.key_bindings {
+            set_key_bindings(&mut updated_profile, key_bindings);
+        }
+
+        *profile = updated_profile;
+
+        Ok(())
+    }
+}
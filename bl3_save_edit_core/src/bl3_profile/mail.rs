@@ -0,0 +1,119 @@
+use std::fmt::Formatter;
+
+use anyhow::Result;
+
+use crate::bl3_item::Bl3Item;
+use crate::protos::oak_shared::OakMailItem;
+
+/// Mail sent by the game (and this editor) is not tied to a player.
+pub const MAIL_FROM_PLAYER_ID: &str = "INVALID";
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum MailItemType {
+    Gear,
+    Unknown(u32),
+}
+
+impl std::default::Default for MailItemType {
+    fn default() -> Self {
+        Self::Gear
+    }
+}
+
+impl std::fmt::Display for MailItemType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Gear => write!(f, "装备"),
+            Self::Unknown(t) => write!(f, "未知 ({})", t),
+        }
+    }
+}
+
+impl MailItemType {
+    // Only gear mail has been seen in profiles, other types are kept as they are
+    const GEAR: u32 = 4;
+
+    pub fn from_u32(mail_item_type: u32) -> Self {
+        match mail_item_type {
+            Self::GEAR => Self::Gear,
+            t => Self::Unknown(t),
+        }
+    }
+
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            Self::Gear => Self::GEAR,
+            Self::Unknown(t) => *t,
+        }
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct MailData {
+    pub mail_guid: String,
+    pub mail_item_type: MailItemType,
+    pub sender_display_name: String,
+    pub subject: String,
+    pub body: String,
+    pub item: Option<Bl3Item>,
+    /// .NET ticks (100 nanoseconds since 0001-01-01)
+    pub date_sent: i64,
+    pub expiration_date: i64,
+    pub has_been_read: bool,
+}
+
+impl MailData {
+    pub fn from_mail_item(mail_item: &OakMailItem) -> Self {
+        // The attached gear is stored as the encrypted serial in base64 without the BL3() wrapper
+        let mail_item_type = MailItemType::from_u32(mail_item.mail_item_type);
+
+        let item = base64::decode(&mail_item.gear_serial_number)
+            .ok()
+            .and_then(|serial| Bl3Item::from_serial_bytes(&serial, None).ok());
+
+        Self {
+            mail_guid: mail_item.mail_guid.clone(),
+            mail_item_type,
+            sender_display_name: mail_item.sender_display_name.clone(),
+            subject: mail_item.subject.clone(),
+            body: mail_item.body.clone(),
+            item,
+            date_sent: mail_item.date_sent,
+            expiration_date: mail_item.expiration_date,
+            has_been_read: mail_item.has_been_read,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewMailData {
+    pub mail_guid: String,
+    pub sender_display_name: String,
+    pub subject: String,
+    pub body: String,
+    /// Only gear can be attached, no profile with money or eridium mail has been seen to
+    /// confirm how the game stores them
+    pub item: Bl3Item,
+    pub date_sent: i64,
+}
+
+impl NewMailData {
+    pub fn to_mail_item(&self) -> Result<OakMailItem> {
+        let gear_serial_number = base64::encode(self.item.get_serial_number(true)?);
+
+        Ok(OakMailItem {
+            mail_item_type: MailItemType::Gear.as_u32(),
+            sender_display_name: self.sender_display_name.clone(),
+            subject: self.subject.clone(),
+            body: self.body.clone(),
+            gear_serial_number,
+            mail_guid: self.mail_guid.clone(),
+            date_sent: self.date_sent,
+            expiration_date: 0,
+            from_player_id: MAIL_FROM_PLAYER_ID.to_owned(),
+            has_been_read: false,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        })
+    }
+}
@@ -8,23 +8,27 @@ use byteorder::{LittleEndian, WriteBytesExt};
 
 use crate::bl3_profile::profile_data::ProfileData;
 use crate::file_helper::FileData;
-use crate::game_data::{
+use crate::load_warning::{LoadMode, LoadWarning, LoadWarnings};
+use crate::models::CustomFormatData;
+use crate::parser::{decrypt, encrypt, HeaderType};
+use crate::protos::oak_profile::Profile;
+use crate::resources::{
     PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
     PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_ROOM_DECORATIONS, PROFILE_SKINS,
     PROFILE_SKINS_DEFAULTS, PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS,
 };
-use crate::models::CustomFormatData;
-use crate::parser::{decrypt, encrypt, HeaderType};
-use crate::protos::oak_profile::Profile;
 use crate::{file_helper, parser};
 
+pub mod game_settings;
 pub mod guardian_reward;
+pub mod mail;
 pub mod profile_currency;
 pub mod profile_data;
 pub mod science_levels;
 pub mod sdu;
 pub mod skins;
 pub mod util;
+pub mod vault_card;
 
 #[derive(Debug, Clone, Default, Eq, Ord, PartialOrd)]
 pub struct Bl3Profile {
@@ -42,6 +46,8 @@ pub struct Bl3Profile {
     pub save_game_type: String,
     pub header_type: HeaderType,
     pub profile_data: ProfileData,
+    pub load_warnings: Vec<LoadWarning>,
+    pub file_hash: u32,
 }
 
 impl std::cmp::PartialEq for Bl3Profile {
@@ -52,11 +58,23 @@ impl std::cmp::PartialEq for Bl3Profile {
 
 impl Bl3Profile {
     pub fn from_file_data(file_data: &FileData, header_type: HeaderType) -> Result<Self> {
+        Self::from_file_data_with_mode(file_data, header_type, LoadMode::Strict)
+    }
+
+    /// Reads the file, in `LoadMode::Lenient` anything which can't be read is kept in
+    /// `load_warnings` instead of failing.
+    pub fn from_file_data_with_mode(
+        file_data: &FileData,
+        header_type: HeaderType,
+        mode: LoadMode,
+    ) -> Result<Self> {
         let remaining_data = file_data.remaining_data;
 
         let profile: Profile = decrypt(remaining_data, &header_type)?;
 
-        let profile_data = ProfileData::from_profile(profile)?;
+        let mut warnings = LoadWarnings::new(mode);
+
+        let profile_data = ProfileData::from_profile_with_warnings(profile, &mut warnings)?;
 
         let FileData {
             file_location,
@@ -71,6 +89,7 @@ impl Bl3Profile {
             custom_format_data_count,
             custom_format_data,
             save_game_type,
+            file_hash,
             ..
         } = file_data.clone();
 
@@ -79,6 +98,8 @@ impl Bl3Profile {
             .map(|s| s.to_string_lossy().to_string())
             .context("failed to read file name")?;
 
+        let load_warnings = warnings.into_warnings(&file_name);
+
         Ok(Self {
             file_name,
             save_game_version: file_version,
@@ -94,13 +115,33 @@ impl Bl3Profile {
             save_game_type,
             header_type,
             profile_data,
+            load_warnings,
+            file_hash,
         })
     }
 
     pub fn from_bytes(file_location: &Path, data: &[u8], header_type: HeaderType) -> Result<Self> {
+        Self::from_bytes_with_mode(file_location, data, header_type, LoadMode::Strict)
+    }
+
+    pub fn from_bytes_with_mode(
+        file_location: &Path,
+        data: &[u8],
+        header_type: HeaderType,
+        mode: LoadMode,
+    ) -> Result<Self> {
         let file_data = file_helper::read_bytes(file_location, data)?;
 
-        Self::from_file_data(&file_data, header_type)
+        Self::from_file_data_with_mode(&file_data, header_type, mode)
+    }
+
+    /// A file which was read with warnings is read back the same way after it is written.
+    fn load_mode(&self) -> LoadMode {
+        if self.load_warnings.is_empty() {
+            LoadMode::Strict
+        } else {
+            LoadMode::Lenient
+        }
     }
 
     pub fn as_bytes(&self) -> Result<(Vec<u8>, Bl3Profile)> {
@@ -133,7 +174,8 @@ impl Bl3Profile {
 
         //Now try re-reading it also - there's no point making an invalid save
         let file_name = Path::new(&self.file_name);
-        let new_profile = Self::from_bytes(file_name, &output, self.header_type)?;
+        let new_profile =
+            Self::from_bytes_with_mode(file_name, &output, self.header_type, self.load_mode())?;
 
         Ok((output, new_profile))
     }
@@ -264,8 +306,16 @@ impl fmt::Display for Bl3Profile {
 mod tests {
     use std::fs;
 
+    use crate::bl3_profile::game_settings::{
+        GameSettingSection, GameSettingValue, GameSettingsPreset,
+    };
+    use crate::bl3_profile::mail::{MailItemType, NewMailData};
     use crate::bl3_profile::science_levels::BorderlandsScienceLevel;
     use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
+    use crate::bl3_profile::vault_card::{
+        VaultCardChallengeSeeds, VaultCardGearRewardData, VaultCardPreviousChallengeData,
+        VaultCardRewardData,
+    };
 
     use super::*;
 
@@ -442,4 +492,529 @@ mod tests {
         assert_eq!(bl3_profile.profile_data.weapon_skins_unlocked(), 24);
         assert_eq!(bl3_profile.profile_data.weapon_trinkets_unlocked(), 63);
     }
+
+    #[test]
+    fn test_lost_loot_pc() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let bank_item = bl3_profile.profile_data.bank_items()[0].clone();
+        let lost_loot_item = bl3_profile.profile_data.lost_loot_items()[0].clone();
+
+        bl3_profile
+            .profile_data
+            .add_lost_loot_item(&bank_item)
+            .expect("failed to add lost loot item");
+
+        assert_eq!(bl3_profile.profile_data.lost_loot_items().len(), 14);
+        assert_eq!(bl3_profile.profile_data.lost_loot_items()[13], bank_item);
+
+        bl3_profile
+            .profile_data
+            .insert_lost_loot_item(0, &bank_item)
+            .expect("failed to insert lost loot item");
+
+        assert_eq!(bl3_profile.profile_data.lost_loot_items().len(), 15);
+        assert_eq!(bl3_profile.profile_data.lost_loot_items()[0], bank_item);
+        assert_eq!(
+            bl3_profile.profile_data.lost_loot_items()[1],
+            lost_loot_item
+        );
+
+        bl3_profile
+            .profile_data
+            .replace_lost_loot_item(1, &bank_item)
+            .expect("failed to replace lost loot item");
+
+        assert_eq!(bl3_profile.profile_data.lost_loot_items().len(), 15);
+        assert_eq!(bl3_profile.profile_data.lost_loot_items()[1], bank_item);
+
+        bl3_profile.profile_data.remove_lost_loot_item(0);
+        bl3_profile.profile_data.remove_lost_loot_item(13);
+
+        assert_eq!(bl3_profile.profile_data.lost_loot_items().len(), 13);
+
+        let (_, written_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        assert_eq!(
+            written_profile.profile_data.lost_loot_items(),
+            bl3_profile.profile_data.lost_loot_items()
+        );
+        assert_eq!(written_profile.profile_data.lost_loot_items()[0], bank_item);
+    }
+
+    #[test]
+    fn test_unreadable_bank_items_pc() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        // The checksum of these items will no longer match
+        bl3_profile.profile_data.profile.bank_inventory_list[1][10] ^= 0xFF;
+        bl3_profile.profile_data.profile.lost_loot_inventory_list[0][10] ^= 0xFF;
+
+        let bank_serials = bl3_profile.profile_data.profile.bank_inventory_list.clone();
+        let lost_loot_serials = bl3_profile
+            .profile_data
+            .profile
+            .lost_loot_inventory_list
+            .clone();
+
+        let (output, _) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let mut unreadable_profile =
+            Bl3Profile::from_bytes(filename, &output, HeaderType::PcProfile)
+                .expect("failed to read profile with unreadable items");
+
+        assert_eq!(unreadable_profile.load_warnings.len(), 2);
+        assert_eq!(
+            unreadable_profile.profile_data.bank_items().len(),
+            bank_serials.len()
+        );
+        assert_eq!(
+            unreadable_profile.profile_data.lost_loot_items().len(),
+            lost_loot_serials.len()
+        );
+
+        // Editing the item after the unreadable one only changes that item
+        let mut edited_item = unreadable_profile.profile_data.bank_items()[2].clone();
+
+        edited_item.set_level(1).expect("failed to set item level");
+
+        unreadable_profile
+            .profile_data
+            .replace_bank_item(2, &edited_item)
+            .expect("failed to replace bank item");
+
+        let (output, _) = unreadable_profile
+            .as_bytes()
+            .expect("failed to write profile with unreadable items");
+
+        let reloaded_profile = Bl3Profile::from_bytes(filename, &output, HeaderType::PcProfile)
+            .expect("failed to reload profile with unreadable items");
+
+        let reloaded_bank_serials = reloaded_profile
+            .profile_data
+            .profile
+            .bank_inventory_list
+            .to_vec();
+
+        assert_eq!(reloaded_bank_serials.len(), bank_serials.len());
+        assert_eq!(reloaded_bank_serials[1], bank_serials[1]);
+        assert_ne!(reloaded_bank_serials[2], bank_serials[2]);
+        assert_eq!(reloaded_bank_serials[3..], bank_serials.to_vec()[3..]);
+        assert_eq!(
+            reloaded_profile
+                .profile_data
+                .profile
+                .lost_loot_inventory_list,
+            lost_loot_serials
+        );
+    }
+
+    #[test]
+    fn test_mail_pc() {
+        let filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let mail = bl3_profile.profile_data.mail();
+
+        assert_eq!(mail.len(), 25);
+        assert_eq!(mail[0].mail_guid, "990877544D54FB1B292A319B89157874");
+        assert_eq!(mail[0].mail_item_type, MailItemType::Gear);
+        assert_eq!(mail[0].sender_display_name, "Atlas");
+        assert_eq!(mail[0].date_sent, 637287013413530000);
+        assert!(mail[0].has_been_read);
+        assert!(mail.iter().all(|m| m.item.is_some()));
+
+        bl3_profile
+            .profile_data
+            .set_mail_read(&mail[0].mail_guid, false)
+            .expect("failed to mark mail as unread");
+
+        assert!(!bl3_profile.profile_data.mail()[0].has_been_read);
+        assert_eq!(
+            bl3_profile.profile_data.profile.unread_mail_guids.to_vec(),
+            vec![mail[0].mail_guid.clone()]
+        );
+
+        bl3_profile
+            .profile_data
+            .set_mail_read(&mail[0].mail_guid, true)
+            .expect("failed to mark mail as read");
+
+        assert!(bl3_profile
+            .profile_data
+            .profile
+            .unread_mail_guids
+            .is_empty());
+
+        let item = bl3_profile.profile_data.bank_items()[0].clone();
+
+        let new_mail = NewMailData {
+            mail_guid: "0123456789ABCDEF0123456789ABCDEF".to_owned(),
+            sender_display_name: "Marcus".to_owned(),
+            subject: "Delivery".to_owned(),
+            body: "Enjoy".to_owned(),
+            item: item.clone(),
+            date_sent: 637700000000000000,
+        };
+
+        bl3_profile
+            .profile_data
+            .add_mail(&new_mail)
+            .expect("failed to add mail");
+
+        assert!(bl3_profile.profile_data.add_mail(&new_mail).is_err());
+
+        assert_eq!(
+            bl3_profile.profile_data.profile.unread_mail_guids.to_vec(),
+            vec![new_mail.mail_guid.clone()]
+        );
+
+        bl3_profile
+            .profile_data
+            .remove_mail(&mail[1].mail_guid)
+            .expect("failed to remove mail");
+
+        let (_, written_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let written_mail = written_profile.profile_data.mail();
+
+        assert_eq!(written_mail.len(), 25);
+        assert!(written_mail
+            .iter()
+            .all(|m| m.mail_guid != mail[1].mail_guid));
+
+        let sent_mail = &written_mail[24];
+
+        assert_eq!(sent_mail.mail_guid, new_mail.mail_guid);
+        assert_eq!(sent_mail.mail_item_type, MailItemType::Gear);
+        assert_eq!(sent_mail.sender_display_name, "Marcus");
+        assert_eq!(sent_mail.item, Some(item));
+        assert!(!sent_mail.has_been_read);
+    }
+
+    #[test]
+    fn test_vault_card_pc() {
+        let filename = Path::new("./test_files/profile.sav");
+
+        let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let vault_cards = bl3_profile.profile_data.vault_cards();
+
+        assert_eq!(
+            vault_cards
+                .iter()
+                .map(|v| v.vault_card_id)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(vault_cards.iter().all(|v| v.rewards.is_empty()));
+        assert_eq!(bl3_profile.profile_data.last_active_vault_card_id(), 0);
+
+        let mut vault_card = vault_cards[1].clone();
+
+        assert_eq!(vault_card.level(), 1);
+
+        vault_card.experience = 125000;
+
+        assert_eq!(vault_card.level(), 12);
+        vault_card.chests = 4;
+        vault_card.chests_opened = 2;
+        vault_card.keys_spent = 7;
+
+        vault_card.add_reward(3, 1).expect("failed to add reward");
+        vault_card.add_reward(0, 2).expect("failed to add reward");
+        assert!(vault_card.add_reward(3, 1).is_err());
+
+        vault_card.rewards[1].redeemed = true;
+
+        vault_card
+            .add_gear_reward(5)
+            .expect("failed to add gear reward");
+        assert!(vault_card.add_gear_reward(5).is_err());
+
+        vault_card.gear_rewards[0].repurchase_count = 2;
+
+        bl3_profile.profile_data.set_vault_card(&vault_card);
+        bl3_profile.profile_data.set_last_active_vault_card_id(2);
+
+        let previous_challenges = vec![VaultCardPreviousChallengeData {
+            challenge_seed: 1234,
+            challenge_id: 18,
+        }];
+
+        bl3_profile
+            .profile_data
+            .set_vault_card_previous_challenges(&previous_challenges);
+
+        assert_eq!(bl3_profile.profile_data.vault_card_2_chests(), 4);
+
+        let challenge_seeds = VaultCardChallengeSeeds {
+            day_seed: 20221018,
+            week_seed: 202242,
+        };
+
+        bl3_profile
+            .profile_data
+            .set_vault_card_challenge_seeds(&challenge_seeds);
+
+        // Rewards already in the profile are updated in place so unknown fields are kept
+        let reward_list = |bl3_profile: &Bl3Profile| {
+            bl3_profile
+                .profile_data
+                .profile
+                .get_vault_card()
+                .vault_card_claimed_rewards
+                .iter()
+                .find(|v| v.vault_card_id == 2)
+                .cloned()
+                .expect("failed to find vault card")
+        };
+
+        let reward_list_mut = bl3_profile
+            .profile_data
+            .profile
+            .mut_vault_card()
+            .vault_card_claimed_rewards
+            .iter_mut()
+            .find(|v| v.vault_card_id == 2)
+            .expect("failed to find vault card");
+
+        reward_list_mut.redeemed_reward_list[0]
+            .unknown_fields
+            .add_varint(1000, 1);
+        reward_list_mut.gear_rewards[0]
+            .unknown_fields
+            .add_varint(1000, 1);
+
+        vault_card.rewards[0].redeemed = true;
+        vault_card.gear_rewards[0].repurchase_count = 3;
+
+        bl3_profile.profile_data.set_vault_card(&vault_card);
+
+        let updated_reward_list = reward_list(&bl3_profile);
+
+        assert_eq!(updated_reward_list.redeemed_reward_list.len(), 2);
+        assert_eq!(updated_reward_list.redeemed_reward_list[0].column_index, 3);
+        assert!(updated_reward_list.redeemed_reward_list[0]
+            .unknown_fields
+            .get(1000)
+            .is_some());
+        assert_eq!(updated_reward_list.gear_rewards[0].repurchase_count, 3);
+        assert!(updated_reward_list.gear_rewards[0]
+            .unknown_fields
+            .get(1000)
+            .is_some());
+
+        vault_card.rewards[0].redeemed = false;
+        vault_card.gear_rewards[0].repurchase_count = 2;
+
+        bl3_profile.profile_data.set_vault_card(&vault_card);
+
+        let (_, written_profile) = bl3_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        let written_vault_card = written_profile.profile_data.vault_cards()[1].clone();
+
+        assert_eq!(written_vault_card, vault_card);
+        assert_eq!(
+            written_vault_card.rewards,
+            vec![
+                VaultCardRewardData {
+                    column_index: 0,
+                    row_index: 2,
+                    unlocked: true,
+                    redeemed: false,
+                },
+                VaultCardRewardData {
+                    column_index: 3,
+                    row_index: 1,
+                    unlocked: true,
+                    redeemed: true,
+                },
+            ]
+        );
+        assert_eq!(
+            written_vault_card.gear_rewards,
+            vec![VaultCardGearRewardData {
+                gear_index: 5,
+                repurchase_count: 2,
+            }]
+        );
+        assert_eq!(written_profile.profile_data.last_active_vault_card_id(), 2);
+        assert_eq!(
+            written_profile.profile_data.vault_card_challenge_seeds(),
+            challenge_seeds
+        );
+        assert_eq!(written_profile.profile_data.vault_card_2_chests(), 4);
+        assert_eq!(
+            written_profile
+                .profile_data
+                .vault_card_previous_challenges(),
+            previous_challenges
+        );
+    }
+
+    #[test]
+    fn test_game_settings_preset_pc() {
+        let read_profile = |file_name: &str| {
+            let filename = Path::new(file_name);
+
+            let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile")
+        };
+
+        let mut source_profile = read_profile("./test_files/1prof.sav");
+        let mut target_profile = read_profile("./test_files/profile.sav");
+
+        let mouse_scale = source_profile
+            .profile_data
+            .game_settings()
+            .into_iter()
+            .find(|s| s.name == "mouse_scale")
+            .expect("failed to find mouse_scale");
+
+        assert_eq!(mouse_scale.section, GameSettingSection::Input);
+        assert_eq!(mouse_scale.value, GameSettingValue::Float(2.0));
+
+        source_profile
+            .profile_data
+            .set_game_setting(
+                GameSettingSection::Audio,
+                "music_volume",
+                GameSettingValue::Int(35),
+            )
+            .expect("failed to set music volume");
+
+        assert!(source_profile
+            .profile_data
+            .set_game_setting(
+                GameSettingSection::Audio,
+                "push_to_talk",
+                GameSettingValue::Float(1.5),
+            )
+            .is_err());
+        assert!(source_profile
+            .profile_data
+            .set_game_setting(
+                GameSettingSection::Video,
+                "golden_keys",
+                GameSettingValue::Int(1),
+            )
+            .is_err());
+
+        let preset = source_profile.profile_data.game_settings_preset();
+
+        assert_eq!(
+            preset.audio.get("music_volume"),
+            Some(&GameSettingValue::Float(35.0))
+        );
+        assert_eq!(preset.key_bindings.as_ref().map(|k| k.len()), Some(3));
+
+        // Presets are stored as TOML files by the editor
+        let preset_toml = toml::to_string_pretty(&preset).expect("failed to serialize preset");
+        let preset: GameSettingsPreset =
+            toml::from_slice(preset_toml.as_bytes()).expect("failed to deserialize preset");
+
+        let golden_keys = target_profile.profile_data.golden_keys();
+        let bank_items = target_profile.profile_data.bank_items().clone();
+
+        target_profile
+            .profile_data
+            .apply_game_settings_preset(&preset)
+            .expect("failed to apply preset");
+
+        let (_, written_profile) = target_profile
+            .as_bytes()
+            .expect("failed to write test profile");
+
+        assert_eq!(
+            written_profile.profile_data.game_settings(),
+            source_profile.profile_data.game_settings()
+        );
+        assert_eq!(
+            written_profile
+                .profile_data
+                .game_settings_preset()
+                .key_bindings,
+            preset.key_bindings
+        );
+        assert_eq!(written_profile.profile_data.golden_keys(), golden_keys);
+        assert_eq!(written_profile.profile_data.bank_items(), &bank_items);
+
+        let mut invalid_preset = GameSettingsPreset::default();
+
+        invalid_preset
+            .video
+            .insert("base_fov".to_owned(), GameSettingValue::Float(70.0));
+        invalid_preset
+            .video
+            .insert("not_a_setting".to_owned(), GameSettingValue::Int(1));
+
+        let settings = target_profile.profile_data.game_settings();
+
+        assert!(target_profile
+            .profile_data
+            .apply_game_settings_preset(&invalid_preset)
+            .is_err());
+        assert_eq!(target_profile.profile_data.game_settings(), settings);
+    }
+
+    #[test]
+    #[cfg(feature = "with-serde")]
+    fn test_json_round_trip_pc() {
+        let filename = Path::new("./test_files/profile.sav");
+
+        let profile_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_profile =
+            Bl3Profile::from_bytes(filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let json = bl3_profile
+            .to_json()
+            .expect("failed to export profile to json");
+
+        let imported = Bl3Profile::from_json(&json).expect("failed to import profile from json");
+
+        assert_eq!(
+            imported
+                .as_bytes()
+                .expect("failed to write imported profile")
+                .0,
+            bl3_profile
+                .as_bytes()
+                .expect("failed to write test profile")
+                .0
+        );
+    }
 }
@@ -1,30 +1,44 @@
 use std::convert::TryInto;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use derivative::Derivative;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
 use tracing::error;
 
 use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::game_settings;
+use crate::bl3_profile::game_settings::{
+    GameSetting, GameSettingSection, GameSettingValue, GameSettingsPreset,
+};
 use crate::bl3_profile::guardian_reward::{GuardianReward, GuardianRewardData};
+use crate::bl3_profile::mail::{MailData, NewMailData};
 use crate::bl3_profile::profile_currency::ProfileCurrency;
 use crate::bl3_profile::science_levels::{BorderlandsScienceInfo, BorderlandsScienceLevel};
 use crate::bl3_profile::sdu::{ProfileSduSlot, ProfileSduSlotData};
 use crate::bl3_profile::skins::{ProfileSkinType, SkinSet, WeaponSkinSet};
 use crate::bl3_profile::util::get_checksum_hash;
-use crate::game_data::{
-    PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
-    PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
-    PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS,
+use crate::bl3_profile::vault_card::{
+    VaultCardChallengeSeeds, VaultCardData, VaultCardPreviousChallengeData, VAULT_CARD_IDS,
 };
+use crate::bl3_save::crew_quarters::{
+    crew_quarters_decorations, decoration_data_path, CrewQuartersDecorationData,
+};
+use crate::game_data::GameDataKv;
+
+use crate::load_warning::{LoadMode, LoadWarnings};
 use crate::protos::oak_profile::{
     GuardianRankProfileData, GuardianRankRewardSaveGameData, Profile,
 };
 use crate::protos::oak_shared::{
     CrewQuartersDecorationItemSaveGameData, InventoryCategorySaveData,
     OakCustomizationSaveGameData, OakInventoryCustomizationPartInfo, OakSDUSaveGameData,
-    VaultCardRewardList, VaultCardSaveGameData,
+    VaultCardRewardList,
+};
+use crate::resources::{
+    PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS,
+    PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
+    PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS,
 };
 
 #[derive(Derivative)]
@@ -58,6 +72,13 @@ pub struct ProfileData {
 
 impl ProfileData {
     pub fn from_profile(profile: Profile) -> Result<Self> {
+        Self::from_profile_with_warnings(profile, &mut LoadWarnings::new(LoadMode::Strict))
+    }
+
+    pub(crate) fn from_profile_with_warnings(
+        profile: Profile,
+        warnings: &mut LoadWarnings,
+    ) -> Result<Self> {
         let golden_keys = ProfileCurrency::GoldenKey
             .get_profile_currency(&profile.bank_inventory_category_list)
             .unwrap_or(0);
@@ -109,10 +130,16 @@ impl ProfileData {
             })
             .unwrap_or(0);
 
-        let guardian_rank_profile_data = profile
-            .guardian_rank
-            .as_ref()
-            .context("failed to read profile Guardian Rank profile data.")?;
+        let guardian_rank_profile_data = warnings
+            .check(
+                "guardian_rank",
+                profile
+                    .guardian_rank
+                    .as_ref()
+                    .context("failed to read profile Guardian Rank profile data."),
+            )?
+            .cloned()
+            .unwrap_or_default();
 
         let guardian_rank = guardian_rank_profile_data.guardian_rank;
 
@@ -173,17 +200,37 @@ impl ProfileData {
 
         sdu_slots.sort();
 
-        let bank_items = profile
-            .bank_inventory_list
-            .par_iter()
-            .filter_map(|i| Bl3Item::from_serial_bytes(i, None).ok())
-            .collect::<Vec<_>>();
+        // Items which can't be read are always kept as they are, so the editor's indexes match
+        // the file's
+        let mut read_items = |field: &str, serials: &[Vec<u8>]| {
+            let mut items = Vec::new();
+
+            for (i, item) in serials
+                .par_iter()
+                .map(|i| Bl3Item::from_serial_bytes(i, None))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .enumerate()
+            {
+                match item {
+                    Ok(item) => items.push(item),
+                    Err(e) => {
+                        warnings.push(field, format!("item {}: {:#}", i, e));
 
-        let lost_loot_items = profile
-            .lost_loot_inventory_list
-            .par_iter()
-            .filter_map(|i| Bl3Item::from_serial_bytes(i, None).ok())
-            .collect::<Vec<_>>();
+                        items.push(Bl3Item::unreadable(&serials[i], None, &e));
+                    }
+                }
+            }
+
+            items
+        };
+
+        let bank_items = read_items("bank_inventory_list", &profile.bank_inventory_list);
+
+        let lost_loot_items = read_items(
+            "lost_loot_inventory_list",
+            &profile.lost_loot_inventory_list,
+        );
 
         let mut character_skins_unlocked = PROFILE_SKINS_DEFAULTS.len();
         let mut character_heads_unlocked = PROFILE_HEADS_DEFAULTS.len();
@@ -348,48 +395,13 @@ impl ProfileData {
     }
 
     pub fn set_vault_card_chests(&mut self, vault_card_id: u32, vault_card_chests: i32) {
-        let vault_card_reward_list = VaultCardRewardList {
-            vault_card_id,
-            vault_card_experience: 0,
-            unlocked_reward_list: Default::default(),
-            redeemed_reward_list: Default::default(),
-            vault_card_chests,
-            vault_card_chests_opened: 0,
-            vault_card_keys_spent: 0,
-            gear_rewards: Default::default(),
-            unknown_fields: Default::default(),
-            cached_size: Default::default(),
-        };
-
-        if let Some(vault_card) = self.profile.vault_card.as_mut() {
-            if vault_card.last_active_vault_card_id == 0 {
-                vault_card.last_active_vault_card_id = vault_card_id;
-            }
+        self.vault_card_reward_list_mut(vault_card_id)
+            .vault_card_chests = vault_card_chests;
 
-            if let Some(claimed_rewards) = vault_card
-                .vault_card_claimed_rewards
-                .iter_mut()
-                .find(|v| v.vault_card_id == vault_card_id)
-            {
-                claimed_rewards.vault_card_chests = vault_card_chests;
-            } else {
-                vault_card
-                    .vault_card_claimed_rewards
-                    .push(vault_card_reward_list);
-            }
-        } else {
-            self.profile.vault_card = Some(VaultCardSaveGameData {
-                last_active_vault_card_id: vault_card_id,
-                current_day_seed: 0,
-                current_week_seed: 0,
-                vault_card_previous_challenges: Default::default(),
-                vault_card_claimed_rewards: vec![vault_card_reward_list].into(),
-                unknown_fields: Default::default(),
-                cached_size: Default::default(),
-            })
-            .into();
-        }
+        self.set_vault_card_chests_cache(vault_card_id, vault_card_chests);
+    }
 
+    fn set_vault_card_chests_cache(&mut self, vault_card_id: u32, vault_card_chests: i32) {
         match vault_card_id {
             1 => self.vault_card_1_chests = vault_card_chests,
             2 => self.vault_card_2_chests = vault_card_chests,
@@ -398,6 +410,119 @@ impl ProfileData {
         }
     }
 
+    fn vault_card_reward_list_mut(&mut self, vault_card_id: u32) -> &mut VaultCardRewardList {
+        let vault_card = self.profile.mut_vault_card();
+
+        if vault_card.last_active_vault_card_id == 0 {
+            vault_card.last_active_vault_card_id = vault_card_id;
+        }
+
+        let index = match vault_card
+            .vault_card_claimed_rewards
+            .iter()
+            .position(|v| v.vault_card_id == vault_card_id)
+        {
+            Some(index) => index,
+            None => {
+                vault_card
+                    .vault_card_claimed_rewards
+                    .push(VaultCardRewardList {
+                        vault_card_id,
+                        ..Default::default()
+                    });
+
+                vault_card.vault_card_claimed_rewards.len() - 1
+            }
+        };
+
+        &mut vault_card.vault_card_claimed_rewards[index]
+    }
+
+    /// Every released vault card plus any other card found in the profile, cards that have not been
+    /// started yet are empty.
+    pub fn vault_cards(&self) -> Vec<VaultCardData> {
+        let claimed_rewards = self
+            .profile
+            .vault_card
+            .as_ref()
+            .map(|vc| vc.vault_card_claimed_rewards.as_slice())
+            .unwrap_or_default();
+
+        let mut vault_cards = claimed_rewards
+            .iter()
+            .map(VaultCardData::from_reward_list)
+            .collect::<Vec<_>>();
+
+        for vault_card_id in VAULT_CARD_IDS {
+            if !vault_cards.iter().any(|v| v.vault_card_id == vault_card_id) {
+                vault_cards.push(VaultCardData::new(vault_card_id));
+            }
+        }
+
+        vault_cards.sort_by_key(|v| v.vault_card_id);
+
+        vault_cards
+    }
+
+    pub fn set_vault_card(&mut self, vault_card: &VaultCardData) {
+        vault_card.apply_to_reward_list(self.vault_card_reward_list_mut(vault_card.vault_card_id));
+
+        self.set_vault_card_chests_cache(vault_card.vault_card_id, vault_card.chests);
+    }
+
+    pub fn last_active_vault_card_id(&self) -> u32 {
+        self.profile
+            .vault_card
+            .as_ref()
+            .map(|vc| vc.last_active_vault_card_id)
+            .unwrap_or_default()
+    }
+
+    pub fn set_last_active_vault_card_id(&mut self, vault_card_id: u32) {
+        self.profile.mut_vault_card().last_active_vault_card_id = vault_card_id;
+    }
+
+    pub fn vault_card_previous_challenges(&self) -> Vec<VaultCardPreviousChallengeData> {
+        self.profile
+            .vault_card
+            .as_ref()
+            .map(|vc| {
+                vc.vault_card_previous_challenges
+                    .iter()
+                    .map(VaultCardPreviousChallengeData::from_previous_challenge)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn set_vault_card_previous_challenges(
+        &mut self,
+        previous_challenges: &[VaultCardPreviousChallengeData],
+    ) {
+        self.profile.mut_vault_card().vault_card_previous_challenges = previous_challenges
+            .iter()
+            .map(VaultCardPreviousChallengeData::to_previous_challenge)
+            .collect();
+    }
+
+    pub fn vault_card_challenge_seeds(&self) -> VaultCardChallengeSeeds {
+        self.profile
+            .vault_card
+            .as_ref()
+            .map(|vc| VaultCardChallengeSeeds {
+                day_seed: vc.current_day_seed,
+                week_seed: vc.current_week_seed,
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn set_vault_card_challenge_seeds(&mut self, challenge_seeds: &VaultCardChallengeSeeds) {
+        let vault_card = self.profile.mut_vault_card();
+
+        vault_card.current_day_seed = challenge_seeds.day_seed;
+        vault_card.current_week_seed = challenge_seeds.week_seed;
+    }
+
     pub fn guardian_rank(&self) -> i32 {
         self.guardian_rank
     }
@@ -550,6 +675,7 @@ impl ProfileData {
         }
     }
 
+    /// Only the item serial is stored, see `Bl3Item::has_cosmetics`.
     pub fn add_bank_item(&mut self, item: &Bl3Item) -> Result<()> {
         let item_serial_number = item.get_serial_number(true)?;
 
@@ -585,6 +711,151 @@ impl ProfileData {
         &self.lost_loot_items
     }
 
+    pub fn lost_loot_items_mut(&mut self) -> &mut Vec<Bl3Item> {
+        &mut self.lost_loot_items
+    }
+
+    pub fn remove_lost_loot_item(&mut self, index: usize) {
+        if index < self.profile.lost_loot_inventory_list.len() {
+            self.profile.lost_loot_inventory_list.remove(index);
+        }
+
+        if index < self.lost_loot_items.len() {
+            self.lost_loot_items.remove(index);
+        }
+    }
+
+    /// Only the item serial is stored, see `Bl3Item::has_cosmetics`.
+    pub fn add_lost_loot_item(&mut self, item: &Bl3Item) -> Result<()> {
+        let item_serial_number = item.get_serial_number(true)?;
+
+        self.profile
+            .lost_loot_inventory_list
+            .push(item_serial_number);
+
+        self.lost_loot_items.push(item.to_owned());
+
+        Ok(())
+    }
+
+    pub fn insert_lost_loot_item(&mut self, item_index: usize, item: &Bl3Item) -> Result<()> {
+        let item_serial_number = item.get_serial_number(true)?;
+
+        self.profile
+            .lost_loot_inventory_list
+            .insert(item_index, item_serial_number);
+
+        self.lost_loot_items.insert(item_index, item.to_owned());
+
+        Ok(())
+    }
+
+    pub fn replace_lost_loot_item(&mut self, item_index: usize, new_item: &Bl3Item) -> Result<()> {
+        self.insert_lost_loot_item(item_index, new_item)?;
+
+        // Remove old item
+        self.remove_lost_loot_item(item_index + 1);
+
+        Ok(())
+    }
+
+    pub fn game_settings(&self) -> Vec<GameSetting> {
+        game_settings::game_settings(&self.profile)
+    }
+
+    pub fn set_game_setting(
+        &mut self,
+        section: GameSettingSection,
+        name: &str,
+        value: GameSettingValue,
+    ) -> Result<()> {
+        game_settings::set_game_setting(&mut self.profile, section, name, value)
+    }
+
+    pub fn game_settings_preset(&self) -> GameSettingsPreset {
+        GameSettingsPreset::from_profile(&self.profile)
+    }
+
+    pub fn apply_game_settings_preset(&mut self, preset: &GameSettingsPreset) -> Result<()> {
+        preset.apply_to_profile(&mut self.profile)
+    }
+
+    pub fn mail(&self) -> Vec<MailData> {
+        self.profile
+            .npc_mail_items
+            .iter()
+            .map(MailData::from_mail_item)
+            .collect()
+    }
+
+    pub fn add_mail(&mut self, new_mail: &NewMailData) -> Result<()> {
+        if self
+            .profile
+            .npc_mail_items
+            .iter()
+            .any(|m| m.mail_guid == new_mail.mail_guid)
+        {
+            bail!("mail already exists: {}", new_mail.mail_guid);
+        }
+
+        let mail_item = new_mail.to_mail_item()?;
+
+        self.profile.npc_mail_items.push(mail_item);
+
+        // New mail is unread, keep the unread list in sync with has_been_read
+        if !self
+            .profile
+            .unread_mail_guids
+            .iter()
+            .any(|g| *g == new_mail.mail_guid)
+        {
+            self.profile
+                .unread_mail_guids
+                .push(new_mail.mail_guid.clone());
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_mail(&mut self, mail_guid: &str) -> Result<()> {
+        let index = self
+            .profile
+            .npc_mail_items
+            .iter()
+            .position(|m| m.mail_guid == mail_guid)
+            .with_context(|| format!("failed to find mail: {}", mail_guid))?;
+
+        self.profile.npc_mail_items.remove(index);
+
+        self.profile.unread_mail_guids.retain(|g| g != mail_guid);
+
+        Ok(())
+    }
+
+    pub fn set_mail_read(&mut self, mail_guid: &str, has_been_read: bool) -> Result<()> {
+        let mail_item = self
+            .profile
+            .npc_mail_items
+            .iter_mut()
+            .find(|m| m.mail_guid == mail_guid)
+            .with_context(|| format!("failed to find mail: {}", mail_guid))?;
+
+        mail_item.has_been_read = has_been_read;
+
+        if has_been_read {
+            self.profile.unread_mail_guids.retain(|g| g != mail_guid);
+        } else if !self
+            .profile
+            .unread_mail_guids
+            .iter()
+            .any(|g| g == mail_guid)
+        {
+            self.profile.unread_mail_guids.push(mail_guid.to_owned());
+        }
+
+        Ok(())
+    }
+
     pub fn character_skins_unlocked(&self) -> usize {
         self.character_skins_unlocked
     }
@@ -613,6 +884,68 @@ impl ProfileData {
         self.weapon_trinkets_unlocked
     }
 
+    /// The emotes which can be equipped on the emote wheel of this profile's saves.
+    pub fn unlocked_emotes(&self) -> Vec<GameDataKv> {
+        PROFILE_EMOTES_DEFAULTS
+            .iter()
+            .chain(PROFILE_EMOTES.iter().filter(|gd| {
+                self.profile
+                    .unlocked_customizations
+                    .iter()
+                    .any(|uc| uc.customization_asset_path == gd.ident)
+            }))
+            .copied()
+            .collect()
+    }
+
+    /// The weapon skins which can be applied to items in this profile's saves.
+    pub fn unlocked_weapon_skins(&self) -> Vec<GameDataKv> {
+        self.unlocked_inventory_customizations(&PROFILE_WEAPON_SKINS)
+    }
+
+    /// The weapon trinkets which can be attached to equipped weapons in this profile's saves.
+    pub fn unlocked_weapon_trinkets(&self) -> Vec<GameDataKv> {
+        self.unlocked_inventory_customizations(&PROFILE_WEAPON_TRINKETS)
+    }
+
+    /// The decorations which can be placed in the crew quarters of this profile's saves.
+    pub fn unlocked_crew_quarters_decorations(&self) -> Vec<CrewQuartersDecorationData> {
+        crew_quarters_decorations()
+            .into_iter()
+            .filter(|d| {
+                self.profile
+                    .unlocked_crew_quarters_decorations
+                    .iter()
+                    .filter_map(|u| decoration_data_path(&u.decoration_item_asset_path))
+                    .any(|p| p.eq_ignore_ascii_case(&d.decoration_data_path))
+            })
+            .collect()
+    }
+
+    /// The crew quarters rooms unlocked in the profile, characters can also use their own room.
+    pub fn unlocked_crew_quarters_rooms(&self) -> Vec<String> {
+        self.profile
+            .unlocked_crew_quarters_rooms
+            .iter()
+            .map(|r| r.room_item_asset_path.clone())
+            .collect()
+    }
+
+    fn unlocked_inventory_customizations(&self, customizations: &[GameDataKv]) -> Vec<GameDataKv> {
+        customizations
+            .iter()
+            .filter(|gd| {
+                let hash = get_checksum_hash(gd.ident).unwrap_or(0);
+
+                self.profile
+                    .unlocked_inventory_customization_parts
+                    .iter()
+                    .any(|uic| uic.customization_part_hash as usize == hash)
+            })
+            .copied()
+            .collect()
+    }
+
     pub fn unlock_skin_set(&mut self, skin_type: &ProfileSkinType) {
         let mut skins = skin_type.skin_set();
 
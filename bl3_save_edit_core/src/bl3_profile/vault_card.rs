@@ -0,0 +1,256 @@
+use anyhow::{bail, Result};
+use protobuf::RepeatedField;
+
+use crate::protos::oak_shared::{
+    VaultCardGearReward, VaultCardPreviousChallenge, VaultCardReward, VaultCardRewardList,
+};
+
+/// The vault cards released for the game, other ids found in a profile are kept as they are.
+pub const VAULT_CARD_IDS: [u32; 3] = [1, 2, 3];
+
+pub const VAULT_CARD_MAX_LEVEL: i32 = 100;
+
+/// Every vault card level needs the same amount of experience. None of the test profiles have
+/// vault card progress so this has not been checked against a profile.
+pub const VAULT_CARD_EXPERIENCE_PER_LEVEL: i64 = 11_000;
+
+/// A cosmetic reward on the vault card grid, unlocked rewards are claimable and redeemed rewards have been claimed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct VaultCardRewardData {
+    pub column_index: i32,
+    pub row_index: i32,
+    pub unlocked: bool,
+    pub redeemed: bool,
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct VaultCardGearRewardData {
+    pub gear_index: i32,
+    pub repurchase_count: u32,
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct VaultCardPreviousChallengeData {
+    pub challenge_seed: i32,
+    pub challenge_id: u32,
+}
+
+impl VaultCardPreviousChallengeData {
+    pub fn from_previous_challenge(previous_challenge: &VaultCardPreviousChallenge) -> Self {
+        Self {
+            challenge_seed: previous_challenge.previous_challenge_seed,
+            challenge_id: previous_challenge.previous_challenge_id,
+        }
+    }
+
+    pub fn to_previous_challenge(&self) -> VaultCardPreviousChallenge {
+        VaultCardPreviousChallenge {
+            previous_challenge_seed: self.challenge_seed,
+            previous_challenge_id: self.challenge_id,
+            ..Default::default()
+        }
+    }
+}
+
+/// The seeds of the daily and weekly challenges currently offered.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct VaultCardChallengeSeeds {
+    pub day_seed: i32,
+    pub week_seed: i32,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct VaultCardData {
+    pub vault_card_id: u32,
+    pub experience: i64,
+    pub chests: i32,
+    pub chests_opened: u32,
+    pub keys_spent: u32,
+    pub rewards: Vec<VaultCardRewardData>,
+    pub gear_rewards: Vec<VaultCardGearRewardData>,
+}
+
+impl VaultCardData {
+    pub fn new(vault_card_id: u32) -> Self {
+        Self {
+            vault_card_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_reward_list(reward_list: &VaultCardRewardList) -> Self {
+        let mut vault_card = Self::new(reward_list.vault_card_id);
+
+        // A reward is stored in both lists once it has been redeemed, merge them by position
+        for (reward, redeemed) in reward_list
+            .unlocked_reward_list
+            .iter()
+            .map(|r| (r, false))
+            .chain(reward_list.redeemed_reward_list.iter().map(|r| (r, true)))
+        {
+            let existing = vault_card
+                .rewards
+                .iter_mut()
+                .find(|r| r.column_index == reward.column_index && r.row_index == reward.row_index);
+
+            match existing {
+                Some(existing) if redeemed => existing.redeemed = true,
+                Some(existing) => existing.unlocked = true,
+                None => vault_card.rewards.push(VaultCardRewardData {
+                    column_index: reward.column_index,
+                    row_index: reward.row_index,
+                    unlocked: !redeemed,
+                    redeemed,
+                }),
+            }
+        }
+
+        vault_card.rewards.sort();
+
+        vault_card.experience = reward_list.vault_card_experience;
+        vault_card.chests = reward_list.vault_card_chests;
+        vault_card.chests_opened = reward_list.vault_card_chests_opened;
+        vault_card.keys_spent = reward_list.vault_card_keys_spent;
+        vault_card.gear_rewards = reward_list
+            .gear_rewards
+            .iter()
+            .map(|g| VaultCardGearRewardData {
+                gear_index: g.gear_index,
+                repurchase_count: g.repurchase_count,
+            })
+            .collect();
+
+        vault_card
+    }
+
+    /// An estimate of the level reached with this card's experience, starting at level 1.
+    /// The experience curve has not been checked against a profile so the level is only shown and
+    /// the experience is what gets edited.
+    pub fn level(&self) -> i32 {
+        let level = self.experience.max(0) / VAULT_CARD_EXPERIENCE_PER_LEVEL + 1;
+
+        level.min(VAULT_CARD_MAX_LEVEL as i64) as i32
+    }
+
+    /// Writes this vault card over `reward_list`, rewards that are neither unlocked nor redeemed are dropped.
+    /// Rewards already in the list are kept as they are so fields the editor doesn't know about are
+    /// not lost.
+    pub fn apply_to_reward_list(&self, reward_list: &mut VaultCardRewardList) {
+        reward_list.vault_card_id = self.vault_card_id;
+        reward_list.vault_card_experience = self.experience;
+        reward_list.vault_card_chests = self.chests;
+        reward_list.vault_card_chests_opened = self.chests_opened;
+        reward_list.vault_card_keys_spent = self.keys_spent;
+
+        let unlocked = self
+            .rewards
+            .iter()
+            .filter(|r| r.unlocked)
+            .collect::<Vec<_>>();
+
+        let redeemed = self
+            .rewards
+            .iter()
+            .filter(|r| r.redeemed)
+            .collect::<Vec<_>>();
+
+        Self::apply_rewards(&mut reward_list.unlocked_reward_list, &unlocked);
+        Self::apply_rewards(&mut reward_list.redeemed_reward_list, &redeemed);
+
+        let gear_rewards = &mut reward_list.gear_rewards;
+
+        gear_rewards.retain(|g| {
+            self.gear_rewards
+                .iter()
+                .any(|n| n.gear_index == g.gear_index)
+        });
+
+        for new_gear_reward in &self.gear_rewards {
+            match gear_rewards
+                .iter_mut()
+                .find(|g| g.gear_index == new_gear_reward.gear_index)
+            {
+                Some(gear_reward) => {
+                    gear_reward.repurchase_count = new_gear_reward.repurchase_count
+                }
+                None => gear_rewards.push(VaultCardGearReward {
+                    gear_index: new_gear_reward.gear_index,
+                    repurchase_count: new_gear_reward.repurchase_count,
+                    ..Default::default()
+                }),
+            }
+        }
+    }
+
+    fn apply_rewards(
+        rewards: &mut RepeatedField<VaultCardReward>,
+        new_rewards: &[&VaultCardRewardData],
+    ) {
+        let matches = |r: &VaultCardReward, n: &VaultCardRewardData| {
+            r.column_index == n.column_index && r.row_index == n.row_index
+        };
+
+        rewards.retain(|r| new_rewards.iter().any(|n| matches(r, n)));
+
+        for new_reward in new_rewards {
+            if !rewards.iter().any(|r| matches(r, new_reward)) {
+                rewards.push(VaultCardReward {
+                    column_index: new_reward.column_index,
+                    row_index: new_reward.row_index,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    pub fn add_reward(&mut self, column_index: i32, row_index: i32) -> Result<()> {
+        if column_index < 0 || row_index < 0 {
+            bail!("reward column and row must not be negative");
+        }
+
+        if self
+            .rewards
+            .iter()
+            .any(|r| r.column_index == column_index && r.row_index == row_index)
+        {
+            bail!(
+                "vault card {} already has a reward at column {}, row {}",
+                self.vault_card_id,
+                column_index,
+                row_index
+            );
+        }
+
+        self.rewards.push(VaultCardRewardData {
+            column_index,
+            row_index,
+            unlocked: true,
+            redeemed: false,
+        });
+
+        self.rewards.sort();
+
+        Ok(())
+    }
+
+    pub fn add_gear_reward(&mut self, gear_index: i32) -> Result<()> {
+        if gear_index < 0 {
+            bail!("gear reward index must not be negative");
+        }
+
+        if self.gear_rewards.iter().any(|g| g.gear_index == gear_index) {
+            bail!(
+                "vault card {} already has gear reward {}",
+                self.vault_card_id,
+                gear_index
+            );
+        }
+
+        self.gear_rewards.push(VaultCardGearRewardData {
+            gear_index,
+            repurchase_count: 0,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1627 @@
+use std::fmt::Formatter;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bl3_save::player_class::PlayerClass;
+use crate::protos::oak_save::OakPlayerAbilitySaveGameData;
+
+/// The skill tree is unlocked at level 2 and a skill point is awarded for every level after it,
+/// so a character has `level - 2` points.
+pub const SKILL_TREE_UNLOCK_LEVEL: i32 = 2;
+
+/// Every tier of a tree needs this many more points spent in the tiers above it than the
+/// previous tier, so the capstone needs 25.
+pub const POINTS_PER_TIER: i32 = 5;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SkillTreeInfo {
+    pub player_class: PlayerClass,
+    pub tree_identifier: i32,
+    pub name: &'static str,
+}
+
+/// Trees are identified by the `tree_identifier` stored on every tree item, the asset paths
+/// differ too much between classes to be useful.
+/// The Beastmaster, Operative and Siren names were matched to the tree assets in the test saves
+/// (ie: tree 0 of a Beastmaster is `AbilityTree_Branch_Bond`), there is no Gunner test save.
+/// Tree 3 is the tree added by the Designer's Cut DLC.
+pub const SKILL_TREES: [SkillTreeInfo; 16] = [
+    SkillTreeInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        name: "Master",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        name: "Hunter",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        name: "Stalker",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        name: "Trapper",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Gunner,
+        tree_identifier: 0,
+        name: "Shield of Retribution",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Gunner,
+        tree_identifier: 1,
+        name: "Demolition Woman",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Gunner,
+        tree_identifier: 2,
+        name: "Bottomless Mags",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Gunner,
+        tree_identifier: 3,
+        name: "Bear Trooper",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        name: "Hitman",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        name: "Doubled Agent",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        name: "Under Cover",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 3,
+        name: "Seein' Dead",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        name: "Mystical Assault",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        name: "Fist of the Elements",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        name: "Brawl",
+    },
+    SkillTreeInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        name: "Ties That Bind",
+    },
+];
+
+/// A skill which can have points spent in it, `item` is the part of the tree item asset path after
+/// the tree asset, ie: `Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1`.
+/// `tier` counts from the action skill tier at 0, so the first tier skill points go into is 1.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SkillInfo {
+    pub player_class: PlayerClass,
+    pub tree_identifier: i32,
+    pub tier: i32,
+    pub item: &'static str,
+    pub max_points: i32,
+}
+
+/// Every skill of a tree in its in-game tier order, taken from the tree items of the test saves
+/// which are the same in every save of a class.
+/// The saves only hold asset names so the in-game skill names are not known. There is no Gunner
+/// test save and none of the Operative saves have the Designer's Cut tree, those trees have no
+/// skills listed and are not checked against the catalogue.
+pub const SKILLS: [SkillInfo; 143] = [
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_ActionAbilityAugment",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 0,
+        tier: 6,
+        item: "Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 1,
+        tier: 6,
+        item: "Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_ActionAbilityAugment",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 2,
+        tier: 6,
+        item: "Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_3",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_3",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_3",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::BeastMaster,
+        tree_identifier: 3,
+        tier: 6,
+        item: "Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_ActionAbilityAugment",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 0,
+        tier: 6,
+        item: "Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_ActionAbilityAugment",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_ActionAbilityAugment",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 1,
+        tier: 6,
+        item: "OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_ActionAbilityAugment",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 1,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 1,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 1,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_7.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_9.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_9.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_9.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Operative,
+        tree_identifier: 2,
+        tier: 6,
+        item: "Tiers_OakPlayerAbilityTreeTierData_11.Items_OakPlayerAbilityTreeItemData_ActionAbilityAugment",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 5,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 0,
+        tier: 6,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 1,
+        item: "OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 1,
+        item: "OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 1,
+        item: "OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 2,
+        item: "OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 2,
+        item: "OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 2,
+        item: "OakPlayerAbilityTreeTierData_2.OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 3,
+        item: "OakPlayerAbilityTreeTierData_5.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_6.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_6.OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_6.Items_OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_8.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 1,
+        tier: 6,
+        item: "OakPlayerAbilityTreeTierData_7.OakPlayerAbilityTreeItemData_Ability_1",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 2,
+        item: "Tiers_OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 3,
+        item: "Tiers_OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 4,
+        item: "Tiers_OakPlayerAbilityTreeTierData_3.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_0.Items_OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 2,
+        tier: 6,
+        item: "OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 1,
+        item: "Tiers_OakPlayerAbilityTreeTierData.OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 2,
+        item: "OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 2,
+        item: "OakPlayerAbilityTreeTierData_1.OakPlayerAbilityTreeItemData_Ability_5",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 2,
+        item: "OakPlayerAbilityTreeTierData_1.Items_OakPlayerAbilityTreeItemData_Ability",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 3,
+        item: "OakPlayerAbilityTreeTierData_2.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 4,
+        item: "OakPlayerAbilityTreeTierData_3.OakPlayerAbilityTreeItemData_Ability_3",
+        max_points: 5,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_4.Items_OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 5,
+        item: "OakPlayerAbilityTreeTierData_4.OakPlayerAbilityTreeItemData_Ability_2",
+        max_points: 3,
+    },
+    SkillInfo {
+        player_class: PlayerClass::Siren,
+        tree_identifier: 3,
+        tier: 6,
+        item: "OakPlayerAbilityTreeTierData_5.OakPlayerAbilityTreeItemData_Ability_0",
+        max_points: 1,
+    },
+];
+
+pub fn skill_trees_for_class(player_class: PlayerClass) -> Vec<SkillTreeInfo> {
+    SKILL_TREES
+        .iter()
+        .filter(|t| t.player_class == player_class)
+        .copied()
+        .collect()
+}
+
+pub fn skill_tree_info(player_class: PlayerClass, tree_identifier: i32) -> Option<SkillTreeInfo> {
+    SKILL_TREES
+        .iter()
+        .find(|t| t.player_class == player_class && t.tree_identifier == tree_identifier)
+        .copied()
+}
+
+pub fn skills_for_tree(player_class: PlayerClass, tree_identifier: i32) -> Vec<SkillInfo> {
+    SKILLS
+        .iter()
+        .filter(|s| s.player_class == player_class && s.tree_identifier == tree_identifier)
+        .copied()
+        .collect()
+}
+
+pub fn skill_info(
+    player_class: PlayerClass,
+    tree_identifier: i32,
+    item_asset_path: &str,
+) -> Option<SkillInfo> {
+    let item = item_asset_path
+        .split_once(':')
+        .map(|(_, item)| item)
+        .unwrap_or(item_asset_path);
+
+    SKILLS
+        .iter()
+        .find(|s| {
+            s.player_class == player_class && s.tree_identifier == tree_identifier && s.item == item
+        })
+        .copied()
+}
+
+pub fn available_skill_points(player_level: i32) -> i32 {
+    (player_level - SKILL_TREE_UNLOCK_LEVEL).max(0)
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AbilityTreeItem {
+    pub item_asset_path: String,
+    pub tree_identifier: i32,
+    pub tier: String,
+    pub points: i32,
+    pub max_points: i32,
+}
+
+impl AbilityTreeItem {
+    /// The item name inside of the tree asset, ie: `Items_OakPlayerAbilityTreeItemData_Ability_1`
+    pub fn name(&self) -> &str {
+        self.item_asset_path
+            .rsplit_once('.')
+            .map(|(_, name)| name)
+            .unwrap_or(&self.item_asset_path)
+    }
+
+    pub fn is_action_skill_augment(&self) -> bool {
+        self.name().contains("ActionAbilityAugment")
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AbilityTreeTier {
+    pub name: String,
+    pub items: Vec<AbilityTreeItem>,
+    /// Points which have to be spent in the tiers above before this tier can be used.
+    pub points_required: i32,
+}
+
+impl AbilityTreeTier {
+    pub fn points(&self) -> i32 {
+        self.items.iter().map(|i| i.points).sum()
+    }
+
+    pub fn max_points(&self) -> i32 {
+        self.items.iter().map(|i| i.max_points).sum()
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AbilitySlot {
+    pub ability_class_path: String,
+    pub slot_asset_path: String,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AugmentSlot {
+    pub action_ability_class_path: String,
+    pub slot_asset_path: String,
+    pub augment_asset_path: String,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AugmentConfiguration {
+    pub ability_class_path: String,
+    pub augment_asset_path: String,
+    pub mod_slot_asset_path: String,
+    pub mod_asset_path: String,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AbilityTree {
+    pub tree_grade: i32,
+    pub items: Vec<AbilityTreeItem>,
+    pub ability_slots: Vec<AbilitySlot>,
+    pub augment_slots: Vec<AugmentSlot>,
+    pub augment_configurations: Vec<AugmentConfiguration>,
+}
+
+impl AbilityTree {
+    pub fn from_ability_data(ability_data: &OakPlayerAbilitySaveGameData) -> Self {
+        let items = ability_data
+            .tree_item_list
+            .iter()
+            .map(|ti| AbilityTreeItem {
+                item_asset_path: ti.item_asset_path.clone(),
+                tree_identifier: ti.tree_identifier,
+                tier: tier_name(&ti.item_asset_path).to_owned(),
+                points: ti.points,
+                max_points: ti.max_points,
+            })
+            .collect();
+
+        let ability_slots = ability_data
+            .ability_slot_list
+            .iter()
+            .map(|s| AbilitySlot {
+                ability_class_path: s.ability_class_path.clone(),
+                slot_asset_path: s.slot_asset_path.clone(),
+            })
+            .collect();
+
+        let augment_slots = ability_data
+            .augment_slot_list
+            .iter()
+            .map(|s| AugmentSlot {
+                action_ability_class_path: s.action_ability_class_path.clone(),
+                slot_asset_path: s.slot_asset_path.clone(),
+                augment_asset_path: s.augment_asset_path.clone(),
+            })
+            .collect();
+
+        let augment_configurations = ability_data
+            .augment_configuration_list
+            .iter()
+            .map(|c| AugmentConfiguration {
+                ability_class_path: c.ability_class_path.clone(),
+                augment_asset_path: c.augment_asset_path.clone(),
+                mod_slot_asset_path: c.mod_slot_asset_path.clone(),
+                mod_asset_path: c.mod_asset_path.clone(),
+            })
+            .collect();
+
+        Self {
+            tree_grade: ability_data.tree_grade,
+            items,
+            ability_slots,
+            augment_slots,
+            augment_configurations,
+        }
+    }
+
+    pub fn tree_identifiers(&self) -> Vec<i32> {
+        let mut tree_identifiers = self
+            .items
+            .iter()
+            .map(|i| i.tree_identifier)
+            .collect::<Vec<_>>();
+
+        tree_identifiers.sort_unstable();
+        tree_identifiers.dedup();
+
+        tree_identifiers
+    }
+
+    /// Tiers of a tree in their in-game order.
+    /// The tier objects are not named in order (`Tiers_OakPlayerAbilityTreeTierData_5` can be
+    /// the third tier), but the game writes every item of a tree to the save tier by tier, which
+    /// is the same order in every test save of a class.
+    /// The tier holding the action skill augments has no points and does not need any.
+    pub fn tiers(&self, tree_identifier: i32) -> Vec<AbilityTreeTier> {
+        let mut tiers: Vec<AbilityTreeTier> = Vec::new();
+
+        for item in self
+            .items
+            .iter()
+            .filter(|i| i.tree_identifier == tree_identifier)
+        {
+            if let Some(tier) = tiers.iter_mut().find(|t| t.name == item.tier) {
+                tier.items.push(item.clone());
+            } else {
+                tiers.push(AbilityTreeTier {
+                    name: item.tier.clone(),
+                    items: vec![item.clone()],
+                    points_required: 0,
+                });
+            }
+        }
+
+        let mut points_required = 0;
+
+        for tier in tiers.iter_mut() {
+            tier.points_required = points_required;
+
+            if tier.max_points() > 0 {
+                points_required += POINTS_PER_TIER;
+            }
+        }
+
+        tiers
+    }
+
+    /// Tiers which have points spent in them without enough points spent in the tiers above.
+    pub fn locked_tiers(&self) -> Vec<AbilityTreeIssue> {
+        let mut issues = Vec::new();
+
+        for tree_identifier in self.tree_identifiers() {
+            let mut points_spent = 0;
+
+            for tier in self.tiers(tree_identifier) {
+                if tier.points() > 0 && points_spent < tier.points_required {
+                    issues.push(AbilityTreeIssue::TierLocked {
+                        tree_identifier,
+                        points_required: tier.points_required,
+                        points_spent,
+                    });
+                }
+
+                points_spent += tier.points();
+            }
+        }
+
+        issues
+    }
+
+    pub fn points_spent(&self) -> i32 {
+        self.items.iter().map(|i| i.points).sum()
+    }
+
+    pub fn points_spent_in_tree(&self, tree_identifier: i32) -> i32 {
+        self.items
+            .iter()
+            .filter(|i| i.tree_identifier == tree_identifier)
+            .map(|i| i.points)
+            .sum()
+    }
+
+    pub fn max_points_in_tree(&self, tree_identifier: i32) -> i32 {
+        self.items
+            .iter()
+            .filter(|i| i.tree_identifier == tree_identifier)
+            .map(|i| i.max_points)
+            .sum()
+    }
+
+    pub fn validate(
+        &self,
+        player_class: PlayerClass,
+        player_level: i32,
+        ability_points: i32,
+    ) -> Vec<AbilityTreeIssue> {
+        let mut issues = Vec::new();
+
+        for tree_identifier in self.tree_identifiers() {
+            if skill_tree_info(player_class, tree_identifier).is_none() {
+                issues.push(AbilityTreeIssue::UnknownTree { tree_identifier });
+            }
+        }
+
+        for item in &self.items {
+            let catalogued = !skills_for_tree(player_class, item.tree_identifier).is_empty();
+
+            if catalogued && item.max_points > 0 {
+                match skill_info(player_class, item.tree_identifier, &item.item_asset_path) {
+                    Some(skill) if skill.max_points != item.max_points => {
+                        issues.push(AbilityTreeIssue::SkillMaxPointsChanged {
+                            item_asset_path: item.item_asset_path.clone(),
+                            max_points: item.max_points,
+                            expected_max_points: skill.max_points,
+                        });
+                    }
+                    None => issues.push(AbilityTreeIssue::UnknownSkill {
+                        item_asset_path: item.item_asset_path.clone(),
+                    }),
+                    _ => (),
+                }
+            }
+
+            if item.points < 0 || item.points > item.max_points {
+                issues.push(AbilityTreeIssue::ItemPointsOutOfRange {
+                    item_asset_path: item.item_asset_path.clone(),
+                    points: item.points,
+                    max_points: item.max_points,
+                });
+            }
+        }
+
+        issues.extend(self.locked_tiers());
+
+        let points_spent = self.points_spent();
+
+        if points_spent > 0 && self.tree_grade == 0 {
+            issues.push(AbilityTreeIssue::TreeLocked { points_spent });
+        }
+
+        let available = available_skill_points(player_level);
+
+        if points_spent + ability_points != available {
+            issues.push(AbilityTreeIssue::PointsMismatch {
+                points_spent,
+                ability_points,
+                available,
+            });
+        }
+
+        issues
+    }
+}
+
+/// The tier object inside of the tree asset, ie: `Tiers_OakPlayerAbilityTreeTierData_0`
+fn tier_name(item_asset_path: &str) -> &str {
+    let tier = item_asset_path
+        .split_once(':')
+        .map(|(_, rest)| rest)
+        .unwrap_or(item_asset_path);
+
+    tier.split_once('.').map(|(tier, _)| tier).unwrap_or(tier)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum AbilityTreeIssue {
+    UnknownTree {
+        tree_identifier: i32,
+    },
+    ItemPointsOutOfRange {
+        item_asset_path: String,
+        points: i32,
+        max_points: i32,
+    },
+    UnknownSkill {
+        item_asset_path: String,
+    },
+    SkillMaxPointsChanged {
+        item_asset_path: String,
+        max_points: i32,
+        expected_max_points: i32,
+    },
+    TreeLocked {
+        points_spent: i32,
+    },
+    TierLocked {
+        tree_identifier: i32,
+        points_required: i32,
+        points_spent: i32,
+    },
+    PointsMismatch {
+        points_spent: i32,
+        ability_points: i32,
+        available: i32,
+    },
+}
+
+impl std::fmt::Display for AbilityTreeIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbilityTreeIssue::UnknownTree { tree_identifier } => {
+                write!(f, "Skill tree {} does not belong to this class", tree_identifier)
+            }
+            AbilityTreeIssue::ItemPointsOutOfRange {
+                item_asset_path,
+                points,
+                max_points,
+            } => write!(
+                f,
+                "{} has {} points but allows between 0 and {}",
+                item_asset_path, points, max_points
+            ),
+            AbilityTreeIssue::UnknownSkill { item_asset_path } => {
+                write!(f, "{} is not a skill of this class", item_asset_path)
+            }
+            AbilityTreeIssue::SkillMaxPointsChanged {
+                item_asset_path,
+                max_points,
+                expected_max_points,
+            } => write!(
+                f,
+                "{} allows {} points but the skill only has {}",
+                item_asset_path, max_points, expected_max_points
+            ),
+            AbilityTreeIssue::TreeLocked { points_spent } => write!(
+                f,
+                "{} points are spent but the skill tree has not been unlocked",
+                points_spent
+            ),
+            AbilityTreeIssue::TierLocked {
+                tree_identifier,
+                points_required,
+                points_spent,
+            } => write!(
+                f,
+                "A tier of skill tree {} needs {} points spent in the tiers above it but only {} are",
+                tree_identifier, points_required, points_spent
+            ),
+            AbilityTreeIssue::PointsMismatch {
+                points_spent,
+                ability_points,
+                available,
+            } => write!(
+                f,
+                "{} spent and {} unspent points do not add up to the {} points available at this level",
+                points_spent, ability_points, available
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct SkillPresetItem {
+    pub item_asset_path: String,
+    pub points: i32,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct SkillPreset {
+    pub name: String,
+    pub items: Vec<SkillPresetItem>,
+}
+
+impl SkillPreset {
+    /// Captures the points currently spent in the tree so they can be applied to another save.
+    pub fn from_ability_tree(name: &str, ability_tree: &AbilityTree) -> Self {
+        let items = ability_tree
+            .items
+            .iter()
+            .filter(|i| i.points > 0)
+            .map(|i| SkillPresetItem {
+                item_asset_path: i.item_asset_path.clone(),
+                points: i.points,
+            })
+            .collect();
+
+        Self {
+            name: name.to_owned(),
+            items,
+        }
+    }
+
+    /// Puts as many of `budget` points as possible into a single tree, tier by tier, maxing out
+    /// each item before moving on to the next one.
+    /// It stops at the first tier which the points spent so far don't unlock.
+    pub fn max_tree(ability_tree: &AbilityTree, tree: &SkillTreeInfo, budget: i32) -> Self {
+        let mut remaining = budget;
+
+        let mut points_spent = 0;
+
+        let mut items = Vec::new();
+
+        for tier in ability_tree.tiers(tree.tree_identifier) {
+            if remaining <= 0 || points_spent < tier.points_required {
+                break;
+            }
+
+            for item in tier.items.iter().filter(|i| i.max_points > 0) {
+                if remaining <= 0 {
+                    break;
+                }
+
+                let points = item.max_points.min(remaining);
+
+                remaining -= points;
+                points_spent += points;
+
+                items.push(SkillPresetItem {
+                    item_asset_path: item.item_asset_path.clone(),
+                    points,
+                });
+            }
+        }
+
+        Self {
+            name: tree.name.to_owned(),
+            items,
+        }
+    }
+
+    pub fn points(&self) -> i32 {
+        self.items.iter().map(|i| i.points).sum()
+    }
+}
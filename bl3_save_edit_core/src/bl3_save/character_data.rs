@@ -1,40 +1,72 @@
 use std::str::FromStr;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use derivative::Derivative;
+use protobuf::RepeatedField;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use strum::{EnumMessage, IntoEnumIterator};
 
 use crate::bl3_item::{Bl3Item, ItemFlags};
 use crate::bl3_profile::guardian_reward::GuardianReward;
+use crate::bl3_save::ability_tree::{
+    available_skill_points, AbilityTree, AbilityTreeIssue, SkillPreset, SKILL_TREE_UNLOCK_LEVEL,
+};
 use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData};
 use crate::bl3_save::challenge_data::Challenge;
 use crate::bl3_save::challenge_data::ChallengeData;
-use crate::bl3_save::inventory_slot::{InventorySlot, InventorySlotData};
+use crate::bl3_save::crew_quarters::{
+    available_crew_quarters_rooms, crew_quarters_decoration_name, crew_quarters_decorations,
+    CrewQuartersDecorationData, GunRackItemData, GunRackSlot, CREW_QUARTERS_ROOMS,
+    EMPTY_CREW_QUARTERS_DECORATION, MAX_CREW_QUARTERS_DECORATION_SLOTS,
+};
+use crate::bl3_save::echo_log::{echo_log_content, EchoLogContent, EchoLogData};
+use crate::bl3_save::fast_travel::{
+    fast_travel_group, fast_travel_level_name, fast_travel_stations, FastTravelGroup,
+    FastTravelStationData,
+};
+use crate::bl3_save::inventory_slot::{EquippedItemData, InventorySlot, InventorySlotData};
 use crate::bl3_save::level_data::{LEVEL_CHALLENGES, LEVEL_STAT};
+use crate::bl3_save::mission::{
+    mission_name, mission_objectives, MissionData, MissionStatus, MAIN_STORY_MISSIONS,
+};
 use crate::bl3_save::models::Currency;
 use crate::bl3_save::player_class::PlayerClass;
+use crate::bl3_save::player_color::{PlayerColorData, PlayerColorSlot};
 use crate::bl3_save::playthrough::Playthrough;
 use crate::bl3_save::sdu::{SaveSduSlot, SaveSduSlotData};
 use crate::bl3_save::util::{currency_amount_from_character, experience_to_level};
 use crate::game_data::{
-    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS,
-    PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS, VEHICLE_CHASSIS_CYCLONE,
-    VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER, VEHICLE_CHASSIS_TECHNICAL,
-    VEHICLE_PARTS_CYCLONE, VEHICLE_PARTS_JETBEAST, VEHICLE_PARTS_OUTRUNNER,
-    VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE, VEHICLE_SKINS_JETBEAST,
-    VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
+    GameDataKv, VEHICLE_CHASSIS_CYCLONE, VEHICLE_CHASSIS_JETBEAST, VEHICLE_CHASSIS_OUTRUNNER,
+    VEHICLE_CHASSIS_TECHNICAL, VEHICLE_PARTS_CYCLONE, VEHICLE_PARTS_JETBEAST,
+    VEHICLE_PARTS_OUTRUNNER, VEHICLE_PARTS_TECHNICAL, VEHICLE_SKINS_CYCLONE,
+    VEHICLE_SKINS_JETBEAST, VEHICLE_SKINS_OUTRUNNER, VEHICLE_SKINS_TECHNICAL,
 };
+use crate::load_warning::{LoadMode, LoadWarnings};
 use crate::protos::oak_save::{
-    Character, GuardianRankCharacterSaveGameData, GuardianRankRewardCharacterSaveGameData,
-    GuardianRankSaveGameData, OakInventoryItemSaveGameData, VehicleUnlockedSaveGameData,
+    ActiveFastTravelSaveData, Character, CrewQuartersDecorationSaveData,
+    CrewQuartersGunRackItemSaveData, CustomPlayerColorSaveGameData, DiscoveredLevelInfo,
+    EchoLogSaveGameData, GameStateSaveData, GuardianRankCharacterSaveGameData,
+    GuardianRankRewardCharacterSaveGameData, GuardianRankSaveGameData,
+    InventoryBalanceStateInitializationData, MissionPlaythroughSaveGameData,
+    MissionStatusPlayerSaveGameData, OakInventoryItemSaveGameData,
+    PlaythroughActiveFastTravelSaveData, VehicleUnlockedSaveGameData,
 };
 use crate::protos::oak_shared::{
     GameStatSaveGameData, InventoryCategorySaveData, OakSDUSaveGameData,
 };
+use crate::resources::{
+    ECHO_LOGS, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_EMOTES,
+    PROFILE_EMOTES_DEFAULTS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS, PROFILE_SKINS,
+    PROFILE_SKINS_DEFAULTS, PROFILE_WEAPON_TRINKETS,
+};
 use crate::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
 
 pub const MAX_CHARACTER_LEVEL: usize = 72;
+pub const MAX_MAYHEM_LEVEL: i32 = 11;
+pub const EMOTE_SLOTS: usize = 4;
+
+// Where a new playthrough starts, the same station the game uses for a new character.
+const NEW_PLAYTHROUGH_TRAVEL_STATION: &str = "/Game/GameData/FastTravel/FTS_Prologue.FTS_Prologue";
 
 #[derive(Derivative)]
 #[derivative(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
@@ -44,6 +76,7 @@ pub struct CharacterData {
     player_class: PlayerClass,
     player_level: i32,
     ability_points: i32,
+    ability_tree: AbilityTree,
     guardian_rank: i32,
     head_skin_selected: GameDataKv,
     character_skin_selected: GameDataKv,
@@ -57,25 +90,57 @@ pub struct CharacterData {
     challenge_milestones: Vec<ChallengeData>,
     vehicle_data: [VehicleData; 12],
     inventory_items: Vec<Bl3Item>,
+    gun_rack_items: Vec<GunRackItemData>,
+    // Set when adding True Vault Hunter Mode raised the completed playthroughs so removing it again
+    // can put the original value back
+    playthroughs_completed_before_tvhm: Option<i32>,
 }
 
 impl CharacterData {
     pub fn from_character(character: Character) -> Result<Self> {
-        let player_class = PlayerClass::from_str(
-            character
-                .player_class_data
-                .as_ref()
-                .map(|p| p.player_class_path.as_str())
-                .context("failed to read player class")?,
-        )?;
+        Self::from_character_with_warnings(character, &mut LoadWarnings::new(LoadMode::Strict))
+    }
 
-        let player_level = experience_to_level(character.experience_points)?;
+    pub(crate) fn from_character_with_warnings(
+        character: Character,
+        warnings: &mut LoadWarnings,
+    ) -> Result<Self> {
+        let player_class = warnings
+            .check(
+                "player_class",
+                character
+                    .player_class_data
+                    .as_ref()
+                    .map(|p| p.player_class_path.as_str())
+                    .context("failed to read player class")
+                    .and_then(|p| {
+                        PlayerClass::from_str(p)
+                            .with_context(|| format!("failed to read player class: {}", p))
+                    }),
+            )?
+            .unwrap_or_default();
+
+        let player_level = warnings
+            .check(
+                "experience_points",
+                experience_to_level(character.experience_points),
+            )?
+            .unwrap_or(1);
+
+        let ability_data = warnings
+            .check(
+                "ability_data",
+                character
+                    .ability_data
+                    .as_ref()
+                    .context("failed to read Player ability data"),
+            )?
+            .cloned()
+            .unwrap_or_default();
 
-        let ability_points = character
-            .ability_data
-            .as_ref()
-            .context("failed to read Player ability data")?
-            .ability_points;
+        let ability_points = ability_data.ability_points;
+
+        let ability_tree = AbilityTree::from_ability_data(&ability_data);
 
         let guardian_rank = character
             .guardian_rank_character_data
@@ -135,18 +200,19 @@ impl CharacterData {
         let eridium = currency_amount_from_character(&character, &Currency::Eridium);
         let playthroughs = Playthrough::playthroughs_from_character(&character)?;
 
-        let mut unlockable_inventory_slots = character
-            .equipped_inventory_list
-            .par_iter()
-            .map(|i| {
-                Ok(InventorySlotData {
-                    slot: InventorySlot::from_str(&i.slot_data_path).with_context(|| {
-                        format!("failed to read inventory slot: {}", &i.slot_data_path)
-                    })?,
+        let mut unlockable_inventory_slots = Vec::new();
+
+        for i in character.equipped_inventory_list.iter() {
+            let slot = InventorySlot::from_str(&i.slot_data_path)
+                .with_context(|| format!("failed to read inventory slot: {}", &i.slot_data_path));
+
+            if let Some(slot) = warnings.check("equipped_inventory_list", slot)? {
+                unlockable_inventory_slots.push(InventorySlotData {
+                    slot,
                     unlocked: i.enabled,
-                })
-            })
-            .collect::<Result<Vec<_>>>()?;
+                });
+            }
+        }
 
         unlockable_inventory_slots.sort();
 
@@ -171,52 +237,57 @@ impl CharacterData {
 
         sdu_slots.sort();
 
-        let mut ammo_pools = character
+        let mut ammo_pools = Vec::new();
+
+        for rp in character
             .resource_pools
             .iter()
             .filter(|rp| !rp.resource_path.contains("Eridium"))
-            .map(|rp| {
-                let ammo = AmmoPool::from_str(&rp.resource_path)
-                    .with_context(|| format!("failed to read ammo: {}", &rp.resource_path))?;
+        {
+            let ammo = AmmoPool::from_str(&rp.resource_path)
+                .with_context(|| format!("failed to read ammo: {}", &rp.resource_path));
 
+            if let Some(ammo) = warnings.check("resource_pools", ammo)? {
                 let max = ammo.maximum();
 
-                Ok(AmmoPoolData {
+                ammo_pools.push(AmmoPoolData {
                     pool: ammo,
                     current: rp.amount as i32,
                     max,
-                })
-            })
-            .collect::<Result<Vec<_>>>()?;
+                });
+            }
+        }
 
         ammo_pools.sort();
 
-        let mut challenge_milestones = Challenge::iter()
-            .filter(|challenge| {
-                let challenge_path = challenge.get_serializations()[0];
-
-                if challenge_path.contains("Character") {
-                    challenge_path.contains(&player_class.to_string())
-                } else {
-                    true
-                }
-            })
-            .map(|challenge| {
-                let chall_path = challenge.get_serializations()[0];
+        let mut challenge_milestones = Vec::new();
 
-                let unlocked = character
-                    .challenge_data
-                    .par_iter()
-                    .find_first(|cd| cd.challenge_class_path.contains(&chall_path))
-                    .map(|cd| cd.currently_completed)
-                    .context("failed to read challenge milestones")?;
+        for challenge in Challenge::iter().filter(|challenge| {
+            let challenge_path = challenge.get_serializations()[0];
 
-                Ok(ChallengeData {
-                    challenge,
-                    unlocked,
-                })
-            })
-            .collect::<Result<Vec<_>>>()?;
+            if challenge_path.contains("Character") {
+                challenge_path.contains(&player_class.to_string())
+            } else {
+                true
+            }
+        }) {
+            let chall_path = challenge.get_serializations()[0];
+
+            let unlocked = character
+                .challenge_data
+                .par_iter()
+                .find_first(|cd| cd.challenge_class_path.contains(&chall_path))
+                .map(|cd| cd.currently_completed)
+                .with_context(|| format!("failed to read challenge milestone: {}", chall_path));
+
+            // A missing milestone is shown as locked, unlocking it adds it back
+            let unlocked = warnings.check("challenge_data", unlocked)?.unwrap_or(false);
+
+            challenge_milestones.push(ChallengeData {
+                challenge,
+                unlocked,
+            });
+        }
 
         challenge_milestones.sort();
 
@@ -340,20 +411,60 @@ impl CharacterData {
             },
         ];
 
-        let inventory_items = character
+        let mut inventory_items = Vec::new();
+
+        // Items which can't be read are always kept as they are, so the editor's indexes match
+        // the file's
+        for (i, (oak_item, item)) in character
             .inventory_items
-            .par_iter()
-            .filter_map(|i| {
-                Bl3Item::from_serial_bytes(&i.item_serial_number, ItemFlags::from_bits(i.flags))
-                    .ok()
-            })
-            .collect::<Vec<_>>();
+            .iter()
+            .zip(
+                character
+                    .inventory_items
+                    .par_iter()
+                    .map(Bl3Item::from_oak_inventory_item)
+                    .collect::<Vec<_>>(),
+            )
+            .enumerate()
+        {
+            match item {
+                Ok(item) => inventory_items.push(item),
+                Err(e) => {
+                    warnings.push("inventory_items", format!("item {}: {:#}", i, e));
+
+                    inventory_items.push(Bl3Item::unreadable(
+                        &oak_item.item_serial_number,
+                        ItemFlags::from_bits(oak_item.flags),
+                        &e,
+                    ));
+                }
+            }
+        }
+
+        let mut gun_rack_items = Vec::new();
+
+        for r in character.get_crew_quarters_gun_rack().rack_save_data.iter() {
+            let item = GunRackSlot::from_str(&r.slot_asset_path)
+                .with_context(|| format!("failed to read gun rack slot: {}", r.slot_asset_path))
+                .and_then(|slot| {
+                    Ok(GunRackItemData {
+                        slot,
+                        item: Bl3Item::from_serial_bytes(&r.encrypted_serial_number, None)?,
+                    })
+                });
+
+            match item {
+                Ok(item) => gun_rack_items.push(item),
+                Err(e) => warnings.push("crew_quarters_gun_rack", format!("{:#}", e)),
+            }
+        }
 
         Ok(Self {
             character,
             player_class,
             player_level,
             ability_points,
+            ability_tree,
             guardian_rank,
             head_skin_selected,
             character_skin_selected,
@@ -367,6 +478,8 @@ impl CharacterData {
             challenge_milestones,
             vehicle_data,
             inventory_items,
+            gun_rack_items,
+            playthroughs_completed_before_tvhm: None,
         })
     }
 
@@ -404,6 +517,8 @@ impl CharacterData {
                 self.ability_points = new_ability_points;
             }
 
+            self.ability_tree = AbilityTree::from_ability_data(ability_data);
+
             self.player_class = player_class;
         }
 
@@ -452,6 +567,8 @@ impl CharacterData {
                 self.ability_points = new_ability_points;
             }
 
+            self.ability_tree = AbilityTree::from_ability_data(ability_data);
+
             for (challenge_level, challenge_obj) in LEVEL_CHALLENGES {
                 if self.player_level >= challenge_level {
                     self.unlock_challenge_obj(challenge_obj, 1, 0)?;
@@ -482,6 +599,149 @@ impl CharacterData {
         Ok(())
     }
 
+    pub fn ability_tree(&self) -> &AbilityTree {
+        &self.ability_tree
+    }
+
+    pub fn validate_ability_tree(&self) -> Vec<AbilityTreeIssue> {
+        self.ability_tree
+            .validate(self.player_class, self.player_level, self.ability_points)
+    }
+
+    /// Sets the points of a single tree item, the unspent ability points are recalculated from
+    /// what is left over for the current level.
+    pub fn set_ability_tree_item_points(
+        &mut self,
+        item_asset_path: &str,
+        points: i32,
+    ) -> Result<()> {
+        let mut ability_tree = self.ability_tree.clone();
+
+        let tree_item = ability_tree
+            .items
+            .iter_mut()
+            .find(|i| i.item_asset_path == item_asset_path)
+            .with_context(|| format!("failed to find skill tree item: {}", item_asset_path))?;
+
+        if !(0..=tree_item.max_points).contains(&points) {
+            bail!(
+                "skill tree item points must be between 0 and {}, got: {}",
+                tree_item.max_points,
+                points
+            );
+        }
+
+        tree_item.points = points;
+
+        self.write_ability_tree(&ability_tree)
+    }
+
+    /// Removes all points from the skill trees and gives back every point available at the
+    /// current level.
+    pub fn respec_ability_tree(&mut self) -> Result<()> {
+        let mut ability_tree = self.ability_tree.clone();
+
+        ability_tree.items.iter_mut().for_each(|i| i.points = 0);
+
+        self.write_ability_tree(&ability_tree)
+    }
+
+    pub fn apply_skill_preset(&mut self, preset: &SkillPreset) -> Result<()> {
+        let available = available_skill_points(self.player_level);
+
+        if preset.points() > available {
+            bail!(
+                "skill preset '{}' needs {} points but only {} are available at level {}",
+                preset.name,
+                preset.points(),
+                available,
+                self.player_level
+            );
+        }
+
+        let mut ability_tree = self.ability_tree.clone();
+
+        ability_tree.items.iter_mut().for_each(|i| i.points = 0);
+
+        for preset_item in &preset.items {
+            let tree_item = ability_tree
+                .items
+                .iter_mut()
+                .find(|i| i.item_asset_path == preset_item.item_asset_path)
+                .with_context(|| {
+                    format!(
+                        "skill preset '{}' has an item which does not belong to a {}: {}",
+                        preset.name, self.player_class, preset_item.item_asset_path
+                    )
+                })?;
+
+            if !(0..=tree_item.max_points).contains(&preset_item.points) {
+                bail!(
+                    "skill preset '{}' has {} points in {} which allows between 0 and {}",
+                    preset.name,
+                    preset_item.points,
+                    preset_item.item_asset_path,
+                    tree_item.max_points
+                );
+            }
+
+            tree_item.points = preset_item.points;
+        }
+
+        self.write_ability_tree(&ability_tree)
+            .with_context(|| format!("failed to apply skill preset '{}'", preset.name))
+    }
+
+    /// Writes the points of every item in `ability_tree` to the save, as long as they fit in the
+    /// points available at the current level and every tier they are spent in is unlocked.
+    fn write_ability_tree(&mut self, ability_tree: &AbilityTree) -> Result<()> {
+        let available = available_skill_points(self.player_level);
+
+        let points_spent = ability_tree.points_spent();
+
+        if points_spent > available {
+            bail!(
+                "{} skill points are spent but only {} are available at level {}",
+                points_spent,
+                available,
+                self.player_level
+            );
+        }
+
+        if let Some(issue) = ability_tree.locked_tiers().first() {
+            bail!("{}", issue);
+        }
+
+        let ability_data = self
+            .character
+            .ability_data
+            .as_mut()
+            .context("failed to read Player ability data")?;
+
+        // The ability tree is read from the tree item list so its items are in the same order
+        for (tree_item, item) in ability_data
+            .tree_item_list
+            .iter_mut()
+            .zip(&ability_tree.items)
+        {
+            tree_item.points = item.points;
+        }
+
+        // The tree grade is 0 until the skill tree is unlocked, the test saves hold either 2 or
+        // the character's level after that, so the level the tree is unlocked at is used.
+        if points_spent > 0 && ability_data.tree_grade == 0 {
+            ability_data.tree_grade = SKILL_TREE_UNLOCK_LEVEL;
+        }
+
+        ability_data.ability_points = available - points_spent;
+
+        self.ability_points = ability_data.ability_points;
+
+        self.ability_tree = AbilityTree::from_ability_data(ability_data);
+
+        Ok(())
+    }
+
     pub fn guardian_rank(&self) -> i32 {
         self.guardian_rank
     }
@@ -632,6 +892,149 @@ impl CharacterData {
         self.echo_theme_selected = echo_theme_selected.to_owned();
     }
 
+    /// The emotes on each slot of the emote wheel, a slot which references an emote that isn't in
+    /// the game data is `None`.
+    pub fn equipped_emotes(&self) -> Vec<Option<GameDataKv>> {
+        self.character
+            .equipped_emote_customizations
+            .iter()
+            .map(|i| {
+                usize::try_from(*i)
+                    .ok()
+                    .and_then(|i| self.character.selected_customizations.get(i))
+                    .and_then(|path| {
+                        PROFILE_EMOTES_DEFAULTS
+                            .iter()
+                            .chain(PROFILE_EMOTES.iter())
+                            .find(|e| e.ident == path)
+                            .cloned()
+                    })
+            })
+            .collect()
+    }
+
+    /// Equips `emote` on the emote wheel `slot`, the emote has to belong to the character's class
+    /// and be one of `unlocked_emotes` (see `ProfileData::unlocked_emotes`).
+    pub fn set_equipped_emote(
+        &mut self,
+        slot: usize,
+        emote: &GameDataKv,
+        unlocked_emotes: &[GameDataKv],
+    ) -> Result<()> {
+        if slot >= EMOTE_SLOTS {
+            bail!("the emote wheel only has {} slots", EMOTE_SLOTS);
+        }
+
+        if !PROFILE_EMOTES_DEFAULTS
+            .iter()
+            .chain(PROFILE_EMOTES.iter())
+            .any(|e| e.ident == emote.ident)
+        {
+            bail!("unknown emote: {}", emote.ident);
+        }
+
+        if !emote.ident.contains(&self.player_class.to_string()) {
+            bail!(
+                "the emote {} can't be used by a {}",
+                emote.name,
+                self.player_class
+            );
+        }
+
+        if !unlocked_emotes.iter().any(|e| e.ident == emote.ident) {
+            bail!(
+                "the emote {} has not been unlocked in the profile",
+                emote.name
+            );
+        }
+
+        let equipped_emotes = &self.character.equipped_emote_customizations;
+
+        let current_index = *equipped_emotes
+            .get(slot)
+            .with_context(|| format!("failed to find emote slot: {}", slot))?;
+
+        if self
+            .equipped_emotes()
+            .iter()
+            .enumerate()
+            .any(|(i, e)| i != slot && e.map(|e| e.ident) == Some(emote.ident))
+        {
+            bail!("the emote {} is already equipped", emote.name);
+        }
+
+        let current_index_shared = equipped_emotes
+            .iter()
+            .enumerate()
+            .any(|(i, e)| i != slot && *e == current_index);
+
+        let selected_customizations = &mut self.character.selected_customizations;
+
+        let new_index = if let Some(existing) = selected_customizations
+            .iter()
+            .position(|c| c == emote.ident)
+        {
+            existing
+        } else {
+            match usize::try_from(current_index)
+                .ok()
+                .filter(|_| !current_index_shared)
+                .and_then(|i| selected_customizations.get_mut(i).map(|c| (i, c)))
+                .filter(|(_, current)| {
+                    PROFILE_EMOTES_DEFAULTS
+                        .iter()
+                        .chain(PROFILE_EMOTES.iter())
+                        .any(|e| e.ident == current.as_str())
+                }) {
+                // Replace the emote this slot had so the list doesn't keep growing, the slot may
+                // point at another kind of customization (like the equipped skin) which is kept
+                Some((i, current)) => {
+                    *current = emote.ident.to_owned();
+                    i
+                }
+                None => {
+                    selected_customizations.push(emote.ident.to_owned());
+                    selected_customizations.len() - 1
+                }
+            }
+        };
+
+        self.character.equipped_emote_customizations[slot] = new_index as i32;
+
+        Ok(())
+    }
+
+    /// The custom colors of every color slot, slots missing from the save use the skin's colors.
+    pub fn player_colors(&self) -> Vec<PlayerColorData> {
+        PlayerColorSlot::iter()
+            .map(|slot| {
+                self.character
+                    .selected_color_customizations
+                    .iter()
+                    .find(|c| PlayerColorSlot::from_str(&c.color_parameter).ok() == Some(slot))
+                    .map(|c| PlayerColorData::from_save_data(slot, c))
+                    .unwrap_or_else(|| PlayerColorData::new(slot))
+            })
+            .collect()
+    }
+
+    pub fn set_player_color(&mut self, player_color: &PlayerColorData) {
+        if let Some(existing) = self
+            .character
+            .selected_color_customizations
+            .iter_mut()
+            .find(|c| PlayerColorSlot::from_str(&c.color_parameter).ok() == Some(player_color.slot))
+        {
+            player_color.apply_to_save_data(existing);
+        } else {
+            let mut new_color = CustomPlayerColorSaveGameData::new();
+
+            player_color.apply_to_save_data(&mut new_color);
+
+            self.character.selected_color_customizations.push(new_color);
+        }
+    }
+
     pub fn money(&self) -> i32 {
         self.money
     }
@@ -678,97 +1081,1236 @@ impl CharacterData {
         &self.playthroughs
     }
 
-    pub fn unlockable_inventory_slots(&self) -> &Vec<InventorySlotData> {
-        &self.unlockable_inventory_slots
+    pub fn set_mayhem_level(&mut self, playthrough_index: usize, mayhem_level: i32) -> Result<()> {
+        self.ensure_playthrough_exists(playthrough_index)?;
+
+        if !(0..=MAX_MAYHEM_LEVEL).contains(&mayhem_level) {
+            bail!(
+                "mayhem level must be between 0 and {}, got {}",
+                MAX_MAYHEM_LEVEL,
+                mayhem_level
+            );
+        }
+
+        self.character.game_state_save_data_for_playthrough[playthrough_index].mayhem_level =
+            mayhem_level;
+
+        self.update_playthroughs()
     }
 
-    pub fn remove_inventory_slot_if_exists(
+    pub fn set_mayhem_random_seed(
         &mut self,
-        inventory_slot: &InventorySlot,
+        playthrough_index: usize,
+        mayhem_random_seed: i32,
     ) -> Result<()> {
-        let slot_path = inventory_slot.get_serializations()[0];
-
-        if let Some(slot) = self
-            .character
-            .equipped_inventory_list
-            .iter_mut()
-            .find(|s| s.slot_data_path == slot_path)
-        {
-            //Lock in character data
-            slot.enabled = false;
-        }
+        self.ensure_playthrough_exists(playthrough_index)?;
 
-        if let Some(current_slot) = self
-            .unlockable_inventory_slots
-            .iter_mut()
-            .find(|i| i.slot == *inventory_slot)
-        {
-            current_slot.unlocked = false;
-        } else {
-            self.unlockable_inventory_slots.push(InventorySlotData {
-                slot: inventory_slot.to_owned(),
-                unlocked: false,
-            });
-        }
+        self.character.game_state_save_data_for_playthrough[playthrough_index].mayhem_random_seed =
+            mayhem_random_seed;
 
-        Ok(())
+        self.update_playthroughs()
     }
 
-    pub fn unlock_inventory_slot(&mut self, inventory_slot: &InventorySlot) -> Result<()> {
-        let slot_path = inventory_slot.get_serializations()[0];
+    /// Adds True Vault Hunter Mode to a save which only has Normal Mode, every vector which is
+    /// stored per playthrough gets an entry for the new playthrough so they stay aligned.
+    pub fn add_tvhm_playthrough(&mut self) -> Result<()> {
+        let number_of_playthroughs = self.character.game_state_save_data_for_playthrough.len();
 
-        let slot = self
-            .character
-            .equipped_inventory_list
-            .iter_mut()
-            .find(|s| s.slot_data_path == slot_path)
-            .with_context(|| {
-                format!(
-                    "failed to find inventory slot: {}",
-                    inventory_slot.to_string()
-                )
-            })?;
+        if number_of_playthroughs != 1 {
+            bail!(
+                "only a save with just Normal Mode can have True Vault Hunter Mode added, this save has {} playthroughs",
+                number_of_playthroughs
+            );
+        }
 
-        //Unlock in character data
-        slot.enabled = true;
+        let playthrough_vectors = [
+            (
+                "mission playthroughs",
+                self.character.mission_playthroughs_data.len(),
+            ),
+            (
+                "last active travel stations",
+                self.character
+                    .last_active_travel_station_for_playthrough
+                    .len(),
+            ),
+            (
+                "active travel stations",
+                self.character.active_travel_stations_for_playthrough.len(),
+            ),
+        ];
 
-        let class_mod_challenge = match self.player_class {
-            PlayerClass::BeastMaster => Challenge::BeastMasterClassModSlot,
-            PlayerClass::Gunner => Challenge::GunnerClassModSlot,
-            PlayerClass::Operative => Challenge::OperativeClassModSlot,
-            PlayerClass::Siren => Challenge::SirenClassModSlot,
-        };
+        // Entries past the first playthrough would be used for the new one as they are, so a save
+        // with more of them than playthroughs is not touched.
+        for (name, len) in playthrough_vectors {
+            if len > number_of_playthroughs {
+                bail!(
+                    "this save has {} {} entries for {} playthrough, True Vault Hunter Mode can't be added",
+                    len,
+                    name,
+                    number_of_playthroughs
+                );
+            }
+        }
 
-        let class_mod_challenge_path = class_mod_challenge.get_serializations()[0];
+        self.character
+            .game_state_save_data_for_playthrough
+            .push(GameStateSaveData::new());
 
-        match inventory_slot {
-            InventorySlot::ClassMod => {
-                self.unlock_challenge_obj(class_mod_challenge_path, 1, 0)?;
-            }
-            InventorySlot::Artifact => {
-                self.unlock_challenge_obj(Challenge::ArtifactSlot.get_serializations()[0], 1, 0)?;
-            }
-            _ => (),
+        while self.character.mission_playthroughs_data.len() < 2 {
+            self.character
+                .mission_playthroughs_data
+                .push(MissionPlaythroughSaveGameData::new());
         }
 
-        if let Some(current_slot) = self
-            .unlockable_inventory_slots
-            .iter_mut()
-            .find(|i| i.slot == *inventory_slot)
+        while self
+            .character
+            .last_active_travel_station_for_playthrough
+            .len()
+            < 2
         {
-            current_slot.unlocked = true;
-        } else {
-            self.unlockable_inventory_slots.push(InventorySlotData {
-                slot: inventory_slot.to_owned(),
-                unlocked: true,
-            });
+            self.character
+                .last_active_travel_station_for_playthrough
+                .push(NEW_PLAYTHROUGH_TRAVEL_STATION.to_owned());
         }
 
-        Ok(())
-    }
+        self.add_fast_travel_station(1, NEW_PLAYTHROUGH_TRAVEL_STATION)?;
 
-    pub fn sdu_slots(&self) -> &Vec<SaveSduSlotData> {
-        &self.sdu_slots
+        // The game only offers True Vault Hunter Mode once the story has been completed
+        if self.character.playthroughs_completed < 1 {
+            self.playthroughs_completed_before_tvhm = Some(self.character.playthroughs_completed);
+            self.character.playthroughs_completed = 1;
+        }
+
+        self.update_playthroughs()
+    }
+
+    /// Removes True Vault Hunter Mode along with everything stored for it in the other
+    /// per playthrough vectors.
+    pub fn remove_tvhm_playthrough(&mut self) -> Result<()> {
+        if self.character.game_state_save_data_for_playthrough.len() < 2 {
+            bail!("this save does not have a True Vault Hunter Mode playthrough");
+        }
+
+        self.character
+            .game_state_save_data_for_playthrough
+            .truncate(1);
+        self.character.mission_playthroughs_data.truncate(1);
+        self.character
+            .last_active_travel_station_for_playthrough
+            .truncate(1);
+        self.character
+            .active_travel_stations_for_playthrough
+            .truncate(1);
+
+        if self.character.last_play_through_index > 0 {
+            self.character.last_play_through_index = 0;
+        }
+
+        if let Some(playthroughs_completed) = self.playthroughs_completed_before_tvhm.take() {
+            self.character.playthroughs_completed = playthroughs_completed;
+        }
+
+        // Levels and areas are flagged per playthrough when discovered, only the Normal Mode flag
+        // is kept and anything which was only discovered in True Vault Hunter Mode is removed.
+        let normal_mode_flag = 1;
+
+        let discovered_levels = self
+            .character
+            .mut_discovery_data()
+            .mut_discovered_level_info();
+
+        for level in discovered_levels.iter_mut() {
+            level.discovered_playthroughs &= normal_mode_flag;
+
+            level
+                .discovered_area_info
+                .iter_mut()
+                .for_each(|a| a.discovered_playthroughs &= normal_mode_flag);
+
+            level
+                .discovered_area_info
+                .retain(|a| a.discovered_playthroughs != 0);
+        }
+
+        discovered_levels.retain(|l| l.discovered_playthroughs != 0);
+
+        self.update_playthroughs()
+    }
+
+    pub fn missions(&self, playthrough_index: usize) -> Vec<MissionData> {
+        self.character
+            .mission_playthroughs_data
+            .get(playthrough_index)
+            .map(|p| {
+                p.mission_list
+                    .iter()
+                    .map(MissionData::from_mission_status)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn mission_status(
+        &self,
+        playthrough_index: usize,
+        mission_class_path: &str,
+    ) -> MissionStatus {
+        self.missions(playthrough_index)
+            .into_iter()
+            .find(|m| {
+                m.mission_class_path
+                    .eq_ignore_ascii_case(mission_class_path)
+            })
+            .map(|m| m.status)
+            .unwrap_or_default()
+    }
+
+    pub fn tracked_mission(&self, playthrough_index: usize) -> Option<&str> {
+        self.character
+            .mission_playthroughs_data
+            .get(playthrough_index)
+            .map(|p| p.tracked_mission_class_path.as_str())
+            .filter(|p| !p.is_empty())
+    }
+
+    fn ensure_playthrough_exists(&self, playthrough_index: usize) -> Result<()> {
+        let number_of_playthroughs = self.character.game_state_save_data_for_playthrough.len();
+
+        if playthrough_index >= number_of_playthroughs {
+            bail!(
+                "playthrough {} does not exist, this save has {} playthroughs",
+                playthrough_index + 1,
+                number_of_playthroughs
+            );
+        }
+
+        Ok(())
+    }
+
+    fn mission_playthrough_data_mut(
+        &mut self,
+        playthrough_index: usize,
+    ) -> Result<&mut MissionPlaythroughSaveGameData> {
+        self.ensure_playthrough_exists(playthrough_index)?;
+
+        // Keep the mission data aligned with the playthroughs when a playthrough has no missions yet
+        while self.character.mission_playthroughs_data.len() <= playthrough_index {
+            self.character
+                .mission_playthroughs_data
+                .push(MissionPlaythroughSaveGameData::new());
+        }
+
+        Ok(&mut self.character.mission_playthroughs_data[playthrough_index])
+    }
+
+    fn update_playthroughs(&mut self) -> Result<()> {
+        self.playthroughs = Playthrough::playthroughs_from_character(&self.character)?;
+
+        Ok(())
+    }
+
+    /// Setting a mission to `NotStarted` removes it from the mission list so the game treats it
+    /// as a mission which has never been picked up.
+    ///
+    /// Missions which aren't in the mission list can only be added when their objectives are
+    /// known, see [`mission_objectives`].
+    pub fn set_mission_status(
+        &mut self,
+        playthrough_index: usize,
+        mission_class_path: &str,
+        status: MissionStatus,
+    ) -> Result<()> {
+        let mission_data = self.mission_playthrough_data_mut(playthrough_index)?;
+
+        let existing = mission_data.mission_list.iter().position(|m| {
+            m.mission_class_path
+                .eq_ignore_ascii_case(mission_class_path)
+        });
+
+        match (status, existing) {
+            (MissionStatus::NotStarted, Some(i)) => {
+                mission_data.mission_list.remove(i);
+
+                if mission_data
+                    .tracked_mission_class_path
+                    .eq_ignore_ascii_case(mission_class_path)
+                {
+                    mission_data.tracked_mission_class_path.clear();
+                }
+            }
+            (MissionStatus::NotStarted, None) => (),
+            (MissionStatus::Complete, Some(i)) => {
+                let mission = &mut mission_data.mission_list[i];
+
+                // Missions which are already complete keep the progress the game stored
+                if MissionStatus::from_state(mission.status) != MissionStatus::Complete {
+                    let objectives = mission_objectives(mission_class_path).with_context(|| {
+                        format!(
+                            "the objectives of this mission are unknown, complete it in game instead: {}",
+                            mission_name(mission_class_path)
+                        )
+                    })?;
+
+                    mission.status = MissionStatus::Complete.to_state();
+                    mission.active_objective_set_path =
+                        objectives.complete_objective_set_path.to_owned();
+                    mission.objectives_progress = vec![1; objectives.number_of_objectives];
+                }
+            }
+            (status, Some(i)) => {
+                mission_data.mission_list[i].status = status.to_state();
+            }
+            (status, None) => {
+                let objectives = mission_objectives(mission_class_path).with_context(|| {
+                    format!(
+                        "the objectives of this mission are unknown, start it in game first: {}",
+                        mission_name(mission_class_path)
+                    )
+                })?;
+
+                let mut mission = MissionStatusPlayerSaveGameData::new();
+                mission.status = status.to_state();
+                mission.mission_class_path = objectives.mission_class_path.to_owned();
+                mission.has_been_viewed_in_log = true;
+                mission.kickoff_played = true;
+
+                // Saves store missions which have just been picked up without an objective set
+                if status == MissionStatus::Complete {
+                    mission.active_objective_set_path =
+                        objectives.complete_objective_set_path.to_owned();
+                    mission.objectives_progress = vec![1; objectives.number_of_objectives];
+                } else {
+                    mission.objectives_progress = vec![0; objectives.number_of_objectives];
+                }
+
+                mission_data.mission_list.push(mission);
+            }
+        }
+
+        self.update_playthroughs()
+    }
+
+    pub fn set_mission_objectives_progress(
+        &mut self,
+        playthrough_index: usize,
+        mission_class_path: &str,
+        objectives_progress: Vec<i32>,
+    ) -> Result<()> {
+        let mission = self
+            .mission_playthrough_data_mut(playthrough_index)?
+            .mission_list
+            .iter_mut()
+            .find(|m| {
+                m.mission_class_path
+                    .eq_ignore_ascii_case(mission_class_path)
+            })
+            .with_context(|| {
+                format!(
+                    "mission has not been started in playthrough {}: {}",
+                    playthrough_index + 1,
+                    mission_class_path
+                )
+            })?;
+
+        mission.objectives_progress = objectives_progress;
+
+        Ok(())
+    }
+
+    /// Only missions which are active can be tracked, `None` stops tracking any mission.
+    pub fn set_tracked_mission(
+        &mut self,
+        playthrough_index: usize,
+        mission_class_path: Option<&str>,
+    ) -> Result<()> {
+        if let Some(mission_class_path) = mission_class_path {
+            if self.mission_status(playthrough_index, mission_class_path) != MissionStatus::Active {
+                bail!(
+                    "only active missions can be tracked: {}",
+                    mission_name(mission_class_path)
+                );
+            }
+        }
+
+        self.mission_playthrough_data_mut(playthrough_index)?
+            .tracked_mission_class_path = mission_class_path.unwrap_or_default().to_owned();
+
+        Ok(())
+    }
+
+    /// Completes every main story mission up to and including `mission_class_path`, the next
+    /// story mission is made active if it has not been started yet and is tracked when the
+    /// currently tracked mission is no longer active.
+    pub fn complete_main_story_up_to(
+        &mut self,
+        playthrough_index: usize,
+        mission_class_path: &str,
+    ) -> Result<()> {
+        let last = MAIN_STORY_MISSIONS
+            .iter()
+            .position(|m| m.eq_ignore_ascii_case(mission_class_path))
+            .with_context(|| {
+                format!(
+                    "not a main story mission: {}",
+                    mission_name(mission_class_path)
+                )
+            })?;
+
+        for mission in &MAIN_STORY_MISSIONS[..=last] {
+            self.set_mission_status(playthrough_index, mission, MissionStatus::Complete)?;
+        }
+
+        let next = MAIN_STORY_MISSIONS.get(last + 1).copied();
+
+        if let Some(next) = next {
+            if self.mission_status(playthrough_index, next) == MissionStatus::NotStarted {
+                self.set_mission_status(playthrough_index, next, MissionStatus::Active)?;
+            }
+        }
+
+        let tracking_active_mission = self
+            .tracked_mission(playthrough_index)
+            .map(|t| self.mission_status(playthrough_index, t) == MissionStatus::Active)
+            .unwrap_or(false);
+
+        if !tracking_active_mission {
+            let next_active =
+                next.filter(|n| self.mission_status(playthrough_index, n) == MissionStatus::Active);
+
+            self.set_tracked_mission(playthrough_index, next_active)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn fast_travel_stations(&self, playthrough_index: usize) -> Vec<FastTravelStationData> {
+        let unlocked = self
+            .character
+            .active_travel_stations_for_playthrough
+            .get(playthrough_index)
+            .map(|p| p.active_travel_stations.as_slice())
+            .unwrap_or_default();
+
+        let mut stations = fast_travel_stations()
+            .into_iter()
+            .map(FastTravelStationData::from_station_name)
+            .collect::<Vec<_>>();
+
+        for unlocked_station in unlocked {
+            let existing = stations.iter_mut().find(|s| {
+                s.active_travel_station_name
+                    .eq_ignore_ascii_case(&unlocked_station.active_travel_station_name)
+            });
+
+            let station = match existing {
+                Some(station) => station,
+                None => {
+                    stations.push(FastTravelStationData::from_station_name(
+                        &unlocked_station.active_travel_station_name,
+                    ));
+
+                    stations.last_mut().expect("station was just added")
+                }
+            };
+
+            station.active_travel_station_name =
+                unlocked_station.active_travel_station_name.clone();
+            station.unlocked = true;
+            station.blacklisted = unlocked_station.blacklisted;
+        }
+
+        stations.sort();
+
+        stations
+    }
+
+    pub fn spawn_station(&self, playthrough_index: usize) -> Option<&str> {
+        self.character
+            .last_active_travel_station_for_playthrough
+            .get(playthrough_index)
+            .map(|s| s.as_str())
+            .filter(|s| !s.is_empty())
+    }
+
+    fn travel_stations_for_playthrough_mut(
+        &mut self,
+        playthrough_index: usize,
+    ) -> Result<&mut RepeatedField<ActiveFastTravelSaveData>> {
+        self.ensure_playthrough_exists(playthrough_index)?;
+
+        while self.character.active_travel_stations_for_playthrough.len() <= playthrough_index {
+            self.character
+                .active_travel_stations_for_playthrough
+                .push(PlaythroughActiveFastTravelSaveData::new());
+        }
+
+        Ok(
+            &mut self.character.active_travel_stations_for_playthrough[playthrough_index]
+                .active_travel_stations,
+        )
+    }
+
+    /// Marks the level the station is in as discovered so it shows on the map in this playthrough,
+    /// stations in levels which are not known are left undiscovered.
+    fn discover_fast_travel_level(&mut self, playthrough_index: usize, station: &str) {
+        let level_name = match fast_travel_level_name(station) {
+            Some(level_name) => level_name,
+            None => return,
+        };
+
+        let playthrough_flag = 1 << playthrough_index;
+
+        let discovered_levels = self
+            .character
+            .mut_discovery_data()
+            .mut_discovered_level_info();
+
+        match discovered_levels
+            .iter_mut()
+            .find(|l| l.discovered_level_name.eq_ignore_ascii_case(level_name))
+        {
+            Some(level) => level.discovered_playthroughs |= playthrough_flag,
+            None => {
+                let mut level = DiscoveredLevelInfo::new();
+                level.discovered_level_name = level_name.to_owned();
+                level.discovered_playthroughs = playthrough_flag;
+
+                discovered_levels.push(level);
+            }
+        }
+    }
+
+    fn add_fast_travel_station(&mut self, playthrough_index: usize, station: &str) -> Result<()> {
+        let stations = self.travel_stations_for_playthrough_mut(playthrough_index)?;
+
+        if !stations
+            .iter()
+            .any(|s| s.active_travel_station_name.eq_ignore_ascii_case(station))
+        {
+            let mut active_station = ActiveFastTravelSaveData::new();
+            active_station.active_travel_station_name = station.to_owned();
+
+            stations.push(active_station);
+        }
+
+        self.discover_fast_travel_level(playthrough_index, station);
+
+        Ok(())
+    }
+
+    pub fn unlock_fast_travel_station(
+        &mut self,
+        playthrough_index: usize,
+        station: &str,
+    ) -> Result<()> {
+        self.add_fast_travel_station(playthrough_index, station)?;
+
+        self.update_playthroughs()
+    }
+
+    pub fn unlock_fast_travel_group(
+        &mut self,
+        playthrough_index: usize,
+        group: FastTravelGroup,
+    ) -> Result<()> {
+        for station in fast_travel_stations()
+            .into_iter()
+            .filter(|s| fast_travel_group(s) == group)
+        {
+            self.add_fast_travel_station(playthrough_index, station)?;
+        }
+
+        self.update_playthroughs()
+    }
+
+    pub fn unlock_all_fast_travel_stations(&mut self, playthrough_index: usize) -> Result<()> {
+        for station in fast_travel_stations() {
+            self.add_fast_travel_station(playthrough_index, station)?;
+        }
+
+        self.update_playthroughs()
+    }
+
+    /// Blacklisted stations stay unlocked but are hidden from the fast travel menu.
+    pub fn set_fast_travel_station_blacklisted(
+        &mut self,
+        playthrough_index: usize,
+        station: &str,
+        blacklisted: bool,
+    ) -> Result<()> {
+        let active_station = self
+            .travel_stations_for_playthrough_mut(playthrough_index)?
+            .iter_mut()
+            .find(|s| s.active_travel_station_name.eq_ignore_ascii_case(station))
+            .with_context(|| {
+                format!(
+                    "station has not been unlocked in playthrough {}: {}",
+                    playthrough_index + 1,
+                    station
+                )
+            })?;
+
+        // Only the playthrough's own list is changed. The character wide
+        // active_or_blacklisted_travel_stations is empty in every save seen, including ones with
+        // blacklisted stations, and writing to it would blacklist the station in every playthrough
+        active_station.blacklisted = blacklisted;
+
+        Ok(())
+    }
+
+    /// Sets the station the character spawns at when loading into this playthrough.
+    pub fn set_spawn_station(&mut self, playthrough_index: usize, station: &str) -> Result<()> {
+        let is_unlocked = self
+            .travel_stations_for_playthrough_mut(playthrough_index)?
+            .iter()
+            .any(|s| s.active_travel_station_name.eq_ignore_ascii_case(station));
+
+        if !is_unlocked {
+            bail!(
+                "station has not been unlocked in playthrough {}: {}",
+                playthrough_index + 1,
+                station
+            );
+        }
+
+        let last_stations = &mut self.character.last_active_travel_station_for_playthrough;
+
+        while last_stations.len() <= playthrough_index {
+            last_stations.push(String::new());
+        }
+
+        last_stations[playthrough_index] = station.to_owned();
+
+        self.update_playthroughs()
+    }
+
+    pub fn echo_logs(&self) -> Vec<EchoLogData> {
+        let mut echo_logs = ECHO_LOGS
+            .iter()
+            .map(|e| EchoLogData::from_echo_log_path(e.ident))
+            .collect::<Vec<_>>();
+
+        for unlocked_echo_log in self.character.unlocked_echo_logs.iter() {
+            let unlocked_echo_log = EchoLogData::from_echo_log(unlocked_echo_log);
+
+            match echo_logs.iter_mut().find(|e| {
+                e.echo_log_path
+                    .eq_ignore_ascii_case(&unlocked_echo_log.echo_log_path)
+            }) {
+                Some(existing) => *existing = unlocked_echo_log,
+                None => echo_logs.push(unlocked_echo_log),
+            }
+        }
+
+        echo_logs.sort();
+
+        echo_logs
+    }
+
+    pub fn unlock_echo_log(&mut self, echo_log_path: &str) {
+        if !self
+            .character
+            .unlocked_echo_logs
+            .iter()
+            .any(|e| e.echo_log_path.eq_ignore_ascii_case(echo_log_path))
+        {
+            let mut echo_log = EchoLogSaveGameData::new();
+            echo_log.echo_log_path = echo_log_path.to_owned();
+
+            self.character.unlocked_echo_logs.push(echo_log);
+        }
+    }
+
+    pub fn unlock_echo_logs_by_content(&mut self, content: EchoLogContent) {
+        for echo_log in ECHO_LOGS
+            .iter()
+            .filter(|e| echo_log_content(e.ident) == content)
+        {
+            self.unlock_echo_log(echo_log.ident);
+        }
+    }
+
+    pub fn unlock_all_echo_logs(&mut self) {
+        for echo_log in ECHO_LOGS.iter() {
+            self.unlock_echo_log(echo_log.ident);
+        }
+    }
+
+    /// Logs which have not been seen are shown as new in the echo log menu.
+    pub fn set_echo_log_seen(&mut self, echo_log_path: &str, seen: bool) -> Result<()> {
+        let echo_log = self
+            .character
+            .unlocked_echo_logs
+            .iter_mut()
+            .find(|e| e.echo_log_path.eq_ignore_ascii_case(echo_log_path))
+            .with_context(|| format!("echo log has not been unlocked: {}", echo_log_path))?;
+
+        echo_log.has_been_seen_in_log = seen;
+
+        Ok(())
+    }
+
+    pub fn mark_all_echo_logs_seen(&mut self) {
+        for echo_log in self.character.unlocked_echo_logs.iter_mut() {
+            echo_log.has_been_seen_in_log = true;
+        }
+    }
+
+    pub fn crew_quarters_room(&self) -> Option<&str> {
+        let room_data_path = &self.character.get_crew_quarters_room().room_data_path;
+
+        if room_data_path.is_empty() {
+            None
+        } else {
+            Some(room_data_path)
+        }
+    }
+
+    /// The room has to be the character's own or one of `unlocked_rooms`
+    /// (see `ProfileData::unlocked_crew_quarters_rooms`).
+    pub fn set_crew_quarters_room(
+        &mut self,
+        room_data_path: &str,
+        unlocked_rooms: &[String],
+    ) -> Result<()> {
+        let (room_data_path, room_name) = CREW_QUARTERS_ROOMS
+            .iter()
+            .find(|(path, _)| path.eq_ignore_ascii_case(room_data_path))
+            .with_context(|| format!("unknown crew quarters room: {}", room_data_path))?;
+
+        if !available_crew_quarters_rooms(self.player_class, unlocked_rooms)
+            .iter()
+            .any(|(path, _)| path == room_data_path)
+        {
+            bail!(
+                "the {} crew quarters room has not been unlocked in the profile",
+                room_name
+            );
+        }
+
+        self.character.mut_crew_quarters_room().room_data_path = (*room_data_path).to_owned();
+
+        Ok(())
+    }
+
+    /// Every decoration slot in the room, slots which have never been decorated are returned empty.
+    pub fn crew_quarters_decorations(&self) -> Vec<CrewQuartersDecorationData> {
+        let decorations = &self.character.get_crew_quarters_room().decorations;
+
+        (1..=MAX_CREW_QUARTERS_DECORATION_SLOTS)
+            .map(|decoration_index| {
+                let decoration_data_path = decorations
+                    .iter()
+                    .find(|d| d.decoration_index == decoration_index)
+                    .map(|d| d.decoration_data_path.as_str())
+                    .unwrap_or_default();
+
+                CrewQuartersDecorationData::new(decoration_index, decoration_data_path)
+            })
+            .collect()
+    }
+
+    /// Places a decoration in a room slot, `None` clears the slot. The decoration has to be one of
+    /// `unlocked_decorations` (see `ProfileData::unlocked_crew_quarters_decorations`).
+    pub fn set_crew_quarters_decoration(
+        &mut self,
+        decoration_index: i32,
+        decoration_data_path: Option<&str>,
+        unlocked_decorations: &[CrewQuartersDecorationData],
+    ) -> Result<()> {
+        if !(1..=MAX_CREW_QUARTERS_DECORATION_SLOTS).contains(&decoration_index) {
+            bail!(
+                "decoration slot must be between 1 and {}: {}",
+                MAX_CREW_QUARTERS_DECORATION_SLOTS,
+                decoration_index
+            );
+        }
+
+        let decoration_data_path = match decoration_data_path {
+            Some(decoration_data_path) => crew_quarters_decorations()
+                .into_iter()
+                .find(|d| {
+                    d.decoration_data_path
+                        .eq_ignore_ascii_case(decoration_data_path)
+                })
+                .map(|d| d.decoration_data_path)
+                .with_context(|| {
+                    format!("unknown crew quarters decoration: {}", decoration_data_path)
+                })?,
+            None => EMPTY_CREW_QUARTERS_DECORATION.to_owned(),
+        };
+
+        if decoration_data_path != EMPTY_CREW_QUARTERS_DECORATION
+            && !unlocked_decorations
+                .iter()
+                .any(|d| d.decoration_data_path == decoration_data_path)
+        {
+            bail!(
+                "the decoration {} has not been unlocked in the profile",
+                crew_quarters_decoration_name(&decoration_data_path)
+            );
+        }
+
+        let decorations = &mut self.character.mut_crew_quarters_room().decorations;
+
+        match decorations
+            .iter_mut()
+            .find(|d| d.decoration_index == decoration_index)
+        {
+            Some(decoration) => decoration.decoration_data_path = decoration_data_path,
+            None => {
+                let mut decoration = CrewQuartersDecorationSaveData::new();
+                decoration.decoration_index = decoration_index;
+                decoration.decoration_data_path = decoration_data_path;
+
+                decorations.push(decoration);
+                decorations.sort_by_key(|d| d.decoration_index);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn gun_rack_items(&self) -> &Vec<GunRackItemData> {
+        &self.gun_rack_items
+    }
+
+    pub fn remove_gun_rack_item(&mut self, index: usize) {
+        if let Some(rack_item) = self.gun_rack_items.get(index) {
+            let slot_asset_path = rack_item.slot.get_serializations()[0];
+
+            self.character
+                .mut_crew_quarters_gun_rack()
+                .rack_save_data
+                .retain(|r| !r.slot_asset_path.eq_ignore_ascii_case(slot_asset_path));
+
+            self.gun_rack_items.remove(index);
+        }
+    }
+
+    fn gun_rack_slot_for_item(
+        &self,
+        item: &Bl3Item,
+        ignore_index: Option<usize>,
+    ) -> Result<GunRackSlot> {
+        let slot = GunRackSlot::from_item(item).with_context(|| {
+            format!(
+                "item can't be placed on the gun rack: {}",
+                item.balance_part()
+                    .short_ident
+                    .as_deref()
+                    .unwrap_or(&item.balance_part().ident)
+            )
+        })?;
+
+        let ignored_slot = ignore_index
+            .and_then(|i| self.gun_rack_items.get(i))
+            .map(|r| r.slot);
+
+        // Items which couldn't be read are left out of gun_rack_items but still hold their slot
+        if ignored_slot != Some(slot)
+            && self
+                .character
+                .get_crew_quarters_gun_rack()
+                .rack_save_data
+                .iter()
+                .any(|r| GunRackSlot::from_str(&r.slot_asset_path).ok() == Some(slot))
+        {
+            bail!("gun rack slot already holds an item: {}", slot);
+        }
+
+        Ok(slot)
+    }
+
+    /// Adds an item to the empty rack slot matching its type.
+    pub fn add_gun_rack_item(&mut self, item: &Bl3Item) -> Result<()> {
+        let slot = self.gun_rack_slot_for_item(item, None)?;
+
+        let mut rack_item = CrewQuartersGunRackItemSaveData::new();
+        rack_item.encrypted_serial_number = item.get_serial_number(true)?;
+        rack_item.slot_asset_path = slot.get_serializations()[0].to_owned();
+
+        self.character
+            .mut_crew_quarters_gun_rack()
+            .rack_save_data
+            .push(rack_item);
+
+        self.gun_rack_items.push(GunRackItemData {
+            slot,
+            item: item.to_owned(),
+        });
+
+        Ok(())
+    }
+
+    /// Replaces the item at this index, the item may move to another slot if its type changed.
+    pub fn replace_gun_rack_item(&mut self, index: usize, new_item: &Bl3Item) -> Result<()> {
+        let old_slot = self
+            .gun_rack_items
+            .get(index)
+            .map(|r| r.slot)
+            .with_context(|| format!("failed to find gun rack item: {}", index))?;
+
+        let slot = self.gun_rack_slot_for_item(new_item, Some(index))?;
+
+        let encrypted_serial_number = new_item.get_serial_number(true)?;
+
+        let rack_item = self
+            .character
+            .mut_crew_quarters_gun_rack()
+            .rack_save_data
+            .iter_mut()
+            .find(|r| {
+                r.slot_asset_path
+                    .eq_ignore_ascii_case(old_slot.get_serializations()[0])
+            })
+            .with_context(|| format!("failed to find gun rack slot: {}", old_slot))?;
+
+        rack_item.encrypted_serial_number = encrypted_serial_number;
+        rack_item.slot_asset_path = slot.get_serializations()[0].to_owned();
+
+        self.gun_rack_items[index] = GunRackItemData {
+            slot,
+            item: new_item.to_owned(),
+        };
+
+        Ok(())
+    }
+
+    pub fn unlockable_inventory_slots(&self) -> &Vec<InventorySlotData> {
+        &self.unlockable_inventory_slots
+    }
+
+    pub fn remove_inventory_slot_if_exists(
+        &mut self,
+        inventory_slot: &InventorySlot,
+    ) -> Result<()> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        if let Some(slot) = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+        {
+            //Lock in character data
+            slot.enabled = false;
+        }
+
+        if let Some(current_slot) = self
+            .unlockable_inventory_slots
+            .iter_mut()
+            .find(|i| i.slot == *inventory_slot)
+        {
+            current_slot.unlocked = false;
+        } else {
+            self.unlockable_inventory_slots.push(InventorySlotData {
+                slot: inventory_slot.to_owned(),
+                unlocked: false,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn unlock_inventory_slot(&mut self, inventory_slot: &InventorySlot) -> Result<()> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        let slot = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+            .with_context(|| {
+                format!(
+                    "failed to find inventory slot: {}",
+                    inventory_slot.to_string()
+                )
+            })?;
+
+        //Unlock in character data
+        slot.enabled = true;
+
+        let class_mod_challenge = match self.player_class {
+            PlayerClass::BeastMaster => Challenge::BeastMasterClassModSlot,
+            PlayerClass::Gunner => Challenge::GunnerClassModSlot,
+            PlayerClass::Operative => Challenge::OperativeClassModSlot,
+            PlayerClass::Siren => Challenge::SirenClassModSlot,
+        };
+
+        let class_mod_challenge_path = class_mod_challenge.get_serializations()[0];
+
+        match inventory_slot {
+            InventorySlot::ClassMod => {
+                self.unlock_challenge_obj(class_mod_challenge_path, 1, 0)?;
+            }
+            InventorySlot::Artifact => {
+                self.unlock_challenge_obj(Challenge::ArtifactSlot.get_serializations()[0], 1, 0)?;
+            }
+            _ => (),
+        }
+
+        if let Some(current_slot) = self
+            .unlockable_inventory_slots
+            .iter_mut()
+            .find(|i| i.slot == *inventory_slot)
+        {
+            current_slot.unlocked = true;
+        } else {
+            self.unlockable_inventory_slots.push(InventorySlotData {
+                slot: inventory_slot.to_owned(),
+                unlocked: true,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn equipped_items(&self) -> Vec<EquippedItemData> {
+        self.character
+            .equipped_inventory_list
+            .iter()
+            .filter_map(|e| {
+                let slot = InventorySlot::from_str(&e.slot_data_path).ok()?;
+
+                let inventory_index = usize::try_from(e.inventory_list_index).ok()?;
+
+                let pickup_order_index = self
+                    .character
+                    .inventory_items
+                    .get(inventory_index)?
+                    .pickup_order_index;
+
+                let item = self.inventory_items.get(inventory_index)?.clone();
+
+                Some(EquippedItemData {
+                    slot,
+                    inventory_index,
+                    pickup_order_index,
+                    item,
+                    trinket_data_path: e.trinket_data_path.clone(),
+                    is_active_weapon: self
+                        .character
+                        .active_weapon_list
+                        .contains(&e.inventory_list_index),
+                })
+            })
+            .collect()
+    }
+
+    pub fn equipped_slot(&self, inventory_index: usize) -> Option<InventorySlot> {
+        self.character
+            .equipped_inventory_list
+            .iter()
+            .find(|e| e.inventory_list_index == inventory_index as i32)
+            .and_then(|e| InventorySlot::from_str(&e.slot_data_path).ok())
+    }
+
+    pub fn equip_item(
+        &mut self,
+        inventory_slot: &InventorySlot,
+        inventory_index: usize,
+    ) -> Result<()> {
+        let item = self
+            .inventory_items
+            .get(inventory_index)
+            .with_context(|| format!("failed to find inventory item: {}", inventory_index))?;
+
+        // Items which couldn't be read have a default item type which says nothing about the item
+        if item.is_opaque() {
+            bail!("this item could not be read and cannot be equipped");
+        }
+
+        if !inventory_slot.accepts(&item.item_type) {
+            bail!(
+                "{} cannot be equipped in the {} slot",
+                item.item_type,
+                inventory_slot
+            );
+        }
+
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        let slot = self
+            .character
+            .equipped_inventory_list
+            .iter()
+            .find(|s| s.slot_data_path == slot_path)
+            .with_context(|| format!("failed to find inventory slot: {}", inventory_slot))?;
+
+        if !slot.enabled {
+            bail!("the {} slot has not been unlocked", inventory_slot);
+        }
+
+        let pickup_order_index = self
+            .character
+            .inventory_items
+            .get(inventory_index)
+            .map(|i| i.pickup_order_index)
+            .with_context(|| format!("failed to find inventory item: {}", inventory_index))?;
+
+        // Items added by the editor can share a pickup order with another item, the equipped item
+        // is given its own so the pickup order points at one item
+        let shares_pickup_order = self
+            .character
+            .inventory_items
+            .iter()
+            .enumerate()
+            .any(|(i, item)| i != inventory_index && item.pickup_order_index == pickup_order_index);
+
+        if shares_pickup_order {
+            let next_pickup_order_index = self
+                .character
+                .inventory_items
+                .iter()
+                .map(|i| i.pickup_order_index)
+                .max()
+                .unwrap_or_default()
+                + 1;
+
+            self.character.inventory_items[inventory_index].pickup_order_index =
+                next_pickup_order_index;
+        }
+
+        let inventory_list_index = inventory_index as i32;
+
+        for equipped in self.character.equipped_inventory_list.iter_mut() {
+            if equipped.slot_data_path == slot_path {
+                equipped.inventory_list_index = inventory_list_index;
+
+                // Trinkets can only be attached to weapons
+                if !inventory_slot.is_weapon() {
+                    equipped.trinket_data_path.clear();
+                }
+            } else if equipped.inventory_list_index == inventory_list_index {
+                // An item can only be equipped in one slot at a time
+                equipped.inventory_list_index = -1;
+            }
+        }
+
+        self.update_active_weapons();
+
+        Ok(())
+    }
+
+    /// Sets the trinket of the weapon equipped in `inventory_slot`, the trinket has to be one of
+    /// `unlocked_weapon_trinkets` (see `ProfileData::unlocked_weapon_trinkets`).
+    pub fn set_equipped_trinket(
+        &mut self,
+        inventory_slot: &InventorySlot,
+        trinket_path: Option<&str>,
+        unlocked_weapon_trinkets: &[GameDataKv],
+    ) -> Result<()> {
+        if !inventory_slot.is_weapon() {
+            bail!("trinkets can only be attached to weapons");
+        }
+
+        if let Some(trinket_path) = trinket_path {
+            if !PROFILE_WEAPON_TRINKETS
+                .iter()
+                .any(|t| t.ident == trinket_path)
+            {
+                bail!("unknown weapon trinket: {}", trinket_path);
+            }
+
+            if !unlocked_weapon_trinkets
+                .iter()
+                .any(|t| t.ident == trinket_path)
+            {
+                bail!(
+                    "the weapon trinket {} has not been unlocked in the profile",
+                    trinket_path
+                );
+            }
+        }
+
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        let slot = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+            .with_context(|| format!("failed to find inventory slot: {}", inventory_slot))?;
+
+        if slot.inventory_list_index < 0 {
+            bail!("there is no weapon equipped in the {} slot", inventory_slot);
+        }
+
+        slot.trinket_data_path = trinket_path.unwrap_or_default().to_owned();
+
+        Ok(())
+    }
+
+    pub fn unequip(&mut self, inventory_slot: &InventorySlot) -> Result<()> {
+        let slot_path = inventory_slot.get_serializations()[0];
+
+        let slot = self
+            .character
+            .equipped_inventory_list
+            .iter_mut()
+            .find(|s| s.slot_data_path == slot_path)
+            .with_context(|| format!("failed to find inventory slot: {}", inventory_slot))?;
+
+        slot.inventory_list_index = -1;
+
+        self.update_active_weapons();
+
+        Ok(())
+    }
+
+    /// Makes sure the active weapons still point at an equipped weapon, otherwise the first
+    /// equipped weapon is used.
+    fn update_active_weapons(&mut self) {
+        let equipped_weapons = self
+            .character
+            .equipped_inventory_list
+            .iter()
+            .filter(|e| {
+                InventorySlot::from_str(&e.slot_data_path)
+                    .map(|s| s.is_weapon())
+                    .unwrap_or(false)
+            })
+            .map(|e| e.inventory_list_index)
+            .filter(|i| *i >= 0)
+            .collect::<Vec<_>>();
+
+        for active_weapon in self.character.active_weapon_list.iter_mut() {
+            if !equipped_weapons.contains(active_weapon) {
+                *active_weapon = equipped_weapons.first().copied().unwrap_or(-1);
+            }
+        }
+    }
+
+    /// Keeps the equipped slots pointing at the same items after an item has been inserted
+    /// (`shift` of 1) or removed (`shift` of -1) at `index`.
+    fn shift_equipped_indexes(&mut self, index: usize, shift: i32) {
+        let index = index as i32;
+
+        let shift_index = |i: &mut i32| {
+            if shift < 0 && *i == index {
+                *i = -1;
+            } else if *i >= index {
+                *i += shift;
+            }
+        };
+
+        self.character
+            .equipped_inventory_list
+            .iter_mut()
+            .for_each(|e| shift_index(&mut e.inventory_list_index));
+
+        self.character
+            .active_weapon_list
+            .iter_mut()
+            .for_each(shift_index);
+
+        self.update_active_weapons();
+    }
+
+    pub fn sdu_slots(&self) -> &Vec<SaveSduSlotData> {
+        &self.sdu_slots
     }
 
     pub fn set_sdu_slot(&mut self, sdu_slot: &SaveSduSlot, level: i32) {
@@ -911,12 +2453,24 @@ impl CharacterData {
 
         let item_serial_number = item.get_serial_number(true)?;
 
+        // The rest of the balance state is read from the serial, only the customization parts
+        // need to be kept here
+        let development_save_data = if item.customization_parts().is_empty() {
+            Default::default()
+        } else {
+            Some(InventoryBalanceStateInitializationData {
+                customization_part_list: RepeatedField::from_slice(item.customization_parts()),
+                ..Default::default()
+            })
+            .into()
+        };
+
         let res = OakInventoryItemSaveGameData {
             item_serial_number,
             pickup_order_index,
             flags,
-            weapon_skin_path: "".to_string(),
-            development_save_data: Default::default(),
+            weapon_skin_path: item.weapon_skin_path().unwrap_or_default().to_owned(),
+            development_save_data,
             unknown_fields: Default::default(),
             cached_size: Default::default(),
         };
@@ -924,9 +2478,49 @@ impl CharacterData {
         Ok(res)
     }
 
+    /// Sets the weapon skin of an inventory item, the skin has to be one of `unlocked_weapon_skins`
+    /// (see `ProfileData::unlocked_weapon_skins`).
+    pub fn set_inventory_item_weapon_skin(
+        &mut self,
+        item_index: usize,
+        weapon_skin_path: Option<&str>,
+        unlocked_weapon_skins: &[GameDataKv],
+    ) -> Result<()> {
+        if let Some(weapon_skin_path) = weapon_skin_path {
+            if !unlocked_weapon_skins
+                .iter()
+                .any(|s| s.ident == weapon_skin_path)
+            {
+                bail!(
+                    "the weapon skin {} has not been unlocked in the profile",
+                    weapon_skin_path
+                );
+            }
+        }
+
+        let oak_item = self
+            .character
+            .inventory_items
+            .get_mut(item_index)
+            .with_context(|| format!("failed to find inventory item: {}", item_index))?;
+
+        let item = self
+            .inventory_items
+            .get_mut(item_index)
+            .with_context(|| format!("failed to find inventory item: {}", item_index))?;
+
+        item.set_weapon_skin_path(weapon_skin_path)?;
+
+        oak_item.weapon_skin_path = weapon_skin_path.unwrap_or_default().to_owned();
+
+        Ok(())
+    }
+
     pub fn remove_inventory_item(&mut self, index: usize) {
         if index < self.character.inventory_items.len() {
             self.character.inventory_items.remove(index);
+
+            self.shift_equipped_indexes(index, -1);
         }
 
         if index < self.inventory_items.len() {
@@ -958,6 +2552,8 @@ impl CharacterData {
 
         self.inventory_items.insert(item_index, item.to_owned());
 
+        self.shift_equipped_indexes(item_index, 1);
+
         Ok(())
     }
 
@@ -967,10 +2563,20 @@ impl CharacterData {
         item_index: usize,
         new_item: &Bl3Item,
     ) -> Result<()> {
-        self.insert_inventory_item(pickup_order_index, item_index, new_item)?;
+        let new_oak_item = Self::create_inventory_item(pickup_order_index, new_item, true)?;
+
+        // Replace in place so anything equipped in this slot stays equipped
+        let oak_item = self
+            .character
+            .inventory_items
+            .get_mut(item_index)
+            .with_context(|| format!("failed to find inventory item: {}", item_index))?;
 
-        // Remove old item
-        self.remove_inventory_item(item_index + 1);
+        *oak_item = new_oak_item;
+
+        if let Some(item) = self.inventory_items.get_mut(item_index) {
+            *item = new_item.to_owned();
+        }
 
         Ok(())
     }
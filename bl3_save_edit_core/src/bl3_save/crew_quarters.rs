@@ -0,0 +1,226 @@
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+use crate::bl3_item::{Bl3Item, ItemType, WeaponType};
+use crate::bl3_save::player_class::PlayerClass;
+use crate::resources::PROFILE_ROOM_DECORATIONS;
+
+pub const MAX_CREW_QUARTERS_DECORATION_SLOTS: i32 = 3;
+
+pub const EMPTY_CREW_QUARTERS_DECORATION: &str = "/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_0_DEFAULT.PlayerQuarterDecoration_0_DEFAULT";
+
+const ROOM_DECORATION_PICKUP_PREFIX: &str = "/Game/Pickups/RoomDecoration/RoomDecoration_";
+const ROOM_DECORATION_DATA_PREFIX: &str =
+    "/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_";
+
+// Room data for each class, the room the character's quarters are built from.
+pub const CREW_QUARTERS_ROOMS: [(&str, &str); 4] = [
+    (
+        "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_BeastMaster.PlayerQuarterActorData_BeastMaster",
+        "Beastmaster",
+    ),
+    (
+        "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Gunner.PlayerQuarterActorData_Gunner",
+        "Gunner",
+    ),
+    (
+        "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Operative.PlayerQuarterActorData_Operative",
+        "Operative",
+    ),
+    (
+        "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Siren.PlayerQuarterActorData_Siren",
+        "Siren",
+    ),
+];
+
+#[derive(
+    Debug, Display, EnumString, EnumMessage, EnumIter, Eq, PartialEq, Ord, PartialOrd, Copy, Clone,
+)]
+pub enum GunRackSlot {
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Assault.GunRack_Slot_Assault",
+        to_string = "步枪"
+    )]
+    Assault,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Shotgun.GunRack_Slot_Shotgun",
+        to_string = "霰弹枪"
+    )]
+    Shotgun,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_SMG.GunRack_Slot_SMG",
+        to_string = "冲锋枪"
+    )]
+    Smg,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Sniper.GunRack_Slot_Sniper",
+        to_string = "狙击枪"
+    )]
+    Sniper,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Heavy.GunRack_Slot_Heavy",
+        to_string = "重型武器"
+    )]
+    Heavy,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Shield.GunRack_Slot_Shield",
+        to_string = "护盾"
+    )]
+    Shield,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Grenade.GunRack_Slot_Grenade",
+        to_string = "手榴弹模组"
+    )]
+    Grenade,
+    #[strum(
+        serialize = "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Artifact.GunRack_Slot_Artifact",
+        to_string = "神器"
+    )]
+    Artifact,
+}
+
+impl GunRackSlot {
+    /// The rack slot an item is displayed in, pistols and class mods have no slot on the rack.
+    pub fn from_item(item: &Bl3Item) -> Option<Self> {
+        match item.item_type {
+            ItemType::Shield => Some(Self::Shield),
+            ItemType::GrenadeMod => Some(Self::Grenade),
+            ItemType::Artifact => Some(Self::Artifact),
+            ItemType::Weapon => {
+                match item
+                    .item_parts
+                    .as_ref()
+                    .and_then(|p| p.weapon_type.as_ref())?
+                {
+                    WeaponType::Ar => Some(Self::Assault),
+                    WeaponType::Shotgun => Some(Self::Shotgun),
+                    WeaponType::Smg => Some(Self::Smg),
+                    WeaponType::Sniper => Some(Self::Sniper),
+                    WeaponType::Heavy => Some(Self::Heavy),
+                    WeaponType::Pistol => None,
+                }
+            }
+            ItemType::ClassMod | ItemType::Other => None,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct GunRackItemData {
+    pub slot: GunRackSlot,
+    pub item: Bl3Item,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct CrewQuartersDecorationData {
+    pub decoration_index: i32,
+    pub name: String,
+    pub decoration_data_path: String,
+}
+
+impl CrewQuartersDecorationData {
+    pub fn new(decoration_index: i32, decoration_data_path: &str) -> Self {
+        Self {
+            decoration_index,
+            name: crew_quarters_decoration_name(decoration_data_path),
+            decoration_data_path: decoration_data_path.to_owned(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.decoration_data_path.is_empty()
+            || self
+                .decoration_data_path
+                .eq_ignore_ascii_case(EMPTY_CREW_QUARTERS_DECORATION)
+    }
+}
+
+/// Decorations which can be placed in the room, the profile unlocks decorations by their pickup
+/// and the room stores the matching decoration data so only base game pickups can be mapped.
+pub fn crew_quarters_decorations() -> Vec<CrewQuartersDecorationData> {
+    let mut decorations = PROFILE_ROOM_DECORATIONS
+        .iter()
+        .filter_map(|d| {
+            let decoration_data_path = decoration_data_path(d.ident)?;
+
+            Some(CrewQuartersDecorationData {
+                decoration_index: 0,
+                name: d.name.to_owned(),
+                decoration_data_path,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    decorations.sort_by(|a, b| a.name.cmp(&b.name));
+
+    decorations
+}
+
+pub fn crew_quarters_decoration_name(decoration_data_path: &str) -> String {
+    if decoration_data_path.is_empty()
+        || decoration_data_path.eq_ignore_ascii_case(EMPTY_CREW_QUARTERS_DECORATION)
+    {
+        return "无".to_owned();
+    }
+
+    crew_quarters_decorations()
+        .into_iter()
+        .find(|d| {
+            d.decoration_data_path
+                .eq_ignore_ascii_case(decoration_data_path)
+        })
+        .map(|d| d.name)
+        .unwrap_or_else(|| {
+            decoration_data_path
+                .rsplit('.')
+                .next()
+                .unwrap_or(decoration_data_path)
+                .to_owned()
+        })
+}
+
+/// The rooms a character can use, the room of its own class and any room unlocked in the profile
+/// (see `ProfileData::unlocked_crew_quarters_rooms`). No profile with unlocked rooms has been seen
+/// so those are matched by their room data.
+pub fn available_crew_quarters_rooms(
+    player_class: PlayerClass,
+    unlocked_rooms: &[String],
+) -> Vec<(&'static str, &'static str)> {
+    CREW_QUARTERS_ROOMS
+        .iter()
+        .filter(|(path, name)| {
+            *name == player_class.to_string()
+                || unlocked_rooms.iter().any(|r| r.eq_ignore_ascii_case(path))
+        })
+        .copied()
+        .collect()
+}
+
+pub fn crew_quarters_room_name(room_data_path: &str) -> String {
+    CREW_QUARTERS_ROOMS
+        .iter()
+        .find(|(path, _)| path.eq_ignore_ascii_case(room_data_path))
+        .map(|(_, name)| (*name).to_owned())
+        .unwrap_or_else(|| {
+            room_data_path
+                .rsplit('.')
+                .next()
+                .unwrap_or(room_data_path)
+                .to_owned()
+        })
+}
+
+/// The decoration data placed in the room for a decoration pickup unlocked in the profile.
+pub(crate) fn decoration_data_path(room_decoration_path: &str) -> Option<String> {
+    let (asset, _) = room_decoration_path
+        .strip_prefix(ROOM_DECORATION_PICKUP_PREFIX)?
+        .split_once('.')?;
+
+    if asset.is_empty() || !asset.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(format!(
+        "{}{}.PlayerQuarterDecoration_{}",
+        ROOM_DECORATION_DATA_PREFIX, asset, asset
+    ))
+}
@@ -0,0 +1,96 @@
+use strum::{Display, EnumIter};
+
+use crate::protos::oak_save::EchoLogSaveGameData;
+use crate::resources::ECHO_LOGS;
+
+#[derive(Debug, Display, EnumIter, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub enum EchoLogContent {
+    #[strum(to_string = "本体")]
+    BaseGame,
+    #[strum(to_string = "活动")]
+    Events,
+    #[strum(to_string = "DLC1 - 莫克西的大劫案")]
+    MoxxisHeist,
+    #[strum(to_string = "DLC2 - 枪械、爱与触手")]
+    GunsLoveAndTentacles,
+    #[strum(to_string = "DLC3 - 血之赏金")]
+    BountyOfBlood,
+    #[strum(to_string = "DLC4 - 疯子克里格")]
+    PsychoKrieg,
+    #[strum(to_string = "设计师剪辑版")]
+    DesignersCut,
+    #[strum(to_string = "导演剪辑版")]
+    DirectorsCut,
+}
+
+impl std::default::Default for EchoLogContent {
+    fn default() -> Self {
+        Self::BaseGame
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct EchoLogData {
+    pub content: EchoLogContent,
+    pub name: String,
+    pub echo_log_path: String,
+    pub unlocked: bool,
+    pub seen: bool,
+}
+
+impl EchoLogData {
+    pub fn from_echo_log_path(echo_log_path: &str) -> Self {
+        Self {
+            content: echo_log_content(echo_log_path),
+            name: echo_log_name(echo_log_path),
+            echo_log_path: echo_log_path.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_echo_log(echo_log: &EchoLogSaveGameData) -> Self {
+        Self {
+            unlocked: true,
+            seen: echo_log.has_been_seen_in_log,
+            ..Self::from_echo_log_path(&echo_log.echo_log_path)
+        }
+    }
+}
+
+const CONTENT_PREFIXES: [(&str, EchoLogContent); 7] = [
+    ("/game/patchdlc/dandelion/", EchoLogContent::MoxxisHeist),
+    (
+        "/game/patchdlc/hibiscus/",
+        EchoLogContent::GunsLoveAndTentacles,
+    ),
+    ("/game/patchdlc/geranium/", EchoLogContent::BountyOfBlood),
+    ("/game/patchdlc/alisma/", EchoLogContent::PsychoKrieg),
+    ("/game/patchdlc/ixora/", EchoLogContent::DesignersCut),
+    ("/game/patchdlc/ixora2/", EchoLogContent::DirectorsCut),
+    ("/game/patchdlc/event", EchoLogContent::Events),
+];
+
+pub fn echo_log_content(echo_log_path: &str) -> EchoLogContent {
+    let echo_log_lower = echo_log_path.to_lowercase();
+
+    CONTENT_PREFIXES
+        .iter()
+        .find(|(prefix, _)| echo_log_lower.starts_with(prefix))
+        .map(|(_, content)| *content)
+        .unwrap_or_default()
+}
+
+pub fn echo_log_name(echo_log_path: &str) -> String {
+    ECHO_LOGS
+        .iter()
+        .find(|e| e.ident.eq_ignore_ascii_case(echo_log_path))
+        .map(|e| e.name.to_owned())
+        .unwrap_or_else(|| {
+            // Fall back to the asset name for logs which are not in the catalogue
+            echo_log_path
+                .rsplit('.')
+                .next()
+                .unwrap_or(echo_log_path)
+                .to_owned()
+        })
+}
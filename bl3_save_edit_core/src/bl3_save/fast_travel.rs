@@ -0,0 +1,455 @@
+use strum::{Display, EnumIter};
+
+use crate::game_data::FAST_TRAVEL_STATIONS;
+use crate::resources::FAST_TRAVEL;
+
+#[derive(Debug, Display, EnumIter, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub enum FastTravelGroup {
+    #[strum(to_string = "避难所三号")]
+    Sanctuary,
+    #[strum(to_string = "潘多拉")]
+    Pandora,
+    #[strum(to_string = "普罗米西亚")]
+    Promethea,
+    #[strum(to_string = "伊甸6号")]
+    Eden6,
+    #[strum(to_string = "内克罗塔菲欧")]
+    Nekrotafeyo,
+    #[strum(to_string = "试炼场")]
+    ProvingGrounds,
+    #[strum(to_string = "讨伐战")]
+    Takedowns,
+    #[strum(to_string = "活动")]
+    Events,
+    #[strum(to_string = "DLC1 - 莫克西的大劫案")]
+    MoxxisHeist,
+    #[strum(to_string = "DLC2 - 枪械、爱与触手")]
+    GunsLoveAndTentacles,
+    #[strum(to_string = "DLC3 - 血之赏金")]
+    BountyOfBlood,
+    #[strum(to_string = "DLC4 - 疯子克里格")]
+    PsychoKrieg,
+    #[strum(to_string = "设计师剪辑版")]
+    DesignersCut,
+    #[strum(to_string = "导演剪辑版")]
+    DirectorsCut,
+    #[strum(to_string = "其他")]
+    Other,
+}
+
+impl std::default::Default for FastTravelGroup {
+    fn default() -> Self {
+        Self::Other
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct FastTravelStationData {
+    pub group: FastTravelGroup,
+    pub name: String,
+    pub active_travel_station_name: String,
+    pub unlocked: bool,
+    pub blacklisted: bool,
+}
+
+impl FastTravelStationData {
+    pub fn from_station_name(active_travel_station_name: &str) -> Self {
+        let short_name = station_short_name(active_travel_station_name);
+
+        let name = match station_location_name(active_travel_station_name) {
+            Some(location) => format!("{} ({})", location, short_name),
+            None => short_name.to_owned(),
+        };
+
+        Self {
+            group: fast_travel_group(active_travel_station_name),
+            name,
+            active_travel_station_name: active_travel_station_name.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+// Location name, the level it is in (used for discovery data) and where it is shown in the fast travel menu.
+const FAST_TRAVEL_LOCATIONS: [(&str, &str, FastTravelGroup); 63] = [
+    (
+        "Sanctuary",
+        "/Game/Maps/Sanctuary3/Sanctuary3_P",
+        FastTravelGroup::Sanctuary,
+    ),
+    (
+        "Slaughterstar 3000",
+        "/Game/Maps/Slaughters/TechSlaughter/TechSlaughter_P",
+        FastTravelGroup::Sanctuary,
+    ),
+    (
+        "Covenant Pass",
+        "/Game/Maps/Zone_0/Recruitment/Recruitment_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Droughts",
+        "/Game/Maps/Zone_0/Prologue/Prologue_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Ascension Bluff",
+        "/Game/Maps/Zone_0/Sacrifice/Sacrifice_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Devil's Razor",
+        "/Game/Maps/Zone_3/Desert/Desert_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Splinterlands",
+        "/Game/Maps/Zone_3/Motorcade/Motorcade_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Carnivora",
+        "/Game/Maps/Zone_3/MotorcadeFestival/MotorcadeFestival_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Guts of Carnivora",
+        "/Game/Maps/Zone_3/MotorcadeInterior/MotorcadeInterior_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Konrad's Hold",
+        "/Game/Maps/Zone_3/Mine/Mine_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Sandblast Scar",
+        "/Game/Maps/Zone_3/Convoy/Convoy_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Cathedral of the Twin Gods",
+        "/Game/Maps/Zone_3/DesertVault/Desertvault_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Great Vault",
+        "/Game/Maps/Zone_3/DesertBoss/DesertBoss_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Slaughter Shaft",
+        "/Game/Maps/Slaughters/COVSlaughter/COVSlaughter_P",
+        FastTravelGroup::Pandora,
+    ),
+    (
+        "Meridian Outskirts",
+        "/Game/Maps/Zone_1/Outskirts/Outskirts_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Meridian Metroplex",
+        "/Game/Maps/Zone_1/City/City_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Lectra City",
+        "/Game/Maps/Zone_1/Towers/Towers_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Athenas",
+        "/Game/Maps/Zone_1/Monastery/Monastery_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Skywell-27",
+        "/Game/Maps/Zone_1/OrbitalPlatform/OrbitalPlatform_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Atlas HQ",
+        "/Game/Maps/Zone_1/AtlasHQ/AtlasHQ_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Neon Arterial",
+        "/Game/Maps/Zone_1/CityVault/CityVault_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Forgotten Basilica",
+        "/Game/Maps/Zone_1/CityBoss/CityBoss_P",
+        FastTravelGroup::Promethea,
+    ),
+    (
+        "Floodmoor Basin",
+        "/Game/Maps/Zone_2/Wetlands/Wetlands_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Ambermire",
+        "/Game/Maps/Zone_2/MarshFields/MarshFields_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Jakobs Estate",
+        "/Game/Maps/Zone_2/Mansion/Mansion_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Voracious Canopy",
+        "/Game/Maps/Zone_2/Watership/Watership_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Anvil",
+        "/Game/Maps/Zone_2/Prison/Prison_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Blackbarrel Cellars",
+        "/Game/Maps/Zone_2/WetlandsVault/WetlandsVault_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Floating Tomb",
+        "/Game/Maps/Zone_2/WetlandsBoss/WetlandsBoss_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Cistern of Slaughter",
+        "/Game/Maps/Slaughters/CreatureSlaughter/CreatureSlaughter_P",
+        FastTravelGroup::Eden6,
+    ),
+    (
+        "Desolation's Edge",
+        "/Game/Maps/Zone_4/Desolate/Desolate_P",
+        FastTravelGroup::Nekrotafeyo,
+    ),
+    (
+        "Tazendeer Ruins",
+        "/Game/Maps/Zone_4/Beach/Beach_P",
+        FastTravelGroup::Nekrotafeyo,
+    ),
+    (
+        "Pyre of Stars",
+        "/Game/Maps/Zone_4/Crypt/Crypt_P",
+        FastTravelGroup::Nekrotafeyo,
+    ),
+    (
+        "Destroyer's Rift",
+        "/Game/Maps/Zone_0/FinalBoss/FinalBoss_P",
+        FastTravelGroup::Nekrotafeyo,
+    ),
+    (
+        "Gradient of Dawn (Survival)",
+        "/Game/Maps/ProvingGrounds/Trial1/ProvingGrounds_Trial1_P",
+        FastTravelGroup::ProvingGrounds,
+    ),
+    (
+        "Skydrowned Pulpit (Fervor)",
+        "/Game/Maps/ProvingGrounds/Trial4/ProvingGrounds_Trial4_P",
+        FastTravelGroup::ProvingGrounds,
+    ),
+    (
+        "Ghostlight Beacon (Cunning)",
+        "/Game/Maps/ProvingGrounds/Trial5/ProvingGrounds_Trial5_P",
+        FastTravelGroup::ProvingGrounds,
+    ),
+    (
+        "Hall Obsidian (Supremacy)",
+        "/Game/Maps/ProvingGrounds/Trial6/ProvingGrounds_Trial6_P",
+        FastTravelGroup::ProvingGrounds,
+    ),
+    (
+        "Precipice Anchor (Discipline)",
+        "/Game/Maps/ProvingGrounds/Trial7/ProvingGrounds_Trial7_P",
+        FastTravelGroup::ProvingGrounds,
+    ),
+    (
+        "Wayward Tether (Instinct)",
+        "/Game/Maps/ProvingGrounds/Trial8/ProvingGrounds_Trial8_P",
+        FastTravelGroup::ProvingGrounds,
+    ),
+    (
+        "Midnight's Cairn (Maliwan Takedown)",
+        "/Game/PatchDLC/Raid1/Maps/Raid/Raid_P",
+        FastTravelGroup::Takedowns,
+    ),
+    (
+        "Minos Prime / The Shattered Tribunal",
+        "/Game/PatchDLC/Takedown2/Maps/GuardianTakedown_P",
+        FastTravelGroup::Takedowns,
+    ),
+    (
+        "Heck Hole",
+        "/Game/PatchDLC/BloodyHarvest/Maps/Seasons/BloodyHarvest/BloodyHarvest_P",
+        FastTravelGroup::Events,
+    ),
+    (
+        "Villa Ultraviolet",
+        "/Game/PatchDLC/Event2/Maps/Cartels_P",
+        FastTravelGroup::Events,
+    ),
+    (
+        "Grand Opening",
+        "/Dandelion/Maps/CasinoIntro/CasinoIntro_P",
+        FastTravelGroup::MoxxisHeist,
+    ),
+    (
+        "Spendopticon",
+        "/Dandelion/Maps/Strip/Strip_P",
+        FastTravelGroup::MoxxisHeist,
+    ),
+    (
+        "Impound Deluxe",
+        "/Dandelion/Maps/Impound/Impound_P",
+        FastTravelGroup::MoxxisHeist,
+    ),
+    (
+        "Compactor",
+        "/Dandelion/Maps/Trashtown/Trashtown_P",
+        FastTravelGroup::MoxxisHeist,
+    ),
+    (
+        "Jack's Secret",
+        "/Dandelion/Maps/Core/Core_P",
+        FastTravelGroup::MoxxisHeist,
+    ),
+    (
+        "VIP Tower",
+        "/Dandelion/Maps/TowerLair/TowerLair_P",
+        FastTravelGroup::MoxxisHeist,
+    ),
+    (
+        "Skittermaw Basin",
+        "/Hibiscus/Maps/Lake/Lake_P",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    (
+        "Lodge",
+        "/Hibiscus/Maps/Bar/Bar_P",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    (
+        "Cursehaven",
+        "/Hibiscus/Maps/Village/Village_P",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    (
+        "Dustbound Archives",
+        "/Hibiscus/Maps/Archive/Archive_P",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    (
+        "Cankerwood",
+        "/Hibiscus/Maps/Woods/Woods_P",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    (
+        "Negul Neshai",
+        "/Hibiscus/Maps/Camp/Camp_P",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    (
+        "Heart's Desire",
+        "/Hibiscus/Maps/Venue/Venue_P",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    (
+        "Vestige",
+        "/Geranium/Maps/Town/Town_P",
+        FastTravelGroup::BountyOfBlood,
+    ),
+    (
+        "The Blastplains",
+        "/Geranium/Maps/Frontier/Frontier_P",
+        FastTravelGroup::BountyOfBlood,
+    ),
+    (
+        "Ashfall Peaks",
+        "/Geranium/Maps/Lodge/Lodge_P",
+        FastTravelGroup::BountyOfBlood,
+    ),
+    (
+        "Obsidian Forest",
+        "/Geranium/Maps/Forest/Forest_P",
+        FastTravelGroup::BountyOfBlood,
+    ),
+    (
+        "Bloodsun Canyon",
+        "/Geranium/Maps/Facility/Facility_P",
+        FastTravelGroup::BountyOfBlood,
+    ),
+    (
+        "Crater's Edge",
+        "/Geranium/Maps/CraterBoss/CraterBoss_P",
+        FastTravelGroup::BountyOfBlood,
+    ),
+];
+
+// DLC stations are grouped by the content pack they are in, the locations above don't cover every DLC map.
+const DLC_GROUPS: [(&str, FastTravelGroup); 8] = [
+    ("/game/patchdlc/dandelion/", FastTravelGroup::MoxxisHeist),
+    (
+        "/game/patchdlc/hibiscus/",
+        FastTravelGroup::GunsLoveAndTentacles,
+    ),
+    ("/game/patchdlc/geranium/", FastTravelGroup::BountyOfBlood),
+    ("/game/patchdlc/alisma/", FastTravelGroup::PsychoKrieg),
+    ("/game/patchdlc/ixora/", FastTravelGroup::DesignersCut),
+    ("/game/patchdlc/ixora2/", FastTravelGroup::DirectorsCut),
+    ("/game/patchdlc/raid1/", FastTravelGroup::Takedowns),
+    ("/game/patchdlc/takedown2/", FastTravelGroup::Takedowns),
+];
+
+fn station_short_name(active_travel_station_name: &str) -> &str {
+    active_travel_station_name
+        .rsplit('/')
+        .next()
+        .and_then(|s| s.split('.').next())
+        .unwrap_or(active_travel_station_name)
+}
+
+fn station_location_name(active_travel_station_name: &str) -> Option<&'static str> {
+    FAST_TRAVEL
+        .iter()
+        .find(|ft| ft.ident.eq_ignore_ascii_case(active_travel_station_name))
+        .map(|ft| ft.name)
+}
+
+pub fn fast_travel_group(active_travel_station_name: &str) -> FastTravelGroup {
+    let station_lower = active_travel_station_name.to_lowercase();
+
+    DLC_GROUPS
+        .iter()
+        .find(|(prefix, _)| station_lower.starts_with(prefix))
+        .map(|(_, group)| *group)
+        .or_else(|| {
+            let location = station_location_name(active_travel_station_name)?;
+
+            FAST_TRAVEL_LOCATIONS
+                .iter()
+                .find(|(name, _, _)| *name == location)
+                .map(|(_, _, group)| *group)
+        })
+        .unwrap_or_default()
+}
+
+/// The level a station is in, `None` when the level is not known.
+pub fn fast_travel_level_name(active_travel_station_name: &str) -> Option<&'static str> {
+    let location = station_location_name(active_travel_station_name)?;
+
+    FAST_TRAVEL_LOCATIONS
+        .iter()
+        .find(|(name, _, _)| *name == location)
+        .map(|(_, level_name, _)| *level_name)
+}
+
+/// Every station which can be shown in the fast travel menu, level transitions and respawn points are not included.
+///
+/// The names use the same casing the game writes, `FAST_TRAVEL` only has lowercase names.
+pub fn fast_travel_stations() -> Vec<&'static str> {
+    FAST_TRAVEL_STATIONS.to_vec()
+}
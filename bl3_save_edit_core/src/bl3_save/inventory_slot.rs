@@ -1,11 +1,23 @@
 use strum::{Display, EnumMessage, EnumString};
 
+use crate::bl3_item::{Bl3Item, ItemType};
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub struct InventorySlotData {
     pub slot: InventorySlot,
     pub unlocked: bool,
 }
 
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct EquippedItemData {
+    pub slot: InventorySlot,
+    pub inventory_index: usize,
+    pub pickup_order_index: i32,
+    pub item: Bl3Item,
+    pub trinket_data_path: String,
+    pub is_active_weapon: bool,
+}
+
 #[derive(Debug, Display, EnumString, EnumMessage, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub enum InventorySlot {
     #[strum(
@@ -55,3 +67,39 @@ impl std::default::Default for InventorySlot {
         Self::Grenade
     }
 }
+
+impl InventorySlot {
+    pub const ALL: [InventorySlot; 8] = [
+        InventorySlot::Weapon1,
+        InventorySlot::Weapon2,
+        InventorySlot::Weapon3,
+        InventorySlot::Weapon4,
+        InventorySlot::Shield,
+        InventorySlot::Grenade,
+        InventorySlot::ClassMod,
+        InventorySlot::Artifact,
+    ];
+
+    pub fn is_weapon(&self) -> bool {
+        matches!(
+            self,
+            InventorySlot::Weapon1
+                | InventorySlot::Weapon2
+                | InventorySlot::Weapon3
+                | InventorySlot::Weapon4
+        )
+    }
+
+    pub fn accepts(&self, item_type: &ItemType) -> bool {
+        match self {
+            InventorySlot::Weapon1
+            | InventorySlot::Weapon2
+            | InventorySlot::Weapon3
+            | InventorySlot::Weapon4 => *item_type == ItemType::Weapon,
+            InventorySlot::Shield => *item_type == ItemType::Shield,
+            InventorySlot::Grenade => *item_type == ItemType::GrenadeMod,
+            InventorySlot::ClassMod => *item_type == ItemType::ClassMod,
+            InventorySlot::Artifact => *item_type == ItemType::Artifact,
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use strum::{Display, EnumIter};
+
+use crate::protos::oak_save::{
+    MissionStatusPlayerSaveGameData, MissionStatusPlayerSaveGameData_MissionState,
+};
+use crate::resources::MISSION;
+
+#[derive(Debug, Display, EnumIter, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum MissionStatus {
+    #[strum(to_string = "未开始")]
+    NotStarted,
+    #[strum(to_string = "进行中")]
+    Active,
+    #[strum(to_string = "已完成")]
+    Complete,
+    #[strum(to_string = "失败")]
+    Failed,
+}
+
+impl std::default::Default for MissionStatus {
+    fn default() -> Self {
+        Self::NotStarted
+    }
+}
+
+impl MissionStatus {
+    pub fn from_state(state: MissionStatusPlayerSaveGameData_MissionState) -> Self {
+        match state {
+            MissionStatusPlayerSaveGameData_MissionState::MS_Active => Self::Active,
+            MissionStatusPlayerSaveGameData_MissionState::MS_Complete => Self::Complete,
+            MissionStatusPlayerSaveGameData_MissionState::MS_Failed => Self::Failed,
+            MissionStatusPlayerSaveGameData_MissionState::MS_NotStarted
+            | MissionStatusPlayerSaveGameData_MissionState::MS_Unknown => Self::NotStarted,
+        }
+    }
+
+    pub fn to_state(self) -> MissionStatusPlayerSaveGameData_MissionState {
+        match self {
+            Self::NotStarted => MissionStatusPlayerSaveGameData_MissionState::MS_NotStarted,
+            Self::Active => MissionStatusPlayerSaveGameData_MissionState::MS_Active,
+            Self::Complete => MissionStatusPlayerSaveGameData_MissionState::MS_Complete,
+            Self::Failed => MissionStatusPlayerSaveGameData_MissionState::MS_Failed,
+        }
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct MissionData {
+    pub mission_class_path: String,
+    pub name: String,
+    pub status: MissionStatus,
+    pub objectives_progress: Vec<i32>,
+}
+
+impl MissionData {
+    pub fn from_mission_status(mission: &MissionStatusPlayerSaveGameData) -> Self {
+        Self {
+            mission_class_path: mission.mission_class_path.clone(),
+            name: mission_name(&mission.mission_class_path),
+            status: MissionStatus::from_state(mission.status),
+            objectives_progress: mission.objectives_progress.clone(),
+        }
+    }
+}
+
+pub fn mission_name(mission_class_path: &str) -> String {
+    MISSION
+        .iter()
+        .find(|m| m.ident.eq_ignore_ascii_case(mission_class_path))
+        .map(|m| m.name.to_owned())
+        .unwrap_or_else(|| mission_class_path.to_owned())
+}
+
+// Main story missions in the order they are played, used to complete the story up to a mission.
+// The order follows the mission lists of saves which finished the story, Desert Vault is picked
+// up after Miner Details even though the file names suggest otherwise.
+pub const MAIN_STORY_MISSIONS: [&str; 23] = [
+    "/Game/Missions/Plot/Mission_Ep01_ChildrenOfTheVault.Mission_Ep01_ChildrenOfTheVault_C",
+    "/Game/Missions/Plot/Mission_Ep02_Sacrifice.Mission_Ep02_Sacrifice_C",
+    "/Game/Missions/Plot/Mission_Ep03_GetVaultMap.Mission_Ep03_GetVaultMap_C",
+    "/Game/Missions/Plot/Mission_Ep04_EarnSpaceship.Mission_Ep04_EarnSpaceship_C",
+    "/Game/Missions/Plot/Mission_Ep05_Sanctuary.Mission_Ep05_Sanctuary_C",
+    "/Game/Missions/Plot/Mission_Ep05_OvercomeHQBlockade.Mission_Ep05_OvercomeHQBlockade_C",
+    "/Game/Missions/Plot/Mission_Ep06_MeetMaya.Mission_Ep06_MeetMaya_C",
+    "/Game/Missions/Plot/Mission_Ep08_OrbitalPlatform.Mission_Ep08_OrbitalPlatform_C",
+    "/Game/Missions/Plot/MIssion_Ep09_AtlasHQ.MIssion_Ep09_AtlasHQ_C",
+    "/Game/Missions/Plot/Mission_Ep10_CityVault.Mission_Ep10_CityVault_C",
+    "/Game/Missions/Plot/Mission_Ep11_PrisonBreak.Mission_Ep11_PrisonBreak_C",
+    "/Game/Missions/Plot/Mission_Ep12_GrandTour.Mission_Ep12_GrandTour_C",
+    "/Game/Missions/Plot/Mission_Ep13_JakobsRebellion.Mission_Ep13_JakobsRebellion_C",
+    "/Game/Missions/Plot/Mission_Ep13_Watership.Mission_Ep13_Watership_C",
+    "/Game/Missions/Plot/Mission_Ep15_MarshFields.Mission_Ep15_MarshFields_C",
+    "/Game/Missions/Plot/Mission_Ep16_SiblingRivalry.Mission_Ep16_SiblingRivalry_C",
+    "/Game/Missions/Plot/Mission_Ep17_BigChase.Mission_Ep17_BigChase_C",
+    "/Game/Missions/Plot/Mission_Ep19_MinerDetails.Mission_Ep19_MinerDetails_C",
+    "/Game/Missions/Plot/Mission_Ep16_DesertVault.Mission_Ep16_DesertVault_C",
+    "/Game/Missions/Plot/Mission_Ep20_FirstVaultHunter.Mission_Ep20_FirstVaultHunter_C",
+    "/Game/Missions/Plot/Mission_Ep21_Beachhead.Mission_Ep21_Beachhead_C",
+    "/Game/Missions/Plot/Mission_Ep22_TheMachine.Mission_Ep22_TheMachine_C",
+    "/Game/Missions/Plot/Mission_Ep23_TyreenFinalBoss.Mission_Ep23_TyreenFinalBoss_C",
+];
+
+/// The objectives the game stores for a mission, needed to add a mission which isn't in the save.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct MissionObjectives {
+    pub mission_class_path: &'static str,
+    pub number_of_objectives: usize,
+    /// The objective set the game leaves a mission on once it has been completed.
+    pub complete_objective_set_path: &'static str,
+}
+
+impl MissionObjectives {
+    const fn new(
+        mission_class_path: &'static str,
+        number_of_objectives: usize,
+        complete_objective_set_path: &'static str,
+    ) -> Self {
+        Self {
+            mission_class_path,
+            number_of_objectives,
+            complete_objective_set_path,
+        }
+    }
+}
+
+pub fn mission_objectives(mission_class_path: &str) -> Option<&'static MissionObjectives> {
+    MAIN_STORY_MISSION_OBJECTIVES.iter().find(|m| {
+        m.mission_class_path
+            .eq_ignore_ascii_case(mission_class_path)
+    })
+}
+
+// Taken from saves which completed the main story, only these missions can be added to a save.
+const MAIN_STORY_MISSION_OBJECTIVES: [MissionObjectives; 23] = [
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep01_ChildrenOfTheVault.Mission_Ep01_ChildrenOfTheVault_C",
+        46,
+        "/Game/Missions/Plot/Mission_Ep01_ChildrenOfTheVault.Set_LilithArrivalAndTurnIn_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep02_Sacrifice.Mission_Ep02_Sacrifice_C",
+        25,
+        "/Game/Missions/Plot/Mission_Ep02_Sacrifice.Set_TalkToLilithAfterEscortingVaughnn_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep03_GetVaultMap.Mission_Ep03_GetVaultMap_C",
+        22,
+        "/Game/Missions/Plot/Mission_Ep03_GetVaultMap.Set_ReturnMapToLilith_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep04_EarnSpaceship.Mission_Ep04_EarnSpaceship_C",
+        33,
+        "/Game/Missions/Plot/Mission_Ep04_EarnSpaceship.Set_Ep04TurnIn_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep05_Sanctuary.Mission_Ep05_Sanctuary_C",
+        53,
+        "/Game/Missions/Plot/Mission_Ep05_Sanctuary.Set_TalkToLilith_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep05_OvercomeHQBlockade.Mission_Ep05_OvercomeHQBlockade_C",
+        92,
+        "/Game/Missions/Plot/Mission_Ep05_OvercomeHQBlockade.SET_InsertGigamind_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep06_MeetMaya.Mission_Ep06_MeetMaya_C",
+        55,
+        "/Game/Missions/Plot/Mission_Ep06_MeetMaya.Set_TalkToLilith_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep08_OrbitalPlatform.Mission_Ep08_OrbitalPlatform_C",
+        61,
+        "/Game/Missions/Plot/Mission_Ep08_OrbitalPlatform.Set_TurnIn_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/MIssion_Ep09_AtlasHQ.MIssion_Ep09_AtlasHQ_C",
+        47,
+        "/Game/Missions/Plot/MIssion_Ep09_AtlasHQ.Set_RhysFinale_TakeShard_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep10_CityVault.Mission_Ep10_CityVault_C",
+        67,
+        "/Game/Missions/Plot/Mission_Ep10_CityVault.Set_CalypsoTauntVideo_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep11_PrisonBreak.Mission_Ep11_PrisonBreak_C",
+        102,
+        "/Game/Missions/Plot/Mission_Ep11_PrisonBreak.Set_SpeakToHammerlock_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep12_GrandTour.Mission_Ep12_GrandTour_C",
+        43,
+        "/Game/Missions/Plot/Mission_Ep12_GrandTour.Set_AddDiscToRecorder_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep13_JakobsRebellion.Mission_Ep13_JakobsRebellion_C",
+        48,
+        "/Game/Missions/Plot/Mission_Ep13_JakobsRebellion.Set_TalkToClayMissionComplete_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep13_Watership.Mission_Ep13_Watership_C",
+        76,
+        "/Game/Missions/Plot/Mission_Ep13_Watership.Set_SanctuaryGiveKeyToTannis2_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep15_MarshFields.Mission_Ep15_MarshFields_C",
+        62,
+        "/Game/Missions/Plot/Mission_Ep15_MarshFields.SET_TurnInMission_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep16_SiblingRivalry.Mission_Ep16_SiblingRivalry_C",
+        65,
+        "/Game/Missions/Plot/Mission_Ep16_SiblingRivalry.Set_TalkToLilith_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep17_BigChase.Mission_Ep17_BigChase_C",
+        75,
+        "/Game/Missions/Plot/Mission_Ep17_BigChase.Set_TalkToTannis_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep19_MinerDetails.Mission_Ep19_MinerDetails_C",
+        90,
+        "/Game/Missions/Plot/Mission_Ep19_MinerDetails.SET_TurnInAfterConvoy_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep16_DesertVault.Mission_Ep16_DesertVault_C",
+        54,
+        "/Game/Missions/Plot/Mission_Ep16_DesertVault.Set_TalkToTannis_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep20_FirstVaultHunter.Mission_Ep20_FirstVaultHunter_C",
+        38,
+        "/Game/Missions/Plot/Mission_Ep20_FirstVaultHunter.Set_FireFinalFabricator_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep21_Beachhead.Mission_Ep21_Beachhead_C",
+        56,
+        "/Game/Missions/Plot/Mission_Ep21_Beachhead.Set_TakeVaultReward_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep22_TheMachine.Mission_Ep22_TheMachine_C",
+        42,
+        "/Game/Missions/Plot/Mission_Ep22_TheMachine.Set_MIssionComplete_ObjectiveSet",
+    ),
+    MissionObjectives::new(
+        "/Game/Missions/Plot/Mission_Ep23_TyreenFinalBoss.Mission_Ep23_TyreenFinalBoss_C",
+        21,
+        "/Game/Missions/Plot/Mission_Ep23_TyreenFinalBoss.Set_PlaceArtifact_ObjectiveSet",
+    ),
+];
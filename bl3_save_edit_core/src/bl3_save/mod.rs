@@ -3,28 +3,37 @@ use std::path::Path;
 
 use anyhow::{Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
+use derivative::Derivative;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::bl3_save::character_data::CharacterData;
 use crate::bl3_save::inventory_slot::InventorySlot;
 use crate::file_helper::FileData;
+use crate::load_warning::{LoadMode, LoadWarning, LoadWarnings};
 use crate::models::CustomFormatData;
 use crate::parser::{decrypt, encrypt, HeaderType};
 use crate::{file_helper, parser};
 
+pub mod ability_tree;
 pub mod ammo;
 pub mod challenge_data;
 pub mod character_data;
+pub mod crew_quarters;
+pub mod echo_log;
+pub mod fast_travel;
 pub mod fast_travel_unlock_data;
 pub mod inventory_slot;
 pub mod level_data;
+pub mod mission;
 pub mod models;
 pub mod player_class;
+pub mod player_color;
 pub mod playthrough;
 pub mod sdu;
 pub mod util;
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Derivative)]
+#[derivative(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Bl3Save {
     pub file_name: String,
     pub save_game_version: u32,
@@ -40,15 +49,31 @@ pub struct Bl3Save {
     pub save_game_type: String,
     pub header_type: HeaderType,
     pub character_data: CharacterData,
+    pub load_warnings: Vec<LoadWarning>,
+    /// Hash of the file when it was read, the same save read from a different file is still equal.
+    #[derivative(PartialEq = "ignore", Ord = "ignore", PartialOrd = "ignore")]
+    pub file_hash: u32,
 }
 
 impl Bl3Save {
     pub fn from_file_data(file_data: &FileData, header_type: HeaderType) -> Result<Self> {
+        Self::from_file_data_with_mode(file_data, header_type, LoadMode::Strict)
+    }
+
+    /// Reads the file, in `LoadMode::Lenient` anything which can't be read is kept in
+    /// `load_warnings` instead of failing.
+    pub fn from_file_data_with_mode(
+        file_data: &FileData,
+        header_type: HeaderType,
+        mode: LoadMode,
+    ) -> Result<Self> {
         let remaining_data = file_data.remaining_data;
 
         let character = decrypt(remaining_data, &header_type)?;
 
-        let character_data = CharacterData::from_character(character)?;
+        let mut warnings = LoadWarnings::new(mode);
+
+        let character_data = CharacterData::from_character_with_warnings(character, &mut warnings)?;
 
         let FileData {
             file_location,
@@ -63,6 +88,7 @@ impl Bl3Save {
             custom_format_data_count,
             custom_format_data,
             save_game_type,
+            file_hash,
             ..
         } = file_data.clone();
 
@@ -71,6 +97,8 @@ impl Bl3Save {
             .map(|s| s.to_string_lossy().to_string())
             .context("failed to read file name")?;
 
+        let load_warnings = warnings.into_warnings(&file_name);
+
         Ok(Self {
             file_name,
             save_game_version: file_version,
@@ -86,13 +114,33 @@ impl Bl3Save {
             save_game_type,
             header_type,
             character_data,
+            load_warnings,
+            file_hash,
         })
     }
 
     pub fn from_bytes(file_name: &Path, data: &[u8], header_type: HeaderType) -> Result<Self> {
+        Self::from_bytes_with_mode(file_name, data, header_type, LoadMode::Strict)
+    }
+
+    pub fn from_bytes_with_mode(
+        file_name: &Path,
+        data: &[u8],
+        header_type: HeaderType,
+        mode: LoadMode,
+    ) -> Result<Self> {
         let file_data = file_helper::read_bytes(file_name, data)?;
 
-        Self::from_file_data(&file_data, header_type)
+        Self::from_file_data_with_mode(&file_data, header_type, mode)
+    }
+
+    /// A file which was read with warnings is read back the same way after it is written.
+    fn load_mode(&self) -> LoadMode {
+        if self.load_warnings.is_empty() {
+            LoadMode::Strict
+        } else {
+            LoadMode::Lenient
+        }
     }
 
     pub fn as_bytes(&self) -> Result<(Vec<u8>, Bl3Save)> {
@@ -125,7 +173,8 @@ impl Bl3Save {
 
         //Now try re-reading it also - there's no point making an invalid save
         let file_name = Path::new(&self.file_name);
-        let new_save = Self::from_bytes(file_name, &output, self.header_type)?;
+        let new_save =
+            Self::from_bytes_with_mode(file_name, &output, self.header_type, self.load_mode())?;
 
         Ok((output, new_save))
     }
@@ -273,11 +322,31 @@ impl std::fmt::Display for Bl3Save {
 mod tests {
     use std::fs;
 
+    use crate::bl3_item::Bl3Item;
+    use crate::bl3_profile::Bl3Profile;
+    use crate::bl3_save::ability_tree::{
+        skill_info, skill_tree_info, skills_for_tree, AbilityTreeIssue, SkillPreset,
+        POINTS_PER_TIER,
+    };
     use crate::bl3_save::ammo::{AmmoPool, AmmoPoolData};
     use crate::bl3_save::challenge_data::{Challenge, ChallengeData};
-    use crate::bl3_save::inventory_slot::InventorySlotData;
+    use crate::bl3_save::character_data::EMOTE_SLOTS;
+    use crate::bl3_save::crew_quarters::{GunRackSlot, EMPTY_CREW_QUARTERS_DECORATION};
+    use crate::bl3_save::echo_log::EchoLogContent;
+    use crate::bl3_save::fast_travel::{fast_travel_stations, FastTravelGroup};
+    use crate::bl3_save::inventory_slot::{InventorySlot, InventorySlotData};
+    use crate::bl3_save::mission::{mission_objectives, MissionStatus, MAIN_STORY_MISSIONS};
     use crate::bl3_save::player_class::PlayerClass;
+    use crate::bl3_save::player_color::{
+        PlayerColor, PlayerColorData, PlayerColorPreset, PlayerColorSlot,
+    };
     use crate::bl3_save::sdu::{SaveSduSlot, SaveSduSlotData};
+    use crate::diff;
+    use crate::diff::{DiffEntry, DiffSection};
+    use crate::file_helper::Bl3FileType;
+    use crate::load_warning::{LoadMode, LoadWarnings};
+    use crate::protos::oak_save::MissionPlaythroughSaveGameData;
+    use crate::resources::{ECHO_LOGS, PROFILE_EMOTES, PROFILE_EMOTES_DEFAULTS};
     use crate::vehicle_data::{VehicleData, VehicleSubType, VehicleType};
 
     use super::*;
@@ -2312,4 +2381,1719 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    #[cfg(feature = "with-serde")]
+    fn test_json_round_trip_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let json = bl3_save.to_json().expect("failed to export save to json");
+
+        assert!(json.contains("\"serial\": \"BL3("));
+
+        let imported = Bl3Save::from_json(&json).expect("failed to import save from json");
+
+        assert_eq!(imported, bl3_save);
+        assert_eq!(
+            imported
+                .as_bytes()
+                .expect("failed to write imported save")
+                .0,
+            bl3_save.as_bytes().expect("failed to write test save").0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-serde")]
+    fn test_json_nested_unknown_fields_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        bl3_save
+            .character_data
+            .character
+            .unknown_fields
+            .add_varint(1000, 1);
+        bl3_save.character_data.character.inventory_items[2]
+            .unknown_fields
+            .add_varint(1000, 1);
+        bl3_save.character_data.character.vehicles_unlocked_data[0]
+            .unknown_fields
+            .add_length_delimited(1001, vec![1, 2, 3]);
+        bl3_save
+            .character_data
+            .character
+            .mut_gbx_zone_map_fod_save_game_data()
+            .level_data[1]
+            .unknown_fields
+            .add_fixed32(1002, 7);
+
+        let json = bl3_save.to_json().expect("failed to export save");
+
+        assert!(json.contains("\"unknown_fields\""));
+
+        let imported = Bl3Save::from_json(&json).expect("failed to import save from json");
+
+        assert_eq!(
+            imported.character_data.character.inventory_items[2]
+                .unknown_fields
+                .get(1000)
+                .map(|v| v.varint.clone()),
+            Some(vec![1])
+        );
+        assert_eq!(
+            imported
+                .as_bytes()
+                .expect("failed to write imported save")
+                .0,
+            bl3_save.as_bytes().expect("failed to write test save").0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-serde")]
+    fn test_yaml_round_trip_ps4() {
+        let filename = Path::new("./test_files/310ps4.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::Ps4Save)
+            .expect("failed to read test save");
+
+        let yaml = bl3_save.to_yaml().expect("failed to export save to yaml");
+
+        let imported = Bl3Save::from_yaml(&yaml).expect("failed to import save from yaml");
+
+        assert_eq!(
+            imported
+                .as_bytes()
+                .expect("failed to write imported save")
+                .0,
+            bl3_save.as_bytes().expect("failed to write test save").0
+        );
+    }
+
+    #[test]
+    fn test_ability_tree_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.validate_ability_tree(), vec![]);
+        assert_eq!(character_data.ability_tree().points_spent(), 58);
+        assert_eq!(
+            character_data.ability_tree().tree_identifiers(),
+            vec![0, 1, 2]
+        );
+
+        character_data
+            .respec_ability_tree()
+            .expect("failed to respec ability tree");
+
+        assert_eq!(character_data.ability_tree().points_spent(), 0);
+        assert_eq!(character_data.ability_points(), 63);
+
+        let tree = skill_tree_info(PlayerClass::Siren, 2).expect("failed to find skill tree");
+
+        let preset = SkillPreset::max_tree(character_data.ability_tree(), &tree, 63);
+
+        character_data
+            .apply_skill_preset(&preset)
+            .expect("failed to apply skill preset");
+
+        let max_points = character_data.ability_tree().max_points_in_tree(2);
+
+        assert_eq!(
+            character_data.ability_tree().points_spent_in_tree(2),
+            max_points
+        );
+        assert_eq!(character_data.ability_points(), 63 - max_points);
+        assert_eq!(character_data.validate_ability_tree(), vec![]);
+    }
+
+    #[test]
+    fn test_ability_tree_overspent_ps4() {
+        let filename = Path::new("./test_files/69ps4_v103.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::Ps4Save)
+            .expect("failed to read test save");
+
+        assert_eq!(
+            bl3_save.character_data.validate_ability_tree(),
+            vec![AbilityTreeIssue::PointsMismatch {
+                points_spent: 132,
+                ability_points: 58,
+                available: 63,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_ability_tree_tiers_pc() {
+        let filename = Path::new("./test_files/quick.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.validate_ability_tree(), vec![]);
+
+        let tiers = character_data.ability_tree().tiers(0);
+
+        assert_eq!(
+            tiers.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            vec![
+                "Tiers_OakPlayerAbilityTreeTierData",
+                "Tiers_OakPlayerAbilityTreeTierData_0",
+                "Tiers_OakPlayerAbilityTreeTierData_1",
+                "Tiers_OakPlayerAbilityTreeTierData_2",
+                "Tiers_OakPlayerAbilityTreeTierData_3",
+                "Tiers_OakPlayerAbilityTreeTierData_4",
+                "Tiers_OakPlayerAbilityTreeTierData_6",
+            ]
+        );
+        assert_eq!(
+            tiers.iter().map(|t| t.points_required).collect::<Vec<_>>(),
+            vec![0, 0, 5, 10, 15, 20, 25]
+        );
+        assert_eq!(
+            tiers.iter().map(|t| t.max_points()).collect::<Vec<_>>(),
+            vec![0, 13, 13, 4, 6, 4, 1]
+        );
+
+        character_data
+            .respec_ability_tree()
+            .expect("failed to respec ability tree");
+
+        let capstone = tiers[6].items[0].item_asset_path.clone();
+
+        assert!(character_data
+            .set_ability_tree_item_points(&capstone, 1)
+            .is_err());
+        assert_eq!(character_data.ability_tree().points_spent(), 0);
+
+        // Maxing a tree fills it tier by tier so the capstone ends up unlocked
+        let tree = skill_tree_info(PlayerClass::BeastMaster, 0).expect("failed to find skill tree");
+
+        let preset = SkillPreset::max_tree(character_data.ability_tree(), &tree, 41);
+
+        assert_eq!(preset.points(), 41);
+        assert_eq!(
+            preset.items.last().map(|i| i.item_asset_path.as_str()),
+            Some(capstone.as_str())
+        );
+
+        character_data
+            .apply_skill_preset(&preset)
+            .expect("failed to apply skill preset");
+
+        assert_eq!(character_data.validate_ability_tree(), vec![]);
+
+        // Taking the points which unlock a tier out of the tier above it is refused
+        let first_item = &tiers[1].items[0];
+        let second_item = &tiers[2].items[0];
+
+        character_data
+            .respec_ability_tree()
+            .expect("failed to respec ability tree");
+        character_data
+            .set_ability_tree_item_points(&first_item.item_asset_path, POINTS_PER_TIER)
+            .expect("failed to set skill tree item points");
+        character_data
+            .set_ability_tree_item_points(&second_item.item_asset_path, 1)
+            .expect("failed to set skill tree item points");
+
+        assert!(character_data
+            .set_ability_tree_item_points(&first_item.item_asset_path, POINTS_PER_TIER - 1)
+            .is_err());
+        assert_eq!(
+            character_data.ability_tree().points_spent(),
+            POINTS_PER_TIER + 1
+        );
+    }
+
+    #[test]
+    fn test_skill_catalogue() {
+        for (file_name, header_type) in [
+            ("./test_files/1.sav", HeaderType::PcSave),
+            ("./test_files/1012.sav", HeaderType::PcSave),
+            ("./test_files/310ps4.sav", HeaderType::Ps4Save),
+            ("./test_files/69ps4_v103.sav", HeaderType::Ps4Save),
+        ] {
+            let filename = Path::new(file_name);
+
+            let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+            let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, header_type)
+                .expect("failed to read test save");
+
+            let character_data = &bl3_save.character_data;
+            let ability_tree = character_data.ability_tree();
+
+            for tree_identifier in ability_tree.tree_identifiers() {
+                let skills = ability_tree
+                    .tiers(tree_identifier)
+                    .into_iter()
+                    .enumerate()
+                    .flat_map(|(tier, t)| {
+                        t.items
+                            .into_iter()
+                            .filter(|i| i.max_points > 0)
+                            .map(move |i| (tier as i32, i.max_points))
+                    })
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    skills_for_tree(character_data.player_class(), tree_identifier)
+                        .iter()
+                        .map(|s| (s.tier, s.max_points))
+                        .collect::<Vec<_>>(),
+                    skills
+                );
+            }
+        }
+
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &bl3_save.character_data;
+
+        let mut ability_tree = character_data.ability_tree().clone();
+
+        let item = ability_tree
+            .items
+            .iter_mut()
+            .find(|i| i.max_points == 5)
+            .expect("failed to find skill");
+
+        assert_eq!(
+            skill_info(
+                PlayerClass::Siren,
+                item.tree_identifier,
+                &item.item_asset_path
+            )
+            .map(|s| s.max_points),
+            Some(5)
+        );
+
+        item.max_points = 10;
+
+        let item_asset_path = item.item_asset_path.clone();
+
+        assert!(ability_tree
+            .validate(
+                PlayerClass::Siren,
+                character_data.player_level(),
+                character_data.ability_points()
+            )
+            .contains(&AbilityTreeIssue::SkillMaxPointsChanged {
+                item_asset_path,
+                max_points: 10,
+                expected_max_points: 5,
+            }));
+    }
+
+    #[test]
+    fn test_ability_tree_points_over_level_pc() {
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.ability_points(), 0);
+
+        let item = character_data
+            .ability_tree()
+            .items
+            .iter()
+            .find(|i| i.tree_identifier == 0 && i.points < i.max_points && i.points > 0)
+            .map(|i| (i.item_asset_path.clone(), i.points))
+            .expect("failed to find a skill tree item");
+
+        // Every point available at this level is already spent
+        assert!(character_data
+            .set_ability_tree_item_points(&item.0, item.1 + 1)
+            .is_err());
+        assert_eq!(character_data.ability_points(), 0);
+
+        character_data
+            .set_ability_tree_item_points(&item.0, item.1 - 1)
+            .expect("failed to set skill tree item points");
+
+        assert_eq!(character_data.ability_points(), 1);
+    }
+
+    #[test]
+    fn test_equip_items_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.equipped_items().len(), 8);
+        assert_eq!(
+            character_data.equipped_slot(41),
+            Some(InventorySlot::Weapon3)
+        );
+        assert_eq!(character_data.character.active_weapon_list, vec![41]);
+
+        character_data
+            .unequip(&InventorySlot::Weapon3)
+            .expect("failed to unequip weapon");
+
+        assert_eq!(character_data.equipped_slot(41), None);
+        assert_eq!(character_data.character.active_weapon_list, vec![46]);
+
+        // Moving a weapon to another slot empties the slot it came from
+        character_data
+            .equip_item(&InventorySlot::Weapon3, 46)
+            .expect("failed to equip weapon");
+
+        assert_eq!(
+            character_data.equipped_slot(46),
+            Some(InventorySlot::Weapon3)
+        );
+        assert_eq!(character_data.equipped_items().len(), 7);
+
+        assert!(character_data
+            .equip_item(&InventorySlot::Shield, 46)
+            .is_err());
+
+        // An equipped item gets its own pickup order when it shares one with another item
+        let weapon = character_data.inventory_items()[46].clone();
+        let weapon_index = character_data.inventory_items().len();
+
+        character_data
+            .add_inventory_item(0, &weapon)
+            .expect("failed to add item");
+        character_data
+            .equip_item(&InventorySlot::Weapon4, weapon_index)
+            .expect("failed to equip weapon");
+
+        assert_eq!(
+            character_data
+                .equipped_items()
+                .into_iter()
+                .find(|e| e.slot == InventorySlot::Weapon4)
+                .map(|e| (e.inventory_index, e.pickup_order_index)),
+            Some((weapon_index, 2810))
+        );
+
+        character_data
+            .equip_item(&InventorySlot::Weapon4, 46)
+            .expect("failed to equip weapon");
+
+        assert_eq!(
+            character_data.character.inventory_items[46].pickup_order_index,
+            2797
+        );
+
+        // Items which couldn't be read can't be equipped
+        let newer_item = Bl3Item::from_serial_base64("BL3(AwAAAAAKG4D/I9xAEzwShMJVX8nPYwsAAA==)")
+            .expect("failed to read newer item");
+
+        character_data
+            .add_inventory_item(2811, &newer_item)
+            .expect("failed to add item");
+
+        assert!(character_data
+            .equip_item(&InventorySlot::Weapon4, weapon_index + 1)
+            .is_err());
+
+        character_data.remove_inventory_item(weapon_index + 1);
+        character_data.remove_inventory_item(weapon_index);
+
+        // Removing an item keeps the other slots pointing at the same items
+        let shield = character_data.inventory_items()[12].clone();
+
+        character_data.remove_inventory_item(2);
+
+        assert_eq!(character_data.equipped_slot(1), None);
+        assert_eq!(
+            character_data
+                .equipped_items()
+                .into_iter()
+                .find(|e| e.slot == InventorySlot::Shield)
+                .map(|e| e.item),
+            Some(shield)
+        );
+        assert_eq!(character_data.character.active_weapon_list, vec![45]);
+    }
+
+    #[test]
+    fn test_diff_saves_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert!(diff::diff_saves(&bl3_save, &bl3_save)
+            .expect("failed to diff saves")
+            .is_empty());
+
+        let mut new_save = bl3_save.clone();
+
+        let removed_item = new_save.character_data.inventory_items()[0].clone();
+
+        new_save
+            .character_data
+            .set_money(bl3_save.character_data.money() + 1)
+            .expect("failed to set money");
+        new_save
+            .character_data
+            .set_sdu_slot(&SaveSduSlot::Backpack, 0);
+        new_save.character_data.remove_inventory_item(0);
+
+        let entries = diff::diff_saves(&bl3_save, &new_save).expect("failed to diff saves");
+
+        assert!(entries.contains(&DiffEntry {
+            section: DiffSection::Currency,
+            field: "金钱".to_owned(),
+            left: Some(bl3_save.character_data.money().to_string()),
+            right: Some((bl3_save.character_data.money() + 1).to_string()),
+        }));
+        assert!(entries
+            .iter()
+            .any(|e| e.section == DiffSection::Sdu && e.right == Some("0".to_owned())));
+        assert!(entries.contains(&DiffEntry {
+            section: DiffSection::Inventory,
+            field: format!(
+                "{} ({})",
+                removed_item.balance_part().name.as_ref().unwrap(),
+                removed_item.level()
+            ),
+            left: Some(removed_item.get_serial_number_base64(false).unwrap()),
+            right: None,
+        }));
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|e| e.section == DiffSection::Inventory)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_missions_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let playthroughs = bl3_save.character_data.playthroughs().len();
+
+        let character_data = &mut bl3_save.character_data;
+
+        let sanctuary = MAIN_STORY_MISSIONS[4];
+        let hostile_takeover = MAIN_STORY_MISSIONS[5];
+
+        assert!(character_data
+            .set_mission_status(playthroughs, sanctuary, MissionStatus::Complete)
+            .is_err());
+
+        character_data
+            .set_mission_status(0, MAIN_STORY_MISSIONS[0], MissionStatus::NotStarted)
+            .expect("failed to reset mission");
+        assert_eq!(
+            character_data.mission_status(0, MAIN_STORY_MISSIONS[0]),
+            MissionStatus::NotStarted
+        );
+        assert!(character_data
+            .missions(0)
+            .iter()
+            .all(|m| m.mission_class_path != MAIN_STORY_MISSIONS[0]));
+
+        character_data
+            .set_mission_status(0, hostile_takeover, MissionStatus::NotStarted)
+            .expect("failed to reset mission");
+
+        character_data
+            .complete_main_story_up_to(0, sanctuary)
+            .expect("failed to complete main story");
+
+        for mission in &MAIN_STORY_MISSIONS[..=4] {
+            assert_eq!(
+                character_data.mission_status(0, mission),
+                MissionStatus::Complete
+            );
+        }
+
+        assert_eq!(
+            character_data.mission_status(0, hostile_takeover),
+            MissionStatus::Active
+        );
+        assert_eq!(character_data.tracked_mission(0), Some(hostile_takeover));
+        assert!(character_data.playthroughs()[0]
+            .missions_completed
+            .contains(&"Sanctuary".to_owned()));
+        assert!(character_data.playthroughs()[0]
+            .active_missions
+            .contains(&"Hostile Takeover".to_owned()));
+
+        let raw_mission = |character_data: &CharacterData, path: &str| {
+            character_data.character.mission_playthroughs_data[0]
+                .mission_list
+                .iter()
+                .find(|m| m.mission_class_path == path)
+                .cloned()
+                .expect("failed to find mission")
+        };
+
+        let children_of_the_vault = raw_mission(character_data, MAIN_STORY_MISSIONS[0]);
+        assert_eq!(
+            children_of_the_vault.active_objective_set_path,
+            "/Game/Missions/Plot/Mission_Ep01_ChildrenOfTheVault.Set_LilithArrivalAndTurnIn_ObjectiveSet"
+        );
+        assert_eq!(children_of_the_vault.objectives_progress, vec![1; 46]);
+
+        let started_hostile_takeover = raw_mission(character_data, hostile_takeover);
+        assert!(started_hostile_takeover
+            .active_objective_set_path
+            .is_empty());
+        assert_eq!(started_hostile_takeover.objectives_progress, vec![0; 92]);
+
+        assert!(character_data
+            .set_mission_status(
+                0,
+                "/Game/Missions/Side/ProvingGrounds/ProvingGrounds4/Mission_ProvingGrounds_Mission04.Mission_ProvingGrounds_Mission04_C",
+                MissionStatus::Active
+            )
+            .is_err());
+
+        character_data
+            .set_mission_objectives_progress(0, hostile_takeover, vec![1, 0, 1])
+            .expect("failed to set objectives progress");
+        assert_eq!(
+            character_data
+                .missions(0)
+                .into_iter()
+                .find(|m| m.mission_class_path == hostile_takeover)
+                .map(|m| m.objectives_progress),
+            Some(vec![1, 0, 1])
+        );
+
+        // Missions which were already complete keep their progress
+        let sacrifice = raw_mission(character_data, MAIN_STORY_MISSIONS[1]);
+
+        character_data
+            .set_mission_status(0, MAIN_STORY_MISSIONS[1], MissionStatus::Complete)
+            .expect("failed to complete mission");
+        assert_eq!(
+            raw_mission(character_data, MAIN_STORY_MISSIONS[1]),
+            sacrifice
+        );
+
+        character_data
+            .set_mission_status(0, hostile_takeover, MissionStatus::Complete)
+            .expect("failed to complete mission");
+
+        let completed_hostile_takeover = raw_mission(character_data, hostile_takeover);
+        let hostile_takeover_objectives =
+            mission_objectives(hostile_takeover).expect("failed to find mission objectives");
+        assert_eq!(
+            completed_hostile_takeover.active_objective_set_path,
+            hostile_takeover_objectives.complete_objective_set_path
+        );
+        assert_eq!(completed_hostile_takeover.objectives_progress, vec![1; 92]);
+
+        character_data
+            .set_mission_status(0, hostile_takeover, MissionStatus::Active)
+            .expect("failed to restart mission");
+
+        assert!(character_data
+            .set_tracked_mission(0, Some(sanctuary))
+            .is_err());
+
+        character_data
+            .set_tracked_mission(0, None)
+            .expect("failed to stop tracking mission");
+        assert_eq!(character_data.tracked_mission(0), None);
+
+        let new_save = Bl3Save::from_bytes(
+            filename,
+            &bl3_save.as_bytes().expect("failed to write save").0,
+            HeaderType::PcSave,
+        )
+        .expect("failed to read written save");
+
+        assert_eq!(
+            new_save.character_data.mission_status(0, hostile_takeover),
+            MissionStatus::Active
+        );
+    }
+
+    #[test]
+    fn test_fast_travel_pc() {
+        let filename = Path::new("./test_files/310pc.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let sanctuary = "/Game/GameData/FastTravel/FTS_Sanctuary.FTS_Sanctuary";
+        let metroplex = "/Game/GameData/FastTravel/FTS_City.FTS_City";
+
+        assert!(character_data.unlock_all_fast_travel_stations(1).is_err());
+        assert!(character_data.set_spawn_station(0, metroplex).is_err());
+
+        let unlocked = |character_data: &CharacterData| {
+            character_data
+                .fast_travel_stations(0)
+                .into_iter()
+                .filter(|s| s.unlocked)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(unlocked(character_data).len(), 9);
+
+        character_data
+            .unlock_fast_travel_group(0, FastTravelGroup::Promethea)
+            .expect("failed to unlock stations");
+
+        let stations = character_data.fast_travel_stations(0);
+
+        assert!(stations
+            .iter()
+            .filter(|s| s.group == FastTravelGroup::Promethea)
+            .all(|s| s.unlocked));
+        assert!(stations
+            .iter()
+            .filter(|s| s.group == FastTravelGroup::Eden6)
+            .all(|s| !s.unlocked));
+        assert!(character_data.playthroughs()[0]
+            .active_travel_stations
+            .iter()
+            .any(|s| s == metroplex));
+        assert!(character_data
+            .character
+            .get_discovery_data()
+            .discovered_level_info
+            .iter()
+            .any(
+                |l| l.discovered_level_name == "/Game/Maps/Zone_1/City/City_P"
+                    && l.discovered_playthroughs == 1
+            ));
+
+        character_data
+            .set_fast_travel_station_blacklisted(0, sanctuary, true)
+            .expect("failed to blacklist station");
+
+        assert!(character_data.fast_travel_stations(0).iter().any(|s| s
+            .active_travel_station_name
+            == sanctuary
+            && s.unlocked
+            && s.blacklisted));
+        assert!(character_data
+            .character
+            .active_or_blacklisted_travel_stations
+            .is_empty());
+
+        character_data
+            .set_fast_travel_station_blacklisted(0, sanctuary, false)
+            .expect("failed to unblacklist station");
+
+        assert!(character_data.fast_travel_stations(0).iter().any(|s| s
+            .active_travel_station_name
+            == sanctuary
+            && s.unlocked
+            && !s.blacklisted));
+
+        character_data
+            .set_fast_travel_station_blacklisted(0, sanctuary, true)
+            .expect("failed to blacklist station");
+
+        character_data
+            .set_spawn_station(0, metroplex)
+            .expect("failed to set spawn station");
+
+        assert_eq!(character_data.spawn_station(0), Some(metroplex));
+        assert_eq!(
+            character_data.playthroughs()[0].current_map.name,
+            "Meridian Metroplex"
+        );
+
+        character_data
+            .unlock_all_fast_travel_stations(0)
+            .expect("failed to unlock stations");
+
+        assert!(character_data
+            .fast_travel_stations(0)
+            .iter()
+            .all(|s| s.unlocked));
+        assert!(character_data.playthroughs()[0]
+            .active_travel_stations
+            .iter()
+            .all(|s| s.starts_with("/Game/")));
+
+        let new_save = Bl3Save::from_bytes(
+            filename,
+            &bl3_save.as_bytes().expect("failed to write save").0,
+            HeaderType::PcSave,
+        )
+        .expect("failed to read written save");
+
+        assert_eq!(new_save.character_data.spawn_station(0), Some(metroplex));
+        assert!(new_save
+            .character_data
+            .fast_travel_stations(0)
+            .iter()
+            .any(|s| s.active_travel_station_name == sanctuary && s.blacklisted));
+    }
+
+    #[test]
+    fn test_fast_travel_station_names_pc() {
+        let filename = Path::new("./test_files/1012.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let stations = fast_travel_stations();
+
+        // Stations written by the game have to match the names the editor writes exactly
+        let game_stations = bl3_save
+            .character_data
+            .playthroughs()
+            .iter()
+            .flat_map(|p| p.active_travel_stations.clone())
+            .filter(|s| s.contains("/FTS_"))
+            .collect::<Vec<_>>();
+
+        assert!(!game_stations.is_empty());
+        assert!(game_stations.iter().all(|s| stations.contains(&s.as_str())));
+    }
+
+    #[test]
+    fn test_playthroughs_pc() {
+        let filename = Path::new("./test_files/310pc.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert!(character_data.remove_tvhm_playthrough().is_err());
+        assert!(character_data.set_mayhem_level(1, 1).is_err());
+
+        let playthroughs_completed = character_data.character.playthroughs_completed;
+
+        character_data
+            .add_tvhm_playthrough()
+            .expect("failed to add tvhm");
+
+        assert!(character_data.add_tvhm_playthrough().is_err());
+        assert_eq!(character_data.playthroughs().len(), 2);
+        assert_eq!(character_data.character.playthroughs_completed, 1);
+        assert_eq!(character_data.character.mission_playthroughs_data.len(), 2);
+        assert_eq!(
+            character_data
+                .character
+                .last_active_travel_station_for_playthrough
+                .len(),
+            2
+        );
+        assert_eq!(
+            character_data
+                .character
+                .active_travel_stations_for_playthrough
+                .len(),
+            2
+        );
+        assert_eq!(
+            character_data.playthroughs()[1].current_map.name,
+            "Droughts"
+        );
+
+        assert!(character_data.set_mayhem_level(1, 12).is_err());
+        assert!(character_data.set_mayhem_level(1, -1).is_err());
+
+        character_data
+            .set_mayhem_level(1, 10)
+            .expect("failed to set mayhem level");
+        character_data
+            .set_mayhem_random_seed(1, 1234)
+            .expect("failed to set mayhem seed");
+
+        assert_eq!(character_data.playthroughs()[1].mayhem_level, 10);
+        assert_eq!(character_data.playthroughs()[1].mayhem_random_seed, 1234);
+
+        let new_save = Bl3Save::from_bytes(
+            filename,
+            &bl3_save.as_bytes().expect("failed to write save").0,
+            HeaderType::PcSave,
+        )
+        .expect("failed to read written save");
+
+        assert_eq!(new_save.character_data.playthroughs().len(), 2);
+        assert_eq!(new_save.character_data.playthroughs()[1].mayhem_level, 10);
+
+        let character_data = &mut bl3_save.character_data;
+
+        // Adding the playthrough discovers the level of its starting station
+        assert!(character_data
+            .character
+            .get_discovery_data()
+            .get_discovered_level_info()
+            .iter()
+            .any(|l| l.discovered_playthroughs & 2 != 0));
+
+        character_data
+            .remove_tvhm_playthrough()
+            .expect("failed to remove tvhm");
+
+        assert_eq!(character_data.playthroughs().len(), 1);
+        assert_eq!(
+            character_data.character.playthroughs_completed,
+            playthroughs_completed
+        );
+        assert_eq!(character_data.character.mission_playthroughs_data.len(), 1);
+        assert_eq!(
+            character_data
+                .character
+                .last_active_travel_station_for_playthrough
+                .len(),
+            1
+        );
+        assert!(character_data
+            .character
+            .get_discovery_data()
+            .get_discovered_level_info()
+            .iter()
+            .all(|l| l.discovered_playthroughs == 1));
+
+        // Extra per playthrough entries would end up in the new playthrough
+        character_data
+            .character
+            .mission_playthroughs_data
+            .push(MissionPlaythroughSaveGameData::new());
+
+        assert!(character_data.add_tvhm_playthrough().is_err());
+        assert_eq!(character_data.playthroughs().len(), 1);
+    }
+
+    #[test]
+    fn test_echo_logs_pc() {
+        let filename = Path::new("./test_files/1.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let unlocked = |character_data: &CharacterData| {
+            character_data
+                .echo_logs()
+                .into_iter()
+                .filter(|e| e.unlocked)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(unlocked(character_data).len(), 33);
+
+        let typhon_log = "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach1.EchoLog_Journal_Beach1";
+
+        assert!(character_data.set_echo_log_seen(typhon_log, true).is_err());
+
+        character_data.unlock_echo_logs_by_content(EchoLogContent::GunsLoveAndTentacles);
+
+        let echo_logs = character_data.echo_logs();
+
+        assert!(echo_logs
+            .iter()
+            .filter(|e| e.content == EchoLogContent::GunsLoveAndTentacles)
+            .all(|e| e.unlocked && !e.seen));
+        assert!(echo_logs
+            .iter()
+            .any(|e| e.content == EchoLogContent::BountyOfBlood && !e.unlocked));
+
+        character_data.unlock_all_echo_logs();
+        character_data.unlock_echo_log(typhon_log);
+
+        assert_eq!(character_data.echo_logs().len(), ECHO_LOGS.len());
+        assert!(character_data.echo_logs().iter().all(|e| e.unlocked));
+
+        character_data
+            .set_echo_log_seen(typhon_log, true)
+            .expect("failed to mark echo log as seen");
+
+        let echo_log = character_data
+            .echo_logs()
+            .into_iter()
+            .find(|e| e.echo_log_path == typhon_log)
+            .expect("failed to find echo log");
+
+        assert_eq!(echo_log.name, "Typhon Log: Beach 1");
+        assert!(echo_log.seen);
+
+        character_data.mark_all_echo_logs_seen();
+
+        assert!(character_data.echo_logs().iter().all(|e| e.seen));
+    }
+
+    #[test]
+    fn test_crew_quarters_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        let siren = "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Siren.PlayerQuarterActorData_Siren";
+        let gunner = "/Game/InteractiveObjects/PlayerQuarters/LevelData/PlayerQuarterActorData_Gunner.PlayerQuarterActorData_Gunner";
+
+        assert_eq!(character_data.crew_quarters_room(), Some(siren));
+
+        // Only the room of the character's class can be used until others are unlocked
+        assert!(character_data.set_crew_quarters_room(gunner, &[]).is_err());
+
+        character_data
+            .set_crew_quarters_room(gunner, &[gunner.to_owned()])
+            .expect("failed to set room");
+
+        assert_eq!(character_data.crew_quarters_room(), Some(gunner));
+        assert!(character_data
+            .set_crew_quarters_room("/Game/Unknown", &[])
+            .is_err());
+
+        character_data
+            .set_crew_quarters_room(siren, &[])
+            .expect("failed to set room");
+
+        let decorations = character_data.crew_quarters_decorations();
+
+        assert_eq!(decorations.len(), 3);
+        assert_eq!(decorations[0].name, "Trooper Disc");
+        assert_eq!(decorations[1].name, "Neon Lips");
+        assert_eq!(decorations[2].name, "Moxxxi's Bar");
+
+        let profile_filename = Path::new("./test_files/profile.sav");
+
+        let profile_file_data = fs::read(profile_filename).expect("failed to read test_file");
+
+        let bl3_profile =
+            Bl3Profile::from_bytes(profile_filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let unlocked_decorations = bl3_profile
+            .profile_data
+            .unlocked_crew_quarters_decorations();
+
+        assert_eq!(unlocked_decorations.len(), 26);
+
+        let bandit_tire = "/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_4.PlayerQuarterDecoration_4";
+        let moxxis_bar = "/Game/InteractiveObjects/PlayerQuarters/Decoration/PlayerQuarterDecoration_5.PlayerQuarterDecoration_5";
+
+        assert!(character_data
+            .set_crew_quarters_decoration(2, Some(moxxis_bar), &unlocked_decorations)
+            .is_err());
+
+        character_data
+            .set_crew_quarters_decoration(2, Some(bandit_tire), &unlocked_decorations)
+            .expect("failed to place decoration");
+        character_data
+            .set_crew_quarters_decoration(3, None, &unlocked_decorations)
+            .expect("failed to clear decoration");
+
+        assert!(character_data
+            .set_crew_quarters_decoration(4, Some(bandit_tire), &unlocked_decorations)
+            .is_err());
+        assert!(character_data
+            .set_crew_quarters_decoration(1, Some("/Game/Unknown"), &unlocked_decorations)
+            .is_err());
+
+        let decorations = character_data.crew_quarters_decorations();
+
+        assert_eq!(decorations[1].name, "Bandit Tire");
+        assert!(decorations[2].is_empty());
+        assert_eq!(
+            character_data
+                .character
+                .get_crew_quarters_room()
+                .decorations[2]
+                .decoration_data_path,
+            EMPTY_CREW_QUARTERS_DECORATION
+        );
+    }
+
+    #[test]
+    fn test_gun_rack_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let character_data = &mut bl3_save.character_data;
+
+        assert_eq!(character_data.gun_rack_items().len(), 8);
+
+        let shotgun_index = character_data
+            .gun_rack_items()
+            .iter()
+            .position(|r| r.slot == GunRackSlot::Shotgun)
+            .expect("failed to find shotgun");
+
+        let shotgun = character_data.gun_rack_items()[shotgun_index].item.clone();
+        let shield = character_data
+            .gun_rack_items()
+            .iter()
+            .find(|r| r.slot == GunRackSlot::Shield)
+            .map(|r| r.item.clone())
+            .expect("failed to find shield");
+
+        assert_eq!(GunRackSlot::from_item(&shotgun), Some(GunRackSlot::Shotgun));
+        assert!(character_data.add_gun_rack_item(&shotgun).is_err());
+        assert!(character_data
+            .replace_gun_rack_item(shotgun_index, &shield)
+            .is_err());
+
+        let mut new_shotgun = shotgun.clone();
+        new_shotgun.set_level(1).expect("failed to set level");
+
+        character_data
+            .replace_gun_rack_item(shotgun_index, &new_shotgun)
+            .expect("failed to replace shotgun");
+
+        character_data.remove_gun_rack_item(shotgun_index);
+
+        assert_eq!(character_data.gun_rack_items().len(), 7);
+        assert_eq!(
+            character_data
+                .character
+                .get_crew_quarters_gun_rack()
+                .rack_save_data
+                .len(),
+            7
+        );
+
+        character_data
+            .add_gun_rack_item(&new_shotgun)
+            .expect("failed to add shotgun");
+
+        let rack_save_data = &character_data
+            .character
+            .get_crew_quarters_gun_rack()
+            .rack_save_data;
+
+        let rack_item = rack_save_data.last().expect("failed to find rack item");
+
+        assert_eq!(
+            rack_item.slot_asset_path,
+            "/Game/InteractiveObjects/WeaponRack/DataAssets/GunRack_Slot_Shotgun.GunRack_Slot_Shotgun"
+        );
+        assert_eq!(
+            Bl3Item::from_serial_bytes(&rack_item.encrypted_serial_number, None)
+                .expect("failed to read rack item")
+                .level(),
+            1
+        );
+
+        // A rack item which can't be read still holds its slot
+        character_data
+            .character
+            .mut_crew_quarters_gun_rack()
+            .rack_save_data
+            .last_mut()
+            .expect("failed to find rack item")
+            .encrypted_serial_number = vec![0; 8];
+
+        let new_save = Bl3Save::from_bytes(
+            filename,
+            &bl3_save.as_bytes().expect("failed to write save").0,
+            HeaderType::PcSave,
+        )
+        .expect("failed to read written save");
+
+        let mut character_data = new_save.character_data;
+
+        assert_eq!(character_data.gun_rack_items().len(), 7);
+        assert!(character_data.add_gun_rack_item(&new_shotgun).is_err());
+    }
+
+    #[test]
+    fn test_weapon_cosmetics_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let profile_filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(&profile_filename).expect("failed to read test_file");
+
+        let bl3_profile =
+            Bl3Profile::from_bytes(profile_filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let unlocked_weapon_skins = bl3_profile.profile_data.unlocked_weapon_skins();
+        let unlocked_weapon_trinkets = bl3_profile.profile_data.unlocked_weapon_trinkets();
+
+        assert_eq!(unlocked_weapon_skins.len(), 23);
+        assert_eq!(unlocked_weapon_trinkets.len(), 63);
+
+        let mut character_data = bl3_save.character_data;
+
+        assert_eq!(
+            character_data.inventory_items()[21].weapon_skin_path(),
+            Some("/Game/Gear/WeaponSkins/_Design/SkinParts/WeaponSkin_15.WeaponSkin_15")
+        );
+        assert_eq!(character_data.inventory_items()[0].weapon_skin_path(), None);
+        assert!(character_data.inventory_items()[21]
+            .customization_parts()
+            .is_empty());
+
+        let weapon_skin = "/Game/Gear/WeaponSkins/_Design/SkinParts/WeaponSkin_20.WeaponSkin_20";
+        let locked_weapon_skin =
+            "/Game/PatchDLC/VaultCard/Customizations/WeaponSkin/WeaponSkin_VC1_1.WeaponSkin_VC1_1";
+
+        character_data
+            .set_inventory_item_weapon_skin(46, Some(weapon_skin), &unlocked_weapon_skins)
+            .expect("failed to set weapon skin");
+
+        assert_eq!(
+            character_data.inventory_items()[46].weapon_skin_path(),
+            Some(weapon_skin)
+        );
+        assert_eq!(
+            character_data.character.inventory_items[46].weapon_skin_path,
+            weapon_skin
+        );
+
+        // Skins have to be unlocked and can only be applied to weapons
+        assert!(character_data
+            .set_inventory_item_weapon_skin(46, Some(locked_weapon_skin), &unlocked_weapon_skins)
+            .is_err());
+        assert!(character_data
+            .set_inventory_item_weapon_skin(0, Some(weapon_skin), &unlocked_weapon_skins)
+            .is_err());
+        assert_eq!(
+            character_data.inventory_items()[46].weapon_skin_path(),
+            Some(weapon_skin)
+        );
+
+        character_data
+            .set_inventory_item_weapon_skin(21, None, &unlocked_weapon_skins)
+            .expect("failed to remove weapon skin");
+
+        assert!(character_data.character.inventory_items[21]
+            .weapon_skin_path
+            .is_empty());
+
+        let trinket =
+            "/Game/Gear/WeaponTrinkets/_Design/TrinketParts/WeaponTrinket_8.WeaponTrinket_8";
+        let locked_trinket =
+            "/Game/PatchDLC/VaultCard/Gear/WeaponTrinkets/_Design/WeaponTrinket_VC1_1.WeaponTrinket_VC1_1";
+
+        character_data
+            .set_equipped_trinket(
+                &InventorySlot::Weapon1,
+                Some(trinket),
+                &unlocked_weapon_trinkets,
+            )
+            .expect("failed to set trinket");
+
+        assert!(character_data
+            .set_equipped_trinket(
+                &InventorySlot::Weapon1,
+                Some(locked_trinket),
+                &unlocked_weapon_trinkets
+            )
+            .is_err());
+        assert!(character_data
+            .set_equipped_trinket(
+                &InventorySlot::Shield,
+                Some(trinket),
+                &unlocked_weapon_trinkets
+            )
+            .is_err());
+        assert!(character_data
+            .set_equipped_trinket(
+                &InventorySlot::Weapon2,
+                Some(weapon_skin),
+                &unlocked_weapon_trinkets
+            )
+            .is_err());
+
+        assert_eq!(
+            character_data
+                .equipped_items()
+                .into_iter()
+                .find(|e| e.slot == InventorySlot::Weapon1)
+                .map(|e| (
+                    e.item.weapon_skin_path().map(|s| s.to_owned()),
+                    e.trinket_data_path
+                )),
+            Some((Some(weapon_skin.to_owned()), trinket.to_owned()))
+        );
+
+        // The skin stays with the item when it is moved to another save
+        let mut new_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        new_save
+            .character_data
+            .add_inventory_item(100, &character_data.inventory_items()[46])
+            .expect("failed to add item");
+
+        let (output, _) = new_save.as_bytes().expect("failed to write test save");
+
+        let new_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read written test save");
+
+        assert_eq!(
+            new_save
+                .character_data
+                .inventory_items()
+                .last()
+                .and_then(|i| i.weapon_skin_path()),
+            Some(weapon_skin)
+        );
+    }
+
+    #[test]
+    fn test_player_colors_pc() {
+        let filename = Path::new("./test_files/1012.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let player_colors = bl3_save.character_data.player_colors();
+
+        assert_eq!(
+            player_colors
+                .iter()
+                .map(|c| (c.slot, c.color.to_hex(), c.use_default_color))
+                .collect::<Vec<_>>(),
+            vec![
+                (PlayerColorSlot::Primary, "#FF0071".to_owned(), false),
+                (PlayerColorSlot::Secondary, "#FF0000".to_owned(), false),
+                (PlayerColorSlot::Tertiary, "#FFFFFF".to_owned(), false),
+            ]
+        );
+
+        assert_eq!(
+            PlayerColor::from_hex("#ff0071")
+                .expect("failed to parse color")
+                .to_hex(),
+            "#FF0071"
+        );
+        assert!(PlayerColor::from_hex("#FF00").is_err());
+        assert!(PlayerColor::from_hex("GG0071").is_err());
+
+        // Presets are applied by setting every color they contain
+        let preset = PlayerColorPreset {
+            name: "Neon".to_owned(),
+            colors: vec![PlayerColorData {
+                slot: PlayerColorSlot::Secondary,
+                use_default_color: false,
+                use_default_split_color: true,
+                color: PlayerColor::from_hex("00D6FF").expect("failed to parse color"),
+                split_color: PlayerColor::from_hex("#141414").expect("failed to parse color"),
+            }],
+        };
+
+        // Presets are stored as TOML files in the config directory
+        let preset_toml = toml::to_string_pretty(&preset).expect("failed to serialize preset");
+        let preset: PlayerColorPreset =
+            toml::from_slice(preset_toml.as_bytes()).expect("failed to deserialize preset");
+
+        for player_color in &preset.colors {
+            bl3_save.character_data.set_player_color(player_color);
+        }
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read written test save");
+
+        let player_colors = bl3_save.character_data.player_colors();
+
+        assert_eq!(player_colors[0].color.to_hex(), "#FF0071");
+        assert_eq!(player_colors[1], preset.colors[0]);
+        assert_eq!(player_colors[2].color.to_hex(), "#FFFFFF");
+        assert_eq!(
+            bl3_save
+                .character_data
+                .character
+                .selected_color_customizations
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_equipped_emotes_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        let profile_filename = Path::new("./test_files/1prof.sav");
+
+        let profile_file_data = fs::read(&profile_filename).expect("failed to read test_file");
+
+        let bl3_profile =
+            Bl3Profile::from_bytes(profile_filename, &profile_file_data, HeaderType::PcProfile)
+                .expect("failed to read test profile");
+
+        let unlocked_emotes = bl3_profile.profile_data.unlocked_emotes();
+
+        assert_eq!(unlocked_emotes.len(), 72);
+
+        let emote = |name: &str| {
+            PROFILE_EMOTES_DEFAULTS
+                .iter()
+                .chain(PROFILE_EMOTES.iter())
+                .find(|e| e.ident.ends_with(name))
+                .cloned()
+                .expect("failed to find emote")
+        };
+
+        assert_eq!(
+            bl3_save.character_data.equipped_emotes(),
+            vec![
+                Some(emote("CustomEmote_Siren_09_RobotDance")),
+                Some(emote("CustomEmote_Siren_01_Wave")),
+                Some(emote("CustomEmote_Siren_16")),
+                Some(emote("CustomEmote_Siren_05_Heart")),
+            ]
+        );
+
+        let make_it_rain = emote("CustomEmote_Siren_14_MakeItRain");
+
+        bl3_save
+            .character_data
+            .set_equipped_emote(1, &make_it_rain, &unlocked_emotes)
+            .expect("failed to set emote");
+
+        // Emotes have to be unlocked and belong to the character's class
+        assert!(bl3_save
+            .character_data
+            .set_equipped_emote(
+                0,
+                &emote("CustomEmote_Beastmaster_14_MakeItRain"),
+                &unlocked_emotes
+            )
+            .is_err());
+        assert!(bl3_save
+            .character_data
+            .set_equipped_emote(0, &emote("CustomEmote_Siren_VC1_1"), &unlocked_emotes)
+            .is_err());
+        assert!(bl3_save
+            .character_data
+            .set_equipped_emote(EMOTE_SLOTS, &make_it_rain, &unlocked_emotes)
+            .is_err());
+
+        // An emote can only be on the wheel once
+        assert!(bl3_save
+            .character_data
+            .set_equipped_emote(3, &emote("CustomEmote_Siren_16"), &unlocked_emotes)
+            .is_err());
+
+        // A slot pointing at another kind of customization keeps it and gets a new entry
+        let mut other_save = bl3_save.clone();
+
+        let character = &mut other_save.character_data.character;
+
+        let (skin_index, skin) = character
+            .selected_customizations
+            .iter()
+            .enumerate()
+            .find(|(_, c)| {
+                !PROFILE_EMOTES_DEFAULTS
+                    .iter()
+                    .chain(PROFILE_EMOTES.iter())
+                    .any(|e| e.ident == c.as_str())
+            })
+            .map(|(i, c)| (i, c.clone()))
+            .expect("failed to find a customization which isn't an emote");
+
+        character.equipped_emote_customizations[3] = skin_index as i32;
+
+        let customizations_len = character.selected_customizations.len();
+
+        other_save
+            .character_data
+            .set_equipped_emote(3, &emote("CustomEmote_Siren_03_Point"), &unlocked_emotes)
+            .expect("failed to set emote");
+
+        let character = &other_save.character_data.character;
+
+        assert_eq!(character.selected_customizations[skin_index], skin);
+        assert_eq!(
+            character.selected_customizations.len(),
+            customizations_len + 1
+        );
+        assert_eq!(
+            character.equipped_emote_customizations[3] as usize,
+            customizations_len
+        );
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read written test save");
+
+        assert_eq!(
+            bl3_save.character_data.equipped_emotes(),
+            vec![
+                Some(emote("CustomEmote_Siren_09_RobotDance")),
+                Some(make_it_rain),
+                Some(emote("CustomEmote_Siren_16")),
+                Some(emote("CustomEmote_Siren_05_Heart")),
+            ]
+        );
+        assert_eq!(
+            bl3_save
+                .character_data
+                .character
+                .selected_customizations
+                .len(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_opaque_items_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        // An item from a newer version of the game than the item parser supports
+        let newer_item = Bl3Item::from_serial_base64("BL3(AwAAAAAKG4D/I9xAEzwShMJVX8nPYwsAAA==)")
+            .expect("failed to read newer item");
+
+        assert!(newer_item.is_opaque());
+
+        let inventory_len = bl3_save.character_data.inventory_items().len();
+
+        bl3_save
+            .character_data
+            .add_inventory_item(100, &newer_item)
+            .expect("failed to add item");
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read written test save");
+
+        let inventory_items = bl3_save.character_data.inventory_items();
+
+        assert_eq!(inventory_items.len(), inventory_len + 1);
+        assert!(inventory_items[inventory_len].is_opaque());
+        assert_eq!(
+            inventory_items[inventory_len].opaque_data(),
+            newer_item.opaque_data()
+        );
+        assert_eq!(
+            bl3_save.character_data.character.inventory_items[inventory_len].item_serial_number,
+            newer_item.get_serial_number(true).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unreadable_items_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let mut bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        // The checksum of this item will no longer match
+        bl3_save.character_data.character.inventory_items[2].item_serial_number[10] ^= 0xFF;
+
+        let raw_serials = bl3_save
+            .character_data
+            .character
+            .inventory_items
+            .iter()
+            .map(|i| i.item_serial_number.clone())
+            .collect::<Vec<_>>();
+
+        let (output, _) = bl3_save.as_bytes().expect("failed to write test save");
+
+        let mut unreadable_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to read save with an unreadable item");
+
+        assert_eq!(unreadable_save.load_warnings.len(), 1);
+        assert_eq!(unreadable_save.load_warnings[0].field, "inventory_items");
+
+        let inventory_items = unreadable_save.character_data.inventory_items().clone();
+
+        assert_eq!(inventory_items.len(), raw_serials.len());
+        assert!(inventory_items[2]
+            .opaque_data()
+            .and_then(|o| o.error.as_ref())
+            .is_some());
+
+        // Every item keeps the index of its serial in the file
+        for (item, raw_serial) in inventory_items.iter().zip(raw_serials.iter()) {
+            assert_eq!(item.get_serial_number(true).unwrap(), *raw_serial);
+        }
+
+        // Editing the item after the unreadable one only changes that item
+        let mut edited_item = inventory_items[3].clone();
+
+        edited_item.set_level(1).expect("failed to set item level");
+
+        unreadable_save
+            .character_data
+            .replace_inventory_item(3, 3, &edited_item)
+            .expect("failed to replace item");
+
+        let (output, _) = unreadable_save
+            .as_bytes()
+            .expect("failed to write save with an unreadable item");
+
+        let reloaded_save = Bl3Save::from_bytes(filename, &output, HeaderType::PcSave)
+            .expect("failed to reload save with an unreadable item");
+
+        let reloaded_serials = reloaded_save
+            .character_data
+            .character
+            .inventory_items
+            .iter()
+            .map(|i| i.item_serial_number.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(reloaded_serials.len(), raw_serials.len());
+        assert_eq!(reloaded_serials[2], raw_serials[2]);
+        assert_ne!(reloaded_serials[3], raw_serials[3]);
+        assert_eq!(reloaded_serials[4..], raw_serials[4..]);
+        assert_eq!(reloaded_save.character_data.inventory_items()[3].level(), 1);
+    }
+
+    #[test]
+    fn test_file_hash_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert_eq!(bl3_save.file_hash, file_helper::file_hash(&save_file_data));
+
+        let mut modified_save = bl3_save.clone();
+
+        modified_save
+            .character_data
+            .set_player_level(0)
+            .expect("failed to set player level");
+
+        let (output, written_save) = modified_save
+            .as_bytes()
+            .expect("failed to write modified save");
+
+        // The written save is compared against the new file
+        assert_ne!(written_save.file_hash, bl3_save.file_hash);
+        assert_eq!(written_save.file_hash, file_helper::file_hash(&output));
+
+        let bl3_file_type =
+            Bl3FileType::from_unknown_data(filename, &save_file_data).expect("failed to read file");
+
+        assert_eq!(bl3_file_type.file_hash(), bl3_save.file_hash);
+    }
+
+    #[test]
+    fn test_lenient_load_pc() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let save_file_data = fs::read(&filename).expect("failed to read test_file");
+
+        let bl3_save = Bl3Save::from_bytes(filename, &save_file_data, HeaderType::PcSave)
+            .expect("failed to read test save");
+
+        assert!(bl3_save.load_warnings.is_empty());
+
+        let mut character = bl3_save.character_data.character.clone();
+
+        let ammo_pools = bl3_save.character_data.ammo_pools().len();
+
+        character.resource_pools[0].resource_path = "/Game/Unknown/Ammo".to_owned();
+        character
+            .player_class_data
+            .as_mut()
+            .expect("failed to read player class")
+            .player_class_path = "/Game/PlayerCharacters/Unknown".to_owned();
+
+        assert!(CharacterData::from_character(character.clone()).is_err());
+
+        let mut warnings = LoadWarnings::new(LoadMode::Lenient);
+
+        let character_data = CharacterData::from_character_with_warnings(character, &mut warnings)
+            .expect("failed to read character leniently");
+
+        assert_eq!(character_data.player_class(), PlayerClass::default());
+        assert_eq!(character_data.ammo_pools().len(), ammo_pools - 1);
+
+        let warnings = warnings.into_warnings(&bl3_save.file_name);
+
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.file.as_str(), w.field.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("19.sav", "player_class"), ("19.sav", "resource_pools")]
+        );
+        assert!(warnings[1].detail.contains("/Game/Unknown/Ammo"));
+    }
 }
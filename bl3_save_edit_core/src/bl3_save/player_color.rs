@@ -0,0 +1,141 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+
+use crate::protos::oak_save::CustomPlayerColorSaveGameData;
+use crate::protos::oak_shared::Vec3;
+
+#[derive(
+    Debug,
+    Display,
+    EnumString,
+    EnumMessage,
+    EnumIter,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Copy,
+    Clone,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerColorSlot {
+    #[strum(serialize = "*Color_Primary1", to_string = "主色")]
+    Primary,
+    #[strum(serialize = "*Color_Secondary1", to_string = "次色")]
+    Secondary,
+    #[strum(serialize = "*Color_Tertiary1", to_string = "第三色")]
+    Tertiary,
+}
+
+impl std::default::Default for PlayerColorSlot {
+    fn default() -> Self {
+        Self::Primary
+    }
+}
+
+/// A color as the game stores it, each channel is between `0.0` and `1.0`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl PlayerColor {
+    pub fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let hex = hex.trim().trim_start_matches('#');
+
+        if hex.len() != 6 || !hex.is_ascii() {
+            bail!("{} is not a color in the format #RRGGBB", hex);
+        }
+
+        let channel = |i: usize| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map(|c| c as f32 / 255.0)
+                .with_context(|| format!("{} is not a color in the format #RRGGBB", hex))
+        };
+
+        Ok(Self::new(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    pub fn to_hex(&self) -> String {
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        format!(
+            "#{:02X}{:02X}{:02X}",
+            channel(self.r),
+            channel(self.g),
+            channel(self.b)
+        )
+    }
+
+    fn from_vec3(vec3: Option<&Vec3>) -> Self {
+        vec3.map(|v| Self::new(v.x, v.y, v.z)).unwrap_or_default()
+    }
+
+    fn to_vec3(self) -> Vec3 {
+        Vec3 {
+            x: self.r,
+            y: self.g,
+            z: self.b,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerColorData {
+    pub slot: PlayerColorSlot,
+    pub use_default_color: bool,
+    pub use_default_split_color: bool,
+    pub color: PlayerColor,
+    pub split_color: PlayerColor,
+}
+
+impl PlayerColorData {
+    /// A slot which hasn't been customized, the game uses the colors of the selected skin.
+    pub fn new(slot: PlayerColorSlot) -> Self {
+        Self {
+            slot,
+            use_default_color: true,
+            use_default_split_color: true,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn from_save_data(
+        slot: PlayerColorSlot,
+        data: &CustomPlayerColorSaveGameData,
+    ) -> Self {
+        Self {
+            slot,
+            use_default_color: data.use_default_color,
+            use_default_split_color: data.use_default_split_color,
+            color: PlayerColor::from_vec3(data.applied_color.as_ref()),
+            split_color: PlayerColor::from_vec3(data.split_color.as_ref()),
+        }
+    }
+
+    pub(crate) fn apply_to_save_data(&self, data: &mut CustomPlayerColorSaveGameData) {
+        data.color_parameter = self.slot.get_serializations()[0].to_owned();
+        data.applied_color = Some(self.color.to_vec3()).into();
+        data.split_color = Some(self.split_color.to_vec3()).into();
+        data.use_default_color = self.use_default_color;
+        data.use_default_split_color = self.use_default_split_color;
+    }
+}
+
+/// A named set of colors which can be applied to any character.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerColorPreset {
+    pub name: String,
+    pub colors: Vec<PlayerColorData>,
+}
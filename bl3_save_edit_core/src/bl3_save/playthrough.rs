@@ -3,8 +3,9 @@ use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIter
 use rayon::slice::ParallelSliceMut;
 
 use crate::bl3_save::util::{get_filtered_mission_list, IMPORTANT_MISSIONS};
-use crate::game_data::{GameDataKv, FAST_TRAVEL, MISSION};
+use crate::game_data::GameDataKv;
 use crate::protos::oak_save::{Character, MissionStatusPlayerSaveGameData_MissionState};
+use crate::resources::{FAST_TRAVEL, MISSION};
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Playthrough {
@@ -43,13 +44,13 @@ impl Playthrough {
                     .unwrap_or_default();
 
                 let mut active_missions = get_filtered_mission_list(
-                    MISSION,
+                    &MISSION,
                     mission_playthrough_data,
                     MissionStatusPlayerSaveGameData_MissionState::MS_Active,
                 );
 
                 let mut missions_completed = get_filtered_mission_list(
-                    MISSION,
+                    &MISSION,
                     mission_playthrough_data,
                     MissionStatusPlayerSaveGameData_MissionState::MS_Complete,
                 );
@@ -134,8 +134,8 @@ pub fn experience_to_level(experience: i32) -> Result<i32> {
         })
 }
 
-pub fn get_filtered_mission_list<const LENGTH: usize>(
-    all_missions: [GameDataKv; LENGTH],
+pub fn get_filtered_mission_list(
+    all_missions: &[GameDataKv],
     m: &MissionPlaythroughSaveGameData,
     status: MissionStatusPlayerSaveGameData_MissionState,
 ) -> Vec<String> {
@@ -0,0 +1,600 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+use anyhow::Result;
+use protobuf::descriptor::FieldDescriptorProto_Type;
+use protobuf::well_known_types::Empty;
+use protobuf::{Message, UnknownFields, UnknownValue, UnknownValueRef};
+use strum::Display;
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::playthrough::Playthrough;
+use crate::bl3_save::Bl3Save;
+use crate::vehicle_data::VehicleType;
+
+#[derive(Debug, Copy, Clone, Display, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DiffSection {
+    #[strum(to_string = "角色")]
+    Character,
+    #[strum(to_string = "金钱")]
+    Currency,
+    #[strum(to_string = "SDU")]
+    Sdu,
+    #[strum(to_string = "弹药")]
+    Ammo,
+    #[strum(to_string = "任务")]
+    Missions,
+    #[strum(to_string = "车辆")]
+    Vehicles,
+    #[strum(to_string = "挑战")]
+    Challenges,
+    #[strum(to_string = "背包")]
+    Inventory,
+    #[strum(to_string = "钥匙")]
+    Keys,
+    #[strum(to_string = "守护者")]
+    Guardian,
+    #[strum(to_string = "科学")]
+    Science,
+    #[strum(to_string = "外观")]
+    Cosmetics,
+    #[strum(to_string = "银行")]
+    Bank,
+    #[strum(to_string = "失物招领")]
+    LostLoot,
+}
+
+/// A single difference between two files, `None` means the value is missing on that side.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct DiffEntry {
+    pub section: DiffSection,
+    pub field: String,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct DiffBuilder {
+    entries: Vec<DiffEntry>,
+}
+
+impl DiffBuilder {
+    fn value<T: PartialEq + Display>(
+        &mut self,
+        section: DiffSection,
+        field: impl Into<String>,
+        left: T,
+        right: T,
+    ) {
+        if left != right {
+            self.entries.push(DiffEntry {
+                section,
+                field: field.into(),
+                left: Some(left.to_string()),
+                right: Some(right.to_string()),
+            });
+        }
+    }
+
+    fn optional<T: PartialEq + Display>(
+        &mut self,
+        section: DiffSection,
+        field: impl Into<String>,
+        left: Option<T>,
+        right: Option<T>,
+    ) {
+        if left != right {
+            self.entries.push(DiffEntry {
+                section,
+                field: field.into(),
+                left: left.map(|l| l.to_string()),
+                right: right.map(|r| r.to_string()),
+            });
+        }
+    }
+
+    /// Values are matched by key, keys which only exist on one side are reported as missing.
+    fn keyed<K: Ord + Display, T: PartialEq + Display>(
+        &mut self,
+        section: DiffSection,
+        left: impl IntoIterator<Item = (K, T)>,
+        right: impl IntoIterator<Item = (K, T)>,
+    ) {
+        let mut all: BTreeMap<K, (Option<T>, Option<T>)> = BTreeMap::new();
+
+        for (k, v) in left {
+            all.entry(k).or_insert((None, None)).0 = Some(v);
+        }
+
+        for (k, v) in right {
+            all.entry(k).or_insert((None, None)).1 = Some(v);
+        }
+
+        for (k, (l, r)) in all {
+            self.optional(section, k.to_string(), l, r);
+        }
+    }
+
+    /// Items are matched by their unencrypted serial so that moving an item around
+    /// in the inventory is not reported as a change.
+    fn items(&mut self, section: DiffSection, left: &[Bl3Item], right: &[Bl3Item]) -> Result<()> {
+        let mut all: BTreeMap<String, (String, i64)> = BTreeMap::new();
+
+        for (items, change) in [(left, 1), (right, -1)] {
+            for item in items {
+                let serial = item.get_serial_number_base64(false)?;
+
+                all.entry(serial).or_insert_with(|| (item_label(item), 0)).1 += change;
+            }
+        }
+
+        for (serial, (label, count)) in all {
+            for _ in 0..count.abs() {
+                let (left, right) = if count > 0 {
+                    (Some(serial.clone()), None)
+                } else {
+                    (None, Some(serial.clone()))
+                };
+
+                self.entries.push(DiffEntry {
+                    section,
+                    field: label.clone(),
+                    left,
+                    right,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn item_label(item: &Bl3Item) -> String {
+    let balance = item.balance_part();
+
+    let name = balance
+        .name
+        .as_ref()
+        .or(balance.short_ident.as_ref())
+        .unwrap_or(&balance.ident);
+
+    format!("{} ({})", name, item.level())
+}
+
+fn vehicle_label(vehicle_type: &VehicleType) -> String {
+    format!("{} {}", vehicle_type, vehicle_type.subtype_name())
+}
+
+fn mission_statuses(playthrough: Option<&Playthrough>) -> Vec<(String, &'static str)> {
+    playthrough
+        .map(|p| {
+            p.missions_completed
+                .iter()
+                .map(|m| (m.to_owned(), "已完成"))
+                .chain(p.active_missions.iter().map(|m| (m.to_owned(), "进行中")))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Compares two saves, `left` is usually the older file such as a backup.
+pub fn diff_saves(left: &Bl3Save, right: &Bl3Save) -> Result<Vec<DiffEntry>> {
+    let mut diff = DiffBuilder::default();
+
+    let l = &left.character_data;
+    let r = &right.character_data;
+
+    diff.value(
+        DiffSection::Character,
+        "名字",
+        &l.character.preferred_character_name,
+        &r.character.preferred_character_name,
+    );
+    diff.value(
+        DiffSection::Character,
+        "职业",
+        l.player_class(),
+        r.player_class(),
+    );
+    diff.value(
+        DiffSection::Character,
+        "等级",
+        l.player_level(),
+        r.player_level(),
+    );
+    diff.value(
+        DiffSection::Character,
+        "经验",
+        l.character.experience_points,
+        r.character.experience_points,
+    );
+    diff.value(
+        DiffSection::Character,
+        "技能点",
+        l.ability_points(),
+        r.ability_points(),
+    );
+    diff.value(
+        DiffSection::Character,
+        "守护者等级",
+        l.guardian_rank(),
+        r.guardian_rank(),
+    );
+
+    diff.value(DiffSection::Currency, "金钱", l.money(), r.money());
+    diff.value(DiffSection::Currency, "镒矿", l.eridium(), r.eridium());
+
+    diff.keyed(
+        DiffSection::Sdu,
+        l.sdu_slots().iter().map(|s| (s.sdu.to_string(), s.current)),
+        r.sdu_slots().iter().map(|s| (s.sdu.to_string(), s.current)),
+    );
+
+    diff.keyed(
+        DiffSection::Ammo,
+        l.ammo_pools()
+            .iter()
+            .map(|a| (a.pool.to_string(), a.current)),
+        r.ammo_pools()
+            .iter()
+            .map(|a| (a.pool.to_string(), a.current)),
+    );
+
+    let playthroughs = l.playthroughs().len().max(r.playthroughs().len());
+
+    diff.value(
+        DiffSection::Missions,
+        "周目数量",
+        l.playthroughs().len(),
+        r.playthroughs().len(),
+    );
+
+    for i in 0..playthroughs {
+        let lp = l.playthroughs().get(i);
+        let rp = r.playthroughs().get(i);
+
+        let prefix = format!("周目 {}", i + 1);
+
+        diff.optional(
+            DiffSection::Missions,
+            format!("{}: 混乱等级", prefix),
+            lp.map(|p| p.mayhem_level),
+            rp.map(|p| p.mayhem_level),
+        );
+        diff.optional(
+            DiffSection::Missions,
+            format!("{}: 混乱种子", prefix),
+            lp.map(|p| p.mayhem_random_seed),
+            rp.map(|p| p.mayhem_random_seed),
+        );
+        diff.optional(
+            DiffSection::Missions,
+            format!("{}: 当前地图", prefix),
+            lp.map(|p| p.current_map.name),
+            rp.map(|p| p.current_map.name),
+        );
+        diff.keyed(
+            DiffSection::Missions,
+            mission_statuses(lp)
+                .into_iter()
+                .map(|(m, s)| (format!("{}: {}", prefix, m), s)),
+            mission_statuses(rp)
+                .into_iter()
+                .map(|(m, s)| (format!("{}: {}", prefix, m), s)),
+        );
+    }
+
+    diff.keyed(
+        DiffSection::Vehicles,
+        l.vehicle_data()
+            .iter()
+            .map(|v| (vehicle_label(&v.vehicle_type), v.current)),
+        r.vehicle_data()
+            .iter()
+            .map(|v| (vehicle_label(&v.vehicle_type), v.current)),
+    );
+
+    diff.keyed(
+        DiffSection::Challenges,
+        l.challenge_milestones()
+            .iter()
+            .map(|c| (c.challenge.to_string(), c.unlocked)),
+        r.challenge_milestones()
+            .iter()
+            .map(|c| (c.challenge.to_string(), c.unlocked)),
+    );
+
+    diff.items(
+        DiffSection::Inventory,
+        l.inventory_items(),
+        r.inventory_items(),
+    )?;
+
+    Ok(diff.entries)
+}
+
+/// Compares two profiles, `left` is usually the older file such as a backup.
+pub fn diff_profiles(left: &Bl3Profile, right: &Bl3Profile) -> Result<Vec<DiffEntry>> {
+    let mut diff = DiffBuilder::default();
+
+    let l = &left.profile_data;
+    let r = &right.profile_data;
+
+    diff.value(
+        DiffSection::Keys,
+        "金钥匙",
+        l.golden_keys(),
+        r.golden_keys(),
+    );
+    diff.value(
+        DiffSection::Keys,
+        "钻石钥匙",
+        l.diamond_keys(),
+        r.diamond_keys(),
+    );
+
+    diff.value(
+        DiffSection::Guardian,
+        "守护者等级",
+        l.guardian_rank(),
+        r.guardian_rank(),
+    );
+    diff.value(
+        DiffSection::Guardian,
+        "守护者令牌",
+        l.guardian_tokens(),
+        r.guardian_tokens(),
+    );
+    diff.keyed(
+        DiffSection::Guardian,
+        l.guardian_rewards()
+            .iter()
+            .map(|g| (g.reward.to_string(), g.current)),
+        r.guardian_rewards()
+            .iter()
+            .map(|g| (g.reward.to_string(), g.current)),
+    );
+
+    let ls = l.borderlands_science_info();
+    let rs = r.borderlands_science_info();
+
+    diff.value(
+        DiffSection::Science,
+        "等级",
+        ls.science_level,
+        rs.science_level,
+    );
+    diff.value(DiffSection::Science, "解题数", ls.solves, rs.solves);
+    diff.value(DiffSection::Science, "令牌", ls.tokens, rs.tokens);
+
+    diff.keyed(
+        DiffSection::Sdu,
+        l.sdu_slots().iter().map(|s| (s.sdu.to_string(), s.current)),
+        r.sdu_slots().iter().map(|s| (s.sdu.to_string(), s.current)),
+    );
+
+    let cosmetics = |p: &Bl3Profile| {
+        let p = &p.profile_data;
+
+        BTreeSet::from([
+            ("角色皮肤", p.character_skins_unlocked()),
+            ("角色头部", p.character_heads_unlocked()),
+            ("回声主题", p.echo_themes_unlocked()),
+            ("表情", p.profile_emotes_unlocked()),
+            ("房间装饰", p.room_decorations_unlocked()),
+            ("武器皮肤", p.weapon_skins_unlocked()),
+            ("武器挂饰", p.weapon_trinkets_unlocked()),
+        ])
+    };
+
+    diff.keyed(DiffSection::Cosmetics, cosmetics(left), cosmetics(right));
+
+    diff.items(DiffSection::Bank, l.bank_items(), r.bank_items())?;
+    diff.items(
+        DiffSection::LostLoot,
+        l.lost_loot_items(),
+        r.lost_loot_items(),
+    )?;
+
+    Ok(diff.entries)
+}
+
+/// Reads a message as `Empty` so that every field is kept as an unknown field under its number.
+fn message_fields<M: Message>(message: &M) -> Result<UnknownFields> {
+    let empty: Empty = protobuf::Message::parse_from_bytes(&message.write_to_bytes()?)?;
+
+    Ok(empty.unknown_fields)
+}
+
+fn owned_value(value: UnknownValueRef) -> UnknownValue {
+    match value {
+        UnknownValueRef::Fixed32(v) => UnknownValue::Fixed32(v),
+        UnknownValueRef::Fixed64(v) => UnknownValue::Fixed64(v),
+        UnknownValueRef::Varint(v) => UnknownValue::Varint(v),
+        UnknownValueRef::LengthDelimited(v) => UnknownValue::LengthDelimited(v.to_vec()),
+    }
+}
+
+/// Applies the fields changed between `base` and `edited` to `reloaded`, all other fields keep the
+/// value from `reloaded`. Entries of a repeated message field are merged one by one, so an item
+/// added in the editor and one picked up in game are both kept. Any other field the editor changed
+/// takes the edited value, even when it was also changed in `reloaded`.
+fn merge_message<M: Message>(base: &M, edited: &M, reloaded: &M) -> Result<M> {
+    let base_fields = message_fields(base)?;
+    let edited_fields = message_fields(edited)?;
+
+    let mut merged = Empty::new();
+    merged.unknown_fields = message_fields(reloaded)?;
+
+    let field_numbers = base_fields
+        .iter()
+        .chain(edited_fields.iter())
+        .map(|(number, _)| number)
+        .collect::<BTreeSet<_>>();
+
+    for number in field_numbers {
+        let base_values = base_fields.get(number).cloned().unwrap_or_default();
+        let edited_values = edited_fields.get(number).cloned().unwrap_or_default();
+
+        if base_values == edited_values {
+            continue;
+        }
+
+        let is_repeated_message = reloaded
+            .descriptor()
+            .get_field_by_number(number)
+            .map(|f| {
+                f.is_repeated()
+                    && f.proto().get_field_type() == FieldDescriptorProto_Type::TYPE_MESSAGE
+            })
+            .unwrap_or(false);
+
+        if !is_repeated_message {
+            merged.unknown_fields.remove(number);
+
+            for value in &edited_values {
+                merged.unknown_fields.add_value(number, owned_value(value));
+            }
+
+            continue;
+        }
+
+        let mut merged_entries = merged
+            .unknown_fields
+            .get(number)
+            .map(|v| v.length_delimited.clone())
+            .unwrap_or_default();
+
+        for removed in base_values
+            .length_delimited
+            .iter()
+            .filter(|e| !edited_values.length_delimited.contains(e))
+        {
+            if let Some(i) = merged_entries.iter().position(|e| e == removed) {
+                merged_entries.remove(i);
+            }
+        }
+
+        merged_entries.extend(
+            edited_values
+                .length_delimited
+                .iter()
+                .filter(|e| !base_values.length_delimited.contains(e))
+                .cloned(),
+        );
+
+        merged.unknown_fields.remove(number);
+
+        for entry in merged_entries {
+            merged.unknown_fields.add_length_delimited(number, entry);
+        }
+    }
+
+    let merged = protobuf::Message::parse_from_bytes(&merged.write_to_bytes()?)?;
+
+    Ok(merged)
+}
+
+/// Applies the changes made in the editor to a save which was changed outside the editor.
+/// `base` is the save as it was loaded, `edited` has the editor's changes and `reloaded` is the
+/// save now on disk. The merged save keeps the hash of `reloaded` so it can be saved over it.
+pub fn merge_saves(base: &Bl3Save, edited: &Bl3Save, reloaded: &Bl3Save) -> Result<Bl3Save> {
+    let mut merged = reloaded.clone();
+
+    merged.character_data.character = merge_message(
+        &base.character_data.character,
+        &edited.character_data.character,
+        &reloaded.character_data.character,
+    )?;
+
+    // Reading the merged save back checks it is valid and brings the rest of the character data
+    // in line with the merged fields
+    let (_, mut merged) = merged.as_bytes()?;
+
+    merged.file_hash = reloaded.file_hash;
+
+    Ok(merged)
+}
+
+/// Applies the changes made in the editor to a profile which was changed outside the editor,
+/// see `merge_saves`.
+pub fn merge_profiles(
+    base: &Bl3Profile,
+    edited: &Bl3Profile,
+    reloaded: &Bl3Profile,
+) -> Result<Bl3Profile> {
+    let mut merged = reloaded.clone();
+
+    merged.profile_data.profile = merge_message(
+        &base.profile_data.profile,
+        &edited.profile_data.profile,
+        &reloaded.profile_data.profile,
+    )?;
+
+    let (_, mut merged) = merged.as_bytes()?;
+
+    merged.file_hash = reloaded.file_hash;
+
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::parser::HeaderType;
+
+    use super::*;
+
+    #[test]
+    fn test_merge_saves() {
+        let filename = Path::new("./test_files/19.sav");
+
+        let data = fs::read(filename).expect("failed to read test_file");
+
+        let base =
+            Bl3Save::from_bytes(filename, &data, HeaderType::PcSave).expect("failed to read save");
+
+        let mut edited = base.clone();
+        edited
+            .character_data
+            .set_money(1234)
+            .expect("failed to set money");
+
+        edited.character_data.remove_inventory_item(0);
+
+        let mut reloaded = base.clone();
+        reloaded
+            .character_data
+            .set_eridium(4321)
+            .expect("failed to set eridium");
+        reloaded.character_data.remove_inventory_item(1);
+        reloaded.file_hash = 1;
+
+        let merged = merge_saves(&base, &edited, &reloaded).expect("failed to merge saves");
+
+        assert_eq!(merged.character_data.money(), 1234);
+        assert_eq!(merged.character_data.eridium(), 4321);
+        assert_eq!(merged.file_hash, 1);
+        assert_eq!(
+            merged.character_data.inventory_items().len(),
+            base.character_data.inventory_items().len() - 2
+        );
+        assert!(!merged
+            .character_data
+            .inventory_items()
+            .contains(&base.character_data.inventory_items()[0]));
+        assert!(!merged
+            .character_data
+            .inventory_items()
+            .contains(&base.character_data.inventory_items()[1]));
+
+        let unchanged = merge_saves(&base, &base, &reloaded).expect("failed to merge saves");
+
+        assert!(diff_saves(&reloaded, &unchanged)
+            .expect("failed to diff saves")
+            .is_empty());
+    }
+}
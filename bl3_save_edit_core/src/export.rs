@@ -0,0 +1,779 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use protobuf::reflect::{ProtobufValue, ReflectFieldRef, ReflectValueRef};
+use protobuf::well_known_types::Empty;
+use protobuf::{Message, UnknownFields};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::bl3_item::Bl3Item;
+use crate::bl3_profile::profile_data::ProfileData;
+use crate::bl3_profile::Bl3Profile;
+use crate::bl3_save::character_data::CharacterData;
+use crate::bl3_save::Bl3Save;
+use crate::models::CustomFormatData;
+use crate::parser::HeaderType;
+use crate::protos::oak_profile::Profile;
+use crate::protos::oak_save::Character;
+
+// Fields holding item serials (either a single serial or a list of them), these are
+// rendered as a decoded item summary alongside the original serial.
+const SERIAL_FIELDS: [&str; 4] = [
+    "item_serial_number",
+    "encrypted_serial_number",
+    "bank_inventory_list",
+    "lost_loot_inventory_list",
+];
+
+// JSON can't represent NaN or infinity, so non-finite floats are written as an object holding
+// the bits of the float, e.g. `{ "non_finite_float": "7ff8000000000000" }`.
+const NON_FINITE_FLOAT: &str = "non_finite_float";
+
+// Fields missing from our protobuf definitions in a nested message are written into the object of
+// that message under this key, those of the top level message are kept in the export itself.
+const UNKNOWN_FIELDS: &str = "unknown_fields";
+
+/// The GVAS header that comes before the encrypted protobuf data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GvasHeader {
+    pub header_type: HeaderType,
+    pub save_game_version: u32,
+    pub package_version: u32,
+    pub engine_major: u16,
+    pub engine_minor: u16,
+    pub engine_patch: u16,
+    pub engine_build: u32,
+    pub build_id: String,
+    pub custom_format_version: u32,
+    pub custom_format_data: Vec<GvasCustomFormatData>,
+    pub save_game_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GvasCustomFormatData {
+    pub guid: String,
+    pub entry: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportedSave {
+    file_name: String,
+    header: GvasHeader,
+    character: Value,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    unknown_fields: BTreeMap<u32, ExportedUnknownValues>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportedProfile {
+    file_name: String,
+    header: GvasHeader,
+    profile: Value,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    unknown_fields: BTreeMap<u32, ExportedUnknownValues>,
+}
+
+/// Fields that are missing from our protobuf definitions, kept so that nothing is lost on import.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ExportedUnknownValues {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fixed32: Vec<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fixed64: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    varint: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    length_delimited: Vec<String>,
+}
+
+/// The unknown fields of the nested messages read from an export, by field name and the position
+/// of the message in that field (always 0 for a field which isn't repeated).
+#[derive(Debug, Default)]
+struct NestedUnknownFields {
+    unknown_fields: BTreeMap<u32, ExportedUnknownValues>,
+    fields: BTreeMap<String, BTreeMap<usize, NestedUnknownFields>>,
+}
+
+/// Only used to make exported items readable, it is ignored when importing.
+#[derive(Debug, Serialize)]
+struct ExportedItemSummary {
+    balance: String,
+    name: Option<String>,
+    manufacturer: String,
+    inv_data: String,
+    item_type: String,
+    rarity: Option<String>,
+    weapon_type: Option<String>,
+    level: usize,
+    parts: Vec<String>,
+    anointments: Vec<String>,
+}
+
+impl Bl3Save {
+    /// Exports the header and the full decrypted `Character` as pretty printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        let exported = self.to_exported()?;
+
+        Ok(serde_json::to_string_pretty(&exported)?)
+    }
+
+    /// Reads a save previously exported with `to_json`, use `as_bytes` to write it back out.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let exported = serde_json::from_str(json).context("failed to read save from json")?;
+
+        Self::from_exported(exported)
+    }
+
+    pub fn to_yaml(&self) -> Result<String> {
+        let exported = self.to_exported()?;
+
+        Ok(serde_yaml::to_string(&exported)?)
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let exported = serde_yaml::from_str(yaml).context("failed to read save from yaml")?;
+
+        Self::from_exported(exported)
+    }
+
+    fn to_exported(&self) -> Result<ExportedSave> {
+        let mut character = message_to_value(&self.character_data.character)?;
+
+        export_nested_unknown_fields(&self.character_data.character, &mut character)?;
+        export_serials(&mut character);
+
+        Ok(ExportedSave {
+            file_name: self.file_name.clone(),
+            header: GvasHeader {
+                header_type: self.header_type,
+                save_game_version: self.save_game_version,
+                package_version: self.package_version,
+                engine_major: self.engine_major,
+                engine_minor: self.engine_minor,
+                engine_patch: self.engine_patch,
+                engine_build: self.engine_build,
+                build_id: self.build_id.clone(),
+                custom_format_version: self.custom_format_version,
+                custom_format_data: export_custom_format_data(&self.custom_format_data),
+                save_game_type: self.save_game_type.clone(),
+            },
+            character,
+            unknown_fields: export_unknown_fields(&self.character_data.character.unknown_fields),
+        })
+    }
+
+    fn from_exported(exported: ExportedSave) -> Result<Self> {
+        let ExportedSave {
+            file_name,
+            header,
+            mut character,
+            unknown_fields,
+        } = exported;
+
+        if !HeaderType::SAVE_TYPES.contains(&header.header_type) {
+            bail!(
+                "expected a save header type but found: {}",
+                header.header_type
+            );
+        }
+
+        import_serials(&mut character)?;
+
+        let nested_unknown_fields = take_nested_unknown_fields(&mut character)?;
+
+        let character: Character =
+            value_to_message(character).context("failed to read character data")?;
+
+        let mut character = import_nested_unknown_fields(character, &nested_unknown_fields)
+            .context("failed to read character data")?;
+
+        character.unknown_fields = import_unknown_fields(unknown_fields)?;
+
+        let character_data = CharacterData::from_character(character)?;
+
+        let custom_format_data = import_custom_format_data(&header.custom_format_data)?;
+
+        Ok(Self {
+            file_name,
+            save_game_version: header.save_game_version,
+            package_version: header.package_version,
+            engine_major: header.engine_major,
+            engine_minor: header.engine_minor,
+            engine_patch: header.engine_patch,
+            engine_build: header.engine_build,
+            build_id: header.build_id,
+            custom_format_version: header.custom_format_version,
+            custom_format_data_count: custom_format_data.len() as u32,
+            custom_format_data,
+            save_game_type: header.save_game_type,
+            header_type: header.header_type,
+            character_data,
+            load_warnings: Vec::new(),
+            file_hash: 0,
+        })
+    }
+}
+
+impl Bl3Profile {
+    /// Exports the header and the full decrypted `Profile` as pretty printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        let exported = self.to_exported()?;
+
+        Ok(serde_json::to_string_pretty(&exported)?)
+    }
+
+    /// Reads a profile previously exported with `to_json`, use `as_bytes` to write it back out.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let exported = serde_json::from_str(json).context("failed to read profile from json")?;
+
+        Self::from_exported(exported)
+    }
+
+    pub fn to_yaml(&self) -> Result<String> {
+        let exported = self.to_exported()?;
+
+        Ok(serde_yaml::to_string(&exported)?)
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let exported = serde_yaml::from_str(yaml).context("failed to read profile from yaml")?;
+
+        Self::from_exported(exported)
+    }
+
+    fn to_exported(&self) -> Result<ExportedProfile> {
+        let mut profile = message_to_value(&self.profile_data.profile)?;
+
+        export_nested_unknown_fields(&self.profile_data.profile, &mut profile)?;
+        export_serials(&mut profile);
+
+        Ok(ExportedProfile {
+            file_name: self.file_name.clone(),
+            header: GvasHeader {
+                header_type: self.header_type,
+                save_game_version: self.save_game_version,
+                package_version: self.package_version,
+                engine_major: self.engine_major,
+                engine_minor: self.engine_minor,
+                engine_patch: self.engine_patch,
+                engine_build: self.engine_build,
+                build_id: self.build_id.clone(),
+                custom_format_version: self.custom_format_version,
+                custom_format_data: export_custom_format_data(&self.custom_format_data),
+                save_game_type: self.save_game_type.clone(),
+            },
+            profile,
+            unknown_fields: export_unknown_fields(&self.profile_data.profile.unknown_fields),
+        })
+    }
+
+    fn from_exported(exported: ExportedProfile) -> Result<Self> {
+        let ExportedProfile {
+            file_name,
+            header,
+            mut profile,
+            unknown_fields,
+        } = exported;
+
+        if !HeaderType::PROFILE_TYPES.contains(&header.header_type) {
+            bail!(
+                "expected a profile header type but found: {}",
+                header.header_type
+            );
+        }
+
+        import_serials(&mut profile)?;
+
+        let nested_unknown_fields = take_nested_unknown_fields(&mut profile)?;
+
+        let profile: Profile = value_to_message(profile).context("failed to read profile data")?;
+
+        let mut profile = import_nested_unknown_fields(profile, &nested_unknown_fields)
+            .context("failed to read profile data")?;
+
+        profile.unknown_fields = import_unknown_fields(unknown_fields)?;
+
+        let profile_data = ProfileData::from_profile(profile)?;
+
+        let custom_format_data = import_custom_format_data(&header.custom_format_data)?;
+
+        Ok(Self {
+            file_name,
+            save_game_version: header.save_game_version,
+            package_version: header.package_version,
+            engine_major: header.engine_major,
+            engine_minor: header.engine_minor,
+            engine_patch: header.engine_patch,
+            engine_build: header.engine_build,
+            build_id: header.build_id,
+            custom_format_version: header.custom_format_version,
+            custom_format_data_count: custom_format_data.len() as u32,
+            custom_format_data,
+            save_game_type: header.save_game_type,
+            header_type: header.header_type,
+            profile_data,
+            load_warnings: Vec::new(),
+            file_hash: 0,
+        })
+    }
+}
+
+fn message_to_value<T: Serialize>(message: &T) -> Result<Value> {
+    // Go through YAML first as it keeps any NaN/infinite floats intact
+    let value = serde_yaml::to_value(message)?;
+
+    yaml_to_json_value(value)
+}
+
+fn value_to_message<T: DeserializeOwned>(value: Value) -> Result<T> {
+    let value = json_to_yaml_value(value)?;
+
+    Ok(serde_yaml::from_value(value)?)
+}
+
+fn yaml_to_json_value(value: serde_yaml::Value) -> Result<Value> {
+    let value = match value {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(b) => Value::Bool(b),
+        serde_yaml::Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                Value::from(n)
+            } else if let Some(n) = n.as_i64() {
+                Value::from(n)
+            } else {
+                let n = n.as_f64().context("failed to read number")?;
+
+                match serde_json::Number::from_f64(n) {
+                    Some(n) => Value::Number(n),
+                    None => {
+                        serde_json::json!({ NON_FINITE_FLOAT: format!("{:016x}", n.to_bits()) })
+                    }
+                }
+            }
+        }
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(values) => Value::Array(
+            values
+                .into_iter()
+                .map(yaml_to_json_value)
+                .collect::<Result<_>>()?,
+        ),
+        serde_yaml::Value::Mapping(mapping) => Value::Object(
+            mapping
+                .into_iter()
+                .map(|(k, v)| {
+                    let k = k.as_str().context("expected a string key")?.to_owned();
+
+                    Ok((k, yaml_to_json_value(v)?))
+                })
+                .collect::<Result<_>>()?,
+        ),
+    };
+
+    Ok(value)
+}
+
+fn json_to_yaml_value(value: Value) -> Result<serde_yaml::Value> {
+    let value = match value {
+        Value::Null => serde_yaml::Value::Null,
+        Value::Bool(b) => serde_yaml::Value::Bool(b),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                serde_yaml::Value::from(n)
+            } else if let Some(n) = n.as_i64() {
+                serde_yaml::Value::from(n)
+            } else {
+                serde_yaml::Value::from(n.as_f64().context("failed to read number")?)
+            }
+        }
+        Value::String(s) => serde_yaml::Value::String(s),
+        Value::Array(values) => serde_yaml::Value::Sequence(
+            values
+                .into_iter()
+                .map(json_to_yaml_value)
+                .collect::<Result<_>>()?,
+        ),
+        Value::Object(map) => {
+            if let (1, Some(Value::String(bits))) = (map.len(), map.get(NON_FINITE_FLOAT)) {
+                let bits = u64::from_str_radix(bits, 16)
+                    .with_context(|| format!("invalid {}: {}", NON_FINITE_FLOAT, bits))?;
+
+                serde_yaml::Value::from(f64::from_bits(bits))
+            } else {
+                serde_yaml::Value::Mapping(
+                    map.into_iter()
+                        .map(|(k, v)| Ok((serde_yaml::Value::String(k), json_to_yaml_value(v)?)))
+                        .collect::<Result<_>>()?,
+                )
+            }
+        }
+    };
+
+    Ok(value)
+}
+
+fn export_unknown_fields(unknown_fields: &UnknownFields) -> BTreeMap<u32, ExportedUnknownValues> {
+    unknown_fields
+        .iter()
+        .map(|(number, values)| {
+            let values = ExportedUnknownValues {
+                fixed32: values.fixed32.clone(),
+                fixed64: values.fixed64.clone(),
+                varint: values.varint.clone(),
+                length_delimited: values.length_delimited.iter().map(base64::encode).collect(),
+            };
+
+            (number, values)
+        })
+        .collect()
+}
+
+/// Writes the unknown fields of every nested message into the object of that message.
+fn export_nested_unknown_fields(message: &dyn Message, value: &mut Value) -> Result<()> {
+    let map = match value {
+        Value::Object(map) => map,
+        _ => return Ok(()),
+    };
+
+    for field in message.descriptor().fields() {
+        let field_value = match map.get_mut(field.name()) {
+            Some(field_value) => field_value,
+            None => continue,
+        };
+
+        match (field.get_reflect(message), field_value) {
+            (ReflectFieldRef::Optional(Some(ReflectValueRef::Message(m))), field_value) => {
+                export_message_unknown_fields(m, field_value)?
+            }
+            (ReflectFieldRef::Repeated(repeated), Value::Array(values)) => {
+                for (i, field_value) in values.iter_mut().enumerate().take(repeated.len()) {
+                    if let ReflectValueRef::Message(m) = ProtobufValue::as_ref(repeated.get(i)) {
+                        export_message_unknown_fields(m, field_value)?;
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+fn export_message_unknown_fields(message: &dyn Message, value: &mut Value) -> Result<()> {
+    export_nested_unknown_fields(message, value)?;
+
+    let unknown_fields = export_unknown_fields(message.get_unknown_fields());
+
+    if let (false, Value::Object(map)) = (unknown_fields.is_empty(), value) {
+        map.insert(
+            UNKNOWN_FIELDS.to_owned(),
+            serde_json::to_value(unknown_fields)?,
+        );
+    }
+
+    Ok(())
+}
+
+/// Removes the unknown fields written by `export_nested_unknown_fields` so the rest of the value
+/// can be read as the message.
+fn take_nested_unknown_fields(value: &mut Value) -> Result<NestedUnknownFields> {
+    let mut nested = NestedUnknownFields::default();
+
+    let map = match value {
+        Value::Object(map) => map,
+        _ => return Ok(nested),
+    };
+
+    for (key, field_value) in map.iter_mut() {
+        let messages = match field_value {
+            Value::Array(values) => values.iter_mut().enumerate().collect::<Vec<_>>(),
+            field_value if field_value.is_object() => vec![(0, field_value)],
+            _ => continue,
+        };
+
+        for (i, message_value) in messages {
+            let mut message_nested = take_nested_unknown_fields(message_value)?;
+
+            if let Some(unknown_fields) = message_value
+                .as_object_mut()
+                .and_then(|m| m.remove(UNKNOWN_FIELDS))
+            {
+                message_nested.unknown_fields = serde_json::from_value(unknown_fields)
+                    .with_context(|| format!("failed to read unknown fields of {}", key))?;
+            }
+
+            if !message_nested.unknown_fields.is_empty() || !message_nested.fields.is_empty() {
+                nested
+                    .fields
+                    .entry(key.clone())
+                    .or_default()
+                    .insert(i, message_nested);
+            }
+        }
+    }
+
+    Ok(nested)
+}
+
+/// The generated messages can't change a nested message through reflection, so the unknown fields
+/// are added to the encoded message which is then read again.
+fn import_nested_unknown_fields<M: Message>(message: M, nested: &NestedUnknownFields) -> Result<M> {
+    if nested.fields.is_empty() {
+        return Ok(message);
+    }
+
+    let bytes = nested_message_bytes(&message, nested)?;
+
+    Ok(M::parse_from_bytes(&bytes)?)
+}
+
+fn nested_message_bytes(message: &dyn Message, nested: &NestedUnknownFields) -> Result<Vec<u8>> {
+    // Reading the message as an empty one keeps every field as an unknown field, by field number
+    let mut fields = Empty::parse_from_bytes(&message.write_to_bytes()?)?.unknown_fields;
+
+    for (name, nested_messages) in &nested.fields {
+        let field = message
+            .descriptor()
+            .get_field_by_name(name)
+            .with_context(|| format!("unknown fields were found in an unknown field: {}", name))?;
+
+        let field_messages = match field.get_reflect(message) {
+            ReflectFieldRef::Optional(Some(ReflectValueRef::Message(m))) => vec![m],
+            ReflectFieldRef::Repeated(repeated) => (0..repeated.len())
+                .filter_map(|i| match ProtobufValue::as_ref(repeated.get(i)) {
+                    ReflectValueRef::Message(m) => Some(m),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        if let Some(i) = nested_messages.keys().find(|i| **i >= field_messages.len()) {
+            bail!(
+                "unknown fields were found in a missing message: {}[{}]",
+                name,
+                i
+            );
+        }
+
+        let number = field.proto().get_number() as u32;
+
+        fields.remove(number);
+
+        for (i, field_message) in field_messages.into_iter().enumerate() {
+            let bytes = match nested_messages.get(&i) {
+                Some(nested) => nested_message_bytes(field_message, nested)?,
+                None => field_message.write_to_bytes()?,
+            };
+
+            fields.add_length_delimited(number, bytes);
+        }
+    }
+
+    add_unknown_fields(&mut fields, &nested.unknown_fields)?;
+
+    let message = Empty {
+        unknown_fields: fields,
+        ..Default::default()
+    };
+
+    Ok(message.write_to_bytes()?)
+}
+
+fn import_unknown_fields(
+    unknown_fields: BTreeMap<u32, ExportedUnknownValues>,
+) -> Result<UnknownFields> {
+    let mut res = UnknownFields::new();
+
+    add_unknown_fields(&mut res, &unknown_fields)?;
+
+    Ok(res)
+}
+
+fn add_unknown_fields(
+    res: &mut UnknownFields,
+    unknown_fields: &BTreeMap<u32, ExportedUnknownValues>,
+) -> Result<()> {
+    for (&number, values) in unknown_fields {
+        values
+            .fixed32
+            .iter()
+            .for_each(|v| res.add_fixed32(number, *v));
+        values
+            .fixed64
+            .iter()
+            .for_each(|v| res.add_fixed64(number, *v));
+        values
+            .varint
+            .iter()
+            .for_each(|v| res.add_varint(number, *v));
+
+        for v in &values.length_delimited {
+            let v = base64::decode(v)
+                .with_context(|| format!("failed to read unknown field: {}", number))?;
+
+            res.add_length_delimited(number, v);
+        }
+    }
+
+    Ok(())
+}
+
+fn export_custom_format_data(custom_format_data: &[CustomFormatData]) -> Vec<GvasCustomFormatData> {
+    custom_format_data
+        .iter()
+        .map(|cfd| GvasCustomFormatData {
+            guid: cfd.guid.iter().map(|b| format!("{:02x}", b)).collect(),
+            entry: cfd.entry,
+        })
+        .collect()
+}
+
+fn import_custom_format_data(
+    custom_format_data: &[GvasCustomFormatData],
+) -> Result<Vec<CustomFormatData>> {
+    custom_format_data
+        .iter()
+        .map(|cfd| {
+            if cfd.guid.len() != 32 || !cfd.guid.is_ascii() {
+                bail!("invalid custom format data guid: {}", cfd.guid);
+            }
+
+            let guid = (0..cfd.guid.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&cfd.guid[i..i + 2], 16))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .with_context(|| format!("invalid custom format data guid: {}", cfd.guid))?;
+
+            Ok(CustomFormatData {
+                guid,
+                entry: cfd.entry,
+            })
+        })
+        .collect()
+}
+
+fn export_serials(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SERIAL_FIELDS.contains(&key.as_str()) {
+                    match value {
+                        Value::Array(serials) if serials.iter().all(Value::is_array) => {
+                            serials.iter_mut().for_each(export_serial)
+                        }
+                        _ => export_serial(value),
+                    }
+                } else {
+                    export_serials(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(export_serials),
+        _ => (),
+    }
+}
+
+fn export_serial(value: &mut Value) {
+    let serial = match serde_json::from_value::<Vec<u8>>(value.clone()) {
+        Ok(serial) => serial,
+        Err(_) => return,
+    };
+
+    let summary = if serial.is_empty() {
+        Value::Null
+    } else {
+        match Bl3Item::from_serial_bytes(&serial, None) {
+            Ok(item) => serde_json::to_value(ExportedItemSummary::from(&item)).unwrap_or_default(),
+            Err(e) => Value::String(format!("failed to decode item: {}", e)),
+        }
+    };
+
+    *value = serde_json::json!({
+        "serial": format!("BL3({})", base64::encode(&serial)),
+        "item": summary,
+    });
+}
+
+fn import_serials(value: &mut Value) -> Result<()> {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SERIAL_FIELDS.contains(&key.as_str()) {
+                    match value {
+                        Value::Array(serials) if !serials.iter().all(Value::is_number) => {
+                            for serial in serials.iter_mut() {
+                                import_serial(serial)
+                                    .with_context(|| format!("failed to read {}", key))?;
+                            }
+                        }
+                        _ => import_serial(value)
+                            .with_context(|| format!("failed to read {}", key))?,
+                    }
+                } else {
+                    import_serials(value)?;
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                import_serials(value)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+fn import_serial(value: &mut Value) -> Result<()> {
+    let serial = match value {
+        Value::Object(map) => map.get("serial").and_then(Value::as_str),
+        Value::String(serial) => Some(serial.as_str()),
+        // Leave raw bytes alone
+        _ => return Ok(()),
+    }
+    .context("expected an item serial")?;
+
+    let serial = serial.trim();
+
+    if serial.len() < 5 || !serial[0..4].eq_ignore_ascii_case("bl3(") || !serial.ends_with(')') {
+        bail!("Serial must start with 'BL3(' and end with ')'.");
+    }
+
+    let decoded = base64::decode(&serial[4..serial.len() - 1])?;
+
+    *value = serde_json::to_value(decoded)?;
+
+    Ok(())
+}
+
+impl From<&Bl3Item> for ExportedItemSummary {
+    fn from(item: &Bl3Item) -> Self {
+        let part_name =
+            |p: &crate::bl3_item::Bl3Part| p.short_ident.clone().unwrap_or_else(|| p.ident.clone());
+
+        let item_parts = item.item_parts.as_ref();
+
+        ExportedItemSummary {
+            balance: item.balance_part().ident.clone(),
+            name: item.balance_part().name.clone(),
+            manufacturer: item.manufacturer_part().ident.clone(),
+            inv_data: item.inv_data_part().ident.clone(),
+            item_type: format!("{:?}", item.item_type),
+            rarity: item_parts.map(|p| format!("{:?}", p.rarity)),
+            weapon_type: item_parts
+                .and_then(|p| p.weapon_type.as_ref())
+                .map(|w| format!("{:?}", w)),
+            level: item.level(),
+            parts: item_parts
+                .map(|p| p.parts().iter().map(part_name).collect())
+                .unwrap_or_default(),
+            anointments: item_parts
+                .map(|p| p.generic_parts().iter().map(part_name).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
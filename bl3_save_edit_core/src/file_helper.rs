@@ -6,6 +6,7 @@ use nom::Finish;
 
 use crate::bl3_profile::Bl3Profile;
 use crate::bl3_save::Bl3Save;
+use crate::load_warning::{LoadMode, LoadWarning};
 use crate::models::CustomFormatData;
 use crate::parser::{
     read_custom_format_data, read_header, read_int, read_short, read_str, HeaderType,
@@ -26,6 +27,12 @@ pub struct FileData<'a> {
     pub custom_format_data: Vec<CustomFormatData>,
     pub save_game_type: String,
     pub remaining_data: &'a [u8],
+    pub file_hash: u32,
+}
+
+/// Identifies the contents of a file so changes made outside the editor can be detected.
+pub fn file_hash(data: &[u8]) -> u32 {
+    crc32fast::hash(data)
 }
 
 pub fn read_bytes<'a>(file_location: &'a Path, data: &'a [u8]) -> Result<FileData<'a>> {
@@ -65,6 +72,7 @@ pub fn read_bytes<'a>(file_location: &'a Path, data: &'a [u8]) -> Result<FileDat
         custom_format_data,
         save_game_type,
         remaining_data,
+        file_hash: file_hash(data),
     })
 }
 
@@ -107,21 +115,52 @@ impl std::fmt::Display for Bl3FileType {
 
 impl Bl3FileType {
     pub fn from_unknown_data(file_location: &Path, data: &[u8]) -> Result<Bl3FileType> {
+        Self::from_unknown_data_with_mode(file_location, data, LoadMode::Strict)
+    }
+
+    /// In `LoadMode::Lenient` the file is only rejected if it can't be decrypted, anything else
+    /// which can't be read is kept in the file's `load_warnings`.
+    pub fn from_unknown_data_with_mode(
+        file_location: &Path,
+        data: &[u8],
+        mode: LoadMode,
+    ) -> Result<Bl3FileType> {
         let file_data = read_bytes(file_location, data)?;
 
-        if let Ok(save) = Bl3Save::from_file_data(&file_data, HeaderType::PcSave) {
+        if let Ok(save) = Bl3Save::from_file_data_with_mode(&file_data, HeaderType::PcSave, mode) {
             Ok(Bl3FileType::PcSave(save))
-        } else if let Ok(profile) = Bl3Profile::from_file_data(&file_data, HeaderType::PcProfile) {
+        } else if let Ok(profile) =
+            Bl3Profile::from_file_data_with_mode(&file_data, HeaderType::PcProfile, mode)
+        {
             Ok(Bl3FileType::PcProfile(profile))
-        } else if let Ok(save) = Bl3Save::from_file_data(&file_data, HeaderType::Ps4Save) {
+        } else if let Ok(save) =
+            Bl3Save::from_file_data_with_mode(&file_data, HeaderType::Ps4Save, mode)
+        {
             Ok(Bl3FileType::Ps4Save(save))
-        } else if let Ok(profile) = Bl3Profile::from_file_data(&file_data, HeaderType::Ps4Profile) {
+        } else if let Ok(profile) =
+            Bl3Profile::from_file_data_with_mode(&file_data, HeaderType::Ps4Profile, mode)
+        {
             Ok(Bl3FileType::Ps4Profile(profile))
         } else {
             bail!("Could not recognize file type.")
         }
     }
 
+    pub fn load_warnings(&self) -> &[LoadWarning] {
+        match self {
+            Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s) => &s.load_warnings,
+            Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => &p.load_warnings,
+        }
+    }
+
+    /// The hash of the file's contents when it was read.
+    pub fn file_hash(&self) -> u32 {
+        match self {
+            Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s) => s.file_hash,
+            Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => p.file_hash,
+        }
+    }
+
     pub fn filename(&self) -> &str {
         match self {
             Bl3FileType::PcSave(s) => &s.file_name,
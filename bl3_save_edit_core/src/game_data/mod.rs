@@ -1082,6 +1082,241 @@ pub const MISSION: [GameDataKv; 208] = [
     GameDataKv { ident: "/Game/PatchDLC/Takedown2/Missions/Side/Mission_Takedown2_Intro.Mission_Takedown2_Intro_C", name: "Discovery: Takedown at the Guardian Breach" },
 ];
 
+// 回声日志
+pub const ECHO_LOGS: [GameDataKv; 231] = [
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_1.EchoLog_Journal_AtlasHQ_1", name: "Typhon Log: Atlas HQ 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_2.EchoLog_Journal_AtlasHQ_2", name: "Typhon Log: Atlas HQ 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/AtlasHQ/EchoLog_Journal_AtlasHQ_3.EchoLog_Journal_AtlasHQ_3", name: "Typhon Log: Atlas HQ 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach1.EchoLog_Journal_Beach1", name: "Typhon Log: Beach 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach2.EchoLog_Journal_Beach2", name: "Typhon Log: Beach 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Beach/EchoLog_Journal_Beach3.EchoLog_Journal_Beach3", name: "Typhon Log: Beach 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City1.EchoLog_Journal_City1", name: "Typhon Log: City 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City2.EchoLog_Journal_City2", name: "Typhon Log: City 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/City/EchoLog_Journal_City3.EchoLog_Journal_City3", name: "Typhon Log: City 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault1.EchoLog_Journal_CityVault1", name: "Typhon Log: City Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault2.EchoLog_Journal_CityVault2", name: "Typhon Log: City Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/CityVault/EchoLog_Journal_CityVault3.EchoLog_Journal_CityVault3", name: "Typhon Log: City Vault 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert1.EchoLog_Journal_Desert1", name: "Typhon Log: Desert 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert2.EchoLog_Journal_Desert2", name: "Typhon Log: Desert 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desert/EchoLog_Journal_Desert3.EchoLog_Journal_Desert3", name: "Typhon Log: Desert 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault1.EchoLog_Journal_DesertVault1", name: "Typhon Log: Desert Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault2.EchoLog_Journal_DesertVault2", name: "Typhon Log: Desert Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/DesertVault/EchoLog_Journal_DesertVault3.EchoLog_Journal_DesertVault3", name: "Typhon Log: Desert Vault 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate1.EchoLog_Journal_Desolate1", name: "Typhon Log: Desolate 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate2.EchoLog_Journal_Desolate2", name: "Typhon Log: Desolate 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Desolate/EchoLog_Journal_Desolate3.EchoLog_Journal_Desolate3", name: "Typhon Log: Desolate 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion1.EchoLog_Journal_Mansion1", name: "Typhon Log: Mansion 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion2.EchoLog_Journal_Mansion2", name: "Typhon Log: Mansion 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mansion/EchoLog_Journal_Mansion3.EchoLog_Journal_Mansion3", name: "Typhon Log: Mansion 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields1.EchoLog_Journal_Marshfields1", name: "Typhon Log: Marshfields 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields2.EchoLog_Journal_Marshfields2", name: "Typhon Log: Marshfields 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Marshfields/EchoLog_Journal_Marshfields3.EchoLog_Journal_Marshfields3", name: "Typhon Log: Marshfields 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine1.EchoLog_Journal_Mine1", name: "Typhon Log: Mine 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine2.EchoLog_Journal_Mine2", name: "Typhon Log: Mine 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Mine/EchoLog_Journal_Mine3.EchoLog_Journal_Mine3", name: "Typhon Log: Mine 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery1.EchoLog_Journal_Monastery1", name: "Typhon Log: Monastery 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery2.EchoLog_Journal_Monastery2", name: "Typhon Log: Monastery 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Monestary/EchoLog_Journal_Monastery3.EchoLog_Journal_Monastery3", name: "Typhon Log: Monastery 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade1.EchoLog_Journal_Motorcade1", name: "Typhon Log: Motorcade 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade2.EchoLog_Journal_Motorcade2", name: "Typhon Log: Motorcade 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Motorcade/EchoLog_Journal_Motorcade3.EchoLog_Journal_Motorcade3", name: "Typhon Log: Motorcade 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival1.EchoLog_Journal_MotorcadeFestival1", name: "Typhon Log: Motorcade Festival 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival2.EchoLog_Journal_MotorcadeFestival2", name: "Typhon Log: Motorcade Festival 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/MotorcadeFestival/EchoLog_Journal_MotorcadeFestival3.EchoLog_Journal_MotorcadeFestival3", name: "Typhon Log: Motorcade Festival 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform1.EchoLog_Journal_OrbitalPlatform1", name: "Typhon Log: Orbital Platform 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform2.EchoLog_Journal_OrbitalPlatform2", name: "Typhon Log: Orbital Platform 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/OrbitalPlatform/EchoLog_Journal_OrbitalPlatform3.EchoLog_Journal_OrbitalPlatform3", name: "Typhon Log: Orbital Platform 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts1.EchoLog_Journal_Outskirts1", name: "Typhon Log: Outskirts 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts2.EchoLog_Journal_Outskirts2", name: "Typhon Log: Outskirts 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Outskirts/EchoLog_Journal_Outskirts3.EchoLog_Journal_Outskirts3", name: "Typhon Log: Outskirts 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison1.EchoLog_Journal_Prison1", name: "Typhon Log: Prison 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison2.EchoLog_Journal_Prison2", name: "Typhon Log: Prison 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prison/EchoLog_Journal_Prison3.EchoLog_Journal_Prison3", name: "Typhon Log: Prison 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue1.EchoLog_Journal_Prologue1", name: "Typhon Log: Prologue 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue2.EchoLog_Journal_Prologue2", name: "Typhon Log: Prologue 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Prologue/EchoLog_Journal_Prologue3.EchoLog_Journal_Prologue3", name: "Typhon Log: Prologue 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice1.EchoLog_Journal_Sacrifice1", name: "Typhon Log: Sacrifice 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice2.EchoLog_Journal_Sacrifice2", name: "Typhon Log: Sacrifice 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Sacrifice/EchoLog_Journal_Sacrifice3.EchoLog_Journal_Sacrifice3", name: "Typhon Log: Sacrifice 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers1.EchoLog_Journal_Towers1", name: "Typhon Log: Towers 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers2.EchoLog_Journal_Towers2", name: "Typhon Log: Towers 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Towers/EchoLog_Journal_Towers3.EchoLog_Journal_Towers3", name: "Typhon Log: Towers 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership1.EchoLog_Journal_Watership1", name: "Typhon Log: Watership 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership2.EchoLog_Journal_Watership2", name: "Typhon Log: Watership 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Watership/EchoLog_Journal_Watership3.EchoLog_Journal_Watership3", name: "Typhon Log: Watership 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands1.EchoLog_Journal_Wetlands1", name: "Typhon Log: Wetlands 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands2.EchoLog_Journal_Wetlands2", name: "Typhon Log: Wetlands 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/Wetlands/EchoLog_Journal_Wetlands3.EchoLog_Journal_Wetlands3", name: "Typhon Log: Wetlands 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault1.EchoLog_Journal_WetlandsVault1", name: "Typhon Log: Wetlands Vault 1" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault2.EchoLog_Journal_WetlandsVault2", name: "Typhon Log: Wetlands Vault 2" },
+    GameDataKv { ident: "/Game/GameData/Challenges/CrewChallenges/Collection/WetlandsVault/EchoLog_Journal_WetlandsVault3.EchoLog_Journal_WetlandsVault3", name: "Typhon Log: Wetlands Vault 3" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Eden6.EchoLog_EridianKeystone_Eden6", name: "Eridian Keystone: Eden 6" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Nekrotafeyo.EchoLog_EridianKeystone_Nekrotafeyo", name: "Eridian Keystone: Nekrotafeyo" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianKeystone_Pandora.EchoLog_EridianKeystone_Pandora", name: "Eridian Keystone: Pandora" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_01.EchoLog_EridianWriting_01", name: "Eridian Writing 01" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_02.EchoLog_EridianWriting_02", name: "Eridian Writing 02" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_03.EchoLog_EridianWriting_03", name: "Eridian Writing 03" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_04.EchoLog_EridianWriting_04", name: "Eridian Writing 04" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_05.EchoLog_EridianWriting_05", name: "Eridian Writing 05" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_06.EchoLog_EridianWriting_06", name: "Eridian Writing 06" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_07.EchoLog_EridianWriting_07", name: "Eridian Writing 07" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_08.EchoLog_EridianWriting_08", name: "Eridian Writing 08" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_09.EchoLog_EridianWriting_09", name: "Eridian Writing 09" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_10.EchoLog_EridianWriting_10", name: "Eridian Writing 10" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_11.EchoLog_EridianWriting_11", name: "Eridian Writing 11" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_12.EchoLog_EridianWriting_12", name: "Eridian Writing 12" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_13.EchoLog_EridianWriting_13", name: "Eridian Writing 13" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_14.EchoLog_EridianWriting_14", name: "Eridian Writing 14" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_15.EchoLog_EridianWriting_15", name: "Eridian Writing 15" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_16.EchoLog_EridianWriting_16", name: "Eridian Writing 16" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_17.EchoLog_EridianWriting_17", name: "Eridian Writing 17" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_18.EchoLog_EridianWriting_18", name: "Eridian Writing 18" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_19.EchoLog_EridianWriting_19", name: "Eridian Writing 19" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_20.EchoLog_EridianWriting_20", name: "Eridian Writing 20" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_21.EchoLog_EridianWriting_21", name: "Eridian Writing 21" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_22.EchoLog_EridianWriting_22", name: "Eridian Writing 22" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_23.EchoLog_EridianWriting_23", name: "Eridian Writing 23" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_24.EchoLog_EridianWriting_24", name: "Eridian Writing 24" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_25.EchoLog_EridianWriting_25", name: "Eridian Writing 25" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_26.EchoLog_EridianWriting_26", name: "Eridian Writing 26" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_27.EchoLog_EridianWriting_27", name: "Eridian Writing 27" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_28.EchoLog_EridianWriting_28", name: "Eridian Writing 28" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_29.EchoLog_EridianWriting_29", name: "Eridian Writing 29" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_30.EchoLog_EridianWriting_30", name: "Eridian Writing 30" },
+    GameDataKv { ident: "/Game/GameData/Challenges/EridianWriting/EchoLogs/EchoLog_EridianWriting_Intro.EchoLog_EridianWriting_Intro", name: "Eridian Writing Intro" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ1.EchoLogData_NonMission_AtlasHQ1", name: "Atlas HQ 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ2.EchoLogData_NonMission_AtlasHQ2", name: "Atlas HQ 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ3.EchoLogData_NonMission_AtlasHQ3", name: "Atlas HQ 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ4.EchoLogData_NonMission_AtlasHQ4", name: "Atlas HQ 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_AtlasHQ5.EchoLogData_NonMission_AtlasHQ5", name: "Atlas HQ 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach1.EchoLogData_NonMission_Beach1", name: "Beach 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach2.EchoLogData_NonMission_Beach2", name: "Beach 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Beach3.EchoLogData_NonMission_Beach3", name: "Beach 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City1.EchoLogData_NonMission_City1", name: "City 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City2.EchoLogData_NonMission_City2", name: "City 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City3.EchoLogData_NonMission_City3", name: "City 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City4.EchoLogData_NonMission_City4", name: "City 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_City5.EchoLogData_NonMission_City5", name: "City 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault1.EchoLogData_NonMission_CityVault1", name: "City Vault 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault2.EchoLogData_NonMission_CityVault2", name: "City Vault 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_CityVault3.EchoLogData_NonMission_CityVault3", name: "City Vault 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert2.EchoLogData_NonMission_Desert2", name: "Desert 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert3.EchoLogData_NonMission_Desert3", name: "Desert 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desert4.EchoLogData_NonMission_Desert4", name: "Desert 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_DesertVault2.EchoLogData_NonMission_DesertVault2", name: "Desert Vault 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desolate1.EchoLogData_NonMission_Desolate1", name: "Desolate 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Desolate2.EchoLogData_NonMission_Desolate2", name: "Desolate 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mansion1.EchoLogData_NonMission_Mansion1", name: "Mansion 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mansion2.EchoLogData_NonMission_Mansion2", name: "Mansion 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields1.EchoLogData_NonMission_Marshfields1", name: "Marshfields 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields2.EchoLogData_NonMission_Marshfields2", name: "Marshfields 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields3.EchoLogData_NonMission_Marshfields3", name: "Marshfields 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Marshfields4.EchoLogData_NonMission_Marshfields4", name: "Marshfields 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine1.EchoLogData_NonMission_Mine1", name: "Mine 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine2.EchoLogData_NonMission_Mine2", name: "Mine 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Mine3.EchoLogData_NonMission_Mine3", name: "Mine 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery1.EchoLogData_NonMission_Monastery1", name: "Monastery 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery2.EchoLogData_NonMission_Monastery2", name: "Monastery 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Monastery3.EchoLogData_NonMission_Monastery3", name: "Monastery 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Motorcade5.EchoLogData_NonMission_Motorcade5", name: "Motorcade 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeFestival1.EchoLogData_NonMission_MotorcadeFestival1", name: "Motorcade Festival 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior1.EchoLogData_NonMission_MotorcadeInterior1", name: "Motorcade Interior 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior2.EchoLogData_NonMission_MotorcadeInterior2", name: "Motorcade Interior 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_MotorcadeInterior3.EchoLogData_NonMission_MotorcadeInterior3", name: "Motorcade Interior 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_OrbitalPlatform1.EchoLogData_NonMission_OrbitalPlatform1", name: "Orbital Platform 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts1.EchoLogData_NonMission_Outskirts1", name: "Outskirts 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts2.EchoLogData_NonMission_Outskirts2", name: "Outskirts 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts3.EchoLogData_NonMission_Outskirts3", name: "Outskirts 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Outskirts4.EchoLogData_NonMission_Outskirts4", name: "Outskirts 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prison1.EchoLogData_NonMission_Prison1", name: "Prison 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prison2.EchoLogData_NonMission_Prison2", name: "Prison 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue1.EchoLogData_NonMission_Prologue1", name: "Prologue 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue2.EchoLogData_NonMission_Prologue2", name: "Prologue 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue3.EchoLogData_NonMission_Prologue3", name: "Prologue 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue4.EchoLogData_NonMission_Prologue4", name: "Prologue 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue5.EchoLogData_NonMission_Prologue5", name: "Prologue 5" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Prologue6.EchoLogData_NonMission_Prologue6", name: "Prologue 6" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice1.EchoLogData_NonMission_Sacrifice1", name: "Sacrifice 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice2.EchoLogData_NonMission_Sacrifice2", name: "Sacrifice 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sacrifice3.EchoLogData_NonMission_Sacrifice3", name: "Sacrifice 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary1.EchoLogData_NonMission_Sanctuary1", name: "Sanctuary 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary2.EchoLogData_NonMission_Sanctuary2", name: "Sanctuary 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary3.EchoLogData_NonMission_Sanctuary3", name: "Sanctuary 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Sanctuary4.EchoLogData_NonMission_Sanctuary4", name: "Sanctuary 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Towers1.EchoLogData_NonMission_Towers1", name: "Towers 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Towers2.EchoLogData_NonMission_Towers2", name: "Towers 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Watership1.EchoLogData_NonMission_Watership1", name: "Watership 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Watership2.EchoLogData_NonMission_Watership2", name: "Watership 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands1.EchoLogData_NonMission_Wetlands1", name: "Wetlands 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands2.EchoLogData_NonMission_Wetlands2", name: "Wetlands 2" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands3.EchoLogData_NonMission_Wetlands3", name: "Wetlands 3" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_Wetlands4.EchoLogData_NonMission_Wetlands4", name: "Wetlands 4" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_WetlandsVault1.EchoLogData_NonMission_WetlandsVault1", name: "Wetlands Vault 1" },
+    GameDataKv { ident: "/Game/InteractiveObjects/EchoLog_NonMission/Data/EchoLogData/EchoLogData_NonMission_WetlandsVault2.EchoLogData_NonMission_WetlandsVault2", name: "Wetlands Vault 2" },
+    GameDataKv { ident: "/Game/Missions/Plot/Ep05_OvercomeHQBlockade/EchoLog_OvercomeHQBlockade_TechnicalEchoLog.EchoLog_OvercomeHQBlockade_TechnicalEchoLog", name: "Overcome HQ Blockade Technical Echo Log" },
+    GameDataKv { ident: "/Game/Missions/Plot/MarshFields/Pickups/SpyEchoLog03/EchoLog_MarshFields_DeadDrop1.EchoLog_MarshFields_DeadDrop1", name: "Marsh Fields Dead Drop 1" },
+    GameDataKv { ident: "/Game/Missions/Plot/MarshFields/Pickups/SpyEchoLog03/EchoLog_MarshFields_DeadDrop2.EchoLog_MarshFields_DeadDrop2", name: "Marsh Fields Dead Drop 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment01.EchoLogData_HeadCase_MemoryFragment01", name: "Head Case Memory Fragment 01" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment02.EchoLogData_HeadCase_MemoryFragment02", name: "Head Case Memory Fragment 02" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment03.EchoLogData_HeadCase_MemoryFragment03", name: "Head Case Memory Fragment 03" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/HeadCase/EchoLogData_HeadCase_MemoryFragment04.EchoLogData_HeadCase_MemoryFragment04", name: "Head Case Memory Fragment 04" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_0/Sacrifice/PandorasNextTopMouthpiece/EchoLogData_PandorasNTM.EchoLogData_PandorasNTM", name: "Pandoras NTM" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_AliveSpy.EchoLogData_OppResearch_AliveSpy", name: "Opp Research Alive Spy" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_Interrogation.EchoLogData_OppResearch_Interrogation", name: "Opp Research Interrogation" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_1/OrbitalPlatform/OppositionResearch/EchoLogData_OppResearch_Toilet.EchoLogData_OppResearch_Toilet", name: "Opp Research Toilet" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Mansion/AureliasSkeletons/EchoLogData_AureliasSkeletons_Echo1.EchoLogData_AureliasSkeletons_Echo1", name: "Aurelias Skeletons Echo 1" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Mansion/AureliasSkeletons/EchoLogData_AureliasSkeletons_Echo2.EchoLogData_AureliasSkeletons_Echo2", name: "Aurelias Skeletons Echo 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue01.EchoLogData_MalevolentPractice_Clue01", name: "Malevolent Practice Clue 01" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue02.EchoLogData_MalevolentPractice_Clue02", name: "Malevolent Practice Clue 02" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue03.EchoLogData_MalevolentPractice_Clue03", name: "Malevolent Practice Clue 03" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Prison/MalevolentPractice/EchoLogData_MalevolentPractice_Clue04.EchoLogData_MalevolentPractice_Clue04", name: "Malevolent Practice Clue 04" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_ECHO_Cage.EchoLog_RumbleJungle_ECHO_Cage", name: "Rumble Jungle ECHO Cage" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_KingBoboECHO.EchoLog_RumbleJungle_KingBoboECHO", name: "Rumble Jungle King Bobo ECHO" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_2/Watership/RumbleJungle/EchoLog_RumbleJungle_LabECHO.EchoLog_RumbleJungle_LabECHO", name: "Rumble Jungle Lab ECHO" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_3/Mine/BridgeInTheDark/EchoLogData_BridgeInTheDark_LostAlkonostLog.EchoLogData_BridgeInTheDark_LostAlkonostLog", name: "Bridge In The Dark Lost Alkonost Log" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_3/Mine/BridgeInTheDark/EchoLogData_BridgeInTheDark_TombLog.EchoLogData_BridgeInTheDark_TombLog", name: "Bridge In The Dark Tomb Log" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_1.EchoLogData_BetterTimes_GetEchoLog_1", name: "Better Times Get Echo Log 1" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_2.EchoLogData_BetterTimes_GetEchoLog_2", name: "Better Times Get Echo Log 2" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/BetterTimes/EchoLogData_BetterTimes_GetEchoLog_3.EchoLogData_BetterTimes_GetEchoLog_3", name: "Better Times Get Echo Log 3" },
+    GameDataKv { ident: "/Game/Missions/Side/Zone_4/Desolate/DestroyerOfWorlds/EchoLogData_DestroyerOfWorlds_FindCartridge.EchoLogData_DestroyerOfWorlds_FindCartridge", name: "Destroyer Of Worlds Find Cartridge" },
+    GameDataKv { ident: "/Game/PatchDLC/Dandelion/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC1_Trashtown4.EchoLogData_DLC1_Trashtown4", name: "DLC1 Trashtown 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_1.EchoLogData_MauriceSideMission_1", name: "Maurice Side Mission 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_2.EchoLogData_MauriceSideMission_2", name: "Maurice Side Mission 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Event2/Missions/Side/MauriceSide/MissionAssets_MauriceSide/EchoLogData_MauriceSideMission_3.EchoLogData_MauriceSideMission_3", name: "Maurice Side Mission 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_01.EchoLog_JakobsJournal_01", name: "Jakobs Journal 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_02.EchoLog_JakobsJournal_02", name: "Jakobs Journal 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_05.EchoLog_JakobsJournal_05", name: "Jakobs Journal 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/GameData/Challenges/CrewChallenges/JakobsJournal/Data/EchoLog_JakobsJournal_06.EchoLog_JakobsJournal_06", name: "Jakobs Journal 06" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Facility01.EchoLogData_NonMission_Geranium_Facility01", name: "Geranium Facility 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Facility02.EchoLogData_NonMission_Geranium_Facility02", name: "Geranium Facility 02" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Frontier05.EchoLogData_NonMission_Geranium_Frontier05", name: "Geranium Frontier 05" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Frontier07.EchoLogData_NonMission_Geranium_Frontier07", name: "Geranium Frontier 07" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Lodge03.EchoLogData_NonMission_Geranium_Lodge03", name: "Geranium Lodge 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Miracle01.EchoLogData_NonMission_Geranium_Miracle01", name: "Geranium Miracle 01" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_Geranium_Miracle03.EchoLogData_NonMission_Geranium_Miracle03", name: "Geranium Miracle 03" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories.EchoLogData_NonMission_GhostStories", name: "Ghost Stories" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories3.EchoLogData_NonMission_GhostStories3", name: "Ghost Stories 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/InteractiveObjects/EchoLogs/EchoLogData/EchoLogData_NonMission_GhostStories6.EchoLogData_NonMission_GhostStories6", name: "Ghost Stories 6" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/Missions/Plot/Facility/EchoLog_DigilineUpgrade.EchoLog_DigilineUpgrade", name: "Digiline Upgrade" },
+    GameDataKv { ident: "/Game/PatchDLC/Geranium/Missions/Side/MoneyBackGuarantee/EchoLog_MoneyBackEchoLog.EchoLog_MoneyBackEchoLog", name: "Money Back Echo Log" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Archives2.EchoLogData_DLC2_Archives2", name: "DLC2 Archives 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Bar1.EchoLogData_DLC2_Bar1", name: "DLC2 Bar 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Bar2.EchoLogData_DLC2_Bar2", name: "DLC2 Bar 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Camp3.EchoLogData_DLC2_Camp3", name: "DLC2 Camp 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Camp4.EchoLogData_DLC2_Camp4", name: "DLC2 Camp 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Lake2.EchoLogData_DLC2_Lake2", name: "DLC2 Lake 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue1.EchoLogData_DLC2_Venue1", name: "DLC2 Venue 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue2.EchoLogData_DLC2_Venue2", name: "DLC2 Venue 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Venue4.EchoLogData_DLC2_Venue4", name: "DLC2 Venue 4" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Woods1.EchoLogData_DLC2_Woods1", name: "DLC2 Woods 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/EchoLogs/DataAssets/EchoLogData_DLC2_Woods2.EchoLogData_DLC2_Woods2", name: "DLC2 Woods 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_1.EchoLogData_IntoTheDeep_1", name: "Into The Deep 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_2.EchoLogData_IntoTheDeep_2", name: "Into The Deep 2" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_IntoTheDeep_3.EchoLogData_IntoTheDeep_3", name: "Into The Deep 3" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/EchoLogData_SinisterSounds_1.EchoLogData_SinisterSounds_1", name: "Sinister Sounds 1" },
+    GameDataKv { ident: "/Game/PatchDLC/Hibiscus/InteractiveObjects/MissionEchoLogs/DataAssets/Hib_EchoLogData_PrivateEye_Crypt.Hib_EchoLogData_PrivateEye_Crypt", name: "Private Eye Crypt" },
+];
+
 // 房间装饰
 pub const PROFILE_ROOM_DECORATIONS: [GameDataKv; 100] = [
     GameDataKv { ident: "/Game/Pickups/RoomDecoration/RoomDecoration_34.RoomDecoration_34", name: "5KAGB8" },
@@ -4406,6 +4641,164 @@ pub const VEHICLE_SKINS_JETBEAST: [&str; 6] = [
     "/Geranium/Vehicles/Horse/Design/Parts/Materials/VehiclePart_Mat_VehiclePart_Horse_Skin5.VehiclePart_Mat_VehiclePart_Horse_Skin5",
 ];
 
+pub const FAST_TRAVEL_STATIONS: [&str; 155] = [
+    "/Game/GameData/FastTravel/FTS_AtlasHQ.FTS_AtlasHQ",
+    "/Game/GameData/FastTravel/FTS_AtlasHQ_SendOnly.FTS_AtlasHQ_SendOnly",
+    "/Game/GameData/FastTravel/FTS_Beach.FTS_Beach",
+    "/Game/GameData/FastTravel/FTS_Beach_SendOnly.FTS_Beach_SendOnly",
+    "/Game/GameData/FastTravel/FTS_City.FTS_City",
+    "/Game/GameData/FastTravel/FTS_CityBoss.FTS_CityBoss",
+    "/Game/GameData/FastTravel/FTS_CityBoss_SendOnly.FTS_CityBoss_SendOnly",
+    "/Game/GameData/FastTravel/FTS_CityVault.FTS_CityVault",
+    "/Game/GameData/FastTravel/FTS_Convoy.FTS_Convoy",
+    "/Game/GameData/FastTravel/FTS_COVSlaughter.FTS_COVSlaughter",
+    "/Game/GameData/FastTravel/FTS_CreatureSlaughter.FTS_CreatureSlaughter",
+    "/Game/GameData/FastTravel/FTS_Crypt.FTS_Crypt",
+    "/Game/GameData/FastTravel/FTS_Desert1.FTS_Desert1",
+    "/Game/GameData/FastTravel/FTS_Desert2.FTS_Desert2",
+    "/Game/GameData/FastTravel/FTS_DesertBoss.FTS_DesertBoss",
+    "/Game/GameData/FastTravel/FTS_DesertBoss_SendOnly.FTS_DesertBoss_SendOnly",
+    "/Game/GameData/FastTravel/FTS_DesertVault.FTS_DesertVault",
+    "/Game/GameData/FastTravel/FTS_Desolate2.FTS_Desolate2",
+    "/Game/GameData/FastTravel/FTS_DesolateDropPod.FTS_DesolateDropPod",
+    "/Game/GameData/FastTravel/FTS_FinalBoss.FTS_FinalBoss",
+    "/Game/GameData/FastTravel/FTS_FinalBoss_SendOnly.FTS_FinalBoss_SendOnly",
+    "/Game/GameData/FastTravel/FTS_FinalBossPortal.FTS_FinalBossPortal",
+    "/Game/GameData/FastTravel/FTS_Mansion.FTS_Mansion",
+    "/Game/GameData/FastTravel/FTS_Marshfields.FTS_Marshfields",
+    "/Game/GameData/FastTravel/FTS_Marshfields_SendOnly.FTS_Marshfields_SendOnly",
+    "/Game/GameData/FastTravel/FTS_MarshfieldsShip.FTS_MarshfieldsShip",
+    "/Game/GameData/FastTravel/FTS_Mine.FTS_Mine",
+    "/Game/GameData/FastTravel/FTS_Monastery.FTS_Monastery",
+    "/Game/GameData/FastTravel/FTS_Monastery_SendOnly.FTS_Monastery_SendOnly",
+    "/Game/GameData/FastTravel/FTS_MonasteryDropPod.FTS_MonasteryDropPod",
+    "/Game/GameData/FastTravel/FTS_Motorcade.FTS_Motorcade",
+    "/Game/GameData/FastTravel/FTS_Motorcade2.FTS_Motorcade2",
+    "/Game/GameData/FastTravel/FTS_MotorcadeFestival.FTS_MotorcadeFestival",
+    "/Game/GameData/FastTravel/FTS_MotorcadeInterior.FTS_MotorcadeInterior",
+    "/Game/GameData/FastTravel/FTS_MotorcadeInterior_SendOnly.FTS_MotorcadeInterior_SendOnly",
+    "/Game/GameData/FastTravel/FTS_OrbitalPlatform.FTS_OrbitalPlatform",
+    "/Game/GameData/FastTravel/FTS_OrbitalPlatform_SendOnly.FTS_OrbitalPlatform_SendOnly",
+    "/Game/GameData/FastTravel/FTS_OrbitalShuttle.FTS_OrbitalShuttle",
+    "/Game/GameData/FastTravel/FTS_Outskirts.FTS_Outskirts",
+    "/Game/GameData/FastTravel/FTS_OutskirtsDropPod.FTS_OutskirtsDropPod",
+    "/Game/GameData/FastTravel/FTS_Prison.FTS_Prison",
+    "/Game/GameData/FastTravel/FTS_Prison_SendOnly.FTS_Prison_SendOnly",
+    "/Game/GameData/FastTravel/FTS_Prologue.FTS_Prologue",
+    "/Game/GameData/FastTravel/FTS_Prologue2.FTS_Prologue2",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds01.FTS_ProvingGrounds01",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds01_Droppod.FTS_ProvingGrounds01_Droppod",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds01_OneWay.FTS_ProvingGrounds01_OneWay",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds04.FTS_ProvingGrounds04",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds04_Droppod.FTS_ProvingGrounds04_Droppod",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds04_OneWay.FTS_ProvingGrounds04_OneWay",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds05.FTS_ProvingGrounds05",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds05_Droppod.FTS_ProvingGrounds05_Droppod",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds05_OneWay.FTS_ProvingGrounds05_OneWay",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds06.FTS_ProvingGrounds06",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds06_Droppod.FTS_ProvingGrounds06_Droppod",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds06_OneWay.FTS_ProvingGrounds06_OneWay",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds07.FTS_ProvingGrounds07",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds07_Droppod.FTS_ProvingGrounds07_Droppod",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds07_OneWay.FTS_ProvingGrounds07_OneWay",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds08.FTS_ProvingGrounds08",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds08_Droppod.FTS_ProvingGrounds08_Droppod",
+    "/Game/GameData/FastTravel/FTS_ProvingGrounds08_OneWay.FTS_ProvingGrounds08_OneWay",
+    "/Game/GameData/FastTravel/FTS_Raid.FTS_Raid",
+    "/Game/GameData/FastTravel/FTS_Recruitment.FTS_Recruitment",
+    "/Game/GameData/FastTravel/FTS_Sacrifice.FTS_Sacrifice",
+    "/Game/GameData/FastTravel/FTS_Sanctuary.FTS_Sanctuary",
+    "/Game/GameData/FastTravel/FTS_SanctuaryBridge.FTS_SanctuaryBridge",
+    "/Game/GameData/FastTravel/FTS_TechSlaughter.FTS_TechSlaughter",
+    "/Game/GameData/FastTravel/FTS_TechSlaughterDropPod.FTS_TechSlaughterDropPod",
+    "/Game/GameData/FastTravel/FTS_Towers.FTS_Towers",
+    "/Game/GameData/FastTravel/FTS_Watership.FTS_Watership",
+    "/Game/GameData/FastTravel/FTS_Watership_SendOnly.FTS_Watership_SendOnly",
+    "/Game/GameData/FastTravel/FTS_Wetlands1.FTS_Wetlands1",
+    "/Game/GameData/FastTravel/FTS_Wetlands2.FTS_Wetlands2",
+    "/Game/GameData/FastTravel/FTS_WetlandsBoss.FTS_WetlandsBoss",
+    "/Game/GameData/FastTravel/FTS_WetlandsBoss_SendOnly.FTS_WetlandsBoss_SendOnly",
+    "/Game/GameData/FastTravel/FTS_WetlandsDropPod.FTS_WetlandsDropPod",
+    "/Game/GameData/FastTravel/FTS_WetlandsVault.FTS_WetlandsVault",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Anger.FTS_Ali_Anger",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Anger_Boss.FTS_Ali_Anger_Boss",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Anger_Castle.FTS_Ali_Anger_Castle",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Chase.FTS_Ali_Chase",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Chase_Boss.FTS_Ali_Chase_Boss",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Eldorado.FTS_Ali_Eldorado",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Eldorado_Boss.FTS_Ali_Eldorado_Boss",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Experiment.FTS_Ali_Experiment",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Experiment_Boss.FTS_Ali_Experiment_Boss",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Sanctum.FTS_Ali_Sanctum",
+    "/Game/PatchDLC/Alisma/GameData/FastTravel/FTS_Ali_Sanctum_DropPod.FTS_Ali_Sanctum_DropPod",
+    "/Game/PatchDLC/BloodyHarvest/GameData/FastTravel/LevelTravelData/FTS_BloodyHarvest.FTS_BloodyHarvest",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_CasinoIntro_DLC1_Pod.FTS_CasinoIntro_DLC1_Pod",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Core_DLC1.FTS_Core_DLC1",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Core_DLC1_Boss.FTS_Core_DLC1_Boss",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Impound_DLC1.FTS_Impound_DLC1",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Impound_DLC1_Exit.FTS_Impound_DLC1_Exit",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Impound_DLC1_LowGrav.FTS_Impound_DLC1_LowGrav",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Entrance.FTS_Strip_DLC1_Entrance",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Hideout.FTS_Strip_DLC1_Hideout",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Market.FTS_Strip_DLC1_Market",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_TricksyNickArea.FTS_Strip_DLC1_TricksyNickArea",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Strip_DLC1_Vice.FTS_Strip_DLC1_Vice",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_TowerLair_DLC1_Lobby.FTS_TowerLair_DLC1_Lobby",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_TowerLair_DLC1_Scrooge.FTS_TowerLair_DLC1_Scrooge",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Trashtown_DLC1.FTS_Trashtown_DLC1",
+    "/Game/PatchDLC/Dandelion/GameData/FastTravel/FTS_Trashtown_DLC1_Town.FTS_Trashtown_DLC1_Town",
+    "/Game/PatchDLC/Event2/GameData/FastTravel/LevelTravelData/FTS_CartelHideout.FTS_CartelHideout",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_CraterBoss_DLC3.FTS_CraterBoss_DLC3",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_CraterBoss_DLC3_Boss.FTS_CraterBoss_DLC3_Boss",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Facility_DLC3.FTS_Facility_DLC3",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Facility_DLC3_mid.FTS_Facility_DLC3_Mid",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Facility_DLC3_SendOnly.FTS_Facility_DLC3_SendOnly",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Forest_DLC3.FTS_Forest_DLC3",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Forest_DLC3_Mid.FTS_Forest_DLC3_Mid",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Frontier_DLC3_Fort.FTS_Frontier_DLC3_Fort",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Frontier_DLC3_Garage.FTS_Frontier_DLC3_Garage",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Frontier_DLC3_GasStation.FTS_Frontier_DLC3_GasStation",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Lodge_DLC3.FTS_Lodge_DLC3",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Lodge_DLC3_Boss.FTS_Lodge_DLC3_Boss",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Lodge_DLC3_Mid.FTS_Lodge_DLC3_Mid",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Town_DLC3.FTS_Town_DLC3",
+    "/Game/PatchDLC/Geranium/GameData/FastTravel/FTS_Town_DLC3_Pod.FTS_Town_DLC3_Pod",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Archive.FTS_DLC2_Archive",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Archives_OneWay.FTS_DLC2_Archives_OneWay",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Bar.FTS_DLC2_Bar",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Camp.FTS_DLC2_Camp",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Camp_OneWay.FTS_DLC2_Camp_OneWay",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_Amourette.FTS_DLC2_Lake_Amourette",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_DropPod.FTS_DLC2_Lake_DropPod",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_Excavation.FTS_DLC2_Lake_Excavation",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Lake_Gondola.FTS_DLC2_Lake_Gondola",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Venue.FTS_DLC2_Venue",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Venue_MapStart.FTS_DLC2_Venue_MapStart",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Venue_OneWay.FTS_DLC2_Venue_OneWay",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Village_NearArchives.FTS_DLC2_Village_NearArchives",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Village_NearBar.FTS_DLC2_Village_NearBar",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_VillageDistrict.FTS_DLC2_VillageDistrict",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_VillageGardens.FTS_DLC2_VillageGardens",
+    "/Game/PatchDLC/Hibiscus/GameData/FastTravel/FTS_DLC2_Woods.FTS_DLC2_Woods",
+    "/Game/PatchDLC/Ixora/GameData/FastTravel/LevelTravel/FTS_GearUpMap.FTS_GearUpMap",
+    "/Game/PatchDLC/Ixora/GameData/FastTravel/LevelTravel/FTS_GearUpMap_SendOnly.FTS_GearUpMap_SendOnly",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_Eden6Mystery.FTS_Ixora2_Eden6Mystery",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_NekroMystery.FTS_Ixora2_NekroMystery",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_NekroMystery_OneWay.FTS_Ixora2_NekroMystery_OneWay",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_PandoraMystery.FTS_Ixora2_PandoraMystery",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_Promethea_OneWay.FTS_Ixora2_Promethea_OneWay",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_PrometheaMystery.FTS_Ixora2_PrometheaMystery",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_SacrificeBoss.FTS_Ixora2_SacrificeBoss",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_SacrificeBoss_One.FTS_Ixora2_SacrificeBoss_One",
+    "/Game/PatchDLC/Ixora2/GameData/FastTravel/LevelTravel/FTS_Ixora2_SacrificeBoss_OneWay.FTS_Ixora2_SacrificeBoss_OneWay",
+    "/Game/PatchDLC/Raid1/GameData/FastTravel/LevelTravelData/FTS_MaliwanTD_SendOnly.FTS_MaliwanTD_SendOnly",
+    "/Game/PatchDLC/Raid1/GameData/FastTravel/LevelTravelData/FTS_Raid1.FTS_Raid1",
+    "/Game/PatchDLC/Raid1/GameData/FastTravel/LevelTravelData/FTS_Raid1DropPod.FTS_Raid1DropPod",
+    "/Game/PatchDLC/Takedown2/GameData/LevelTravel/FTS_GuardianTD_SendOnly_2.FTS_GuardianTD_SendOnly_2",
+    "/Game/PatchDLC/Takedown2/GameData/LevelTravel/FTS_TD2.FTS_TD2",
+    "/Game/PatchDLC/Takedown2/GameData/LevelTravel/FTS_TD2DropPod1.FTS_TD2DropPod1",
+];
+
 pub trait GameDataExt {
     fn get_value_by_key(&self, key: &str) -> Result<&str>;
 }
@@ -1,12 +1,18 @@
 pub mod arbitrary_bits;
+pub mod backup;
 pub mod bl3_item;
 pub mod bl3_profile;
 pub mod bl3_save;
+pub mod diff;
 pub mod error;
+#[cfg(feature = "with-serde")]
+pub mod export;
 pub mod file_helper;
 pub mod game_data;
+pub mod load_warning;
 pub mod models;
 pub mod parser;
 pub mod protos;
 pub mod resources;
+pub mod transfer;
 pub mod vehicle_data;
@@ -0,0 +1,79 @@
+use std::fmt::Formatter;
+
+use anyhow::Result;
+use tracing::warn;
+
+/// How strictly a file is read, a strict load fails on the first value which can't be read while
+/// a lenient load skips it (or uses a default) and reports it as a `LoadWarning`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LoadMode {
+    Strict,
+    Lenient,
+}
+
+/// Something which couldn't be read when loading a file.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct LoadWarning {
+    pub file: String,
+    pub field: String,
+    pub detail: String,
+}
+
+impl std::fmt::Display for LoadWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} - {}", self.file, self.field, self.detail)
+    }
+}
+
+/// Collects the warnings while a file is being read, the file name is filled in once the whole
+/// file has been read.
+#[derive(Debug)]
+pub(crate) struct LoadWarnings {
+    mode: LoadMode,
+    warnings: Vec<LoadWarning>,
+}
+
+impl LoadWarnings {
+    pub(crate) fn new(mode: LoadMode) -> Self {
+        Self {
+            mode,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Returns the error in strict mode, otherwise it is kept as a warning and `None` is returned.
+    pub(crate) fn check<T>(&mut self, field: &str, result: Result<T>) -> Result<Option<T>> {
+        match result {
+            Ok(v) => Ok(Some(v)),
+            Err(e) if self.mode == LoadMode::Lenient => {
+                self.push(field, format!("{:#}", e));
+
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Keeps a warning for something which is skipped in both modes.
+    pub(crate) fn push<S: Into<String>>(&mut self, field: &str, detail: S) {
+        let detail = detail.into();
+
+        warn!("Skipped {} when reading file: {}", field, detail);
+
+        self.warnings.push(LoadWarning {
+            file: String::new(),
+            field: field.to_owned(),
+            detail,
+        });
+    }
+
+    pub(crate) fn into_warnings(self, file: &str) -> Vec<LoadWarning> {
+        self.warnings
+            .into_iter()
+            .map(|w| LoadWarning {
+                file: file.to_owned(),
+                ..w
+            })
+            .collect()
+    }
+}
@@ -6,7 +6,8 @@ use json::JsonValue;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
 use crate::bl3_item::Bl3Part;
-use crate::resources::INVENTORY_SERIAL_DB_JSON_COMPRESSED;
+use crate::resources::data_pack::data_pack_file;
+use crate::resources::{INVENTORY_SERIAL_DB_JSON_COMPRESSED, INVENTORY_SERIAL_DB_NAME};
 
 pub struct InventorySerialDb {
     pub data: JsonValue,
@@ -14,8 +15,15 @@ pub struct InventorySerialDb {
 }
 
 impl InventorySerialDb {
+    /// Loads the serial db from the data pack when it has one, otherwise from the embedded copy.
     pub fn load() -> Result<Self> {
-        let mut rdr = snap::read::FrameDecoder::new(INVENTORY_SERIAL_DB_JSON_COMPRESSED);
+        Self::from_compressed(
+            data_pack_file(INVENTORY_SERIAL_DB_NAME).unwrap_or(INVENTORY_SERIAL_DB_JSON_COMPRESSED),
+        )
+    }
+
+    pub fn from_compressed(input: &[u8]) -> Result<Self> {
+        let mut rdr = snap::read::FrameDecoder::new(input);
 
         let mut decompressed_bytes = String::new();
 
@@ -1,12 +1,13 @@
 use anyhow::{Context, Result};
 use byteorder::{LittleEndian, WriteBytesExt};
+use serde::{Deserialize, Serialize};
 use strum::Display;
 
 use crate::error::BL3ParserError;
 use crate::error::ErrorExt;
 use crate::models::CustomFormatData;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Display, Serialize, Deserialize)]
 pub enum HeaderType {
     #[strum(to_string = "PC Save")]
     PcSave,
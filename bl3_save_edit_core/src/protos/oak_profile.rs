@@ -24,12 +24,15 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_25_2;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlayerInputBinding_Button {
     // message fields
     pub rebind_data_path: ::std::string::String,
     pub key_names: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -224,12 +227,15 @@ impl ::protobuf::reflect::ProtobufValue for PlayerInputBinding_Button {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlayerInputBinding_Axis_Key {
     // message fields
     pub key_name: ::std::string::String,
     pub scale_3d: ::protobuf::SingularPtrField<super::oak_shared::Vec3>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -440,12 +446,15 @@ impl ::protobuf::reflect::ProtobufValue for PlayerInputBinding_Axis_Key {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlayerInputBinding_Axis {
     // message fields
     pub rebind_data_path: ::std::string::String,
     pub keys: ::protobuf::RepeatedField<PlayerInputBinding_Axis_Key>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -648,6 +657,7 @@ impl ::protobuf::reflect::ProtobufValue for PlayerInputBinding_Axis {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlayerInputBinding_Category {
     // message fields
     pub category_data_path: ::std::string::String,
@@ -655,7 +665,9 @@ pub struct PlayerInputBinding_Category {
     pub button_bindings: ::protobuf::RepeatedField<PlayerInputBinding_Button>,
     pub axis_bindings: ::protobuf::RepeatedField<PlayerInputBinding_Axis>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -947,11 +959,14 @@ impl ::protobuf::reflect::ProtobufValue for PlayerInputBinding_Category {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlayerInputBindings {
     // message fields
     pub categories: ::protobuf::RepeatedField<PlayerInputBinding_Category>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1113,12 +1128,15 @@ impl ::protobuf::reflect::ProtobufValue for PlayerInputBindings {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakProfileLastInventoryFilterInfo {
     // message fields
     pub slot_type_id: ::std::string::String,
     pub last_filter_index: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1307,13 +1325,16 @@ impl ::protobuf::reflect::ProtobufValue for OakProfileLastInventoryFilterInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakProfileMenuTutorialInfo {
     // message fields
     pub seen_tutorials: ::protobuf::RepeatedField<::std::string::String>,
     pub tutorials_disabled: bool,
     pub tutorials_allowed_in_non_game_modes: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1535,12 +1556,15 @@ impl ::protobuf::reflect::ProtobufValue for OakProfileMenuTutorialInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakFriendEncounterData {
     // message fields
     pub num_encounters: u32,
     pub time_last_encounter: i64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1722,13 +1746,16 @@ impl ::protobuf::reflect::ProtobufValue for OakFriendEncounterData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GearSoldByFriendData {
     // message fields
     pub gear_serial_number: ::std::string::String,
     pub player_class_identifier_hash: i32,
     pub friend_net_id: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1958,12 +1985,15 @@ impl ::protobuf::reflect::ProtobufValue for GearSoldByFriendData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GuardianRankRewardSaveGameData {
     // message fields
     pub num_tokens: i32,
     pub reward_data_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2152,6 +2182,7 @@ impl ::protobuf::reflect::ProtobufValue for GuardianRankRewardSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GuardianRankProfileData {
     // message fields
     pub available_tokens: i32,
@@ -2161,7 +2192,9 @@ pub struct GuardianRankProfileData {
     pub guardian_reward_random_seed: i32,
     pub new_guardian_experience: i64,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2493,13 +2526,16 @@ impl ::protobuf::reflect::ProtobufValue for GuardianRankProfileData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct RecentlyMetPlayer {
     // message fields
     pub shift_player_id: ::std::string::String,
     pub first_party_player_id: ::std::string::String,
     pub show_shift_player_entry: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2729,6 +2765,7 @@ impl ::protobuf::reflect::ProtobufValue for RecentlyMetPlayer {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Profile {
     // message fields
     pub enable_aim_assist: bool,
@@ -2935,7 +2972,9 @@ pub struct Profile {
     pub player_selected_league: u32,
     pub needs_shift_first_boot_primary: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -10303,12 +10342,15 @@ impl ::protobuf::reflect::ProtobufValue for Profile {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Profile_FriendEncountersEntry {
     // message fields
     pub key: ::std::string::String,
     pub value: ::protobuf::SingularPtrField<OakFriendEncounterData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
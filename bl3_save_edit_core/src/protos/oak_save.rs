@@ -24,12 +24,15 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_25_2;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlayerClassSaveGameData {
     // message fields
     pub player_class_path: ::std::string::String,
     pub dlc_package_id: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -218,12 +221,15 @@ impl ::protobuf::reflect::ProtobufValue for PlayerClassSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ResourcePoolSavegameData {
     // message fields
     pub amount: f32,
     pub resource_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -412,6 +418,7 @@ impl ::protobuf::reflect::ProtobufValue for ResourcePoolSavegameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct RegionSaveGameData {
     // message fields
     pub game_stage: i32,
@@ -419,7 +426,9 @@ pub struct RegionSaveGameData {
     pub region_path: ::std::string::String,
     pub dlc_package_id: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -676,6 +685,7 @@ impl ::protobuf::reflect::ProtobufValue for RegionSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct InventoryBalanceStateInitializationData {
     // message fields
     pub game_stage: i32,
@@ -687,7 +697,9 @@ pub struct InventoryBalanceStateInitializationData {
     pub additional_data: ::std::vec::Vec<u8>,
     pub customization_part_list: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1119,6 +1131,7 @@ impl ::protobuf::reflect::ProtobufValue for InventoryBalanceStateInitializationD
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakInventoryItemSaveGameData {
     // message fields
     pub item_serial_number: ::std::vec::Vec<u8>,
@@ -1127,7 +1140,9 @@ pub struct OakInventoryItemSaveGameData {
     pub weapon_skin_path: ::std::string::String,
     pub development_save_data: ::protobuf::SingularPtrField<InventoryBalanceStateInitializationData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1447,6 +1462,7 @@ impl ::protobuf::reflect::ProtobufValue for OakInventoryItemSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct EquippedInventorySaveGameData {
     // message fields
     pub inventory_list_index: i32,
@@ -1454,7 +1470,9 @@ pub struct EquippedInventorySaveGameData {
     pub slot_data_path: ::std::string::String,
     pub trinket_data_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1718,6 +1736,7 @@ impl ::protobuf::reflect::ProtobufValue for EquippedInventorySaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakAbilityTreeItemSaveGameData {
     // message fields
     pub item_asset_path: ::std::string::String,
@@ -1725,7 +1744,9 @@ pub struct OakAbilityTreeItemSaveGameData {
     pub max_points: i32,
     pub tree_identifier: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1982,12 +2003,15 @@ impl ::protobuf::reflect::ProtobufValue for OakAbilityTreeItemSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakAbilitySlotSaveGameData {
     // message fields
     pub ability_class_path: ::std::string::String,
     pub slot_asset_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2183,13 +2207,16 @@ impl ::protobuf::reflect::ProtobufValue for OakAbilitySlotSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakActionAbilityAugmentSaveGameData {
     // message fields
     pub action_ability_class_path: ::std::string::String,
     pub slot_asset_path: ::std::string::String,
     pub augment_asset_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2426,6 +2453,7 @@ impl ::protobuf::reflect::ProtobufValue for OakActionAbilityAugmentSaveGameData
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakActionAbilityAugmentConfigurationSaveGameData {
     // message fields
     pub ability_class_path: ::std::string::String,
@@ -2433,7 +2461,9 @@ pub struct OakActionAbilityAugmentConfigurationSaveGameData {
     pub mod_slot_asset_path: ::std::string::String,
     pub mod_asset_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2711,6 +2741,7 @@ impl ::protobuf::reflect::ProtobufValue for OakActionAbilityAugmentConfiguration
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakPlayerAbilitySaveGameData {
     // message fields
     pub ability_points: i32,
@@ -2720,7 +2751,9 @@ pub struct OakPlayerAbilitySaveGameData {
     pub augment_configuration_list: ::protobuf::RepeatedField<OakActionAbilityAugmentConfigurationSaveGameData>,
     pub tree_grade: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3094,6 +3127,7 @@ impl ::protobuf::reflect::ProtobufValue for OakPlayerAbilitySaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct MissionStatusPlayerSaveGameData {
     // message fields
     pub status: MissionStatusPlayerSaveGameData_MissionState,
@@ -3105,7 +3139,9 @@ pub struct MissionStatusPlayerSaveGameData {
     pub kickoff_played: bool,
     pub league_instance: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3507,6 +3543,7 @@ impl ::protobuf::reflect::ProtobufValue for MissionStatusPlayerSaveGameData {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum MissionStatusPlayerSaveGameData_MissionState {
     MS_NotStarted = 0,
     MS_Active = 1,
@@ -3566,12 +3603,15 @@ impl ::protobuf::reflect::ProtobufValue for MissionStatusPlayerSaveGameData_Miss
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct MissionPlaythroughSaveGameData {
     // message fields
     pub mission_list: ::protobuf::RepeatedField<MissionStatusPlayerSaveGameData>,
     pub tracked_mission_class_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3774,12 +3814,15 @@ impl ::protobuf::reflect::ProtobufValue for MissionPlaythroughSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ActiveFastTravelSaveData {
     // message fields
     pub active_travel_station_name: ::std::string::String,
     pub blacklisted: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3968,11 +4011,14 @@ impl ::protobuf::reflect::ProtobufValue for ActiveFastTravelSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlaythroughActiveFastTravelSaveData {
     // message fields
     pub active_travel_stations: ::protobuf::RepeatedField<ActiveFastTravelSaveData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4134,12 +4180,15 @@ impl ::protobuf::reflect::ProtobufValue for PlaythroughActiveFastTravelSaveData
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct DiscoveredAreaInfo {
     // message fields
     pub discovered_area_name: ::std::string::String,
     pub discovered_playthroughs: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4328,13 +4377,16 @@ impl ::protobuf::reflect::ProtobufValue for DiscoveredAreaInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct DiscoveredLevelInfo {
     // message fields
     pub discovered_level_name: ::std::string::String,
     pub discovered_playthroughs: u32,
     pub discovered_area_info: ::protobuf::RepeatedField<DiscoveredAreaInfo>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4571,12 +4623,15 @@ impl ::protobuf::reflect::ProtobufValue for DiscoveredLevelInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct DiscoveredPlanetInfo {
     // message fields
     pub discovered_planet: ::std::string::String,
     pub is_new_planet: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4765,11 +4820,14 @@ impl ::protobuf::reflect::ProtobufValue for DiscoveredPlanetInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct DiscoverySaveData {
     // message fields
     pub discovered_level_info: ::protobuf::RepeatedField<DiscoveredLevelInfo>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4931,12 +4989,15 @@ impl ::protobuf::reflect::ProtobufValue for DiscoverySaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct VehicleUnlockedSaveGameData {
     // message fields
     pub asset_path: ::std::string::String,
     pub just_unlocked: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5125,6 +5186,7 @@ impl ::protobuf::reflect::ProtobufValue for VehicleUnlockedSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakCARMenuVehicleConfigSaveData {
     // message fields
     pub loadout_save_name: ::std::string::String,
@@ -5141,7 +5203,9 @@ pub struct OakCARMenuVehicleConfigSaveData {
     pub color_index_2: i32,
     pub color_index_3: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -5767,6 +5831,7 @@ impl ::protobuf::reflect::ProtobufValue for OakCARMenuVehicleConfigSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CustomPlayerColorSaveGameData {
     // message fields
     pub color_parameter: ::std::string::String,
@@ -5775,7 +5840,9 @@ pub struct CustomPlayerColorSaveGameData {
     pub use_default_color: bool,
     pub use_default_split_color: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -6110,12 +6177,15 @@ impl ::protobuf::reflect::ProtobufValue for CustomPlayerColorSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GuardianRankSaveGameData {
     // message fields
     pub guardian_rank: i32,
     pub guardian_experience: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -6297,13 +6367,16 @@ impl ::protobuf::reflect::ProtobufValue for GuardianRankSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GuardianRankRewardCharacterSaveGameData {
     // message fields
     pub num_tokens: i32,
     pub is_enabled: bool,
     pub reward_data_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -6526,12 +6599,15 @@ impl ::protobuf::reflect::ProtobufValue for GuardianRankRewardCharacterSaveGameD
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GuardianRankPerkCharacterSaveGameData {
     // message fields
     pub is_enabled: bool,
     pub perk_data_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -6720,6 +6796,7 @@ impl ::protobuf::reflect::ProtobufValue for GuardianRankPerkCharacterSaveGameDat
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GuardianRankCharacterSaveGameData {
     // message fields
     pub guardian_available_tokens: i32,
@@ -6731,7 +6808,9 @@ pub struct GuardianRankCharacterSaveGameData {
     pub new_guardian_experience: i64,
     pub is_rank_system_enabled: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -7145,12 +7224,15 @@ impl ::protobuf::reflect::ProtobufValue for GuardianRankCharacterSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CrewQuartersDecorationSaveData {
     // message fields
     pub decoration_index: i32,
     pub decoration_data_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -7339,13 +7421,16 @@ impl ::protobuf::reflect::ProtobufValue for CrewQuartersDecorationSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CrewQuartersSaveData {
     // message fields
     pub preferred_room_assignment: i32,
     pub decorations: ::protobuf::RepeatedField<CrewQuartersDecorationSaveData>,
     pub room_data_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -7582,13 +7667,16 @@ impl ::protobuf::reflect::ProtobufValue for CrewQuartersSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CrewQuartersGunRackItemSaveData {
     // message fields
     pub encrypted_serial_number: ::std::vec::Vec<u8>,
     pub slot_asset_path: ::std::string::String,
     pub development_save_data: ::protobuf::SingularPtrField<InventoryBalanceStateInitializationData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -7840,11 +7928,14 @@ impl ::protobuf::reflect::ProtobufValue for CrewQuartersGunRackItemSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CrewQuartersGunRackSaveData {
     // message fields
     pub rack_save_data: ::protobuf::RepeatedField<CrewQuartersGunRackItemSaveData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -8006,12 +8097,15 @@ impl ::protobuf::reflect::ProtobufValue for CrewQuartersGunRackSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct EchoLogSaveGameData {
     // message fields
     pub has_been_seen_in_log: bool,
     pub echo_log_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -8200,12 +8294,15 @@ impl ::protobuf::reflect::ProtobufValue for EchoLogSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct MapIDData {
     // message fields
     pub zone_name_id: u32,
     pub map_name_id: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -8387,13 +8484,16 @@ impl ::protobuf::reflect::ProtobufValue for MapIDData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GameStateSaveData {
     // message fields
     pub last_traveled_map_id: ::protobuf::SingularPtrField<MapIDData>,
     pub mayhem_level: i32,
     pub mayhem_random_seed: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -8631,11 +8731,14 @@ impl ::protobuf::reflect::ProtobufValue for GameStateSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ChallengeCategoryProgressSaveData {
     // message fields
     pub category_progress: ::std::vec::Vec<u8>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -8790,12 +8893,15 @@ impl ::protobuf::reflect::ProtobufValue for ChallengeCategoryProgressSaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakPlayerCharacterAugmentSaveGameData {
     // message fields
     pub slot_asset_path: ::std::string::String,
     pub augment_asset_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -8991,11 +9097,14 @@ impl ::protobuf::reflect::ProtobufValue for OakPlayerCharacterAugmentSaveGameDat
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakPlayerCharacterSlotSaveGameData {
     // message fields
     pub augment_slot_list: ::protobuf::RepeatedField<OakPlayerCharacterAugmentSaveGameData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -9157,6 +9266,7 @@ impl ::protobuf::reflect::ProtobufValue for OakPlayerCharacterSlotSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct UITrackingSaveGameData {
     // message fields
     pub has_seen_skill_menu_unlock: bool,
@@ -9168,7 +9278,9 @@ pub struct UITrackingSaveGameData {
     pub inventory_slot_unlocks_seen: ::protobuf::RepeatedField<::std::string::String>,
     pub saved_spin_offset: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -9560,13 +9672,16 @@ impl ::protobuf::reflect::ProtobufValue for UITrackingSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct PlanetCycleInfo {
     // message fields
     pub planet_name: ::std::string::String,
     pub cycle_length: f32,
     pub last_cached_time: f32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -9789,12 +9904,15 @@ impl ::protobuf::reflect::ProtobufValue for PlanetCycleInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct TimeOfDaySaveGameData {
     // message fields
     pub planet_cycle_info: ::protobuf::RepeatedField<PlanetCycleInfo>,
     pub planet_cycle: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -9997,12 +10115,15 @@ impl ::protobuf::reflect::ProtobufValue for TimeOfDaySaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct LevelPersistence_Actor_SaveGameData {
     // message fields
     pub actor_name: ::std::string::String,
     pub timer_remaining: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -10191,12 +10312,15 @@ impl ::protobuf::reflect::ProtobufValue for LevelPersistence_Actor_SaveGameData
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct LevelPersistence_Level_SaveGameData {
     // message fields
     pub level_name: ::std::string::String,
     pub saved_actors: ::protobuf::RepeatedField<LevelPersistence_Actor_SaveGameData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -10399,6 +10523,7 @@ impl ::protobuf::reflect::ProtobufValue for LevelPersistence_Level_SaveGameData
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GbxZoneMapFODSavedLevelData {
     // message fields
     pub level_name: ::std::string::String,
@@ -10409,7 +10534,9 @@ pub struct GbxZoneMapFODSavedLevelData {
     pub data_revision: u32,
     pub fod_data: ::std::vec::Vec<u8>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -10775,11 +10902,14 @@ impl ::protobuf::reflect::ProtobufValue for GbxZoneMapFODSavedLevelData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GbxZoneMapFODSaveGameData {
     // message fields
     pub level_data: ::protobuf::RepeatedField<GbxZoneMapFODSavedLevelData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -10941,6 +11071,7 @@ impl ::protobuf::reflect::ProtobufValue for GbxZoneMapFODSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakProfileCloudData {
     // message fields
     pub profile_stats_data: ::protobuf::RepeatedField<super::oak_shared::GameStatSaveGameData>,
@@ -10961,7 +11092,9 @@ pub struct OakProfileCloudData {
     pub bCitizenScienceHasSeenIntroVideo: bool,
     pub bCitizenScienceTutorialDone: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -11811,6 +11944,7 @@ impl ::protobuf::reflect::ProtobufValue for OakProfileCloudData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Character {
     // message fields
     pub save_game_id: u32,
@@ -11875,7 +12009,9 @@ pub struct Character {
     pub levelled_save_vehicle_part_rewards_fixup_applied: bool,
     pub profile_cloud_data: ::protobuf::SingularPtrField<OakProfileCloudData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -14708,12 +14844,15 @@ impl ::protobuf::reflect::ProtobufValue for Character {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Character_NicknameMappingsEntry {
     // message fields
     pub key: ::std::string::String,
     pub value: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -14909,12 +15048,15 @@ impl ::protobuf::reflect::ProtobufValue for Character_NicknameMappingsEntry {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Character_ActiveLeagueInstanceForEventEntry {
     // message fields
     pub key: u32,
     pub value: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
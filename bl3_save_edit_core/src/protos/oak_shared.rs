@@ -24,13 +24,16 @@
 // const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_25_2;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Vec3 {
     // message fields
     pub x: f32,
     pub y: f32,
     pub z: f32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -246,12 +249,15 @@ impl ::protobuf::reflect::ProtobufValue for Vec3 {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct GameStatSaveGameData {
     // message fields
     pub stat_value: i32,
     pub stat_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -440,12 +446,15 @@ impl ::protobuf::reflect::ProtobufValue for GameStatSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct InventoryCategorySaveData {
     // message fields
     pub base_category_definition_hash: u32,
     pub quantity: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -627,12 +636,15 @@ impl ::protobuf::reflect::ProtobufValue for InventoryCategorySaveData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakSDUSaveGameData {
     // message fields
     pub sdu_level: i32,
     pub sdu_data_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -821,6 +833,7 @@ impl ::protobuf::reflect::ProtobufValue for OakSDUSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct RegisteredDownloadableEntitlement {
     // message fields
     pub id: i32,
@@ -828,7 +841,9 @@ pub struct RegisteredDownloadableEntitlement {
     pub registered: bool,
     pub seen: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1078,13 +1093,16 @@ impl ::protobuf::reflect::ProtobufValue for RegisteredDownloadableEntitlement {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct RegisteredDownloadableEntitlements {
     // message fields
     pub entitlement_source_asset_path: ::std::string::String,
     pub entitlement_ids: ::std::vec::Vec<i64>,
     pub entitlements: ::protobuf::RepeatedField<RegisteredDownloadableEntitlement>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1327,12 +1345,15 @@ impl ::protobuf::reflect::ProtobufValue for RegisteredDownloadableEntitlements {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ChallengeStatSaveGameData {
     // message fields
     pub current_stat_value: i32,
     pub challenge_stat_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1521,11 +1542,14 @@ impl ::protobuf::reflect::ProtobufValue for ChallengeStatSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakChallengeRewardSaveGameData {
     // message fields
     pub challenge_reward_claimed: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -1673,6 +1697,7 @@ impl ::protobuf::reflect::ProtobufValue for OakChallengeRewardSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ChallengeSaveGameData {
     // message fields
     pub completed_count: i32,
@@ -1684,7 +1709,9 @@ pub struct ChallengeSaveGameData {
     pub challenge_class_path: ::std::string::String,
     pub challenge_reward_info: ::protobuf::RepeatedField<OakChallengeRewardSaveGameData>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2105,6 +2132,7 @@ impl ::protobuf::reflect::ProtobufValue for ChallengeSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakMailItem {
     // message fields
     pub mail_item_type: u32,
@@ -2118,7 +2146,9 @@ pub struct OakMailItem {
     pub from_player_id: ::std::string::String,
     pub has_been_read: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2614,12 +2644,15 @@ impl ::protobuf::reflect::ProtobufValue for OakMailItem {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakCustomizationSaveGameData {
     // message fields
     pub is_new: bool,
     pub customization_asset_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2808,12 +2841,15 @@ impl ::protobuf::reflect::ProtobufValue for OakCustomizationSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct OakInventoryCustomizationPartInfo {
     // message fields
     pub customization_part_hash: u32,
     pub is_new: bool,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -2995,12 +3031,15 @@ impl ::protobuf::reflect::ProtobufValue for OakInventoryCustomizationPartInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CrewQuartersDecorationItemSaveGameData {
     // message fields
     pub is_new: bool,
     pub decoration_item_asset_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3189,12 +3228,15 @@ impl ::protobuf::reflect::ProtobufValue for CrewQuartersDecorationItemSaveGameDa
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct CrewQuartersRoomItemSaveGameData {
     // message fields
     pub is_new: bool,
     pub room_item_asset_path: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3383,6 +3425,7 @@ impl ::protobuf::reflect::ProtobufValue for CrewQuartersRoomItemSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct VaultCardSaveGameData {
     // message fields
     pub last_active_vault_card_id: u32,
@@ -3391,7 +3434,9 @@ pub struct VaultCardSaveGameData {
     pub vault_card_previous_challenges: ::protobuf::RepeatedField<VaultCardPreviousChallenge>,
     pub vault_card_claimed_rewards: ::protobuf::RepeatedField<VaultCardRewardList>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3703,12 +3748,15 @@ impl ::protobuf::reflect::ProtobufValue for VaultCardSaveGameData {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct VaultCardReward {
     // message fields
     pub column_index: i32,
     pub row_index: i32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -3890,12 +3938,15 @@ impl ::protobuf::reflect::ProtobufValue for VaultCardReward {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct VaultCardGearReward {
     // message fields
     pub gear_index: i32,
     pub repurchase_count: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4077,6 +4128,7 @@ impl ::protobuf::reflect::ProtobufValue for VaultCardGearReward {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct VaultCardRewardList {
     // message fields
     pub vault_card_id: u32,
@@ -4088,7 +4140,9 @@ pub struct VaultCardRewardList {
     pub vault_card_keys_spent: u32,
     pub gear_rewards: ::protobuf::RepeatedField<VaultCardGearReward>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
@@ -4516,12 +4570,15 @@ impl ::protobuf::reflect::ProtobufValue for VaultCardRewardList {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct VaultCardPreviousChallenge {
     // message fields
     pub previous_challenge_seed: i32,
     pub previous_challenge_id: u32,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     pub cached_size: ::protobuf::CachedSize,
 }
 
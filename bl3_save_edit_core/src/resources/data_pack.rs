@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::bl3_item::{BalancePart, InvDataPart, ManufacturerPart};
+use crate::models::inventory_serial_db::InventorySerialDb;
+use crate::resources::{
+    try_load_compressed_data, try_load_game_data_kv, InventoryPartsAll,
+    InventorySerialDbCategorizedParts, LootlemonItemRaw, BALANCE_NAME_MAPPING_NAME,
+    BALANCE_TO_INV_KEY_NAME, ECHO_LOGS_NAME, FAST_TRAVEL_NAME, INVENTORY_BALANCE_PARTS_NAME,
+    INVENTORY_INV_DATA_PARTS_NAME, INVENTORY_MANUFACTURER_PARTS_NAME,
+    INVENTORY_PARTS_ALL_CATEGORIZED_NAME, INVENTORY_SERIAL_DB_JSON_COMPRESSED,
+    INVENTORY_SERIAL_DB_NAME, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_NAME, LOOTLEMON_ITEMS_NAME,
+    MISSION_NAME, PROFILE_ECHO_THEMES_DEFAULTS_NAME, PROFILE_ECHO_THEMES_NAME,
+    PROFILE_EMOTES_DEFAULTS_NAME, PROFILE_EMOTES_NAME, PROFILE_HEADS_DEFAULTS_NAME,
+    PROFILE_HEADS_NAME, PROFILE_ROOM_DECORATIONS_NAME, PROFILE_SKINS_DEFAULTS_NAME,
+    PROFILE_SKINS_NAME, PROFILE_WEAPON_SKINS_NAME, PROFILE_WEAPON_TRINKETS_NAME,
+};
+
+pub const DATA_PACK_DIR_NAME: &str = "data_pack";
+pub const DATA_PACK_MANIFEST_NAME: &str = "data_pack.ron";
+pub const DATA_PACK_SCHEMA_VERSION: u32 = 1;
+
+static DATA_PACK: OnceCell<DataPack> = OnceCell::new();
+
+/// Describes a data pack, stored as `data_pack.ron` next to the pack's files.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DataPackManifest {
+    pub name: String,
+    pub schema_version: u32,
+    /// The highest item serial version in the pack's `INVENTORY_SERIAL_DB.json.sz`.
+    pub max_version: usize,
+}
+
+/// What was loaded at startup, the embedded serial db's max version is always reported so it can
+/// be compared to the pack's.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct DataPackInfo {
+    pub manifest: DataPackManifest,
+    pub files: Vec<String>,
+    pub embedded_max_version: usize,
+}
+
+/// A versioned copy of the generated resources (the `*.sz` files written by `build.rs`) and the
+/// `game_data` tables which replaces the embedded ones without needing a new release when the
+/// game is patched. Any file missing from the pack falls back to the embedded copy, except the
+/// serial db which every pack has to include.
+///
+/// Only the `game_data` lists without names (vehicle parts and `FAST_TRAVEL_STATIONS`) are
+/// compiled into the editor as constants and can't be replaced by a pack.
+#[derive(Debug, Default)]
+pub struct DataPack {
+    pub manifest: DataPackManifest,
+    files: HashMap<&'static str, Vec<u8>>,
+}
+
+impl DataPack {
+    /// Reads and validates the pack in `dir`, returns `None` when there is no pack.
+    pub fn load(dir: &Path) -> Result<Option<Self>> {
+        let manifest_path = dir.join(DATA_PACK_MANIFEST_NAME);
+
+        if !manifest_path.exists() {
+            return Ok(None);
+        }
+
+        let manifest_data = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+
+        let manifest: DataPackManifest = ron::de::from_str(&manifest_data)
+            .with_context(|| format!("failed to parse {}", manifest_path.display()))?;
+
+        if manifest.schema_version != DATA_PACK_SCHEMA_VERSION {
+            bail!(
+                "data pack schema version {} is not supported, expected: {}",
+                manifest.schema_version,
+                DATA_PACK_SCHEMA_VERSION
+            );
+        }
+
+        let mut files = HashMap::new();
+
+        for name in DATA_PACK_FILES.into_iter().chain(GAME_DATA_FILES) {
+            let path = dir.join(name);
+
+            if path.exists() {
+                let data = fs::read(&path)
+                    .with_context(|| format!("failed to read {}", path.display()))?;
+
+                files.insert(name, data);
+            }
+        }
+
+        let data_pack = Self { manifest, files };
+
+        data_pack.validate()?;
+
+        Ok(Some(data_pack))
+    }
+
+    pub fn file_names(&self) -> Vec<String> {
+        let mut file_names = self.files.keys().map(|f| f.to_string()).collect::<Vec<_>>();
+
+        file_names.sort();
+
+        file_names
+    }
+
+    pub(crate) fn file(&self, name: &str) -> Option<&[u8]> {
+        self.files.get(name).map(|f| f.as_slice())
+    }
+
+    fn validate(&self) -> Result<()> {
+        let serial_db_data = self
+            .file(INVENTORY_SERIAL_DB_NAME)
+            .with_context(|| format!("data pack is missing {}", INVENTORY_SERIAL_DB_NAME))?;
+
+        let serial_db = InventorySerialDb::from_compressed(serial_db_data)
+            .with_context(|| format!("failed to read {}", INVENTORY_SERIAL_DB_NAME))?;
+
+        if serial_db.max_version != self.manifest.max_version {
+            bail!(
+                "data pack max version is {} but {} has a max version of {}",
+                self.manifest.max_version,
+                INVENTORY_SERIAL_DB_NAME,
+                serial_db.max_version
+            );
+        }
+
+        self.validate_file::<InventoryPartsAll>(INVENTORY_PARTS_ALL_CATEGORIZED_NAME)?;
+        self.validate_file::<InventorySerialDbCategorizedParts>(
+            INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_NAME,
+        )?;
+        self.validate_file::<Vec<BalancePart>>(INVENTORY_BALANCE_PARTS_NAME)?;
+        self.validate_file::<Vec<InvDataPart>>(INVENTORY_INV_DATA_PARTS_NAME)?;
+        self.validate_file::<Vec<ManufacturerPart>>(INVENTORY_MANUFACTURER_PARTS_NAME)?;
+        self.validate_file::<Vec<LootlemonItemRaw>>(LOOTLEMON_ITEMS_NAME)?;
+
+        for name in GAME_DATA_FILES {
+            self.validate_game_data_file(name)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_game_data_file(&self, name: &str) -> Result<()> {
+        if let Some(data) = self.file(name) {
+            let records = try_load_game_data_kv(data)
+                .with_context(|| format!("{} doesn't match the expected schema", name))?;
+
+            if records.is_empty() {
+                bail!("{} is empty", name);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_file<T: serde::de::DeserializeOwned>(&self, name: &str) -> Result<()> {
+        if let Some(data) = self.file(name) {
+            try_load_compressed_data::<T>(data)
+                .with_context(|| format!("{} doesn't match the expected schema", name))?;
+        }
+
+        Ok(())
+    }
+}
+
+const DATA_PACK_FILES: [&str; 7] = [
+    INVENTORY_SERIAL_DB_NAME,
+    INVENTORY_PARTS_ALL_CATEGORIZED_NAME,
+    INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_NAME,
+    INVENTORY_BALANCE_PARTS_NAME,
+    INVENTORY_INV_DATA_PARTS_NAME,
+    INVENTORY_MANUFACTURER_PARTS_NAME,
+    LOOTLEMON_ITEMS_NAME,
+];
+
+/// The `game_data` tables a pack can replace, in the same CSV format they are generated from.
+const GAME_DATA_FILES: [&str; 16] = [
+    BALANCE_NAME_MAPPING_NAME,
+    BALANCE_TO_INV_KEY_NAME,
+    FAST_TRAVEL_NAME,
+    MISSION_NAME,
+    ECHO_LOGS_NAME,
+    PROFILE_ROOM_DECORATIONS_NAME,
+    PROFILE_WEAPON_SKINS_NAME,
+    PROFILE_WEAPON_TRINKETS_NAME,
+    PROFILE_ECHO_THEMES_NAME,
+    PROFILE_ECHO_THEMES_DEFAULTS_NAME,
+    PROFILE_EMOTES_NAME,
+    PROFILE_EMOTES_DEFAULTS_NAME,
+    PROFILE_HEADS_NAME,
+    PROFILE_HEADS_DEFAULTS_NAME,
+    PROFILE_SKINS_NAME,
+    PROFILE_SKINS_DEFAULTS_NAME,
+];
+
+/// Loads the data pack in `<config_dir>/data_pack` so the resources use it instead of the
+/// embedded copies, this has to happen before any of the resources are first used.
+///
+/// An invalid pack is returned as an error and the embedded copies are used instead.
+pub fn init_data_pack(config_dir: &Path) -> Result<Option<DataPackInfo>> {
+    let dir = config_dir.join(DATA_PACK_DIR_NAME);
+
+    let data_pack = match DataPack::load(&dir)? {
+        Some(data_pack) => data_pack,
+        None => {
+            info!("No data pack found in: {}", dir.display());
+
+            return Ok(None);
+        }
+    };
+
+    let embedded_max_version =
+        InventorySerialDb::from_compressed(INVENTORY_SERIAL_DB_JSON_COMPRESSED)
+            .context("failed to load embedded inventory serial db")?
+            .max_version;
+
+    if data_pack.manifest.max_version < embedded_max_version {
+        bail!(
+            "data pack {} has a max version of {} which is older than the embedded data ({})",
+            data_pack.manifest.name,
+            data_pack.manifest.max_version,
+            embedded_max_version
+        );
+    }
+
+    let info = DataPackInfo {
+        manifest: data_pack.manifest.clone(),
+        files: data_pack.file_names(),
+        embedded_max_version,
+    };
+
+    if DATA_PACK.set(data_pack).is_err() {
+        warn!(
+            "A data pack was already loaded, ignoring: {}",
+            dir.display()
+        );
+    } else {
+        info!(
+            "Loaded data pack {} (max version {}) with: {}",
+            info.manifest.name,
+            info.manifest.max_version,
+            info.files.join(", ")
+        );
+    }
+
+    Ok(Some(info))
+}
+
+pub(crate) fn data_pack_file(name: &str) -> Option<&'static [u8]> {
+    DATA_PACK.get().and_then(|d| d.file(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::resources::INVENTORY_BALANCE_PARTS_COMPRESSED;
+
+    use super::*;
+
+    fn write_data_pack(name: &str, max_version: usize) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bl3_save_edit_data_pack_{}", name));
+
+        let _ = fs::remove_dir_all(&dir);
+
+        fs::create_dir_all(&dir).expect("failed to create data pack dir");
+
+        let manifest = DataPackManifest {
+            name: name.to_owned(),
+            schema_version: DATA_PACK_SCHEMA_VERSION,
+            max_version,
+        };
+
+        fs::write(
+            dir.join(DATA_PACK_MANIFEST_NAME),
+            ron::ser::to_string(&manifest).expect("failed to serialize manifest"),
+        )
+        .expect("failed to write manifest");
+
+        fs::write(
+            dir.join(INVENTORY_SERIAL_DB_NAME),
+            INVENTORY_SERIAL_DB_JSON_COMPRESSED,
+        )
+        .expect("failed to write serial db");
+
+        fs::write(
+            dir.join(INVENTORY_BALANCE_PARTS_NAME),
+            INVENTORY_BALANCE_PARTS_COMPRESSED,
+        )
+        .expect("failed to write balance parts");
+
+        dir
+    }
+
+    #[test]
+    fn test_load_data_pack() {
+        let embedded_max_version =
+            InventorySerialDb::from_compressed(INVENTORY_SERIAL_DB_JSON_COMPRESSED)
+                .expect("failed to load embedded serial db")
+                .max_version;
+
+        let dir = write_data_pack("valid", embedded_max_version);
+
+        let data_pack = DataPack::load(&dir)
+            .expect("failed to load data pack")
+            .expect("data pack was missing");
+
+        assert_eq!(data_pack.manifest.max_version, embedded_max_version);
+        assert_eq!(
+            data_pack.file_names(),
+            vec![
+                INVENTORY_BALANCE_PARTS_NAME.to_owned(),
+                INVENTORY_SERIAL_DB_NAME.to_owned()
+            ]
+        );
+        assert!(data_pack.file(LOOTLEMON_ITEMS_NAME).is_none());
+
+        // The manifest has to match the serial db
+        let dir = write_data_pack("wrong_version", embedded_max_version + 1);
+
+        assert!(DataPack::load(&dir).is_err());
+
+        // Files have to match the schema of the resource they replace
+        let dir = write_data_pack("wrong_schema", embedded_max_version);
+
+        fs::write(
+            dir.join(LOOTLEMON_ITEMS_NAME),
+            INVENTORY_BALANCE_PARTS_COMPRESSED,
+        )
+        .expect("failed to write lootlemon items");
+
+        assert!(DataPack::load(&dir).is_err());
+
+        // The item tables from game_data can be replaced as well
+        let dir = write_data_pack("game_data", embedded_max_version);
+
+        fs::write(
+            dir.join(BALANCE_NAME_MAPPING_NAME),
+            "/game/gear/artifacts/_design/balancedefs/invbald_artifact_01_common,Artifact\n",
+        )
+        .expect("failed to write balance name mapping");
+
+        let data_pack = DataPack::load(&dir)
+            .expect("failed to load data pack")
+            .expect("data pack was missing");
+
+        let balance_name_mapping = try_load_game_data_kv(
+            data_pack
+                .file(BALANCE_NAME_MAPPING_NAME)
+                .expect("balance name mapping was missing"),
+        )
+        .expect("failed to read balance name mapping");
+
+        assert_eq!(balance_name_mapping.len(), 1);
+        assert_eq!(balance_name_mapping[0].name, "Artifact");
+
+        fs::write(dir.join(BALANCE_TO_INV_KEY_NAME), "missing_inv_key\n")
+            .expect("failed to write balance to inv key");
+
+        assert!(DataPack::load(&dir).is_err());
+
+        // So can the mission, fast travel, echo log and customization tables
+        let dir = write_data_pack("game_data_tables", embedded_max_version);
+
+        fs::write(
+            dir.join(MISSION_NAME),
+            "/game/missions/plot/mission_plot00.mission_plot00_c,Children of the Vault\n",
+        )
+        .expect("failed to write mission");
+
+        let data_pack = DataPack::load(&dir)
+            .expect("failed to load data pack")
+            .expect("data pack was missing");
+
+        assert!(data_pack.file_names().contains(&MISSION_NAME.to_owned()));
+
+        fs::write(dir.join(PROFILE_WEAPON_SKINS_NAME), "").expect("failed to write weapon skins");
+
+        assert!(DataPack::load(&dir).is_err());
+
+        // No pack falls back to the embedded data
+        assert!(DataPack::load(&dir.join("missing"))
+            .expect("failed to load data pack")
+            .is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use anyhow::Result;
 use once_cell::sync::Lazy;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rayon::prelude::ParallelSliceMut;
@@ -8,11 +9,40 @@ use serde::Deserialize;
 use tracing::info;
 
 use crate::bl3_item::{BalancePart, Bl3Item, InvDataPart, ManufacturerPart};
+use crate::game_data::GameDataKv;
 use crate::models::inventory_serial_db::InventorySerialDb;
+use crate::resources::data_pack::data_pack_file;
+
+pub mod data_pack;
 
 type InventoryPartsAll = HashMap<String, ResourceItem>;
 type InventorySerialDbCategorizedParts = HashMap<String, Vec<ResourceCategorizedParts>>;
 
+pub const INVENTORY_SERIAL_DB_NAME: &str = "INVENTORY_SERIAL_DB.json.sz";
+pub const INVENTORY_PARTS_ALL_CATEGORIZED_NAME: &str = "INVENTORY_PARTS_ALL_CATEGORIZED.ron.sz";
+pub const INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_NAME: &str =
+    "INVENTORY_SERIAL_DB_PARTS_CATEGORIZED.ron.sz";
+pub const INVENTORY_BALANCE_PARTS_NAME: &str = "INVENTORY_BALANCE_PARTS.ron.sz";
+pub const INVENTORY_INV_DATA_PARTS_NAME: &str = "INVENTORY_INV_DATA_PARTS.ron.sz";
+pub const INVENTORY_MANUFACTURER_PARTS_NAME: &str = "INVENTORY_MANUFACTURER_PARTS.ron.sz";
+pub const LOOTLEMON_ITEMS_NAME: &str = "LOOTLEMON_ITEMS.ron.sz";
+pub const BALANCE_NAME_MAPPING_NAME: &str = "BALANCE_NAME_MAPPING.csv";
+pub const BALANCE_TO_INV_KEY_NAME: &str = "BALANCE_TO_INV_KEY.csv";
+pub const FAST_TRAVEL_NAME: &str = "FAST_TRAVEL.csv";
+pub const MISSION_NAME: &str = "MISSION.csv";
+pub const ECHO_LOGS_NAME: &str = "ECHO_LOGS.csv";
+pub const PROFILE_ROOM_DECORATIONS_NAME: &str = "PROFILE_ROOM_DECORATIONS.csv";
+pub const PROFILE_WEAPON_SKINS_NAME: &str = "PROFILE_WEAPON_SKINS.csv";
+pub const PROFILE_WEAPON_TRINKETS_NAME: &str = "PROFILE_WEAPON_TRINKETS.csv";
+pub const PROFILE_ECHO_THEMES_NAME: &str = "PROFILE_ECHO_THEMES.csv";
+pub const PROFILE_ECHO_THEMES_DEFAULTS_NAME: &str = "PROFILE_ECHO_THEMES_DEFAULTS.csv";
+pub const PROFILE_EMOTES_NAME: &str = "PROFILE_EMOTES.csv";
+pub const PROFILE_EMOTES_DEFAULTS_NAME: &str = "PROFILE_EMOTES_DEFAULTS.csv";
+pub const PROFILE_HEADS_NAME: &str = "PROFILE_HEADS.csv";
+pub const PROFILE_HEADS_DEFAULTS_NAME: &str = "PROFILE_HEADS_DEFAULTS.csv";
+pub const PROFILE_SKINS_NAME: &str = "PROFILE_SKINS.csv";
+pub const PROFILE_SKINS_DEFAULTS_NAME: &str = "PROFILE_SKINS_DEFAULTS.csv";
+
 pub const INVENTORY_SERIAL_DB_JSON_COMPRESSED: &[u8] =
     include_bytes!("../../resources/INVENTORY_SERIAL_DB.json.sz");
 
@@ -22,7 +52,7 @@ const INVENTORY_PARTS_ALL_CATEGORIZED_RON_COMPRESSED: &[u8] =
 const INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_RON_COMPRESSED: &[u8] =
     include_bytes!("../../resources/INVENTORY_SERIAL_DB_PARTS_CATEGORIZED.ron.sz");
 
-const INVENTORY_BALANCE_PARTS_COMPRESSED: &[u8] =
+pub(crate) const INVENTORY_BALANCE_PARTS_COMPRESSED: &[u8] =
     include_bytes!("../../resources/INVENTORY_BALANCE_PARTS.ron.sz");
 
 const INVENTORY_INV_DATA_COMPRESSED: &[u8] =
@@ -36,23 +66,129 @@ const LOOTLEMON_ITEMS_COMPRESSED: &[u8] = include_bytes!("../../resources/LOOTLE
 pub static INVENTORY_SERIAL_DB: Lazy<InventorySerialDb> =
     Lazy::new(|| InventorySerialDb::load().expect("failed to load inventory serial db"));
 
-pub static INVENTORY_PARTS_ALL_CATEGORIZED: Lazy<InventoryPartsAll> =
-    Lazy::new(|| load_compressed_data(INVENTORY_PARTS_ALL_CATEGORIZED_RON_COMPRESSED));
+pub static INVENTORY_PARTS_ALL_CATEGORIZED: Lazy<InventoryPartsAll> = Lazy::new(|| {
+    load_resource(
+        INVENTORY_PARTS_ALL_CATEGORIZED_NAME,
+        INVENTORY_PARTS_ALL_CATEGORIZED_RON_COMPRESSED,
+    )
+});
 
 pub static INVENTORY_SERIAL_DB_PARTS_CATEGORIZED: Lazy<InventorySerialDbCategorizedParts> =
-    Lazy::new(|| load_compressed_data(INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_RON_COMPRESSED));
+    Lazy::new(|| {
+        load_resource(
+            INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_NAME,
+            INVENTORY_SERIAL_DB_PARTS_CATEGORIZED_RON_COMPRESSED,
+        )
+    });
 
-pub static INVENTORY_BALANCE_PARTS: Lazy<Vec<BalancePart>> =
-    Lazy::new(|| load_compressed_data(INVENTORY_BALANCE_PARTS_COMPRESSED));
+pub static INVENTORY_BALANCE_PARTS: Lazy<Vec<BalancePart>> = Lazy::new(|| {
+    load_resource(
+        INVENTORY_BALANCE_PARTS_NAME,
+        INVENTORY_BALANCE_PARTS_COMPRESSED,
+    )
+});
 
 pub static INVENTORY_INV_DATA_PARTS: Lazy<Vec<InvDataPart>> =
-    Lazy::new(|| load_compressed_data(INVENTORY_INV_DATA_COMPRESSED));
+    Lazy::new(|| load_resource(INVENTORY_INV_DATA_PARTS_NAME, INVENTORY_INV_DATA_COMPRESSED));
+
+pub static INVENTORY_MANUFACTURER_PARTS: Lazy<Vec<ManufacturerPart>> = Lazy::new(|| {
+    load_resource(
+        INVENTORY_MANUFACTURER_PARTS_NAME,
+        INVENTORY_MANUFACTURER_PARTS_COMPRESSED,
+    )
+});
+
+pub static BALANCE_NAME_MAPPING: Lazy<Vec<ResourceKv>> = Lazy::new(|| {
+    load_game_data_kv(
+        BALANCE_NAME_MAPPING_NAME,
+        &crate::game_data::BALANCE_NAME_MAPPING,
+    )
+});
+
+pub static BALANCE_TO_INV_KEY: Lazy<Vec<ResourceKv>> = Lazy::new(|| {
+    load_game_data_kv(
+        BALANCE_TO_INV_KEY_NAME,
+        &crate::game_data::BALANCE_TO_INV_KEY,
+    )
+});
+
+pub static FAST_TRAVEL: Lazy<Vec<GameDataKv>> =
+    Lazy::new(|| load_game_data_table(FAST_TRAVEL_NAME, &crate::game_data::FAST_TRAVEL));
+
+pub static MISSION: Lazy<Vec<GameDataKv>> =
+    Lazy::new(|| load_game_data_table(MISSION_NAME, &crate::game_data::MISSION));
+
+pub static ECHO_LOGS: Lazy<Vec<GameDataKv>> =
+    Lazy::new(|| load_game_data_table(ECHO_LOGS_NAME, &crate::game_data::ECHO_LOGS));
 
-pub static INVENTORY_MANUFACTURER_PARTS: Lazy<Vec<ManufacturerPart>> =
-    Lazy::new(|| load_compressed_data(INVENTORY_MANUFACTURER_PARTS_COMPRESSED));
+pub static PROFILE_ROOM_DECORATIONS: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_ROOM_DECORATIONS_NAME,
+        &crate::game_data::PROFILE_ROOM_DECORATIONS,
+    )
+});
+
+pub static PROFILE_WEAPON_SKINS: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_WEAPON_SKINS_NAME,
+        &crate::game_data::PROFILE_WEAPON_SKINS,
+    )
+});
+
+pub static PROFILE_WEAPON_TRINKETS: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_WEAPON_TRINKETS_NAME,
+        &crate::game_data::PROFILE_WEAPON_TRINKETS,
+    )
+});
+
+pub static PROFILE_ECHO_THEMES: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_ECHO_THEMES_NAME,
+        &crate::game_data::PROFILE_ECHO_THEMES,
+    )
+});
+
+pub static PROFILE_ECHO_THEMES_DEFAULTS: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_ECHO_THEMES_DEFAULTS_NAME,
+        &crate::game_data::PROFILE_ECHO_THEMES_DEFAULTS,
+    )
+});
+
+pub static PROFILE_EMOTES: Lazy<Vec<GameDataKv>> =
+    Lazy::new(|| load_game_data_table(PROFILE_EMOTES_NAME, &crate::game_data::PROFILE_EMOTES));
+
+pub static PROFILE_EMOTES_DEFAULTS: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_EMOTES_DEFAULTS_NAME,
+        &crate::game_data::PROFILE_EMOTES_DEFAULTS,
+    )
+});
+
+pub static PROFILE_HEADS: Lazy<Vec<GameDataKv>> =
+    Lazy::new(|| load_game_data_table(PROFILE_HEADS_NAME, &crate::game_data::PROFILE_HEADS));
+
+pub static PROFILE_HEADS_DEFAULTS: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_HEADS_DEFAULTS_NAME,
+        &crate::game_data::PROFILE_HEADS_DEFAULTS,
+    )
+});
+
+pub static PROFILE_SKINS: Lazy<Vec<GameDataKv>> =
+    Lazy::new(|| load_game_data_table(PROFILE_SKINS_NAME, &crate::game_data::PROFILE_SKINS));
+
+pub static PROFILE_SKINS_DEFAULTS: Lazy<Vec<GameDataKv>> = Lazy::new(|| {
+    load_game_data_table(
+        PROFILE_SKINS_DEFAULTS_NAME,
+        &crate::game_data::PROFILE_SKINS_DEFAULTS,
+    )
+});
 
 pub static LOOTLEMON_ITEMS: Lazy<Vec<LootlemonItem>> = Lazy::new(|| {
-    let items = load_compressed_data::<Vec<LootlemonItemRaw>>(LOOTLEMON_ITEMS_COMPRESSED);
+    let items =
+        load_resource::<Vec<LootlemonItemRaw>>(LOOTLEMON_ITEMS_NAME, LOOTLEMON_ITEMS_COMPRESSED);
 
     let start_time = std::time::Instant::now();
 
@@ -77,10 +213,65 @@ pub static LOOTLEMON_ITEMS: Lazy<Vec<LootlemonItem>> = Lazy::new(|| {
     lootlemon_items
 });
 
-pub fn load_compressed_data<T: DeserializeOwned>(input: &'static [u8]) -> T {
+pub fn load_compressed_data<T: DeserializeOwned>(input: &[u8]) -> T {
+    try_load_compressed_data(input).expect("failed to read compressed data")
+}
+
+pub fn try_load_compressed_data<T: DeserializeOwned>(input: &[u8]) -> Result<T> {
     let mut rdr = snap::read::FrameDecoder::new(input);
 
-    ron::de::from_reader(&mut rdr).expect("failed to read compressed data")
+    Ok(ron::de::from_reader(&mut rdr)?)
+}
+
+/// Reads a `game_data` table in the same format as the CSV files it is generated from.
+pub fn try_load_game_data_kv(input: &[u8]) -> Result<Vec<ResourceKv>> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(input);
+
+    Ok(rdr.deserialize().collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Loads a resource from the data pack when it has one, otherwise from the embedded copy.
+fn load_resource<T: DeserializeOwned>(name: &str, embedded: &'static [u8]) -> T {
+    load_compressed_data(data_pack_file(name).unwrap_or(embedded))
+}
+
+/// Loads a `game_data` table from the data pack when it has one, otherwise from the table
+/// compiled into the editor.
+fn load_game_data_kv(name: &str, embedded: &[GameDataKv]) -> Vec<ResourceKv> {
+    match data_pack_file(name) {
+        Some(data) => try_load_game_data_kv(data).expect("failed to read game data"),
+        None => embedded
+            .iter()
+            .map(|gd| ResourceKv {
+                ident: gd.ident.to_owned(),
+                name: gd.name.to_owned(),
+            })
+            .collect(),
+    }
+}
+
+/// Loads a `game_data` table which is used as `GameDataKv`, a table from the data pack is read
+/// once and kept for the rest of the program so its strings can be borrowed like the embedded ones.
+fn load_game_data_table(name: &str, embedded: &'static [GameDataKv]) -> Vec<GameDataKv> {
+    if data_pack_file(name).is_none() {
+        return embedded.to_vec();
+    }
+
+    load_game_data_kv(name, embedded)
+        .into_iter()
+        .map(|kv| GameDataKv {
+            ident: Box::leak(kv.ident.into_boxed_str()),
+            name: Box::leak(kv.name.into_boxed_str()),
+        })
+        .collect()
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize)]
+pub struct ResourceKv {
+    pub ident: String,
+    pub name: String,
 }
 
 #[derive(Debug, Default, Clone, Deserialize)]
@@ -0,0 +1,384 @@
+use anyhow::{bail, Result};
+use strum::Display;
+
+use crate::bl3_item::Bl3Item;
+use crate::file_helper::Bl3FileType;
+
+#[derive(Debug, Copy, Clone, Display, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ItemContainer {
+    #[strum(to_string = "背包")]
+    Inventory,
+    #[strum(to_string = "银行")]
+    Bank,
+    #[strum(to_string = "遗失物品")]
+    LostLoot,
+}
+
+impl ItemContainer {
+    /// Weapon skins and customization parts are only kept by the inventory.
+    pub fn keeps_cosmetics(self) -> bool {
+        self == Self::Inventory
+    }
+
+    /// The containers a file can hold items in, saves only have an inventory.
+    pub fn for_file(file: &Bl3FileType) -> Vec<Self> {
+        match file {
+            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_) => vec![Self::Inventory],
+            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_) => {
+                vec![Self::Bank, Self::LostLoot]
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Display, Eq, PartialEq)]
+pub enum TransferMode {
+    #[strum(to_string = "移动")]
+    Move,
+    #[strum(to_string = "复制")]
+    Copy,
+}
+
+pub fn container_items(file: &Bl3FileType, container: ItemContainer) -> Result<&Vec<Bl3Item>> {
+    let items = match (file, container) {
+        (Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s), ItemContainer::Inventory) => {
+            s.character_data.inventory_items()
+        }
+        (Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p), ItemContainer::Bank) => {
+            p.profile_data.bank_items()
+        }
+        (Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p), ItemContainer::LostLoot) => {
+            p.profile_data.lost_loot_items()
+        }
+        _ => bail!(
+            "{} does not have a {} container",
+            file.filename(),
+            container
+        ),
+    };
+
+    Ok(items)
+}
+
+pub fn add_items(
+    file: &mut Bl3FileType,
+    container: ItemContainer,
+    items: &[Bl3Item],
+) -> Result<()> {
+    // Check the container exists before adding anything so a file is never partially modified
+    container_items(file, container)?;
+
+    for item in items {
+        match (&mut *file, container) {
+            (Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s), ItemContainer::Inventory) => {
+                let pickup_order_index = s.character_data.inventory_items().len() as i32;
+
+                s.character_data
+                    .add_inventory_item(pickup_order_index, item)?;
+            }
+            (Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p), ItemContainer::Bank) => {
+                p.profile_data.add_bank_item(item)?;
+            }
+            (Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p), ItemContainer::LostLoot) => {
+                p.profile_data.add_lost_loot_item(item)?;
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+pub fn remove_items(
+    file: &mut Bl3FileType,
+    container: ItemContainer,
+    indexes: &[usize],
+) -> Result<()> {
+    container_items(file, container)?;
+
+    let mut indexes = indexes.to_vec();
+
+    // Remove from the back so earlier indexes stay valid
+    indexes.sort_unstable();
+    indexes.dedup();
+
+    for index in indexes.into_iter().rev() {
+        match (&mut *file, container) {
+            (Bl3FileType::PcSave(s) | Bl3FileType::Ps4Save(s), ItemContainer::Inventory) => {
+                s.character_data.remove_inventory_item(index);
+            }
+            (Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p), ItemContainer::Bank) => {
+                p.profile_data.remove_bank_item(index);
+            }
+            (Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p), ItemContainer::LostLoot) => {
+                p.profile_data.remove_lost_loot_item(index);
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+fn selected_items(
+    file: &Bl3FileType,
+    container: ItemContainer,
+    indexes: &[usize],
+) -> Result<Vec<Bl3Item>> {
+    let items = container_items(file, container)?;
+
+    indexes
+        .iter()
+        .map(|i| match items.get(*i) {
+            Some(item) => Ok(item.clone()),
+            None => bail!("failed to find item {} in {}", i, file.filename()),
+        })
+        .collect()
+}
+
+/// The number of items at `indexes` which would lose their weapon skin or customization parts
+/// when they are added to the target container.
+pub fn items_losing_cosmetics(
+    file: &Bl3FileType,
+    source_container: ItemContainer,
+    target_container: ItemContainer,
+    indexes: &[usize],
+) -> Result<usize> {
+    if target_container.keeps_cosmetics() {
+        return Ok(0);
+    }
+
+    let items = selected_items(file, source_container, indexes)?;
+
+    Ok(items.iter().filter(|i| i.has_cosmetics()).count())
+}
+
+/// Adds the items at `indexes` from the source container to the target container,
+/// moving removes them from the source afterwards.
+pub fn transfer_items(
+    source: &mut Bl3FileType,
+    source_container: ItemContainer,
+    target: &mut Bl3FileType,
+    target_container: ItemContainer,
+    indexes: &[usize],
+    mode: TransferMode,
+) -> Result<()> {
+    let items = selected_items(source, source_container, indexes)?;
+
+    add_items(target, target_container, &items)?;
+
+    if mode == TransferMode::Move {
+        remove_items(source, source_container, indexes)?;
+    }
+
+    Ok(())
+}
+
+/// Same as `transfer_items` for moving between two containers of the same file, such as bank and lost loot.
+pub fn transfer_items_within_file(
+    file: &mut Bl3FileType,
+    source_container: ItemContainer,
+    target_container: ItemContainer,
+    indexes: &[usize],
+    mode: TransferMode,
+) -> Result<()> {
+    if source_container == target_container {
+        bail!("cannot transfer items to the container they are already in");
+    }
+
+    let items = selected_items(file, source_container, indexes)?;
+
+    add_items(file, target_container, &items)?;
+
+    if mode == TransferMode::Move {
+        remove_items(file, source_container, indexes)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    fn read_file(file_name: &str) -> Bl3FileType {
+        let filename = Path::new(file_name);
+
+        let file_data = fs::read(&filename).expect("failed to read test_file");
+
+        Bl3FileType::from_unknown_data(filename, &file_data).expect("failed to read test file")
+    }
+
+    #[test]
+    fn test_transfer_save_to_profile() {
+        let mut save = read_file("./test_files/1.sav");
+        let mut profile = read_file("./test_files/1prof.sav");
+
+        let inventory_items = container_items(&save, ItemContainer::Inventory)
+            .expect("failed to read inventory")
+            .clone();
+        let bank_len = container_items(&profile, ItemContainer::Bank)
+            .expect("failed to read bank")
+            .len();
+
+        transfer_items(
+            &mut save,
+            ItemContainer::Inventory,
+            &mut profile,
+            ItemContainer::Bank,
+            &[2, 0],
+            TransferMode::Move,
+        )
+        .expect("failed to move items");
+
+        let bank_items = container_items(&profile, ItemContainer::Bank).unwrap();
+
+        assert_eq!(bank_items.len(), bank_len + 2);
+        assert_eq!(bank_items[bank_len], inventory_items[2]);
+        assert_eq!(bank_items[bank_len + 1], inventory_items[0]);
+
+        let remaining_items = container_items(&save, ItemContainer::Inventory).unwrap();
+
+        assert_eq!(remaining_items.len(), inventory_items.len() - 2);
+        assert_eq!(remaining_items[0], inventory_items[1]);
+
+        transfer_items(
+            &mut profile,
+            ItemContainer::Bank,
+            &mut save,
+            ItemContainer::Inventory,
+            &[bank_len],
+            TransferMode::Copy,
+        )
+        .expect("failed to copy items");
+
+        assert_eq!(
+            container_items(&profile, ItemContainer::Bank)
+                .unwrap()
+                .len(),
+            bank_len + 2
+        );
+        assert_eq!(
+            container_items(&save, ItemContainer::Inventory)
+                .unwrap()
+                .last(),
+            Some(&inventory_items[2])
+        );
+
+        assert!(transfer_items(
+            &mut save,
+            ItemContainer::Bank,
+            &mut profile,
+            ItemContainer::Bank,
+            &[0],
+            TransferMode::Move,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_transfer_within_profile() {
+        let mut profile = read_file("./test_files/1prof.sav");
+
+        let lost_loot_items = container_items(&profile, ItemContainer::LostLoot)
+            .expect("failed to read lost loot")
+            .clone();
+        let bank_len = container_items(&profile, ItemContainer::Bank)
+            .expect("failed to read bank")
+            .len();
+
+        transfer_items_within_file(
+            &mut profile,
+            ItemContainer::LostLoot,
+            ItemContainer::Bank,
+            &[0],
+            TransferMode::Move,
+        )
+        .expect("failed to move items");
+
+        assert_eq!(
+            container_items(&profile, ItemContainer::Bank)
+                .unwrap()
+                .last(),
+            Some(&lost_loot_items[0])
+        );
+        assert_eq!(
+            container_items(&profile, ItemContainer::Bank)
+                .unwrap()
+                .len(),
+            bank_len + 1
+        );
+        assert_eq!(
+            container_items(&profile, ItemContainer::LostLoot)
+                .unwrap()
+                .len(),
+            lost_loot_items.len() - 1
+        );
+    }
+
+    #[test]
+    fn test_transfer_drops_cosmetics_in_bank() {
+        let mut save = read_file("./test_files/19.sav");
+        let mut profile = read_file("./test_files/profile.sav");
+
+        let inventory_items = container_items(&save, ItemContainer::Inventory)
+            .expect("failed to read inventory")
+            .clone();
+
+        assert!(!inventory_items[0].has_cosmetics());
+        assert!(inventory_items[21].has_cosmetics());
+
+        assert_eq!(
+            items_losing_cosmetics(
+                &save,
+                ItemContainer::Inventory,
+                ItemContainer::Bank,
+                &[0, 21]
+            )
+            .expect("failed to check items"),
+            1
+        );
+        assert_eq!(
+            items_losing_cosmetics(
+                &save,
+                ItemContainer::Inventory,
+                ItemContainer::Inventory,
+                &[0, 21]
+            )
+            .expect("failed to check items"),
+            0
+        );
+
+        transfer_items(
+            &mut save,
+            ItemContainer::Inventory,
+            &mut profile,
+            ItemContainer::Bank,
+            &[21],
+            TransferMode::Copy,
+        )
+        .expect("failed to copy items");
+
+        let profile = match profile {
+            Bl3FileType::PcProfile(p) | Bl3FileType::Ps4Profile(p) => p,
+            _ => panic!("expected a profile"),
+        };
+
+        let (_, new_profile) = profile.as_bytes().expect("failed to write profile");
+
+        let bank_item = new_profile
+            .profile_data
+            .bank_items()
+            .last()
+            .expect("failed to find bank item");
+
+        assert!(!bank_item.has_cosmetics());
+        assert_eq!(
+            bank_item.get_serial_number(true).unwrap(),
+            inventory_items[21].get_serial_number(true).unwrap()
+        );
+    }
+}
@@ -1,27 +1,40 @@
 use std::mem;
 use std::path::PathBuf;
 
+use anyhow::anyhow;
+use chrono::Utc;
 use iced::alignment::Horizontal;
 use iced::{
     button, pick_list, svg, tooltip, Alignment, Application, Button, Color, Column, Command,
-    Container, Element, Length, PickList, Row, Svg, Text, Tooltip,
+    Container, Element, Length, PickList, Row, Subscription, Svg, Text, Tooltip,
 };
 use tracing::{error, info};
 
+use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::bl3_profile::game_settings::GameSettingValue;
+use bl3_save_edit_core::bl3_profile::mail::NewMailData;
 use bl3_save_edit_core::bl3_profile::sdu::ProfileSduSlot;
+use bl3_save_edit_core::bl3_profile::vault_card::VaultCardPreviousChallengeData;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::ability_tree::available_skill_points;
 use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
+use bl3_save_edit_core::bl3_save::player_color::PlayerColorPreset;
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
 use bl3_save_edit_core::bl3_save::util::{experience_to_level, REQUIRED_XP_LIST};
 use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::diff;
 use bl3_save_edit_core::file_helper::Bl3FileType;
 use bl3_save_edit_core::parser::HeaderType;
+use bl3_save_edit_core::resources::INVENTORY_SERIAL_DB;
+use bl3_save_edit_core::transfer;
+use bl3_save_edit_core::transfer::TransferMode;
 
 use crate::bl3_ui_style::{
     Bl3UiContentStyle, Bl3UiMenuBarStyle, Bl3UiPositiveButtonStyle, Bl3UiStyle, Bl3UiTooltipStyle,
 };
+use crate::commands::interaction::choose_save_directory::SaveDirLocation;
 use crate::commands::{initialization, interaction};
-use crate::config::{Bl3Config, ConfigMessage};
+use crate::config::{BackupRetention, Bl3Config, ConfigMessage, PlayerColorPresets};
 use crate::resources::fonts::{
     ST_HEI_TI_LIGHT,
 };
@@ -29,32 +42,47 @@ use crate::resources::svgs::REFRESH;
 use crate::state_mappers::{manage_profile, manage_save};
 use crate::update::Release;
 use crate::util::ErrorExt;
+use crate::views::backups::{BackupRow, BackupsInteractionMessage, BackupsState};
 use crate::views::choose_save_directory::{
     ChooseSaveDirectoryState, ChooseSaveInteractionMessage, ChooseSaveMessage,
 };
+use crate::views::diff::{DiffInteractionMessage, DiffState};
+use crate::views::file_changed::{FileChangedInteractionMessage, FileChangedState};
 use crate::views::initialization::InitializationMessage;
 use crate::views::item_editor::ItemEditorFileType;
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
+use crate::views::manage_profile::game_settings::ProfileGameSettingsInteractionMessage;
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
+use crate::views::manage_profile::lost_loot::ProfileLostLootInteractionMessage;
+use crate::views::manage_profile::mail::ProfileMailInteractionMessage;
 use crate::views::manage_profile::main::{ProfileTabBarInteractionMessage, ProfileTabBarView};
 use crate::views::manage_profile::profile::{
     GuardianRewardMessage, ProfileInteractionMessage, SduMessage, SkinUnlockedMessage,
 };
+use crate::views::manage_profile::vault_card::{
+    ProfileVaultCardInteractionMessage, VaultCardChallengeField,
+};
 use crate::views::manage_profile::{
     ManageProfileInteractionMessage, ManageProfileState, ManageProfileView,
 };
+use crate::views::manage_save::character::colors::PlayerColorField;
 use crate::views::manage_save::character::{
-    CharacterAmmoMessage, CharacterGearUnlockedMessage, CharacterSduMessage,
-    CharacterSkinSelectedMessage, SaveCharacterInteractionMessage,
+    CharacterAmmoMessage, CharacterColorMessage, CharacterGearUnlockedMessage, CharacterSduMessage,
+    CharacterSkillMessage, CharacterSkinSelectedMessage, SaveCharacterInteractionMessage,
 };
 use crate::views::manage_save::currency::SaveCurrencyInteractionMessage;
+use crate::views::manage_save::crew_quarters::SaveCrewQuartersInteractionMessage;
+use crate::views::manage_save::echo_logs::SaveEchoLogsInteractionMessage;
+use crate::views::manage_save::fast_travel::SaveFastTravelInteractionMessage;
 use crate::views::manage_save::general::SaveGeneralInteractionMessage;
 use crate::views::manage_save::inventory::SaveInventoryInteractionMessage;
 use crate::views::manage_save::main::{SaveTabBarInteractionMessage, SaveTabBarView};
+use crate::views::manage_save::missions::SaveMissionsInteractionMessage;
 use crate::views::manage_save::vehicle::{SaveVehicleInteractionMessage, VehicleUnlockedMessage};
 use crate::views::manage_save::{ManageSaveInteractionMessage, ManageSaveState, ManageSaveView};
 use crate::views::settings::{SettingsInteractionMessage, SettingsState};
+use crate::views::transfer::{TransferInteractionMessage, TransferState};
 use crate::views::InteractionExt;
 use crate::widgets::notification::{Notification, NotificationSentiment};
 use crate::{state_mappers, update, views, VERSION};
@@ -68,7 +96,7 @@ pub struct Bl3Application {
     pub manage_profile_state: ManageProfileState,
     loaded_files_selector: pick_list::State<Bl3FileType>,
     pub loaded_files_selected: Box<Bl3FileType>,
-    loaded_files: Vec<Bl3FileType>,
+    pub loaded_files: Vec<Bl3FileType>,
     refresh_button_state: button::State,
     update_button_state: button::State,
     save_file_button_state: button::State,
@@ -77,6 +105,11 @@ pub struct Bl3Application {
     is_updating: bool,
     is_reloading_saves: bool,
     settings_state: SettingsState,
+    pub diff_state: DiffState,
+    backups_state: BackupsState,
+    transfer_state: TransferState,
+    discovered_save_dirs: Vec<SaveDirLocation>,
+    file_changed_state: FileChangedState,
 }
 
 #[derive(Debug, Clone)]
@@ -91,6 +124,8 @@ pub enum Bl3Message {
     SaveFileCompleted(MessageResult<Bl3Save>),
     SaveProfileCompleted(MessageResult<Bl3Profile>),
     FilesLoadedAfterSave(MessageResult<(Bl3FileType, Vec<Bl3FileType>)>),
+    WatchFiles,
+    WatchFilesCompleted(MessageResult<Vec<String>>),
     ClearNotification,
 }
 
@@ -127,6 +162,10 @@ pub enum InteractionMessage {
     ManageSaveInteraction(ManageSaveInteractionMessage),
     ManageProfileInteraction(ManageProfileInteractionMessage),
     SettingsInteraction(SettingsInteractionMessage),
+    DiffInteraction(DiffInteractionMessage),
+    BackupsInteraction(BackupsInteractionMessage),
+    TransferInteraction(TransferInteractionMessage),
+    FileChangedInteraction(FileChangedInteractionMessage),
     LoadedFileSelected(Box<Bl3FileType>),
     RefreshSavesDirectory,
     Ignore,
@@ -147,6 +186,121 @@ impl std::default::Default for ViewState {
     }
 }
 
+impl Bl3Application {
+    fn load_backups(&mut self) -> Command<Bl3Message> {
+        self.backups_state.is_loading = true;
+
+        Command::perform(
+            interaction::backups::load_backups(self.config.backup_dir().to_path_buf()),
+            |r| {
+                Bl3Message::Interaction(InteractionMessage::BackupsInteraction(
+                    BackupsInteractionMessage::RefreshCompleted(MessageResult::handle_result(r)),
+                ))
+            },
+        )
+    }
+
+    fn update_save_dirs(&mut self) {
+        let save_dirs = interaction::choose_save_directory::with_recent_save_dirs(
+            self.discovered_save_dirs.clone(),
+            self.config.recent_saves_dirs(),
+        );
+
+        self.choose_save_directory_state.save_dirs = save_dirs.clone();
+        self.settings_state.save_dirs = save_dirs;
+    }
+
+    fn loaded_file_hashes(&self) -> Vec<(String, u32)> {
+        self.loaded_files
+            .iter()
+            .map(|f| (f.filename().to_owned(), f.file_hash()))
+            .collect()
+    }
+
+    fn current_file_name(&self) -> Option<String> {
+        match self.view_state {
+            ViewState::ManageSave(_) => Some(self.manage_save_state.current_file.file_name.clone()),
+            ViewState::ManageProfile(_) => {
+                Some(self.manage_profile_state.current_file.file_name.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the editor has changes to `file_name` which haven't been written yet, the editor is
+    /// applied to a copy of the file the same way saving does and compared with the loaded file.
+    fn has_unsaved_changes(&mut self, file_name: &str) -> anyhow::Result<bool> {
+        if self.current_file_name().as_deref() != Some(file_name) {
+            return Ok(false);
+        }
+
+        match self.view_state {
+            ViewState::ManageSave(_) => {
+                let mut current_file = self.manage_save_state.current_file.clone();
+
+                manage_save::map_all_states_to_save(
+                    &mut self.manage_save_state,
+                    &mut current_file,
+                )?;
+
+                Ok(current_file != self.manage_save_state.current_file)
+            }
+            ViewState::ManageProfile(_) => {
+                let mut current_file = self.manage_profile_state.current_file.clone();
+
+                let guardian_data_injection_required = manage_profile::map_all_states_to_profile(
+                    &mut self.manage_profile_state,
+                    &mut current_file,
+                )?;
+
+                Ok(guardian_data_injection_required
+                    || current_file != self.manage_profile_state.current_file)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Stops a save from overwriting a file which was written outside the editor since it was
+    /// loaded, the user has to reload, merge or confirm the overwrite first.
+    fn file_changed_before_save(&mut self, file_name: String) {
+        let msg = format!(
+            "Failed to save file: {} was changed outside the editor, reload, merge or overwrite it before saving.",
+            file_name
+        );
+
+        error!("{}", msg);
+
+        self.notification = Some(Notification::new(msg, NotificationSentiment::Negative));
+
+        if !self.file_changed_state.changed_files.contains(&file_name) {
+            self.file_changed_state.changed_files.push(file_name);
+        }
+    }
+
+    fn replace_loaded_file(&mut self, file: Bl3FileType) {
+        self.file_changed_state
+            .changed_files
+            .retain(|f| f != file.filename());
+
+        if let Some(loaded_file) = self
+            .loaded_files
+            .iter_mut()
+            .find(|f| f.filename() == file.filename())
+        {
+            *loaded_file = file.clone();
+        }
+
+        self.loaded_files.sort();
+
+        self.loaded_files_selected = Box::new(file);
+
+        state_mappers::transfer::map_files_to_transfer_state(
+            &mut self.transfer_state,
+            &self.loaded_files,
+        );
+    }
+}
+
 impl Application for Bl3Application {
     type Executor = tokio::runtime::Runtime;
     type Message = Bl3Message;
@@ -154,18 +308,28 @@ impl Application for Bl3Application {
 
     fn new(config: Self::Flags) -> (Self, Command<Self::Message>) {
         let startup_commands = [
-            Command::perform(initialization::load_lazy_data(), |_| {
-                Bl3Message::Initialization(InitializationMessage::LoadSaves)
-            }),
+            Command::perform(
+                initialization::load_lazy_data(config.config_dir().to_path_buf()),
+                |r| {
+                    Bl3Message::Initialization(InitializationMessage::LazyDataLoaded(
+                        MessageResult::handle_result(r),
+                    ))
+                },
+            ),
             Command::perform(update::get_latest_release(), |r| {
                 Bl3Message::LatestRelease(MessageResult::handle_result(r))
             }),
+            Command::perform(
+                interaction::choose_save_directory::discover_save_dirs(),
+                |r| Bl3Message::ChooseSave(ChooseSaveMessage::SaveDirsDiscovered(r)),
+            ),
         ];
 
         let config_dir_input = config.config_dir().to_string_lossy().to_string();
         let saves_dir_input = config.saves_dir().to_string_lossy().to_string();
         let backup_dir_input = config.backup_dir().to_string_lossy().to_string();
         let ui_scale_factor = config.ui_scale_factor();
+        let backup_retention = config.backup_retention();
 
         (
             Bl3Application {
@@ -178,6 +342,11 @@ impl Application for Bl3Application {
                     ui_scale_factor,
                     ..SettingsState::default()
                 },
+                backups_state: BackupsState {
+                    keep_per_character_input: backup_retention.keep_per_character,
+                    max_age_days_input: backup_retention.max_age_days,
+                    ..BackupsState::default()
+                },
                 ..Bl3Application::default()
             },
             Command::batch(startup_commands),
@@ -191,10 +360,29 @@ impl Application for Bl3Application {
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
         match message {
             Bl3Message::Initialization(initialization_msg) => match initialization_msg {
-                InitializationMessage::LoadSaves => {
+                InitializationMessage::LazyDataLoaded(res) => {
+                    self.settings_state.embedded_max_version = INVENTORY_SERIAL_DB.max_version;
+
+                    match res {
+                        MessageResult::Success(Some(data_pack)) => {
+                            self.settings_state.embedded_max_version =
+                                data_pack.embedded_max_version;
+                            self.settings_state.data_pack = Some(data_pack);
+                        }
+                        MessageResult::Success(None) => (),
+                        MessageResult::Error(e) => {
+                            let msg = format!("加载数据包失败, 使用内置数据: {}", e);
+
+                            error!("{}", msg);
+
+                            self.notification =
+                                Some(Notification::new(msg, NotificationSentiment::Negative));
+                        }
+                    }
+
                     if self.config.saves_dir().exists() {
                         return Command::perform(
-                            interaction::choose_save_directory::load_files_in_directory(
+                            interaction::choose_save_directory::load_files_in_directory_with_report(
                                 self.config.saves_dir().to_path_buf(),
                             ),
                             |r| {
@@ -270,6 +458,28 @@ impl Application for Bl3Application {
                 match interaction_msg {
                     InteractionMessage::ChooseSaveInteraction(choose_save_msg) => {
                         return match choose_save_msg {
+                            ChooseSaveInteractionMessage::ContinuePressed => {
+                                state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+                                    "Failed to map loaded file to editor",
+                                    &mut self.notification,
+                                );
+
+                                Command::none()
+                            }
+                            ChooseSaveInteractionMessage::SaveDirSelected(save_dir) => {
+                                self.view_state = ViewState::Loading;
+
+                                Command::perform(
+                                    interaction::choose_save_directory::load_files_in_directory_with_report(
+                                        save_dir.path,
+                                    ),
+                                    |r| {
+                                        Bl3Message::ChooseSave(ChooseSaveMessage::FilesLoaded(
+                                            MessageResult::handle_result(r),
+                                        ))
+                                    },
+                                )
+                            }
                             ChooseSaveInteractionMessage::ChooseDirPressed => {
                                 self.choose_save_directory_state.choose_dir_window_open = true;
 
@@ -302,6 +512,11 @@ impl Application for Bl3Application {
                                             ManageSaveView::TabBar(SaveTabBarView::角色),
                                         )
                                     }
+                                    SaveTabBarInteractionMessage::技能 => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::技能),
+                                        )
+                                    }
                                     SaveTabBarInteractionMessage::背包 => {
                                         self.view_state = ViewState::ManageSave(
                                             ManageSaveView::TabBar(SaveTabBarView::背包),
@@ -317,6 +532,48 @@ impl Application for Bl3Application {
                                             ManageSaveView::TabBar(SaveTabBarView::车辆),
                                         )
                                     }
+                                    SaveTabBarInteractionMessage::任务 => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::任务),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::传送 => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::传送),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::日志 => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::日志),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::宿舍 => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::宿舍),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::转移 => {
+                                        state_mappers::transfer::map_files_to_transfer_state(
+                                            &mut self.transfer_state,
+                                            &self.loaded_files,
+                                        );
+
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::转移),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::对比 => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::对比),
+                                        )
+                                    }
+                                    SaveTabBarInteractionMessage::备份 => {
+                                        self.view_state = ViewState::ManageSave(
+                                            ManageSaveView::TabBar(SaveTabBarView::备份),
+                                        );
+
+                                        return self.load_backups();
+                                    }
                                     SaveTabBarInteractionMessage::设置 => {
                                         self.view_state = ViewState::ManageSave(
                                             ManageSaveView::TabBar(SaveTabBarView::设置),
@@ -362,6 +619,95 @@ impl Application for Bl3Application {
                                         .general_state
                                         .save_type_selected = save_type;
                                 }
+                                SaveGeneralInteractionMessage::MayhemLevel(
+                                    playthrough_index,
+                                    mayhem_level,
+                                ) => {
+                                    if let Some(playthrough) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .playthroughs
+                                        .get_mut(playthrough_index)
+                                    {
+                                        playthrough.mayhem_level = mayhem_level;
+                                    }
+
+                                    self.manage_save_state
+                                        .current_file
+                                        .character_data
+                                        .set_mayhem_level(playthrough_index, mayhem_level)
+                                        .handle_ui_error(
+                                            "Failed to set mayhem level",
+                                            &mut self.notification,
+                                        );
+                                }
+                                SaveGeneralInteractionMessage::MayhemRandomSeed(
+                                    playthrough_index,
+                                    mayhem_random_seed,
+                                ) => {
+                                    if let Some(playthrough) = self
+                                        .manage_save_state
+                                        .save_view_state
+                                        .general_state
+                                        .playthroughs
+                                        .get_mut(playthrough_index)
+                                    {
+                                        playthrough.mayhem_random_seed = mayhem_random_seed;
+                                    }
+
+                                    self.manage_save_state
+                                        .current_file
+                                        .character_data
+                                        .set_mayhem_random_seed(
+                                            playthrough_index,
+                                            mayhem_random_seed,
+                                        )
+                                        .handle_ui_error(
+                                            "Failed to set mayhem seed",
+                                            &mut self.notification,
+                                        );
+                                }
+                                SaveGeneralInteractionMessage::AddTvhmPressed => {
+                                    self.manage_save_state
+                                        .current_file
+                                        .character_data
+                                        .add_tvhm_playthrough()
+                                        .handle_ui_error(
+                                            "Failed to add True Vault Hunter Mode",
+                                            &mut self.notification,
+                                        );
+
+                                    manage_save::general::map_save_to_playthroughs_state(
+                                        &mut self.manage_save_state,
+                                    );
+                                    manage_save::missions::map_save_to_missions_state(
+                                        &mut self.manage_save_state,
+                                    );
+                                    manage_save::fast_travel::map_save_to_fast_travel_state(
+                                        &mut self.manage_save_state,
+                                    );
+                                }
+                                SaveGeneralInteractionMessage::RemoveTvhmPressed => {
+                                    self.manage_save_state
+                                        .current_file
+                                        .character_data
+                                        .remove_tvhm_playthrough()
+                                        .handle_ui_error(
+                                            "Failed to remove True Vault Hunter Mode",
+                                            &mut self.notification,
+                                        );
+
+                                    manage_save::general::map_save_to_playthroughs_state(
+                                        &mut self.manage_save_state,
+                                    );
+                                    manage_save::missions::map_save_to_missions_state(
+                                        &mut self.manage_save_state,
+                                    );
+                                    manage_save::fast_travel::map_save_to_fast_travel_state(
+                                        &mut self.manage_save_state,
+                                    );
+                                }
                             },
                             ManageSaveInteractionMessage::Character(character_msg) => {
                                 match character_msg {
@@ -495,6 +841,40 @@ impl Application for Bl3Application {
                                             }
                                         }
                                     }
+                                    SaveCharacterInteractionMessage::SkillMessage(
+                                        skill_message,
+                                    ) => {
+                                        let character_state = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state;
+
+                                        let available =
+                                            available_skill_points(character_state.level_input);
+
+                                        let skills_editor = &mut character_state.skills_editor;
+
+                                        match skill_message {
+                                            CharacterSkillMessage::ItemPoints(
+                                                item_asset_path,
+                                                points,
+                                            ) => {
+                                                skills_editor
+                                                    .set_item_points(&item_asset_path, points);
+                                            }
+                                            CharacterSkillMessage::MaxTreePressed(
+                                                tree_identifier,
+                                            ) => {
+                                                skills_editor.max_tree(tree_identifier, available);
+                                            }
+                                            CharacterSkillMessage::RespecPressed => {
+                                                skills_editor.respec();
+                                            }
+                                        }
+
+                                        character_state.ability_points_input =
+                                            (available - skills_editor.points_spent()).max(0);
+                                    }
                                     SaveCharacterInteractionMessage::MaxAmmoAmountsPressed => {
                                         let ammo_setter = &mut self
                                             .manage_save_state
@@ -579,6 +959,191 @@ impl Application for Bl3Application {
                                             }
                                         }
                                     }
+                                    SaveCharacterInteractionMessage::EmoteSelected(i, emote) => {
+                                        if let Some(slot) = self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .emote_selectors
+                                            .slots
+                                            .get_mut(i)
+                                        {
+                                            slot.selected = Some(emote);
+                                        }
+                                    }
+                                    SaveCharacterInteractionMessage::ColorMessage(color_msg) => {
+                                        let config_dir = self.config.config_dir().to_path_buf();
+
+                                        let color_editor = &mut self
+                                            .manage_save_state
+                                            .save_view_state
+                                            .character_state
+                                            .color_editor;
+
+                                        let mut presets_changed = false;
+
+                                        match color_msg {
+                                            CharacterColorMessage::ColorInputChanged(i, input) => {
+                                                if let Some(field) = color_editor.fields.get_mut(i)
+                                                {
+                                                    field.color_input = input;
+                                                }
+                                            }
+                                            CharacterColorMessage::SplitColorInputChanged(
+                                                i,
+                                                input,
+                                            ) => {
+                                                if let Some(field) = color_editor.fields.get_mut(i)
+                                                {
+                                                    field.split_color_input = input;
+                                                }
+                                            }
+                                            CharacterColorMessage::UseDefaultColor(i, b) => {
+                                                if let Some(field) = color_editor.fields.get_mut(i)
+                                                {
+                                                    field.player_color.use_default_color = b;
+                                                }
+                                            }
+                                            CharacterColorMessage::UseDefaultSplitColor(i, b) => {
+                                                if let Some(field) = color_editor.fields.get_mut(i)
+                                                {
+                                                    field.player_color.use_default_split_color = b;
+                                                }
+                                            }
+                                            CharacterColorMessage::PresetSelected(name) => {
+                                                if let Some(preset) = color_editor
+                                                    .presets
+                                                    .iter()
+                                                    .find(|p| p.name == name)
+                                                {
+                                                    for color in &preset.colors {
+                                                        if let Some(field) = color_editor
+                                                            .fields
+                                                            .iter_mut()
+                                                            .find(|f| {
+                                                                f.player_color.slot == color.slot
+                                                            })
+                                                        {
+                                                            *field = PlayerColorField::new(
+                                                                color.clone(),
+                                                            );
+                                                        }
+                                                    }
+
+                                                    color_editor.preset_name_input = name.clone();
+                                                    color_editor.preset_selected = Some(name);
+                                                }
+                                            }
+                                            CharacterColorMessage::PresetNameInputChanged(name) => {
+                                                color_editor.preset_name_input = name;
+                                            }
+                                            CharacterColorMessage::SavePresetPressed => {
+                                                let name = color_editor
+                                                    .preset_name_input
+                                                    .trim()
+                                                    .to_owned();
+
+                                                if name.is_empty() {
+                                                    self.notification = Some(Notification::new(
+                                                        "请输入预设名称",
+                                                        NotificationSentiment::Negative,
+                                                    ));
+                                                } else {
+                                                    match color_editor.edited_player_colors() {
+                                                        Ok(colors) => {
+                                                            let preset = PlayerColorPreset {
+                                                                name: name.clone(),
+                                                                colors,
+                                                            };
+
+                                                            if let Some(existing) = color_editor
+                                                                .presets
+                                                                .iter_mut()
+                                                                .find(|p| p.name == name)
+                                                            {
+                                                                *existing = preset;
+                                                            } else {
+                                                                color_editor.presets.push(preset);
+                                                            }
+
+                                                            color_editor.preset_selected =
+                                                                Some(name);
+
+                                                            presets_changed = true;
+                                                        }
+                                                        Err(e) => e.handle_ui_error(
+                                                            "保存颜色预设失败",
+                                                            &mut self.notification,
+                                                        ),
+                                                    }
+                                                }
+                                            }
+                                            CharacterColorMessage::DeletePresetPressed => {
+                                                if let Some(selected) =
+                                                    color_editor.preset_selected.take()
+                                                {
+                                                    color_editor
+                                                        .presets
+                                                        .retain(|p| p.name != selected);
+
+                                                    presets_changed = true;
+                                                }
+                                            }
+                                            CharacterColorMessage::PresetsSaveCompleted(res) => {
+                                                match res {
+                                                    MessageResult::Success(_) => {
+                                                        self.notification =
+                                                            Some(Notification::new(
+                                                                "已保存颜色预设",
+                                                                NotificationSentiment::Positive,
+                                                            ));
+                                                    }
+                                                    MessageResult::Error(e) => {
+                                                        let msg =
+                                                            format!("保存颜色预设失败: {}", e);
+
+                                                        error!("{}", msg);
+
+                                                        self.notification =
+                                                            Some(Notification::new(
+                                                                msg,
+                                                                NotificationSentiment::Negative,
+                                                            ));
+                                                    }
+                                                }
+                                            }
+                                        }
+
+                                        if let Some(e) = &color_editor.presets_load_error {
+                                            if presets_changed {
+                                                self.notification = Some(Notification::new(
+                                                    format!("颜色预设文件读取失败, 为避免覆盖已有预设, 未保存预设: {}", e),
+                                                    NotificationSentiment::Negative,
+                                                ));
+                                            }
+                                        } else if presets_changed {
+                                            let presets = PlayerColorPresets {
+                                                presets: color_editor.presets.clone(),
+                                            };
+
+                                            return Command::perform(
+                                                presets.save(config_dir),
+                                                |r| {
+                                                    Bl3Message::Interaction(
+                                                    InteractionMessage::ManageSaveInteraction(
+                                                        ManageSaveInteractionMessage::Character(
+                                                            SaveCharacterInteractionMessage::ColorMessage(
+                                                                CharacterColorMessage::PresetsSaveCompleted(
+                                                                    MessageResult::handle_result(r),
+                                                                ),
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                                },
+                                            );
+                                        }
+                                    }
                                 }
                             }
                             ManageSaveInteractionMessage::Inventory(inventory_msg) => {
@@ -695,86 +1260,421 @@ impl Application for Bl3Application {
                                     }
                                 }
                             },
-                            ManageSaveInteractionMessage::SaveFilePressed => {
-                                //Lets not make any modifications to the current file just in case we have any errors
-                                let mut current_file = self.manage_save_state.current_file.clone();
+                            ManageSaveInteractionMessage::Missions(missions_msg) => {
+                                let missions_state =
+                                    &mut self.manage_save_state.save_view_state.missions_state;
 
-                                if let Err(e) = manage_save::map_all_states_to_save(
-                                    &mut self.manage_save_state,
-                                    &mut current_file,
-                                ) {
-                                    let msg = format!("Failed to save file: {}", e);
-
-                                    error!("{}", msg);
-
-                                    self.notification = Some(Notification::new(
-                                        msg,
-                                        NotificationSentiment::Negative,
-                                    ));
+                                let playthrough_index = missions_state.playthrough_selected.0;
 
-                                    return Command::none();
-                                }
+                                let character_data =
+                                    &mut self.manage_save_state.current_file.character_data;
 
-                                let output_file = self
-                                    .config
-                                    .saves_dir()
-                                    .join(&self.manage_save_state.current_file.file_name);
+                                let res = match missions_msg {
+                                    SaveMissionsInteractionMessage::PlaythroughSelected(
+                                        playthrough,
+                                    ) => {
+                                        missions_state.playthrough_selected = playthrough;
 
-                                match current_file.as_bytes() {
-                                    Ok((output, save_file)) => {
-                                        return Command::perform(
-                                            interaction::file_save::save_file(
-                                                self.config.backup_dir().to_path_buf(),
-                                                output_file,
-                                                output,
-                                                self.manage_save_state.current_file.clone(),
-                                                save_file,
-                                            ),
-                                            |r| {
-                                                Bl3Message::SaveFileCompleted(
-                                                    MessageResult::handle_result(r),
-                                                )
-                                            },
-                                        );
+                                        Ok(())
                                     }
-                                    Err(e) => {
-                                        let msg = format!("Failed to save file: {}", e);
+                                    SaveMissionsInteractionMessage::StatusSelected(
+                                        mission_class_path,
+                                        status,
+                                    ) => character_data.set_mission_status(
+                                        playthrough_index,
+                                        &mission_class_path,
+                                        status,
+                                    ),
+                                    SaveMissionsInteractionMessage::ObjectivesProgressInput(
+                                        mission_class_path,
+                                        objectives_input,
+                                    ) => {
+                                        if let Some(mission) =
+                                            missions_state.missions.iter_mut().find(|m| {
+                                                m.mission.mission_class_path == mission_class_path
+                                            })
+                                        {
+                                            mission.objectives_input = objectives_input;
+                                        }
 
-                                        error!("{}", msg);
+                                        return Command::none();
+                                    }
+                                    SaveMissionsInteractionMessage::ObjectivesProgressSubmitted(
+                                        mission_class_path,
+                                    ) => missions_state
+                                        .missions
+                                        .iter()
+                                        .find(|m| m.mission.mission_class_path == mission_class_path)
+                                        .map(|m| {
+                                            m.objectives_input
+                                                .split(',')
+                                                .map(|o| o.trim())
+                                                .filter(|o| !o.is_empty())
+                                                .map(|o| o.parse::<i32>())
+                                                .collect::<Result<Vec<_>, _>>()
+                                        })
+                                        .unwrap_or_else(|| Ok(Vec::new()))
+                                        .map_err(|_| {
+                                            anyhow!("objectives progress must be a comma separated list of numbers")
+                                        })
+                                        .and_then(|objectives_progress| {
+                                            character_data.set_mission_objectives_progress(
+                                                playthrough_index,
+                                                &mission_class_path,
+                                                objectives_progress,
+                                            )
+                                        }),
+                                    SaveMissionsInteractionMessage::TrackMission(
+                                        mission_class_path,
+                                    ) => character_data.set_tracked_mission(
+                                        playthrough_index,
+                                        Some(&mission_class_path),
+                                    ),
+                                    SaveMissionsInteractionMessage::StopTrackingPressed => {
+                                        character_data.set_tracked_mission(playthrough_index, None)
+                                    }
+                                    SaveMissionsInteractionMessage::StoryMissionSelected(
+                                        story_mission,
+                                    ) => {
+                                        missions_state.story_mission_selected = Some(story_mission);
 
-                                        self.notification = Some(Notification::new(
-                                            msg,
-                                            NotificationSentiment::Negative,
-                                        ));
+                                        return Command::none();
+                                    }
+                                    SaveMissionsInteractionMessage::CompleteStoryPressed => {
+                                        match missions_state.story_mission_selected {
+                                            Some(story_mission) => character_data
+                                                .complete_main_story_up_to(
+                                                    playthrough_index,
+                                                    story_mission.0,
+                                                ),
+                                            None => Ok(()),
+                                        }
                                     }
                                 };
+
+                                res.handle_ui_error(
+                                    "Failed to update missions",
+                                    &mut self.notification,
+                                );
+
+                                manage_save::missions::map_save_to_missions_state(
+                                    &mut self.manage_save_state,
+                                );
                             }
-                        }
-                    }
-                    InteractionMessage::ManageProfileInteraction(manage_profile_msg) => {
-                        match manage_profile_msg {
-                            ManageProfileInteractionMessage::TabBar(tab_bar_msg) => {
-                                match tab_bar_msg {
-                                    ProfileTabBarInteractionMessage::General => {
-                                        self.view_state = ViewState::ManageProfile(
-                                            ManageProfileView::TabBar(ProfileTabBarView::General),
-                                        )
+                            ManageSaveInteractionMessage::FastTravel(fast_travel_msg) => {
+                                let fast_travel_state =
+                                    &mut self.manage_save_state.save_view_state.fast_travel_state;
+
+                                let playthrough_index = fast_travel_state.playthrough_selected.0;
+
+                                let character_data =
+                                    &mut self.manage_save_state.current_file.character_data;
+
+                                let res = match fast_travel_msg {
+                                    SaveFastTravelInteractionMessage::PlaythroughSelected(
+                                        playthrough,
+                                    ) => {
+                                        fast_travel_state.playthrough_selected = playthrough;
+
+                                        Ok(())
                                     }
-                                    ProfileTabBarInteractionMessage::Profile => {
-                                        self.view_state = ViewState::ManageProfile(
-                                            ManageProfileView::TabBar(ProfileTabBarView::Profile),
-                                        )
+                                    SaveFastTravelInteractionMessage::GroupSelected(group) => {
+                                        fast_travel_state.group_selected = group;
+
+                                        return Command::none();
                                     }
-                                    ProfileTabBarInteractionMessage::Keys => {
-                                        self.view_state = ViewState::ManageProfile(
-                                            ManageProfileView::TabBar(ProfileTabBarView::Keys),
-                                        )
+                                    SaveFastTravelInteractionMessage::UnlockGroupPressed => {
+                                        match fast_travel_state.group_selected.0 {
+                                            Some(group) => character_data
+                                                .unlock_fast_travel_group(playthrough_index, group),
+                                            None => Ok(()),
+                                        }
                                     }
-                                    ProfileTabBarInteractionMessage::Bank => {
+                                    SaveFastTravelInteractionMessage::UnlockAllPressed => {
+                                        character_data
+                                            .unlock_all_fast_travel_stations(playthrough_index)
+                                    }
+                                    SaveFastTravelInteractionMessage::UnlockStation(station) => {
+                                        character_data
+                                            .unlock_fast_travel_station(playthrough_index, &station)
+                                    }
+                                    SaveFastTravelInteractionMessage::BlacklistStation(
+                                        station,
+                                        blacklisted,
+                                    ) => character_data.set_fast_travel_station_blacklisted(
+                                        playthrough_index,
+                                        &station,
+                                        blacklisted,
+                                    ),
+                                    SaveFastTravelInteractionMessage::SetSpawnStation(station) => {
+                                        character_data
+                                            .set_spawn_station(playthrough_index, &station)
+                                    }
+                                };
+
+                                res.handle_ui_error(
+                                    "Failed to update fast travel stations",
+                                    &mut self.notification,
+                                );
+
+                                manage_save::fast_travel::map_save_to_fast_travel_state(
+                                    &mut self.manage_save_state,
+                                );
+                            }
+                            ManageSaveInteractionMessage::EchoLogs(echo_logs_msg) => {
+                                let echo_logs_state =
+                                    &mut self.manage_save_state.save_view_state.echo_logs_state;
+
+                                let character_data =
+                                    &mut self.manage_save_state.current_file.character_data;
+
+                                match echo_logs_msg {
+                                    SaveEchoLogsInteractionMessage::ContentSelected(content) => {
+                                        echo_logs_state.content_selected = content;
+
+                                        return Command::none();
+                                    }
+                                    SaveEchoLogsInteractionMessage::UnlockContentPressed => {
+                                        if let Some(content) = echo_logs_state.content_selected.0 {
+                                            character_data.unlock_echo_logs_by_content(content);
+                                        }
+                                    }
+                                    SaveEchoLogsInteractionMessage::UnlockAllPressed => {
+                                        character_data.unlock_all_echo_logs();
+                                    }
+                                    SaveEchoLogsInteractionMessage::MarkAllSeenPressed => {
+                                        character_data.mark_all_echo_logs_seen();
+                                    }
+                                    SaveEchoLogsInteractionMessage::UnlockEchoLog(
+                                        echo_log_path,
+                                    ) => {
+                                        character_data.unlock_echo_log(&echo_log_path);
+                                    }
+                                    SaveEchoLogsInteractionMessage::EchoLogSeen(
+                                        echo_log_path,
+                                        seen,
+                                    ) => {
+                                        character_data
+                                            .set_echo_log_seen(&echo_log_path, seen)
+                                            .handle_ui_error(
+                                                "Failed to update echo log",
+                                                &mut self.notification,
+                                            );
+                                    }
+                                }
+
+                                manage_save::echo_logs::map_save_to_echo_logs_state(
+                                    &mut self.manage_save_state,
+                                );
+                            }
+                            ManageSaveInteractionMessage::CrewQuarters(crew_quarters_msg) => {
+                                let character_data =
+                                    &mut self.manage_save_state.current_file.character_data;
+
+                                let crew_quarters_state =
+                                    &self.manage_save_state.save_view_state.crew_quarters_state;
+
+                                match crew_quarters_msg {
+                                    SaveCrewQuartersInteractionMessage::RoomSelected(room) => {
+                                        character_data
+                                            .set_crew_quarters_room(
+                                                room.room_data_path,
+                                                &crew_quarters_state.unlocked_rooms,
+                                            )
+                                            .handle_ui_error(
+                                                "Failed to update crew quarters room",
+                                                &mut self.notification,
+                                            );
+                                    }
+                                    SaveCrewQuartersInteractionMessage::DecorationSelected(
+                                        decoration_index,
+                                        decoration,
+                                    ) => {
+                                        character_data
+                                            .set_crew_quarters_decoration(
+                                                decoration_index,
+                                                decoration.decoration_data_path.as_deref(),
+                                                &crew_quarters_state.unlocked_decorations,
+                                            )
+                                            .handle_ui_error(
+                                                "Failed to update crew quarters decoration",
+                                                &mut self.notification,
+                                            );
+                                    }
+                                    SaveCrewQuartersInteractionMessage::GunRack(
+                                        item_editor_message,
+                                    ) => {
+                                        let res = item_editor_message.update_state(
+                                            &mut self
+                                                .manage_save_state
+                                                .save_view_state
+                                                .crew_quarters_state
+                                                .gun_rack_state,
+                                            ItemEditorFileType::SaveGunRack(
+                                                &mut self.manage_save_state.current_file,
+                                            ),
+                                        );
+
+                                        self.notification = res.notification;
+
+                                        if let Some(command) = res.command {
+                                            return command.map(|m| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageSaveInteraction(
+                                                        ManageSaveInteractionMessage::CrewQuarters(
+                                                            SaveCrewQuartersInteractionMessage::GunRack(m),
+                                                        ),
+                                                    ),
+                                                )
+                                            });
+                                        }
+
+                                        return Command::none();
+                                    }
+                                }
+
+                                // Only the room is remapped so the gun rack editor keeps its state
+                                manage_save::crew_quarters::map_save_to_crew_quarters_room_state(
+                                    &mut self.manage_save_state,
+                                );
+                            }
+                            ManageSaveInteractionMessage::SaveFilePressed => {
+                                //Lets not make any modifications to the current file just in case we have any errors
+                                let mut current_file = self.manage_save_state.current_file.clone();
+
+                                if let Err(e) = manage_save::map_all_states_to_save(
+                                    &mut self.manage_save_state,
+                                    &mut current_file,
+                                ) {
+                                    let msg = format!("Failed to save file: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+
+                                    return Command::none();
+                                }
+
+                                let output_file = self
+                                    .config
+                                    .saves_dir()
+                                    .join(&self.manage_save_state.current_file.file_name);
+
+                                if interaction::file_watch::file_changed(
+                                    &output_file,
+                                    self.manage_save_state.current_file.file_hash,
+                                ) {
+                                    self.file_changed_before_save(
+                                        self.manage_save_state.current_file.file_name.clone(),
+                                    );
+
+                                    return Command::none();
+                                }
+
+                                match current_file.as_bytes() {
+                                    Ok((output, save_file)) => {
+                                        self.file_changed_state.is_saving = true;
+
+                                        return Command::perform(
+                                            interaction::file_save::save_file(
+                                                self.config.backup_dir().to_path_buf(),
+                                                self.config.backup_retention(),
+                                                output_file,
+                                                output,
+                                                self.manage_save_state.current_file.clone(),
+                                                save_file,
+                                            ),
+                                            |r| {
+                                                Bl3Message::SaveFileCompleted(
+                                                    MessageResult::handle_result(r),
+                                                )
+                                            },
+                                        );
+                                    }
+                                    Err(e) => {
+                                        let msg = format!("Failed to save file: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                };
+                            }
+                        }
+                    }
+                    InteractionMessage::ManageProfileInteraction(manage_profile_msg) => {
+                        match manage_profile_msg {
+                            ManageProfileInteractionMessage::TabBar(tab_bar_msg) => {
+                                match tab_bar_msg {
+                                    ProfileTabBarInteractionMessage::General => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::General),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Profile => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Profile),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Keys => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Keys),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::VaultCard => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::VaultCard),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Bank => {
                                         self.view_state = ViewState::ManageProfile(
                                             ManageProfileView::TabBar(ProfileTabBarView::Bank),
                                         )
                                     }
+                                    ProfileTabBarInteractionMessage::LostLoot => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::LostLoot),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Mail => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Mail),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::GameSettings => {
+                                        self.view_state =
+                                            ViewState::ManageProfile(ManageProfileView::TabBar(
+                                                ProfileTabBarView::GameSettings,
+                                            ))
+                                    }
+                                    ProfileTabBarInteractionMessage::Transfer => {
+                                        state_mappers::transfer::map_files_to_transfer_state(
+                                            &mut self.transfer_state,
+                                            &self.loaded_files,
+                                        );
+
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Transfer),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Compare => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Compare),
+                                        )
+                                    }
+                                    ProfileTabBarInteractionMessage::Backups => {
+                                        self.view_state = ViewState::ManageProfile(
+                                            ManageProfileView::TabBar(ProfileTabBarView::Backups),
+                                        );
+
+                                        return self.load_backups();
+                                    }
                                     ProfileTabBarInteractionMessage::Settings => {
                                         self.view_state = ViewState::ManageProfile(
                                             ManageProfileView::TabBar(ProfileTabBarView::Settings),
@@ -1057,6 +1957,178 @@ impl Application for Bl3Application {
                                     }
                                 }
                             }
+                            ManageProfileInteractionMessage::VaultCard(vault_card_message) => {
+                                let vault_card_state = &mut self
+                                    .manage_profile_state
+                                    .profile_view_state
+                                    .vault_card_state;
+
+                                match vault_card_message {
+                                    ProfileVaultCardInteractionMessage::VaultCardSelected(
+                                        vault_card_id,
+                                    ) => {
+                                        vault_card_state.selected_vault_card_id = vault_card_id;
+
+                                        manage_profile::vault_card::map_vault_card_to_gear_reward_fields(
+                                            vault_card_state,
+                                        );
+                                    }
+                                    ProfileVaultCardInteractionMessage::LastActiveVaultCard(
+                                        vault_card_id,
+                                    ) => {
+                                        vault_card_state.last_active_vault_card_input = vault_card_id;
+                                    }
+                                    ProfileVaultCardInteractionMessage::Experience(experience) => {
+                                        if let Some(vault_card) =
+                                            vault_card_state.selected_vault_card_mut()
+                                        {
+                                            vault_card.experience = experience;
+                                        }
+                                    }
+                                    ProfileVaultCardInteractionMessage::ChestsOpened(
+                                        chests_opened,
+                                    ) => {
+                                        if let Some(vault_card) =
+                                            vault_card_state.selected_vault_card_mut()
+                                        {
+                                            vault_card.chests_opened = chests_opened;
+                                        }
+                                    }
+                                    ProfileVaultCardInteractionMessage::KeysSpent(keys_spent) => {
+                                        if let Some(vault_card) =
+                                            vault_card_state.selected_vault_card_mut()
+                                        {
+                                            vault_card.keys_spent = keys_spent;
+                                        }
+                                    }
+                                    ProfileVaultCardInteractionMessage::RewardUnlocked(
+                                        index,
+                                        unlocked,
+                                    ) => {
+                                        if let Some(reward) = vault_card_state
+                                            .selected_vault_card_mut()
+                                            .and_then(|v| v.rewards.get_mut(index))
+                                        {
+                                            reward.unlocked = unlocked;
+                                        }
+                                    }
+                                    ProfileVaultCardInteractionMessage::RewardRedeemed(
+                                        index,
+                                        redeemed,
+                                    ) => {
+                                        if let Some(reward) = vault_card_state
+                                            .selected_vault_card_mut()
+                                            .and_then(|v| v.rewards.get_mut(index))
+                                        {
+                                            reward.redeemed = redeemed;
+                                        }
+                                    }
+                                    ProfileVaultCardInteractionMessage::NewRewardColumn(column) => {
+                                        vault_card_state.new_reward_column_input = column;
+                                    }
+                                    ProfileVaultCardInteractionMessage::NewRewardRow(row) => {
+                                        vault_card_state.new_reward_row_input = row;
+                                    }
+                                    ProfileVaultCardInteractionMessage::AddRewardPressed => {
+                                        let column_index = vault_card_state.new_reward_column_input;
+                                        let row_index = vault_card_state.new_reward_row_input;
+
+                                        if let Some(vault_card) =
+                                            vault_card_state.selected_vault_card_mut()
+                                        {
+                                            vault_card
+                                                .add_reward(column_index, row_index)
+                                                .handle_ui_error(
+                                                    "Failed to add vault card reward",
+                                                    &mut self.notification,
+                                                );
+                                        }
+                                    }
+                                    ProfileVaultCardInteractionMessage::GearRewardRepurchaseCount(
+                                        index,
+                                        repurchase_count,
+                                    ) => {
+                                        if let Some(gear_reward) = vault_card_state
+                                            .selected_vault_card_mut()
+                                            .and_then(|v| v.gear_rewards.get_mut(index))
+                                        {
+                                            gear_reward.repurchase_count = repurchase_count;
+                                        }
+
+                                        if let Some(gear_reward_field) =
+                                            vault_card_state.gear_reward_fields.get_mut(index)
+                                        {
+                                            gear_reward_field.gear_reward.repurchase_count =
+                                                repurchase_count;
+                                        }
+                                    }
+                                    ProfileVaultCardInteractionMessage::RemoveGearReward(index) => {
+                                        if let Some(vault_card) =
+                                            vault_card_state.selected_vault_card_mut()
+                                        {
+                                            if index < vault_card.gear_rewards.len() {
+                                                vault_card.gear_rewards.remove(index);
+                                            }
+                                        }
+
+                                        manage_profile::vault_card::map_vault_card_to_gear_reward_fields(
+                                            vault_card_state,
+                                        );
+                                    }
+                                    ProfileVaultCardInteractionMessage::NewGearRewardIndex(
+                                        gear_index,
+                                    ) => {
+                                        vault_card_state.new_gear_reward_index_input = gear_index;
+                                    }
+                                    ProfileVaultCardInteractionMessage::AddGearRewardPressed => {
+                                        let gear_index = vault_card_state.new_gear_reward_index_input;
+
+                                        if let Some(vault_card) =
+                                            vault_card_state.selected_vault_card_mut()
+                                        {
+                                            vault_card.add_gear_reward(gear_index).handle_ui_error(
+                                                "Failed to add vault card gear reward",
+                                                &mut self.notification,
+                                            );
+                                        }
+
+                                        manage_profile::vault_card::map_vault_card_to_gear_reward_fields(
+                                            vault_card_state,
+                                        );
+                                    }
+                                    ProfileVaultCardInteractionMessage::DaySeed(day_seed) => {
+                                        vault_card_state.challenge_seeds.day_seed = day_seed;
+                                    }
+                                    ProfileVaultCardInteractionMessage::WeekSeed(week_seed) => {
+                                        vault_card_state.challenge_seeds.week_seed = week_seed;
+                                    }
+                                    ProfileVaultCardInteractionMessage::NewChallengeId(
+                                        challenge_id,
+                                    ) => {
+                                        vault_card_state.new_challenge_id_input = challenge_id;
+                                    }
+                                    ProfileVaultCardInteractionMessage::NewChallengeSeed(
+                                        challenge_seed,
+                                    ) => {
+                                        vault_card_state.new_challenge_seed_input = challenge_seed;
+                                    }
+                                    ProfileVaultCardInteractionMessage::AddChallengePressed => {
+                                        let challenge = VaultCardPreviousChallengeData {
+                                            challenge_seed: vault_card_state.new_challenge_seed_input,
+                                            challenge_id: vault_card_state.new_challenge_id_input,
+                                        };
+
+                                        vault_card_state
+                                            .challenge_fields
+                                            .push(VaultCardChallengeField::new(challenge));
+                                    }
+                                    ProfileVaultCardInteractionMessage::RemoveChallenge(index) => {
+                                        if index < vault_card_state.challenge_fields.len() {
+                                            vault_card_state.challenge_fields.remove(index);
+                                        }
+                                    }
+                                }
+                            }
                             ManageProfileInteractionMessage::Bank(bank_message) => {
                                 match bank_message {
                                     ProfileBankInteractionMessage::Editor(item_editor_message) => {
@@ -1089,28 +2161,300 @@ impl Application for Bl3Application {
                                     }
                                 }
                             }
-                            ManageProfileInteractionMessage::SaveProfilePressed => {
-                                //Lets not make any modifications to the current file just in case we have any errors
-                                let mut current_file =
-                                    self.manage_profile_state.current_file.clone();
+                            ManageProfileInteractionMessage::LostLoot(lost_loot_message) => {
+                                match lost_loot_message {
+                                    ProfileLostLootInteractionMessage::Editor(
+                                        item_editor_message,
+                                    ) => {
+                                        let res = item_editor_message.update_state(
+                                            &mut self
+                                                .manage_profile_state
+                                                .profile_view_state
+                                                .lost_loot_state
+                                                .item_editor_state,
+                                            ItemEditorFileType::ProfileLostLoot(
+                                                &mut self.manage_profile_state.current_file,
+                                            ),
+                                        );
 
-                                let guardian_data_injection_required =
-                                    match manage_profile::map_all_states_to_profile(
-                                        &mut self.manage_profile_state,
-                                        &mut current_file,
-                                    ) {
-                                        Ok(injection_required) => injection_required,
-                                        Err(e) => {
-                                            let msg = format!("Failed to save profile: {}", e);
+                                        self.notification = res.notification;
 
-                                            error!("{}", msg);
+                                        if let Some(command) = res.command {
+                                            return command.map(|m| {
+                                                Bl3Message::Interaction(
+                                                    InteractionMessage::ManageProfileInteraction(
+                                                        ManageProfileInteractionMessage::LostLoot(
+                                                            ProfileLostLootInteractionMessage::Editor(
+                                                                m,
+                                                            ),
+                                                        ),
+                                                    ),
+                                                )
+                                            });
+                                        }
+                                    }
+                                }
+                            }
+                            ManageProfileInteractionMessage::Mail(mail_message) => {
+                                let mail_state =
+                                    &mut self.manage_profile_state.profile_view_state.mail_state;
 
-                                            self.notification = Some(Notification::new(
-                                                msg,
-                                                NotificationSentiment::Negative,
-                                            ));
+                                let profile_data =
+                                    &mut self.manage_profile_state.current_file.profile_data;
 
-                                            return Command::none();
+                                match mail_message {
+                                    ProfileMailInteractionMessage::SenderInputChanged(sender) => {
+                                        mail_state.sender_input = sender;
+
+                                        return Command::none();
+                                    }
+                                    ProfileMailInteractionMessage::SubjectInputChanged(subject) => {
+                                        mail_state.subject_input = subject;
+
+                                        return Command::none();
+                                    }
+                                    ProfileMailInteractionMessage::BodyInputChanged(body) => {
+                                        mail_state.body_input = body;
+
+                                        return Command::none();
+                                    }
+                                    ProfileMailInteractionMessage::ItemSerialInputChanged(
+                                        item_serial,
+                                    ) => {
+                                        mail_state.item_serial_input = item_serial;
+
+                                        return Command::none();
+                                    }
+                                    ProfileMailInteractionMessage::SendPressed => {
+                                        let new_mail = Bl3Item::from_serial_base64(
+                                            mail_state.item_serial_input.trim(),
+                                        )
+                                        .map(|item| NewMailData {
+                                            mail_guid:
+                                                interaction::manage_save::general::generate_random_guid(),
+                                            sender_display_name: mail_state.sender_input.clone(),
+                                            subject: mail_state.subject_input.clone(),
+                                            body: mail_state.body_input.clone(),
+                                            item,
+                                            date_sent: manage_profile::mail::ticks_from_date_time(
+                                                Utc::now().naive_utc(),
+                                            ),
+                                        });
+
+                                        match new_mail.and_then(|m| profile_data.add_mail(&m)) {
+                                            Ok(()) => {
+                                                mail_state.subject_input.clear();
+                                                mail_state.body_input.clear();
+                                                mail_state.item_serial_input.clear();
+
+                                                self.notification = Some(Notification::new(
+                                                    "Mail added, save the profile and it will be delivered in game.",
+                                                    NotificationSentiment::Positive,
+                                                ));
+                                            }
+                                            Err(e) => e.handle_ui_error(
+                                                "Failed to send mail",
+                                                &mut self.notification,
+                                            ),
+                                        }
+                                    }
+                                    ProfileMailInteractionMessage::MailRead(mail_guid, read) => {
+                                        profile_data
+                                            .set_mail_read(&mail_guid, read)
+                                            .handle_ui_error(
+                                                "Failed to update mail",
+                                                &mut self.notification,
+                                            );
+                                    }
+                                    ProfileMailInteractionMessage::DeleteMail(mail_guid) => {
+                                        profile_data.remove_mail(&mail_guid).handle_ui_error(
+                                            "Failed to delete mail",
+                                            &mut self.notification,
+                                        );
+                                    }
+                                }
+
+                                manage_profile::mail::map_profile_to_mail_state(
+                                    &mut self.manage_profile_state,
+                                );
+                            }
+                            ManageProfileInteractionMessage::GameSettings(
+                                game_settings_message,
+                            ) => match game_settings_message {
+                                ProfileGameSettingsInteractionMessage::BoolChanged(
+                                    index,
+                                    checked,
+                                ) => {
+                                    if let Some(field) = self
+                                        .manage_profile_state
+                                        .profile_view_state
+                                        .game_settings_state
+                                        .settings
+                                        .get_mut(index)
+                                    {
+                                        field.setting.value = GameSettingValue::Bool(checked);
+                                    }
+                                }
+                                ProfileGameSettingsInteractionMessage::ValueChanged(
+                                    index,
+                                    value,
+                                ) => {
+                                    if let Some(field) = self
+                                        .manage_profile_state
+                                        .profile_view_state
+                                        .game_settings_state
+                                        .settings
+                                        .get_mut(index)
+                                    {
+                                        field.input = value;
+                                    }
+                                }
+                                ProfileGameSettingsInteractionMessage::ExportPressed => {
+                                    // Export what is shown in the tab, including edits that haven't been saved yet
+                                    let mut profile =
+                                        self.manage_profile_state.current_file.clone();
+
+                                    match manage_profile::game_settings::map_game_settings_state_to_profile(
+                                        &mut self.manage_profile_state,
+                                        &mut profile,
+                                    ) {
+                                        Ok(()) => {
+                                            self.manage_profile_state
+                                                .profile_view_state
+                                                .game_settings_state
+                                                .preset_window_open = true;
+
+                                            return Command::perform(
+                                                interaction::game_settings::export_game_settings_preset(
+                                                    self.config.config_dir().to_path_buf(),
+                                                    profile.profile_data.game_settings_preset(),
+                                                ),
+                                                |r| {
+                                                    Bl3Message::Interaction(
+                                                        InteractionMessage::ManageProfileInteraction(
+                                                            ManageProfileInteractionMessage::GameSettings(
+                                                                ProfileGameSettingsInteractionMessage::ExportCompleted(
+                                                                    MessageResult::handle_result(r),
+                                                                ),
+                                                            ),
+                                                        ),
+                                                    )
+                                                },
+                                            );
+                                        }
+                                        Err(e) => e.handle_ui_error(
+                                            "Failed to export game settings",
+                                            &mut self.notification,
+                                        ),
+                                    }
+                                }
+                                ProfileGameSettingsInteractionMessage::ExportCompleted(res) => {
+                                    self.manage_profile_state
+                                        .profile_view_state
+                                        .game_settings_state
+                                        .preset_window_open = false;
+
+                                    match res {
+                                        MessageResult::Success(file) => {
+                                            self.notification = Some(Notification::new(
+                                                format!(
+                                                    "Exported game settings to: {}",
+                                                    file.display()
+                                                ),
+                                                NotificationSentiment::Positive,
+                                            ));
+                                        }
+                                        MessageResult::Error(e) => {
+                                            let msg =
+                                                format!("Failed to export game settings: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+                                        }
+                                    }
+                                }
+                                ProfileGameSettingsInteractionMessage::ImportPressed => {
+                                    self.manage_profile_state
+                                        .profile_view_state
+                                        .game_settings_state
+                                        .preset_window_open = true;
+
+                                    return Command::perform(
+                                        interaction::game_settings::import_game_settings_preset(
+                                            self.config.config_dir().to_path_buf(),
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::ManageProfileInteraction(
+                                                    ManageProfileInteractionMessage::GameSettings(
+                                                        ProfileGameSettingsInteractionMessage::ImportCompleted(
+                                                            MessageResult::handle_result(r),
+                                                        ),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                                ProfileGameSettingsInteractionMessage::ImportCompleted(res) => {
+                                    self.manage_profile_state
+                                        .profile_view_state
+                                        .game_settings_state
+                                        .preset_window_open = false;
+
+                                    let res = match res {
+                                        MessageResult::Success(preset) => self
+                                            .manage_profile_state
+                                            .current_file
+                                            .profile_data
+                                            .apply_game_settings_preset(&preset),
+                                        MessageResult::Error(e) => Err(anyhow!(e)),
+                                    };
+
+                                    match res {
+                                        Ok(()) => {
+                                            manage_profile::game_settings::map_profile_to_game_settings_state(
+                                                &mut self.manage_profile_state,
+                                            );
+
+                                            self.notification = Some(Notification::new(
+                                                "Imported game settings, save the profile to keep them.",
+                                                NotificationSentiment::Positive,
+                                            ));
+                                        }
+                                        Err(e) => e.handle_ui_error(
+                                            "Failed to import game settings",
+                                            &mut self.notification,
+                                        ),
+                                    }
+                                }
+                            },
+                            ManageProfileInteractionMessage::SaveProfilePressed => {
+                                //Lets not make any modifications to the current file just in case we have any errors
+                                let mut current_file =
+                                    self.manage_profile_state.current_file.clone();
+
+                                let guardian_data_injection_required =
+                                    match manage_profile::map_all_states_to_profile(
+                                        &mut self.manage_profile_state,
+                                        &mut current_file,
+                                    ) {
+                                        Ok(injection_required) => injection_required,
+                                        Err(e) => {
+                                            let msg = format!("Failed to save profile: {}", e);
+
+                                            error!("{}", msg);
+
+                                            self.notification = Some(Notification::new(
+                                                msg,
+                                                NotificationSentiment::Negative,
+                                            ));
+
+                                            return Command::none();
                                         }
                                     };
 
@@ -1119,11 +2463,25 @@ impl Application for Bl3Application {
                                     .saves_dir()
                                     .join(&self.manage_profile_state.current_file.file_name);
 
+                                if interaction::file_watch::file_changed(
+                                    &output_file,
+                                    self.manage_profile_state.current_file.file_hash,
+                                ) {
+                                    self.file_changed_before_save(
+                                        self.manage_profile_state.current_file.file_name.clone(),
+                                    );
+
+                                    return Command::none();
+                                }
+
                                 match current_file.as_bytes() {
                                     Ok((output, profile)) => {
+                                        self.file_changed_state.is_saving = true;
+
                                         return Command::perform(
                                             interaction::file_save::save_profile(
                                                 self.config.backup_dir().to_path_buf(),
+                                                self.config.backup_retention(),
                                                 self.config.saves_dir().to_path_buf(),
                                                 output_file,
                                                 output,
@@ -1191,45 +2549,705 @@ impl Application for Bl3Application {
                                 },
                             );
                         }
-                        SettingsInteractionMessage::OpenBackupDirCompleted(res) => {
-                            res.handle_ui_error(
-                                "Failed to open backups folder",
-                                &mut self.notification,
+                        SettingsInteractionMessage::OpenBackupDirCompleted(res) => {
+                            res.handle_ui_error(
+                                "Failed to open backups folder",
+                                &mut self.notification,
+                            );
+                        }
+                        SettingsInteractionMessage::ChangeBackupDir => {
+                            self.settings_state.choose_backup_dir_window_open = true;
+
+                            return Command::perform(
+                                interaction::choose_dir(self.config.backup_dir().to_path_buf()),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::ChangeBackupDirCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::ChangeBackupDirCompleted(choose_dir_res) => {
+                            self.settings_state.choose_backup_dir_window_open = false;
+
+                            match choose_dir_res {
+                                MessageResult::Success(dir) => {
+                                    self.config.set_backup_dir(dir);
+                                    self.settings_state.backup_dir_input =
+                                        self.config.backup_dir().to_string_lossy().to_string();
+
+                                    return Command::perform(self.config.clone().save(), |r| {
+                                        Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                            MessageResult::handle_result(r),
+                                        ))
+                                    });
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to choose backups folder: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
+                        }
+                        SettingsInteractionMessage::OpenSavesDir => {
+                            return Command::perform(
+                                interaction::settings::open_dir(
+                                    self.config.saves_dir().to_path_buf(),
+                                ),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::OpenSavesDirCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::OpenSavesDirCompleted(res) => {
+                            res.handle_ui_error(
+                                "Failed to open saves folder",
+                                &mut self.notification,
+                            );
+                        }
+                        SettingsInteractionMessage::ChangeSavesDir => {
+                            self.settings_state.choose_saves_dir_window_open = true;
+
+                            return Command::perform(
+                                interaction::choose_dir(self.config.saves_dir().to_path_buf()),
+                                |r| {
+                                    Bl3Message::Interaction(
+                                        InteractionMessage::SettingsInteraction(
+                                            SettingsInteractionMessage::ChangeSavesDirCompleted(
+                                                MessageResult::handle_result(r),
+                                            ),
+                                        ),
+                                    )
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::ChangeSavesDirCompleted(choose_dir_res) => {
+                            self.settings_state.choose_saves_dir_window_open = false;
+
+                            match choose_dir_res {
+                                MessageResult::Success(dir) => {
+                                    self.view_state = ViewState::Loading;
+
+                                    return Command::perform(
+                                        interaction::choose_save_directory::load_files_in_directory_with_report(
+                                            dir,
+                                        ),
+                                        |r| {
+                                            Bl3Message::ChooseSave(ChooseSaveMessage::FilesLoaded(
+                                                MessageResult::handle_result(r),
+                                            ))
+                                        },
+                                    );
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to choose saves folder: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
+                        }
+                        SettingsInteractionMessage::SaveDirSelected(save_dir) => {
+                            self.view_state = ViewState::Loading;
+
+                            return Command::perform(
+                                interaction::choose_save_directory::load_files_in_directory_with_report(
+                                    save_dir.path,
+                                ),
+                                |r| {
+                                    Bl3Message::ChooseSave(ChooseSaveMessage::FilesLoaded(
+                                        MessageResult::handle_result(r),
+                                    ))
+                                },
+                            );
+                        }
+                        SettingsInteractionMessage::DecreaseUIScale => {
+                            if self.settings_state.ui_scale_factor >= 0.50 {
+                                self.settings_state.ui_scale_factor -= 0.05;
+
+                                self.config
+                                    .set_ui_scale_factor(self.settings_state.ui_scale_factor);
+
+                                return Command::perform(self.config.clone().save(), |r| {
+                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                        MessageResult::handle_result(r),
+                                    ))
+                                });
+                            }
+                        }
+                        SettingsInteractionMessage::IncreaseUIScale => {
+                            if self.settings_state.ui_scale_factor < 2.0 {
+                                self.settings_state.ui_scale_factor += 0.05;
+
+                                self.config
+                                    .set_ui_scale_factor(self.settings_state.ui_scale_factor);
+
+                                return Command::perform(self.config.clone().save(), |r| {
+                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                        MessageResult::handle_result(r),
+                                    ))
+                                });
+                            }
+                        }
+                    },
+                    InteractionMessage::FileChangedInteraction(file_changed_msg) => {
+                        match file_changed_msg {
+                            FileChangedInteractionMessage::ReloadPressed => {
+                                if let Some(file_name) = self.current_file_name() {
+                                    return Command::perform(
+                                        interaction::file_watch::reload_file(
+                                            self.config.saves_dir().to_path_buf(),
+                                            file_name,
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::FileChangedInteraction(
+                                                    FileChangedInteractionMessage::ReloadCompleted(
+                                                        MessageResult::handle_result(r),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                            }
+                            FileChangedInteractionMessage::ReloadCompleted(res) => match res {
+                                MessageResult::Success(file) => {
+                                    let msg = format!(
+                                        "Reloaded {}, unsaved changes were discarded.",
+                                        file.filename()
+                                    );
+
+                                    self.replace_loaded_file(file);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Positive,
+                                    ));
+
+                                    state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+                                        "Failed to map loaded file to editor",
+                                        &mut self.notification,
+                                    );
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to reload file: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            },
+                            FileChangedInteractionMessage::MergePressed => {
+                                if let Some(file_name) = self.current_file_name() {
+                                    return Command::perform(
+                                        interaction::file_watch::reload_file(
+                                            self.config.saves_dir().to_path_buf(),
+                                            file_name,
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::FileChangedInteraction(
+                                                    FileChangedInteractionMessage::MergeCompleted(
+                                                        MessageResult::handle_result(r),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                            }
+                            FileChangedInteractionMessage::MergeCompleted(res) => {
+                                let merged = match res {
+                                    MessageResult::Success(file) => {
+                                        // The unsaved edits are the differences between the
+                                        // loaded file and the editor's states, those are applied
+                                        // on top of the reloaded file
+                                        let merged = match (&self.view_state, &file) {
+                                            (
+                                                ViewState::ManageSave(_),
+                                                Bl3FileType::PcSave(save)
+                                                | Bl3FileType::Ps4Save(save),
+                                            ) => {
+                                                let mut edited =
+                                                    self.manage_save_state.current_file.clone();
+
+                                                manage_save::map_all_states_to_save(
+                                                    &mut self.manage_save_state,
+                                                    &mut edited,
+                                                )
+                                                .and_then(|_| {
+                                                    diff::merge_saves(
+                                                        &self.manage_save_state.current_file,
+                                                        &edited,
+                                                        save,
+                                                    )
+                                                })
+                                                .map(|merged| match file {
+                                                    Bl3FileType::Ps4Save(_) => {
+                                                        Bl3FileType::Ps4Save(merged)
+                                                    }
+                                                    _ => Bl3FileType::PcSave(merged),
+                                                })
+                                            }
+                                            (
+                                                ViewState::ManageProfile(_),
+                                                Bl3FileType::PcProfile(profile)
+                                                | Bl3FileType::Ps4Profile(profile),
+                                            ) => {
+                                                let mut edited =
+                                                    self.manage_profile_state.current_file.clone();
+
+                                                manage_profile::map_all_states_to_profile(
+                                                    &mut self.manage_profile_state,
+                                                    &mut edited,
+                                                )
+                                                .and_then(|_| {
+                                                    diff::merge_profiles(
+                                                        &self.manage_profile_state.current_file,
+                                                        &edited,
+                                                        profile,
+                                                    )
+                                                })
+                                                .map(|merged| match file {
+                                                    Bl3FileType::Ps4Profile(_) => {
+                                                        Bl3FileType::Ps4Profile(merged)
+                                                    }
+                                                    _ => Bl3FileType::PcProfile(merged),
+                                                })
+                                            }
+                                            _ => Err(anyhow!(
+                                                "{} is no longer the same type of file",
+                                                file.filename()
+                                            )),
+                                        };
+
+                                        merged.map(|merged| (file, merged))
+                                    }
+                                    MessageResult::Error(e) => Err(anyhow!(e)),
+                                };
+
+                                match merged {
+                                    Ok((file, merged)) => {
+                                        let msg = format!(
+                                            "Merged your unsaved changes into the new {}, save to write them.",
+                                            file.filename()
+                                        );
+
+                                        self.replace_loaded_file(file.clone());
+
+                                        self.loaded_files_selected = Box::new(merged);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Positive,
+                                        ));
+
+                                        state_mappers::map_loaded_file_to_state(self)
+                                            .handle_ui_error(
+                                                "Failed to map loaded file to editor",
+                                                &mut self.notification,
+                                            );
+
+                                        // The editor shows the merged file while the file on disk
+                                        // stays the loaded one, so the merged changes are still
+                                        // unsaved and get written over it when saving
+                                        match &file {
+                                            Bl3FileType::PcSave(save)
+                                            | Bl3FileType::Ps4Save(save) => {
+                                                self.manage_save_state.current_file = save.clone();
+                                            }
+                                            Bl3FileType::PcProfile(profile)
+                                            | Bl3FileType::Ps4Profile(profile) => {
+                                                self.manage_profile_state.current_file =
+                                                    profile.clone();
+                                            }
+                                        }
+
+                                        self.loaded_files_selected = Box::new(file);
+                                    }
+                                    Err(e) => {
+                                        let msg = format!("Failed to merge file: {}", e);
+
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+                                    }
+                                }
+                            }
+                            FileChangedInteractionMessage::OverwritePressed => {
+                                if let Some(file_name) = self.current_file_name() {
+                                    let file_hash = interaction::file_watch::file_hash_on_disk(
+                                        &self.config.saves_dir().join(&file_name),
+                                    );
+
+                                    self.file_changed_state
+                                        .changed_files
+                                        .retain(|f| *f != file_name);
+
+                                    // The file on disk is treated as the loaded one so the save
+                                    // can go ahead
+                                    let save_msg = match self.view_state {
+                                        ViewState::ManageProfile(_) => {
+                                            if let Ok(file_hash) = file_hash {
+                                                self.manage_profile_state.current_file.file_hash =
+                                                    file_hash;
+                                            }
+
+                                            InteractionMessage::ManageProfileInteraction(
+                                                ManageProfileInteractionMessage::SaveProfilePressed,
+                                            )
+                                        }
+                                        _ => {
+                                            if let Ok(file_hash) = file_hash {
+                                                self.manage_save_state.current_file.file_hash =
+                                                    file_hash;
+                                            }
+
+                                            InteractionMessage::ManageSaveInteraction(
+                                                ManageSaveInteractionMessage::SaveFilePressed,
+                                            )
+                                        }
+                                    };
+
+                                    return self.update(Bl3Message::Interaction(save_msg));
+                                }
+                            }
+                        }
+                    }
+                    InteractionMessage::DiffInteraction(diff_msg) => match diff_msg {
+                        DiffInteractionMessage::ChooseFilePressed => {
+                            self.diff_state.choose_file_window_open = true;
+
+                            return Command::perform(
+                                interaction::diff::choose_compare_file(
+                                    self.config.backup_dir().to_path_buf(),
+                                ),
+                                |r| {
+                                    Bl3Message::Interaction(InteractionMessage::DiffInteraction(
+                                        DiffInteractionMessage::ChooseFileCompleted(
+                                            MessageResult::handle_result(r),
+                                        ),
+                                    ))
+                                },
+                            );
+                        }
+                        DiffInteractionMessage::ChooseFileCompleted(res) => {
+                            self.diff_state.choose_file_window_open = false;
+
+                            let entries = match res {
+                                MessageResult::Success(compare_file) => {
+                                    let entries = match (
+                                        &*self.loaded_files_selected,
+                                        &compare_file,
+                                    ) {
+                                        (
+                                            Bl3FileType::PcSave(_) | Bl3FileType::Ps4Save(_),
+                                            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save),
+                                        ) => diff::diff_saves(
+                                            save,
+                                            &self.manage_save_state.current_file,
+                                        ),
+                                        (
+                                            Bl3FileType::PcProfile(_) | Bl3FileType::Ps4Profile(_),
+                                            Bl3FileType::PcProfile(profile)
+                                            | Bl3FileType::Ps4Profile(profile),
+                                        ) => diff::diff_profiles(
+                                            profile,
+                                            &self.manage_profile_state.current_file,
+                                        ),
+                                        _ => Err(anyhow!(
+                                            "a save can only be compared with a save and a profile with a profile"
+                                        )),
+                                    };
+
+                                    entries.map(|e| (compare_file.to_string(), e))
+                                }
+                                MessageResult::Error(e) => Err(anyhow!("{}", e)),
+                            };
+
+                            match entries {
+                                Ok((compare_file_name, entries)) => {
+                                    self.diff_state.compare_file_name = Some(compare_file_name);
+                                    self.diff_state.entries = entries;
+                                }
+                                Err(e) => e.handle_ui_error(
+                                    "Failed to compare files",
+                                    &mut self.notification,
+                                ),
+                            }
+                        }
+                    },
+                    InteractionMessage::TransferInteraction(transfer_msg) => match transfer_msg {
+                        TransferInteractionMessage::SourceFileSelected(file) => {
+                            self.transfer_state.source_file = Some(*file);
+                            self.transfer_state.source_container = None;
+
+                            state_mappers::transfer::map_transfer_containers(
+                                &mut self.transfer_state,
+                            );
+                        }
+                        TransferInteractionMessage::SourceContainerSelected(container) => {
+                            self.transfer_state.source_container = Some(container);
+
+                            state_mappers::transfer::map_transfer_items(&mut self.transfer_state);
+                        }
+                        TransferInteractionMessage::TargetFileSelected(file) => {
+                            self.transfer_state.target_file = Some(*file);
+                            self.transfer_state.target_container = None;
+
+                            state_mappers::transfer::map_transfer_containers(
+                                &mut self.transfer_state,
                             );
                         }
-                        SettingsInteractionMessage::ChangeBackupDir => {
-                            self.settings_state.choose_backup_dir_window_open = true;
+                        TransferInteractionMessage::TargetContainerSelected(container) => {
+                            self.transfer_state.target_container = Some(container);
+                        }
+                        TransferInteractionMessage::ItemSelected(index, selected) => {
+                            if let Some(item) = self.transfer_state.items.get_mut(index) {
+                                item.selected = selected;
+                            }
+                        }
+                        TransferInteractionMessage::SelectAll(selected) => {
+                            self.transfer_state
+                                .items
+                                .iter_mut()
+                                .for_each(|i| i.selected = selected);
+                        }
+                        TransferInteractionMessage::TransferPressed(mode) => {
+                            // The files to transfer between are the loaded files, edits to the open
+                            // file which haven't been saved yet would be lost when it is written.
+                            let transfer_file_names = [
+                                &self.transfer_state.source_file,
+                                &self.transfer_state.target_file,
+                            ]
+                            .iter()
+                            .filter_map(|f| f.as_ref().map(|f| f.filename().to_owned()))
+                            .collect::<Vec<_>>();
+
+                            for file_name in transfer_file_names {
+                                match self.has_unsaved_changes(&file_name) {
+                                    Ok(false) => (),
+                                    Ok(true) => {
+                                        let msg = format!(
+                                            "Failed to transfer items: {} has unsaved changes, save or reload it before transferring items.",
+                                            file_name
+                                        );
 
-                            return Command::perform(
-                                interaction::choose_dir(self.config.backup_dir().to_path_buf()),
-                                |r| {
-                                    Bl3Message::Interaction(
-                                        InteractionMessage::SettingsInteraction(
-                                            SettingsInteractionMessage::ChangeBackupDirCompleted(
-                                                MessageResult::handle_result(r),
-                                            ),
-                                        ),
+                                        error!("{}", msg);
+
+                                        self.notification = Some(Notification::new(
+                                            msg,
+                                            NotificationSentiment::Negative,
+                                        ));
+
+                                        return Command::none();
+                                    }
+                                    Err(e) => {
+                                        e.handle_ui_error(
+                                            "Failed to transfer items",
+                                            &mut self.notification,
+                                        );
+
+                                        return Command::none();
+                                    }
+                                }
+                            }
+
+                            let transfer_state = &self.transfer_state;
+
+                            let indexes = transfer_state
+                                .items
+                                .iter()
+                                .filter(|i| i.selected)
+                                .map(|i| i.index)
+                                .collect::<Vec<_>>();
+
+                            // Errors are left to the transfer itself which checks the same items
+                            let items_losing_cosmetics = match (
+                                &transfer_state.source_file,
+                                transfer_state.source_container,
+                                transfer_state.target_container,
+                            ) {
+                                (Some(source), Some(source_container), Some(target_container)) => {
+                                    transfer::items_losing_cosmetics(
+                                        source,
+                                        source_container,
+                                        target_container,
+                                        &indexes,
                                     )
-                                },
-                            );
+                                    .unwrap_or_default()
+                                }
+                                _ => 0,
+                            };
+
+                            let files = match (
+                                &transfer_state.source_file,
+                                transfer_state.source_container,
+                                &transfer_state.target_file,
+                                transfer_state.target_container,
+                            ) {
+                                (
+                                    Some(source),
+                                    Some(source_container),
+                                    Some(target),
+                                    Some(target_container),
+                                ) if source.filename() == target.filename() => {
+                                    let mut transferred = source.clone();
+
+                                    transfer::transfer_items_within_file(
+                                        &mut transferred,
+                                        source_container,
+                                        target_container,
+                                        &indexes,
+                                        mode,
+                                    )
+                                    .map(|_| vec![(source.clone(), transferred)])
+                                }
+                                (
+                                    Some(source),
+                                    Some(source_container),
+                                    Some(target),
+                                    Some(target_container),
+                                ) => {
+                                    let mut transferred_source = source.clone();
+                                    let mut transferred_target = target.clone();
+
+                                    transfer::transfer_items(
+                                        &mut transferred_source,
+                                        source_container,
+                                        &mut transferred_target,
+                                        target_container,
+                                        &indexes,
+                                        mode,
+                                    )
+                                    .map(|_| match mode {
+                                        TransferMode::Move => vec![
+                                            (source.clone(), transferred_source),
+                                            (target.clone(), transferred_target),
+                                        ],
+                                        TransferMode::Copy => {
+                                            vec![(target.clone(), transferred_target)]
+                                        }
+                                    })
+                                }
+                                _ => Err(anyhow!("choose a source and a target to transfer items")),
+                            };
+
+                            match files {
+                                Ok(files) => {
+                                    self.transfer_state.is_transferring = true;
+                                    self.transfer_state.items_losing_cosmetics =
+                                        items_losing_cosmetics;
+
+                                    return Command::perform(
+                                        interaction::transfer::save_transferred_files(
+                                            self.config.backup_dir().to_path_buf(),
+                                            self.config.backup_retention(),
+                                            self.config.saves_dir().to_path_buf(),
+                                            files,
+                                        ),
+                                        |r| {
+                                            Bl3Message::Interaction(
+                                                InteractionMessage::TransferInteraction(
+                                                    TransferInteractionMessage::TransferCompleted(
+                                                        MessageResult::handle_result(r),
+                                                    ),
+                                                ),
+                                            )
+                                        },
+                                    );
+                                }
+                                Err(e) => e.handle_ui_error(
+                                    "Failed to transfer items",
+                                    &mut self.notification,
+                                ),
+                            }
                         }
-                        SettingsInteractionMessage::ChangeBackupDirCompleted(choose_dir_res) => {
-                            self.settings_state.choose_backup_dir_window_open = false;
+                        TransferInteractionMessage::TransferCompleted(res) => {
+                            self.transfer_state.is_transferring = false;
+
+                            match res {
+                                MessageResult::Success(mut files) => {
+                                    files.sort();
+
+                                    self.loaded_files = files;
+
+                                    let selected_file = self
+                                        .loaded_files
+                                        .iter()
+                                        .find(|f| {
+                                            f.filename() == self.loaded_files_selected.filename()
+                                        })
+                                        .or_else(|| self.loaded_files.get(0))
+                                        .expect("loaded_files was empty")
+                                        .clone();
+
+                                    // Only remap the open file if it was transferred to or from,
+                                    // it had no unsaved changes so the editor loses nothing.
+                                    let selected_file_changed =
+                                        selected_file != *self.loaded_files_selected;
+
+                                    self.loaded_files_selected = Box::new(selected_file);
+
+                                    if selected_file_changed {
+                                        state_mappers::map_loaded_file_to_state(self)
+                                            .handle_ui_error(
+                                                "Failed to map loaded file to editor",
+                                                &mut self.notification,
+                                            );
+                                    }
 
-                            match choose_dir_res {
-                                MessageResult::Success(dir) => {
-                                    self.config.set_backup_dir(dir);
-                                    self.settings_state.backup_dir_input =
-                                        self.config.backup_dir().to_string_lossy().to_string();
+                                    state_mappers::transfer::map_files_to_transfer_state(
+                                        &mut self.transfer_state,
+                                        &self.loaded_files,
+                                    );
 
-                                    return Command::perform(self.config.clone().save(), |r| {
-                                        Bl3Message::Config(ConfigMessage::SaveCompleted(
-                                            MessageResult::handle_result(r),
+                                    let items_losing_cosmetics =
+                                        self.transfer_state.items_losing_cosmetics;
+
+                                    self.notification = if items_losing_cosmetics > 0 {
+                                        Some(Notification::new(
+                                            format!(
+                                                "Transferred items, {} item(s) lost their weapon skin or cosmetic parts as only the inventory can store them.",
+                                                items_losing_cosmetics
+                                            ),
+                                            NotificationSentiment::Info,
                                         ))
-                                    });
+                                    } else {
+                                        Some(Notification::new(
+                                            "Successfully transferred items!",
+                                            NotificationSentiment::Positive,
+                                        ))
+                                    };
                                 }
                                 MessageResult::Error(e) => {
-                                    let msg = format!("Failed to choose backups folder: {}", e);
+                                    let msg = format!("Failed to transfer items: {}", e);
 
                                     error!("{}", msg);
 
@@ -1240,64 +3258,92 @@ impl Application for Bl3Application {
                                 }
                             }
                         }
-                        SettingsInteractionMessage::OpenSavesDir => {
-                            return Command::perform(
-                                interaction::settings::open_dir(
-                                    self.config.saves_dir().to_path_buf(),
-                                ),
-                                |r| {
-                                    Bl3Message::Interaction(
-                                        InteractionMessage::SettingsInteraction(
-                                            SettingsInteractionMessage::OpenSavesDirCompleted(
-                                                MessageResult::handle_result(r),
-                                            ),
-                                        ),
-                                    )
-                                },
-                            );
+                    },
+                    InteractionMessage::BackupsInteraction(backups_msg) => match backups_msg {
+                        BackupsInteractionMessage::Refresh => {
+                            return self.load_backups();
                         }
-                        SettingsInteractionMessage::OpenSavesDirCompleted(res) => {
-                            res.handle_ui_error(
-                                "Failed to open saves folder",
-                                &mut self.notification,
-                            );
+                        BackupsInteractionMessage::RefreshCompleted(res) => {
+                            self.backups_state.is_loading = false;
+
+                            match res {
+                                MessageResult::Success(backups) => {
+                                    self.backups_state.backups =
+                                        backups.into_iter().map(BackupRow::new).collect();
+                                }
+                                MessageResult::Error(e) => {
+                                    let msg = format!("Failed to load backups: {}", e);
+
+                                    error!("{}", msg);
+
+                                    self.notification = Some(Notification::new(
+                                        msg,
+                                        NotificationSentiment::Negative,
+                                    ));
+                                }
+                            }
                         }
-                        SettingsInteractionMessage::ChangeSavesDir => {
-                            self.settings_state.choose_saves_dir_window_open = true;
+                        BackupsInteractionMessage::Restore(index) => {
+                            if let Some(backup) = self
+                                .backups_state
+                                .backups
+                                .get(index)
+                                .map(|row| row.backup.clone())
+                            {
+                                self.backups_state.is_restoring = true;
 
-                            return Command::perform(
-                                interaction::choose_dir(self.config.saves_dir().to_path_buf()),
-                                |r| {
-                                    Bl3Message::Interaction(
-                                        InteractionMessage::SettingsInteraction(
-                                            SettingsInteractionMessage::ChangeSavesDirCompleted(
-                                                MessageResult::handle_result(r),
+                                return Command::perform(
+                                    interaction::backups::restore_backup(
+                                        self.config.backup_dir().to_path_buf(),
+                                        self.config.saves_dir().to_path_buf(),
+                                        backup,
+                                        self.loaded_file_hashes(),
+                                    ),
+                                    |r| {
+                                        Bl3Message::Interaction(
+                                            InteractionMessage::BackupsInteraction(
+                                                BackupsInteractionMessage::RestoreCompleted(
+                                                    MessageResult::handle_result(r),
+                                                ),
                                             ),
-                                        ),
-                                    )
-                                },
-                            );
+                                        )
+                                    },
+                                );
+                            }
                         }
-                        SettingsInteractionMessage::ChangeSavesDirCompleted(choose_dir_res) => {
-                            self.settings_state.choose_saves_dir_window_open = false;
+                        BackupsInteractionMessage::RestoreCompleted(res) => {
+                            self.backups_state.is_restoring = false;
 
-                            match choose_dir_res {
-                                MessageResult::Success(dir) => {
-                                    self.view_state = ViewState::Loading;
+                            match res {
+                                MessageResult::Success((restored_file, mut files)) => {
+                                    files.sort();
 
-                                    return Command::perform(
-                                        interaction::choose_save_directory::load_files_in_directory(
-                                            dir,
-                                        ),
-                                        |r| {
-                                            Bl3Message::ChooseSave(ChooseSaveMessage::FilesLoaded(
-                                                MessageResult::handle_result(r),
-                                            ))
-                                        },
+                                    self.loaded_files = files;
+
+                                    let selected_file = self
+                                        .loaded_files
+                                        .iter()
+                                        .find(|f| **f == restored_file)
+                                        .or_else(|| self.loaded_files.get(0))
+                                        .expect("loaded_files was empty")
+                                        .clone();
+
+                                    self.loaded_files_selected = Box::new(selected_file);
+
+                                    state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+                                        "Failed to map loaded file to editor",
+                                        &mut self.notification,
                                     );
+
+                                    self.notification = Some(Notification::new(
+                                        "Successfully restored backup!",
+                                        NotificationSentiment::Positive,
+                                    ));
+
+                                    return self.load_backups();
                                 }
                                 MessageResult::Error(e) => {
-                                    let msg = format!("Failed to choose saves folder: {}", e);
+                                    let msg = format!("Failed to restore backup: {}", e);
 
                                     error!("{}", msg);
 
@@ -1308,34 +3354,67 @@ impl Application for Bl3Application {
                                 }
                             }
                         }
-                        SettingsInteractionMessage::DecreaseUIScale => {
-                            if self.settings_state.ui_scale_factor >= 0.50 {
-                                self.settings_state.ui_scale_factor -= 0.05;
+                        BackupsInteractionMessage::KeepPerCharacter(keep_per_character) => {
+                            self.backups_state.keep_per_character_input = keep_per_character;
 
-                                self.config
-                                    .set_ui_scale_factor(self.settings_state.ui_scale_factor);
+                            self.config.set_backup_retention(BackupRetention {
+                                keep_per_character,
+                                ..self.config.backup_retention()
+                            });
 
-                                return Command::perform(self.config.clone().save(), |r| {
-                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
-                                        MessageResult::handle_result(r),
-                                    ))
-                                });
-                            }
+                            return Command::perform(self.config.clone().save(), |r| {
+                                Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                    MessageResult::handle_result(r),
+                                ))
+                            });
                         }
-                        SettingsInteractionMessage::IncreaseUIScale => {
-                            if self.settings_state.ui_scale_factor < 2.0 {
-                                self.settings_state.ui_scale_factor += 0.05;
+                        BackupsInteractionMessage::MaxAgeDays(max_age_days) => {
+                            self.backups_state.max_age_days_input = max_age_days;
 
-                                self.config
-                                    .set_ui_scale_factor(self.settings_state.ui_scale_factor);
+                            self.config.set_backup_retention(BackupRetention {
+                                max_age_days,
+                                ..self.config.backup_retention()
+                            });
 
-                                return Command::perform(self.config.clone().save(), |r| {
-                                    Bl3Message::Config(ConfigMessage::SaveCompleted(
-                                        MessageResult::handle_result(r),
+                            return Command::perform(self.config.clone().save(), |r| {
+                                Bl3Message::Config(ConfigMessage::SaveCompleted(
+                                    MessageResult::handle_result(r),
+                                ))
+                            });
+                        }
+                        BackupsInteractionMessage::ApplyRetention => {
+                            return Command::perform(
+                                interaction::backups::apply_retention(
+                                    self.config.backup_dir().to_path_buf(),
+                                    self.config.backup_retention(),
+                                ),
+                                |r| {
+                                    Bl3Message::Interaction(InteractionMessage::BackupsInteraction(
+                                        BackupsInteractionMessage::ApplyRetentionCompleted(
+                                            MessageResult::handle_result(r),
+                                        ),
                                     ))
-                                });
-                            }
+                                },
+                            );
                         }
+                        BackupsInteractionMessage::ApplyRetentionCompleted(res) => match res {
+                            MessageResult::Success(removed) => {
+                                self.notification = Some(Notification::new(
+                                    format!("Removed {} old backups", removed),
+                                    NotificationSentiment::Positive,
+                                ));
+
+                                return self.load_backups();
+                            }
+                            MessageResult::Error(e) => {
+                                let msg = format!("Failed to remove old backups: {}", e);
+
+                                error!("{}", msg);
+
+                                self.notification =
+                                    Some(Notification::new(msg, NotificationSentiment::Negative));
+                            }
+                        },
                     },
                     InteractionMessage::LoadedFileSelected(loaded_file) => {
                         self.loaded_files_selected = loaded_file;
@@ -1349,7 +3428,7 @@ impl Application for Bl3Application {
                         self.view_state = ViewState::Loading;
 
                         return Command::perform(
-                            interaction::choose_save_directory::load_files_in_directory(
+                            interaction::choose_save_directory::load_files_in_directory_with_report(
                                 self.config.saves_dir().to_path_buf(),
                             ),
                             |r| {
@@ -1363,6 +3442,11 @@ impl Application for Bl3Application {
                 }
             }
             Bl3Message::ChooseSave(choose_save_msg) => match choose_save_msg {
+                ChooseSaveMessage::SaveDirsDiscovered(save_dirs) => {
+                    self.discovered_save_dirs = save_dirs;
+
+                    self.update_save_dirs();
+                }
                 ChooseSaveMessage::ChooseDirCompleted(choose_dir_res) => {
                     self.choose_save_directory_state.choose_dir_window_open = false;
 
@@ -1371,7 +3455,7 @@ impl Application for Bl3Application {
                             self.view_state = ViewState::Loading;
 
                             return Command::perform(
-                                interaction::choose_save_directory::load_files_in_directory(dir),
+                                interaction::choose_save_directory::load_files_in_directory_with_report(dir),
                                 |r| {
                                     Bl3Message::ChooseSave(ChooseSaveMessage::FilesLoaded(
                                         MessageResult::handle_result(r),
@@ -1390,7 +3474,23 @@ impl Application for Bl3Application {
                     }
                 }
                 ChooseSaveMessage::FilesLoaded(res) => match res {
-                    MessageResult::Success((dir, mut files)) => {
+                    MessageResult::Success((dir, mut files, load_report)) => {
+                        self.choose_save_directory_state.can_continue = !files.is_empty();
+                        self.choose_save_directory_state.load_report = load_report;
+
+                        if files.is_empty() {
+                            let msg = "Failed to load save folder: 没有找到存档或配置文件。";
+
+                            error!("{}", msg);
+
+                            self.view_state = ViewState::ChooseSaveDirectory;
+
+                            self.notification =
+                                Some(Notification::new(msg, NotificationSentiment::Negative));
+
+                            return Command::none();
+                        }
+
                         files.sort();
                         self.loaded_files = files;
 
@@ -1401,15 +3501,23 @@ impl Application for Bl3Application {
                                 .clone(),
                         );
 
-                        state_mappers::map_loaded_file_to_state(self).handle_ui_error(
-                            "Failed to map loaded file to editor",
-                            &mut self.notification,
-                        );
+                        // Files which couldn't be fully read are listed first, the editor is
+                        // opened once they have been looked at
+                        if self.choose_save_directory_state.load_report.is_empty() {
+                            state_mappers::map_loaded_file_to_state(self).handle_ui_error(
+                                "Failed to map loaded file to editor",
+                                &mut self.notification,
+                            );
+                        } else {
+                            self.view_state = ViewState::ChooseSaveDirectory;
+                        }
 
                         self.config.set_saves_dir(dir);
                         self.settings_state.saves_dir_input =
                             self.config.saves_dir().to_string_lossy().to_string();
 
+                        self.update_save_dirs();
+
                         return Command::perform(self.config.clone().save(), |r| {
                             Bl3Message::Config(ConfigMessage::SaveCompleted(
                                 MessageResult::handle_result(r),
@@ -1457,6 +3565,8 @@ impl Application for Bl3Application {
                     );
                 }
                 MessageResult::Error(e) => {
+                    self.file_changed_state.is_saving = false;
+
                     let msg = format!("Failed to save file: {}", e);
 
                     error!("{}", msg);
@@ -1494,6 +3604,8 @@ impl Application for Bl3Application {
                     );
                 }
                 MessageResult::Error(e) => {
+                    self.file_changed_state.is_saving = false;
+
                     let msg = format!("Failed to save profile: {}", e);
 
                     error!("{}", msg);
@@ -1503,12 +3615,20 @@ impl Application for Bl3Application {
                 }
             },
             Bl3Message::FilesLoadedAfterSave(res) => {
+                self.file_changed_state.is_saving = false;
+                self.file_changed_state.changed_files.clear();
+
                 match res {
                     MessageResult::Success((saved_file, mut files)) => {
                         files.sort();
 
                         self.loaded_files = files;
 
+                        state_mappers::transfer::map_files_to_transfer_state(
+                            &mut self.transfer_state,
+                            &self.loaded_files,
+                        );
+
                         let selected_file = self.loaded_files.iter().find(|f| **f == saved_file);
 
                         if let Some(selected_file) = selected_file {
@@ -1551,6 +3671,57 @@ impl Application for Bl3Application {
 
                 self.is_reloading_saves = false;
             }
+            Bl3Message::WatchFiles => {
+                if self.file_changed_state.is_checking
+                    || self.file_changed_state.is_saving
+                    || self.is_reloading_saves
+                {
+                    return Command::none();
+                }
+
+                self.file_changed_state.is_checking = true;
+
+                return Command::perform(
+                    interaction::file_watch::changed_files(
+                        self.config.saves_dir().to_path_buf(),
+                        self.loaded_file_hashes(),
+                    ),
+                    |r| Bl3Message::WatchFilesCompleted(MessageResult::handle_result(r)),
+                );
+            }
+            Bl3Message::WatchFilesCompleted(res) => {
+                self.file_changed_state.is_checking = false;
+
+                match res {
+                    MessageResult::Success(changed_files) => {
+                        // Our own save is still being written or reloaded
+                        if self.file_changed_state.is_saving || self.is_reloading_saves {
+                            return Command::none();
+                        }
+
+                        if let Some(file_name) = self.current_file_name() {
+                            if changed_files.contains(&file_name)
+                                && !self.file_changed_state.changed_files.contains(&file_name)
+                            {
+                                let msg = format!("{} was changed outside the editor.", file_name);
+
+                                info!("{}", msg);
+
+                                self.notification =
+                                    Some(Notification::new(msg, NotificationSentiment::Info));
+                            }
+                        }
+
+                        self.file_changed_state.changed_files = changed_files;
+                    }
+                    MessageResult::Error(e) => {
+                        error!(
+                            "Failed to check for files changed outside the editor: {}",
+                            e
+                        );
+                    }
+                }
+            }
             Bl3Message::ClearNotification => {
                 self.notification = None;
             }
@@ -1559,7 +3730,21 @@ impl Application for Bl3Application {
         Command::none()
     }
 
+    fn subscription(&self) -> Subscription<Self::Message> {
+        match self.view_state {
+            ViewState::ManageSave(_) | ViewState::ManageProfile(_) => {
+                iced::time::every(interaction::file_watch::FILE_WATCH_INTERVAL)
+                    .map(|_| Bl3Message::WatchFiles)
+            }
+            _ => Subscription::none(),
+        }
+    }
+
     fn view(&mut self) -> Element<'_, Self::Message> {
+        let changed_file_name = self
+            .current_file_name()
+            .filter(|f| self.file_changed_state.changed_files.contains(f));
+
         let title = Text::new("无主之地 3 存档编辑器".to_uppercase())
             .font(ST_HEI_TI_LIGHT)
             .size(40)
@@ -1699,6 +3884,9 @@ impl Application for Bl3Application {
             ViewState::ManageSave(manage_save_view) => match manage_save_view {
                 ManageSaveView::TabBar(main_tab_bar_view) => views::manage_save::main::view(
                     &mut self.settings_state,
+                    &mut self.diff_state,
+                    &mut self.transfer_state,
+                    &mut self.backups_state,
                     &mut self.manage_save_state,
                     main_tab_bar_view,
                 ),
@@ -1706,6 +3894,9 @@ impl Application for Bl3Application {
             ViewState::ManageProfile(manage_profile_view) => match manage_profile_view {
                 ManageProfileView::TabBar(main_tab_bar_view) => views::manage_profile::main::view(
                     &mut self.settings_state,
+                    &mut self.diff_state,
+                    &mut self.transfer_state,
+                    &mut self.backups_state,
                     &mut self.manage_profile_state,
                     main_tab_bar_view,
                 ),
@@ -1718,6 +3909,13 @@ impl Application for Bl3Application {
             all_content = all_content.push(notification.view());
         }
 
+        if let Some(file_name) = &changed_file_name {
+            all_content = all_content.push(views::file_changed::view(
+                &mut self.file_changed_state,
+                file_name,
+            ));
+        }
+
         all_content = all_content.push(content);
 
         Container::new(all_content)
@@ -1,12 +1,20 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
 use tracing::info;
 
+use bl3_save_edit_core::resources::data_pack::{init_data_pack, DataPackInfo};
 use bl3_save_edit_core::resources::{
     INVENTORY_BALANCE_PARTS, INVENTORY_INV_DATA_PARTS, INVENTORY_MANUFACTURER_PARTS,
     INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
     LOOTLEMON_ITEMS,
 };
 
-pub async fn load_lazy_data() {
+/// Loads the data pack from the config folder before any of the lazy data, an invalid data pack is
+/// returned as an error after the lazy data has been loaded from the embedded copies.
+pub async fn load_lazy_data(config_dir: PathBuf) -> Result<Option<DataPackInfo>> {
+    let data_pack = init_data_pack(&config_dir);
+
     info!("Loading lazy data...");
 
     let _ = &*INVENTORY_SERIAL_DB;
@@ -16,4 +24,6 @@ pub async fn load_lazy_data() {
     let _ = &*INVENTORY_INV_DATA_PARTS;
     let _ = &*INVENTORY_MANUFACTURER_PARTS;
     let _ = &*LOOTLEMON_ITEMS;
+
+    data_pack
 }
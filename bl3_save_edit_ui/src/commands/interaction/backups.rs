@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Local, NaiveDateTime};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use tracing::{error, info};
+
+use bl3_save_edit_core::backup::{parse_backup_name, profile_backup_name, save_backup_name};
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::commands::interaction::{choose_save_directory, file_watch};
+use crate::config::BackupRetention;
+
+#[derive(Debug, Clone)]
+pub struct BackupFile {
+    pub path: PathBuf,
+    pub created: NaiveDateTime,
+    pub file: Bl3FileType,
+}
+
+async fn backup_paths(backup_dir: &Path) -> Result<Vec<(PathBuf, String, NaiveDateTime)>> {
+    let mut dirs = tokio::fs::read_dir(backup_dir)
+        .await
+        .context("failed to read backups folder")?;
+
+    let mut backups = vec![];
+
+    while let Some(entry) = dirs.next_entry().await? {
+        let path = entry.path();
+
+        if path.is_dir() {
+            continue;
+        }
+
+        if let Some((group, created)) = parse_backup_name(&path) {
+            backups.push((path, group, created));
+        }
+    }
+
+    Ok(backups)
+}
+
+pub async fn load_backups(backup_dir: PathBuf) -> Result<Vec<BackupFile>> {
+    let mut all_data = vec![];
+
+    for (path, _, created) in backup_paths(&backup_dir).await? {
+        match tokio::fs::read(&path).await {
+            Ok(data) => all_data.push((path, created, data)),
+            Err(e) => error!("{}", e),
+        }
+    }
+
+    let mut backups: Vec<BackupFile> = tokio_rayon::spawn(move || {
+        all_data
+            .into_par_iter()
+            .filter_map(|(path, created, data)| {
+                Bl3FileType::from_unknown_data(&path, &data)
+                    .ok()
+                    .map(|file| BackupFile {
+                        path,
+                        created,
+                        file,
+                    })
+            })
+            .collect::<Vec<_>>()
+    })
+    .await;
+
+    backups.sort_by(|a, b| b.created.cmp(&a.created));
+
+    Ok(backups)
+}
+
+/// Removes backups which fall outside of the retention rule, returns the number of backups removed.
+pub async fn apply_retention(backup_dir: PathBuf, retention: BackupRetention) -> Result<usize> {
+    if retention.keep_per_character == 0 && retention.max_age_days == 0 {
+        return Ok(0);
+    }
+
+    let mut groups: BTreeMap<String, Vec<(PathBuf, NaiveDateTime)>> = BTreeMap::new();
+
+    for (path, group, created) in backup_paths(&backup_dir).await? {
+        groups.entry(group).or_default().push((path, created));
+    }
+
+    let oldest_allowed = Local::now().naive_local() - Duration::days(retention.max_age_days as i64);
+
+    let mut removed = 0;
+
+    for (_, mut backups) in groups {
+        backups.sort_by(|a, b| b.1.cmp(&a.1));
+
+        for (i, (path, created)) in backups.into_iter().enumerate() {
+            let too_many = retention.keep_per_character > 0 && i >= retention.keep_per_character;
+            let too_old = retention.max_age_days > 0 && created < oldest_allowed;
+
+            if too_many || too_old {
+                info!("Removing old backup: {}", path.display());
+
+                tokio::fs::remove_file(&path).await?;
+
+                removed += 1;
+            }
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Writes a backup over the file it was made from, the file being replaced is backed up first.
+///
+/// `loaded_files` are the names and hashes of the files the editor has loaded, a file which was
+/// changed outside the editor since then isn't replaced.
+pub async fn restore_backup(
+    backup_dir: PathBuf,
+    saves_dir: PathBuf,
+    backup: BackupFile,
+    loaded_files: Vec<(String, u32)>,
+) -> Result<(Bl3FileType, Vec<Bl3FileType>)> {
+    let (_, all_files) = choose_save_directory::load_files_in_directory(saves_dir.clone()).await?;
+
+    // A backup of "profile.sav" is named "profile-<time>.sav", profiles are matched by this name
+    let original_name = parse_backup_name(&backup.path).map(|(group, _)| group);
+
+    let original = all_files.into_iter().find(|f| match (&backup.file, f) {
+        (
+            Bl3FileType::PcSave(backup_save) | Bl3FileType::Ps4Save(backup_save),
+            Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save),
+        ) => {
+            backup_save.header_type == save.header_type
+                && backup_save.character_data.character.save_game_guid
+                    == save.character_data.character.save_game_guid
+        }
+        (
+            Bl3FileType::PcProfile(backup_profile) | Bl3FileType::Ps4Profile(backup_profile),
+            Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile),
+        ) => {
+            backup_profile.header_type == profile.header_type
+                && original_name.as_deref() == Some(profile.file_name.replace(".sav", "").as_str())
+        }
+        _ => false,
+    });
+
+    let (original_path, backup_name) = match &original {
+        Some(Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save)) => {
+            (saves_dir.join(&save.file_name), save_backup_name(save))
+        }
+        Some(Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile)) => (
+            saves_dir.join(&profile.file_name),
+            profile_backup_name(profile),
+        ),
+        None => bail!("could not find the original file for this backup in the saves folder"),
+    };
+
+    let loaded_file_hash = original.as_ref().and_then(|original| {
+        loaded_files
+            .iter()
+            .find(|(file_name, _)| file_name == original.filename())
+            .map(|(_, file_hash)| *file_hash)
+    });
+
+    if let Some(loaded_file_hash) = loaded_file_hash {
+        if file_watch::file_changed(&original_path, loaded_file_hash) {
+            bail!(
+                "{} was changed outside the editor, reload it before restoring a backup",
+                original_path.display()
+            );
+        }
+    }
+
+    info!(
+        "Making a backup of existing file: {}",
+        original_path.display()
+    );
+
+    tokio::fs::copy(&original_path, backup_dir.join(backup_name)).await?;
+
+    info!(
+        "Restoring backup: {} to {}",
+        backup.path.display(),
+        original_path.display()
+    );
+
+    let data = tokio::fs::read(&backup.path).await?;
+
+    tokio::fs::write(&original_path, &data).await?;
+
+    let restored = Bl3FileType::from_unknown_data(&original_path, &data)?;
+
+    let (_, all_files) = choose_save_directory::load_files_in_directory(saves_dir).await?;
+
+    Ok((restored, all_files))
+}
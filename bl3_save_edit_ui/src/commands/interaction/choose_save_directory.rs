@@ -1,14 +1,96 @@
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::fmt::Formatter;
+use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use tracing::{error, info};
 
 use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::load_warning::{LoadMode, LoadWarning};
 
 use crate::commands::interaction::choose_dir;
 
+const BL3_STEAM_APP_ID: &str = "397540";
+const DOCUMENTS_SAVE_GAMES_PATH: &str = "My Games/Borderlands 3/Saved/SaveGames";
+const MACOS_SAVE_GAMES_PATH: &str =
+    "Library/Application Support/GearboxSoftware/OakGame/Saved/SaveGames";
+
+/// The launcher a save folder belongs to, based on the name of the account folder.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum SaveDirAccount {
+    Steam,
+    Epic,
+    Unknown,
+}
+
+impl SaveDirAccount {
+    fn from_dir_name(name: &str) -> Self {
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_digit()) {
+            // Steam uses the account's 64 bit id
+            Self::Steam
+        } else if name.len() == 32 && name.chars().all(|c| c.is_ascii_hexdigit()) {
+            // Epic uses the account's hex id
+            Self::Epic
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+impl std::fmt::Display for SaveDirAccount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveDirAccount::Steam => write!(f, "Steam"),
+            SaveDirAccount::Epic => write!(f, "Epic"),
+            SaveDirAccount::Unknown => write!(f, "其他"),
+        }
+    }
+}
+
+/// A folder containing saves, either found in one of the game's known save locations or
+/// previously used.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SaveDirLocation {
+    pub account: SaveDirAccount,
+    pub path: PathBuf,
+    pub proton: bool,
+    pub recent: bool,
+}
+
+impl SaveDirLocation {
+    fn new(path: PathBuf, proton: bool) -> Self {
+        let account = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .map(SaveDirAccount::from_dir_name)
+            .unwrap_or(SaveDirAccount::Unknown);
+
+        Self {
+            account,
+            path,
+            proton,
+            recent: false,
+        }
+    }
+}
+
+impl std::fmt::Display for SaveDirLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}", self.account)?;
+
+        if self.proton {
+            write!(f, " / Proton")?;
+        }
+
+        if self.recent {
+            write!(f, " / 最近使用")?;
+        }
+
+        write!(f, "] {}", self.path.display())
+    }
+}
+
 #[cfg(not(target_os = "macos"))]
 pub async fn choose(existing_dir: PathBuf) -> Result<PathBuf> {
     let home_dir = if existing_dir.exists() {
@@ -39,7 +121,210 @@ pub async fn choose(existing_dir: PathBuf) -> Result<PathBuf> {
     choose_dir(default_dir).await
 }
 
+/// Finds every account folder with saves in the known Steam/Epic `SaveGames` layouts, including
+/// Proton and Wine prefixes on Linux.
+pub async fn discover_save_dirs() -> Vec<SaveDirLocation> {
+    tokio_rayon::spawn(move || {
+        let home_dir = dirs::home_dir().unwrap_or_default();
+
+        let mut save_dirs = Vec::new();
+
+        for (save_games_dir, proton) in save_games_dirs(&home_dir) {
+            for account_dir in account_dirs(&save_games_dir) {
+                let account_dir = account_dir.canonicalize().unwrap_or(account_dir);
+
+                if !save_dirs
+                    .iter()
+                    .any(|d: &SaveDirLocation| d.path == account_dir)
+                {
+                    save_dirs.push(SaveDirLocation::new(account_dir, proton));
+                }
+            }
+        }
+
+        save_dirs.sort();
+
+        info!("Found {} save folders", save_dirs.len());
+
+        save_dirs
+    })
+    .await
+}
+
+/// Marks the discovered folders which were used before and adds the other recent folders.
+pub fn with_recent_save_dirs(
+    mut save_dirs: Vec<SaveDirLocation>,
+    recent_saves_dirs: &[PathBuf],
+) -> Vec<SaveDirLocation> {
+    for recent_dir in recent_saves_dirs.iter().filter(|d| d.is_dir()) {
+        let recent_dir_canonical = recent_dir
+            .canonicalize()
+            .unwrap_or_else(|_| recent_dir.clone());
+
+        if let Some(save_dir) = save_dirs
+            .iter_mut()
+            .find(|d| d.path == recent_dir_canonical)
+        {
+            save_dir.recent = true;
+        } else {
+            save_dirs.push(SaveDirLocation {
+                recent: true,
+                ..SaveDirLocation::new(recent_dir.clone(), false)
+            });
+        }
+    }
+
+    save_dirs
+}
+
+/// The `SaveGames` folders which exist, along with whether they are inside a Proton prefix.
+fn save_games_dirs(home_dir: &Path) -> Vec<(PathBuf, bool)> {
+    let mut documents_dirs = vec![home_dir.join("Documents")];
+
+    if let Some(document_dir) = dirs::document_dir() {
+        documents_dirs.push(document_dir);
+    }
+
+    let mut save_games_dirs = documents_dirs
+        .into_iter()
+        .map(|d| (d.join(DOCUMENTS_SAVE_GAMES_PATH), false))
+        .collect::<Vec<_>>();
+
+    save_games_dirs.push((home_dir.join(MACOS_SAVE_GAMES_PATH), false));
+
+    // Proton keeps a Wine prefix per game in each Steam library
+    for library_dir in steam_library_dirs(home_dir) {
+        save_games_dirs.push((
+            library_dir
+                .join("steamapps/compatdata")
+                .join(BL3_STEAM_APP_ID)
+                .join("pfx/drive_c/users/steamuser/Documents")
+                .join(DOCUMENTS_SAVE_GAMES_PATH),
+            true,
+        ));
+    }
+
+    // A plain Wine prefix uses the name of the user
+    if let Ok(wine_users) = std::fs::read_dir(home_dir.join(".wine/drive_c/users")) {
+        for wine_user in wine_users.flatten() {
+            save_games_dirs.push((
+                wine_user
+                    .path()
+                    .join("Documents")
+                    .join(DOCUMENTS_SAVE_GAMES_PATH),
+                false,
+            ));
+        }
+    }
+
+    save_games_dirs.retain(|(d, _)| d.is_dir());
+
+    save_games_dirs
+}
+
+/// The default Steam installs along with any other libraries listed in their `libraryfolders.vdf`.
+fn steam_library_dirs(home_dir: &Path) -> Vec<PathBuf> {
+    let steam_dirs = [
+        home_dir.join(".steam/steam"),
+        home_dir.join(".steam/root"),
+        home_dir.join(".local/share/Steam"),
+        home_dir.join(".var/app/com.valvesoftware.Steam/.local/share/Steam"),
+    ];
+
+    let mut library_dirs: Vec<PathBuf> = Vec::new();
+
+    for steam_dir in steam_dirs.iter().filter(|d| d.is_dir()) {
+        let mut dirs = vec![steam_dir.clone()];
+
+        if let Ok(library_folders) =
+            std::fs::read_to_string(steam_dir.join("steamapps/libraryfolders.vdf"))
+        {
+            dirs.extend(library_folder_paths(&library_folders));
+        }
+
+        for dir in dirs {
+            let dir = dir.canonicalize().unwrap_or(dir);
+
+            if !library_dirs.contains(&dir) {
+                library_dirs.push(dir);
+            }
+        }
+    }
+
+    library_dirs
+}
+
+/// Reads the `"path"` values from Steam's `libraryfolders.vdf`.
+fn library_folder_paths(library_folders: &str) -> Vec<PathBuf> {
+    library_folders
+        .lines()
+        .filter_map(|l| {
+            let mut values = l.split('"').filter(|v| !v.trim().is_empty());
+
+            match (values.next(), values.next()) {
+                (Some("path"), Some(path)) => Some(PathBuf::from(path.replace("\\\\", "\\"))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// The account folders inside a `SaveGames` folder which contain saves.
+fn account_dirs(save_games_dir: &Path) -> Vec<PathBuf> {
+    let mut account_dirs = std::fs::read_dir(save_games_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_dir() && has_save_files(p))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    account_dirs.sort();
+
+    account_dirs
+}
+
+fn has_save_files(dir: &Path) -> bool {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .any(|e| e.path().extension().and_then(OsStr::to_str) == Some("sav"))
+        })
+        .unwrap_or(false)
+}
+
+/// What went wrong while reading the files in the saves folder, files which couldn't be read at all
+/// are in `failed` and anything skipped in the files which were read is in `warnings`.
+#[derive(Debug, Clone, Default)]
+pub struct LoadReport {
+    pub failed: Vec<LoadWarning>,
+    pub warnings: Vec<LoadWarning>,
+}
+
+impl LoadReport {
+    pub fn is_empty(&self) -> bool {
+        self.failed.is_empty() && self.warnings.is_empty()
+    }
+}
+
 pub async fn load_files_in_directory(dir: PathBuf) -> Result<(PathBuf, Vec<Bl3FileType>)> {
+    let (dir, all_files, _) = load_files_in_directory_with_report(dir).await?;
+
+    if all_files.is_empty() {
+        bail!("没有找到存档或配置文件。")
+    }
+
+    Ok((dir, all_files))
+}
+
+/// Reads every file leniently so a single value which can't be read doesn't hide the whole file,
+/// unlike `load_files_in_directory` this doesn't fail when no files could be read.
+pub async fn load_files_in_directory_with_report(
+    dir: PathBuf,
+) -> Result<(PathBuf, Vec<Bl3FileType>, LoadReport)> {
     let start_time = tokio::time::Instant::now();
 
     let mut dirs = tokio::fs::read_dir(&*dir).await?;
@@ -66,18 +351,51 @@ pub async fn load_files_in_directory(dir: PathBuf) -> Result<(PathBuf, Vec<Bl3Fi
         }
     }
 
-    let all_files: Vec<Bl3FileType> = tokio_rayon::spawn(move || {
+    let results = tokio_rayon::spawn(move || {
         all_data
             .par_iter()
-            .filter_map(|(file_name, data)| Bl3FileType::from_unknown_data(file_name, data).ok())
+            .map(|(file_name, data)| {
+                (
+                    file_name.clone(),
+                    Bl3FileType::from_unknown_data_with_mode(file_name, data, LoadMode::Lenient),
+                )
+            })
             .collect::<Vec<_>>()
     })
     .await;
 
-    if all_files.is_empty() {
-        bail!("没有找到存档或配置文件。")
+    let mut all_files = Vec::with_capacity(results.len());
+    let mut load_report = LoadReport::default();
+
+    for (file_name, result) in results {
+        match result {
+            Ok(file) => {
+                load_report
+                    .warnings
+                    .extend(file.load_warnings().iter().cloned());
+
+                all_files.push(file);
+            }
+            Err(e) => {
+                let file_name = file_name
+                    .file_name()
+                    .map(|f| f.to_string_lossy().to_string())
+                    .unwrap_or_default();
+
+                error!("Failed to read {}: {:#}", file_name, e);
+
+                load_report.failed.push(LoadWarning {
+                    file: file_name,
+                    field: "file".to_owned(),
+                    detail: format!("{:#}", e),
+                });
+            }
+        }
     }
 
+    load_report.failed.sort();
+    load_report.warnings.sort();
+
     if let Some(end_time) = tokio::time::Instant::now().checked_duration_since(start_time) {
         info!(
             "Read {} files in {} milliseconds",
@@ -86,5 +404,5 @@ pub async fn load_files_in_directory(dir: PathBuf) -> Result<(PathBuf, Vec<Bl3Fi
         );
     }
 
-    Ok((dir, all_files))
+    Ok((dir, all_files, load_report))
 }
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use tracing::info;
+
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::commands::interaction::choose_file;
+
+pub async fn choose_compare_file(existing_dir: PathBuf) -> Result<Bl3FileType> {
+    let file = choose_file(existing_dir, "Borderlands 3 Save", &["sav"]).await?;
+
+    info!("Loading file to compare: {}", file.display());
+
+    let data = tokio::fs::read(&file)
+        .await
+        .with_context(|| format!("failed to read file: {}", file.display()))?;
+
+    Bl3FileType::from_unknown_data(&file, &data)
+}
@@ -1,18 +1,20 @@
 use std::path::PathBuf;
 
 use anyhow::Result;
-use chrono::Local;
-use tracing::info;
+use tracing::{error, info};
 
+use bl3_save_edit_core::backup;
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 use bl3_save_edit_core::file_helper::Bl3FileType;
 
-use crate::commands::interaction::choose_save_directory;
+use crate::commands::interaction::{backups, choose_save_directory};
+use crate::config::BackupRetention;
 use crate::state_mappers;
 
 pub async fn save_file(
     backup_dir: PathBuf,
+    backup_retention: BackupRetention,
     output_file: PathBuf,
     output: Vec<u8>,
     existing_save: Bl3Save,
@@ -23,19 +25,7 @@ pub async fn save_file(
         existing_save.file_name
     );
 
-    let current_time = Local::now().format("%d-%m-%Y_%H.%M.%S");
-
-    let backup_name = format!(
-        "{}_{}-{}.sav",
-        existing_save.character_data.player_class(),
-        existing_save
-            .character_data
-            .character
-            .preferred_character_name,
-        current_time
-    );
-
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    let backup_name = backup::save_backup_name(&existing_save);
 
     let (existing_save_output, _) = existing_save.as_bytes()?;
 
@@ -45,11 +35,14 @@ pub async fn save_file(
 
     tokio::fs::write(output_file, output).await?;
 
+    remove_old_backups(backup_dir, backup_retention).await;
+
     Ok(new_save)
 }
 
 pub async fn save_profile(
     backup_dir: PathBuf,
+    backup_retention: BackupRetention,
     saves_dir: PathBuf,
     output_file: PathBuf,
     output: Vec<u8>,
@@ -62,15 +55,7 @@ pub async fn save_profile(
         existing_profile.file_name
     );
 
-    let current_time = Local::now().format("%d-%m-%Y_%H.%M.%S");
-
-    let backup_name = format!(
-        "{}-{}.sav",
-        existing_profile.file_name.replace(".sav", ""),
-        current_time
-    );
-
-    let backup_name = sanitize_filename::sanitize(backup_name);
+    let backup_name = backup::profile_backup_name(&existing_profile);
 
     let (existing_profile_output, _) = existing_profile.as_bytes()?;
 
@@ -80,6 +65,8 @@ pub async fn save_profile(
 
     tokio::fs::write(output_file, output).await?;
 
+    remove_old_backups(backup_dir.clone(), backup_retention).await;
+
     if guardian_data_injection_required {
         let guardian_rank = new_profile.profile_data.guardian_rank();
 
@@ -100,6 +87,15 @@ pub async fn save_profile(
     Ok(new_profile)
 }
 
+/// The file has already been saved at this point so failing to clean up backups is only logged.
+pub async fn remove_old_backups(backup_dir: PathBuf, backup_retention: BackupRetention) {
+    match backups::apply_retention(backup_dir, backup_retention).await {
+        Ok(removed) if removed > 0 => info!("Removed {} old backups", removed),
+        Ok(_) => (),
+        Err(e) => error!("Failed to remove old backups: {}", e),
+    }
+}
+
 pub async fn load_files_after_save(
     saves_dir: PathBuf,
     file_saved: Bl3FileType,
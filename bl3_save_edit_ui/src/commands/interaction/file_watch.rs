@@ -0,0 +1,64 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tracing::info;
+
+use bl3_save_edit_core::file_helper;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::load_warning::LoadMode;
+
+/// How often the loaded files are compared to the files in the saves folder.
+pub const FILE_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+pub fn file_hash_on_disk(file: &Path) -> Result<u32> {
+    let data = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+
+    Ok(file_helper::file_hash(&data))
+}
+
+/// Whether the file on disk no longer matches the hash captured when it was loaded, a file which
+/// was removed is not treated as changed as writing it can't lose anything.
+pub fn file_changed(file: &Path, file_hash: u32) -> bool {
+    match file_hash_on_disk(file) {
+        Ok(file_hash_on_disk) => file_hash_on_disk != file_hash,
+        Err(_) => false,
+    }
+}
+
+/// The names of the loaded files which were written by something else (the game or Steam Cloud)
+/// since they were loaded.
+pub async fn changed_files(saves_dir: PathBuf, files: Vec<(String, u32)>) -> Result<Vec<String>> {
+    let changed_files = tokio_rayon::spawn(move || {
+        files
+            .into_iter()
+            .filter(|(file_name, file_hash)| file_changed(&saves_dir.join(file_name), *file_hash))
+            .map(|(file_name, _)| file_name)
+            .collect::<Vec<_>>()
+    })
+    .await;
+
+    if !changed_files.is_empty() {
+        info!(
+            "Files changed outside the editor: {}",
+            changed_files.join(", ")
+        );
+    }
+
+    Ok(changed_files)
+}
+
+/// Reads a single file again, it is read leniently the same as when the saves folder is loaded.
+pub async fn reload_file(saves_dir: PathBuf, file_name: String) -> Result<Bl3FileType> {
+    let path = saves_dir.join(&file_name);
+
+    let data = tokio::fs::read(&path)
+        .await
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    tokio_rayon::spawn(move || {
+        Bl3FileType::from_unknown_data_with_mode(&path, &data, LoadMode::Lenient)
+            .with_context(|| format!("failed to load {}", file_name))
+    })
+    .await
+}
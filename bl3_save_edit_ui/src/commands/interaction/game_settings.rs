@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use tracing::info;
+
+use bl3_save_edit_core::bl3_profile::game_settings::GameSettingsPreset;
+
+use crate::commands::interaction::{choose_dir, choose_file};
+
+pub async fn export_game_settings_preset(
+    existing_dir: PathBuf,
+    preset: GameSettingsPreset,
+) -> Result<PathBuf> {
+    let output = toml::to_string_pretty(&preset).context("failed to serialize game settings")?;
+
+    let dir = choose_dir(existing_dir).await?;
+
+    let file = dir.join(format!(
+        "game_settings_{}.toml",
+        Local::now().format("%Y%m%d_%H%M%S")
+    ));
+
+    info!("Exporting game settings to: {}", file.display());
+
+    tokio::fs::write(&file, output)
+        .await
+        .with_context(|| format!("failed to write file: {}", file.display()))?;
+
+    Ok(file)
+}
+
+pub async fn import_game_settings_preset(existing_dir: PathBuf) -> Result<GameSettingsPreset> {
+    let file = choose_file(existing_dir, "Game Settings Preset", &["toml"]).await?;
+
+    info!("Importing game settings from: {}", file.display());
+
+    let data = tokio::fs::read(&file)
+        .await
+        .with_context(|| format!("failed to read file: {}", file.display()))?;
+
+    toml::from_slice(&data).with_context(|| format!("failed to parse file: {}", file.display()))
+}
@@ -2,10 +2,15 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 
+pub mod backups;
 pub mod choose_save_directory;
+pub mod diff;
 pub mod file_save;
+pub mod file_watch;
+pub mod game_settings;
 pub mod manage_save;
 pub mod settings;
+pub mod transfer;
 
 #[cfg(not(target_os = "macos"))]
 pub async fn choose_dir(existing_dir: PathBuf) -> Result<PathBuf> {
@@ -44,3 +49,52 @@ pub async fn choose_dir(existing_dir: PathBuf) -> Result<PathBuf> {
 
     Ok(res)
 }
+
+#[cfg(not(target_os = "macos"))]
+pub async fn choose_file(
+    existing_dir: PathBuf,
+    filter_name: &'static str,
+    extensions: &'static [&'static str],
+) -> Result<PathBuf> {
+    use native_dialog::FileDialog;
+
+    let mut file_dialog = FileDialog::new().add_filter(filter_name, extensions);
+
+    if existing_dir.exists() {
+        file_dialog = file_dialog.set_location(&existing_dir);
+    }
+
+    let res = file_dialog
+        .show_open_single_file()?
+        .context("No file was selected.")?;
+
+    Ok(res)
+}
+
+#[cfg(target_os = "macos")]
+pub async fn choose_file(
+    existing_dir: PathBuf,
+    _filter_name: &'static str,
+    extensions: &'static [&'static str],
+) -> Result<PathBuf> {
+    use native_dialog::{Dialog, OpenSingleFile};
+
+    let mut default_dir = None;
+
+    if existing_dir.exists() {
+        let existing_dir_str = existing_dir.to_str().unwrap_or("");
+
+        if !existing_dir_str.is_empty() {
+            default_dir = Some(existing_dir_str);
+        }
+    }
+
+    let dialog = OpenSingleFile {
+        dir: default_dir,
+        filter: Some(extensions),
+    };
+
+    let res = dialog.show()?.context("No file was selected.")?;
+
+    Ok(res)
+}
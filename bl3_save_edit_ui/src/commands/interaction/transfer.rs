@@ -0,0 +1,120 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use tracing::{error, info};
+
+use bl3_save_edit_core::backup;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::commands::interaction::{choose_save_directory, file_save, file_watch};
+use crate::config::BackupRetention;
+
+fn file_as_bytes(file: &Bl3FileType) -> Result<Vec<u8>> {
+    let output = match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => save.as_bytes()?.0,
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => profile.as_bytes()?.0,
+    };
+
+    Ok(output)
+}
+
+fn backup_name(file: &Bl3FileType) -> String {
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => backup::save_backup_name(save),
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => {
+            backup::profile_backup_name(profile)
+        }
+    }
+}
+
+fn temp_path(output_file: &Path) -> PathBuf {
+    output_file.with_extension("sav.tmp")
+}
+
+/// Saves every file changed by a transfer, each pair is the file as it was loaded and the file after the transfer.
+/// All files are written next to the originals first and only then swapped in, so a failure part way through
+/// leaves the saves folder as it was.
+pub async fn save_transferred_files(
+    backup_dir: PathBuf,
+    backup_retention: BackupRetention,
+    saves_dir: PathBuf,
+    files: Vec<(Bl3FileType, Bl3FileType)>,
+) -> Result<Vec<Bl3FileType>> {
+    let mut outputs = Vec::with_capacity(files.len());
+
+    for (existing_file, transferred_file) in &files {
+        let output_file = saves_dir.join(transferred_file.filename());
+
+        if file_watch::file_changed(&output_file, existing_file.file_hash()) {
+            bail!(
+                "{} was changed outside the editor, reload it before transferring items",
+                existing_file.filename()
+            );
+        }
+
+        let existing_output = file_as_bytes(existing_file)
+            .with_context(|| format!("failed to read {}", existing_file.filename()))?;
+
+        let output = file_as_bytes(transferred_file)
+            .with_context(|| format!("failed to write {}", transferred_file.filename()))?;
+
+        outputs.push((
+            output_file,
+            backup_name(existing_file),
+            existing_output,
+            output,
+        ));
+    }
+
+    for (output_file, backup_name, existing_output, _) in &outputs {
+        info!(
+            "Making a backup of existing file: {}",
+            output_file.display()
+        );
+
+        tokio::fs::write(backup_dir.join(backup_name), existing_output).await?;
+    }
+
+    for (output_file, _, _, output) in &outputs {
+        if let Err(e) = tokio::fs::write(temp_path(output_file), output).await {
+            remove_temp_files(&outputs).await;
+
+            return Err(e).with_context(|| format!("failed to write {}", output_file.display()));
+        }
+    }
+
+    for (i, (output_file, _, _, _)) in outputs.iter().enumerate() {
+        info!("Saving file: {}", output_file.display());
+
+        if let Err(e) = tokio::fs::rename(temp_path(output_file), output_file).await {
+            // Put back the files which were already swapped in
+            for (restore_file, _, existing_output, _) in &outputs[..i] {
+                if let Err(e) = tokio::fs::write(restore_file, existing_output).await {
+                    error!("Failed to restore {}: {}", restore_file.display(), e);
+                }
+            }
+
+            remove_temp_files(&outputs).await;
+
+            return Err(e).with_context(|| format!("failed to save {}", output_file.display()));
+        }
+    }
+
+    file_save::remove_old_backups(backup_dir, backup_retention).await;
+
+    let (_, all_files) = choose_save_directory::load_files_in_directory(saves_dir).await?;
+
+    Ok(all_files)
+}
+
+async fn remove_temp_files(outputs: &[(PathBuf, String, Vec<u8>, Vec<u8>)]) {
+    for (output_file, _, _, _) in outputs {
+        let temp_file = temp_path(output_file);
+
+        if temp_file.exists() {
+            if let Err(e) = tokio::fs::remove_file(&temp_file).await {
+                error!("Failed to remove {}: {}", temp_file.display(), e);
+            }
+        }
+    }
+}
@@ -1,15 +1,19 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use tokio::io::AsyncWriteExt;
 use tracing::info;
 
+use bl3_save_edit_core::bl3_save::player_color::PlayerColorPreset;
+
 use crate::bl3_ui::MessageResult;
 
 const CONFIG_DIR: &str = "bl3_save_editor";
 const BACKUP_DIR: &str = "backups";
 const CONFIG_NAME: &str = "config.toml";
+const PLAYER_COLOR_PRESETS_NAME: &str = "player_color_presets.toml";
+const MAX_RECENT_SAVES_DIRS: usize = 10;
 
 #[derive(Debug, Clone)]
 pub enum ConfigMessage {
@@ -24,6 +28,21 @@ pub struct Bl3Config {
     saves_dir: PathBuf,
     #[serde(default = "default_scale_factor")]
     ui_scale_factor: f64,
+    // Plain values have to come before tables in TOML
+    #[serde(default)]
+    recent_saves_dirs: Vec<PathBuf>,
+    #[serde(default)]
+    backup_retention: BackupRetention,
+}
+
+/// Rule used to remove old backups, a value of `0` disables that part of the rule.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
+pub struct BackupRetention {
+    /// Backups are counted per save file (or profile) they were made from.
+    #[serde(default)]
+    pub keep_per_character: usize,
+    #[serde(default)]
+    pub max_age_days: u32,
 }
 
 fn default_scale_factor() -> f64 {
@@ -69,6 +88,8 @@ impl Bl3Config {
                 backup_dir,
                 saves_dir: Default::default(),
                 ui_scale_factor: default_scale_factor(),
+                recent_saves_dirs: Vec::new(),
+                backup_retention: BackupRetention::default(),
             }
         }
     }
@@ -112,10 +133,19 @@ impl Bl3Config {
         &self.saves_dir
     }
 
+    /// Also keeps the folder at the top of the recent folders.
     pub fn set_saves_dir(&mut self, dir: PathBuf) {
+        self.recent_saves_dirs.retain(|d| *d != dir);
+        self.recent_saves_dirs.insert(0, dir.clone());
+        self.recent_saves_dirs.truncate(MAX_RECENT_SAVES_DIRS);
+
         self.saves_dir = dir;
     }
 
+    pub fn recent_saves_dirs(&self) -> &[PathBuf] {
+        &self.recent_saves_dirs
+    }
+
     pub fn ui_scale_factor(&self) -> f64 {
         self.ui_scale_factor
     }
@@ -123,4 +153,51 @@ impl Bl3Config {
     pub fn set_ui_scale_factor(&mut self, ui_scale_factor: f64) {
         self.ui_scale_factor = ui_scale_factor;
     }
+
+    pub fn backup_retention(&self) -> BackupRetention {
+        self.backup_retention
+    }
+
+    pub fn set_backup_retention(&mut self, backup_retention: BackupRetention) {
+        self.backup_retention = backup_retention;
+    }
+}
+
+/// Named player colors, kept next to the config so they can be used with any save.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PlayerColorPresets {
+    #[serde(default)]
+    pub presets: Vec<PlayerColorPreset>,
+}
+
+impl PlayerColorPresets {
+    /// A missing file means no presets have been saved yet. A file which can't be read or parsed
+    /// is returned as an error so the presets in it are not overwritten by the next save.
+    pub fn load(config_dir: &Path) -> Result<Self> {
+        let presets_file = config_dir.join(PLAYER_COLOR_PRESETS_NAME);
+
+        if !presets_file.exists() {
+            return Ok(Self::default());
+        }
+
+        let data = std::fs::read(&presets_file)
+            .with_context(|| format!("failed to read file: {}", presets_file.display()))?;
+
+        toml::from_slice(&data)
+            .with_context(|| format!("failed to parse file: {}", presets_file.display()))
+    }
+
+    pub async fn save(self, config_dir: PathBuf) -> Result<()> {
+        info!("Saving player color presets...");
+
+        if !config_dir.exists() {
+            tokio::fs::create_dir_all(&config_dir).await?;
+        }
+
+        let output = toml::to_vec(&self)?;
+
+        tokio::fs::write(config_dir.join(PLAYER_COLOR_PRESETS_NAME), output).await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,67 @@
+use anyhow::{Context, Result};
+
+use bl3_save_edit_core::bl3_profile::game_settings::GameSettingValue;
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::game_settings::GameSettingField;
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_game_settings_state(manage_profile_state: &mut ManageProfileState) {
+    let profile_data = &manage_profile_state.current_file.profile_data;
+
+    let game_settings_state = &mut manage_profile_state.profile_view_state.game_settings_state;
+
+    game_settings_state.settings = profile_data
+        .game_settings()
+        .into_iter()
+        .map(GameSettingField::new)
+        .collect();
+
+    let key_bindings = profile_data
+        .game_settings_preset()
+        .key_bindings
+        .unwrap_or_default();
+
+    game_settings_state.key_binding_categories = key_bindings.len();
+
+    game_settings_state.key_bindings = key_bindings
+        .iter()
+        .map(|c| c.buttons.len() + c.axes.len())
+        .sum();
+}
+
+pub fn map_game_settings_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) -> Result<()> {
+    let game_settings_state = &manage_profile_state.profile_view_state.game_settings_state;
+
+    for field in &game_settings_state.settings {
+        let setting = &field.setting;
+
+        // Number settings are edited as text, the original value decides how it is parsed
+        let value = match setting.value {
+            GameSettingValue::Bool(v) => GameSettingValue::Bool(v),
+            GameSettingValue::Int(_) => field
+                .input
+                .trim()
+                .parse()
+                .map(GameSettingValue::Int)
+                .with_context(|| format!("{} must be a whole number", setting.name))?,
+            GameSettingValue::Float(_) => {
+                field
+                    .input
+                    .trim()
+                    .parse()
+                    .map(GameSettingValue::Float)
+                    .with_context(|| format!("{} must be a number", setting.name))?
+            }
+        };
+
+        profile
+            .profile_data
+            .set_game_setting(setting.section, setting.name, value)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+
+use bl3_save_edit_core::bl3_item::Bl3Item;
+
+use crate::views::manage_profile::mail::MailField;
+use crate::views::manage_profile::ManageProfileState;
+
+// Mail dates are stored as .NET ticks, 100 nanosecond intervals since 0001-01-01
+const TICKS_PER_SECOND: i64 = 10_000_000;
+const TICKS_AT_UNIX_EPOCH: i64 = 621_355_968_000_000_000;
+
+pub fn ticks_from_date_time(date_time: NaiveDateTime) -> i64 {
+    date_time.timestamp() * TICKS_PER_SECOND + TICKS_AT_UNIX_EPOCH
+}
+
+fn date_time_from_ticks(ticks: i64) -> Option<NaiveDateTime> {
+    let seconds = (ticks - TICKS_AT_UNIX_EPOCH) / TICKS_PER_SECOND;
+
+    NaiveDateTime::from_timestamp_opt(seconds, 0)
+}
+
+fn item_name(item: Option<&Bl3Item>) -> String {
+    match item {
+        Some(item) => {
+            let balance = item.balance_part();
+
+            let name = balance
+                .name
+                .as_ref()
+                .or(balance.short_ident.as_ref())
+                .unwrap_or(&balance.ident);
+
+            format!("{} (Level {})", name, item.level())
+        }
+        None => "-".to_owned(),
+    }
+}
+
+pub fn map_profile_to_mail_state(manage_profile_state: &mut ManageProfileState) {
+    let mail = manage_profile_state.current_file.profile_data.mail();
+
+    manage_profile_state.profile_view_state.mail_state.mail = mail
+        .into_iter()
+        .map(|m| {
+            let item_name = item_name(m.item.as_ref());
+
+            let date_sent = date_time_from_ticks(m.date_sent)
+                .map(|d| d.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "-".to_owned());
+
+            MailField::new(m, item_name, date_sent)
+        })
+        .collect();
+}
@@ -6,9 +6,13 @@ use crate::state_mappers::manage_profile;
 use crate::views::manage_profile::ManageProfileState;
 
 pub mod bank;
+pub mod game_settings;
 pub mod general;
 pub mod keys;
+pub mod lost_loot;
+pub mod mail;
 pub mod profile;
+pub mod vault_card;
 
 pub fn map_all_states_to_profile(
     manage_profile_state: &mut ManageProfileState,
@@ -19,9 +23,19 @@ pub fn map_all_states_to_profile(
     let guardian_data_injection_required =
         manage_profile::profile::map_profile_state_to_profile(manage_profile_state, current_file)?;
 
+    // Vault card chests are edited in the keys tab so it has to be applied after the vault cards
+    manage_profile::vault_card::map_vault_card_state_to_profile(manage_profile_state, current_file);
+
     manage_profile::keys::map_keys_state_to_profile(manage_profile_state, current_file)?;
 
+    manage_profile::game_settings::map_game_settings_state_to_profile(
+        manage_profile_state,
+        current_file,
+    )?;
+
     manage_profile::bank::map_bank_state_to_profile(manage_profile_state, current_file)?;
 
+    manage_profile::lost_loot::map_lost_loot_state_to_profile(manage_profile_state, current_file)?;
+
     Ok(guardian_data_injection_required)
 }
@@ -0,0 +1,82 @@
+use bl3_save_edit_core::bl3_profile::vault_card::VaultCardPreviousChallengeData;
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+
+use crate::views::manage_profile::vault_card::{
+    VaultCardChallengeField, VaultCardGearRewardField, VaultCardState,
+};
+use crate::views::manage_profile::ManageProfileState;
+
+pub fn map_profile_to_vault_card_state(manage_profile_state: &mut ManageProfileState) {
+    let profile_data = &manage_profile_state.current_file.profile_data;
+
+    let vault_card_state = &mut manage_profile_state.profile_view_state.vault_card_state;
+
+    vault_card_state.vault_cards = profile_data.vault_cards();
+
+    vault_card_state.last_active_vault_card_input = profile_data.last_active_vault_card_id();
+
+    // Open the card the player last had active, falling back to the first card
+    vault_card_state.selected_vault_card_id = vault_card_state
+        .vault_cards
+        .iter()
+        .map(|v| v.vault_card_id)
+        .find(|id| *id == vault_card_state.last_active_vault_card_input)
+        .or_else(|| {
+            vault_card_state
+                .vault_cards
+                .first()
+                .map(|v| v.vault_card_id)
+        })
+        .unwrap_or_default();
+
+    vault_card_state.challenge_fields = profile_data
+        .vault_card_previous_challenges()
+        .into_iter()
+        .map(VaultCardChallengeField::new)
+        .collect();
+
+    vault_card_state.challenge_seeds = profile_data.vault_card_challenge_seeds();
+
+    map_vault_card_to_gear_reward_fields(vault_card_state);
+}
+
+pub fn map_vault_card_to_gear_reward_fields(vault_card_state: &mut VaultCardState) {
+    let gear_rewards = vault_card_state
+        .selected_vault_card_mut()
+        .map(|v| v.gear_rewards.clone())
+        .unwrap_or_default();
+
+    vault_card_state.gear_reward_fields = gear_rewards
+        .into_iter()
+        .map(VaultCardGearRewardField::new)
+        .collect();
+}
+
+pub fn map_vault_card_state_to_profile(
+    manage_profile_state: &mut ManageProfileState,
+    profile: &mut Bl3Profile,
+) {
+    let vault_card_state = &manage_profile_state.profile_view_state.vault_card_state;
+
+    for vault_card in &vault_card_state.vault_cards {
+        profile.profile_data.set_vault_card(vault_card);
+    }
+
+    profile
+        .profile_data
+        .set_last_active_vault_card_id(vault_card_state.last_active_vault_card_input);
+
+    let previous_challenges = vault_card_state
+        .challenge_fields
+        .iter()
+        .map(|c| c.challenge)
+        .collect::<Vec<VaultCardPreviousChallengeData>>();
+
+    profile
+        .profile_data
+        .set_vault_card_previous_challenges(&previous_challenges);
+
+    profile
+        .profile_data
+        .set_vault_card_challenge_seeds(&vault_card_state.challenge_seeds);
+}
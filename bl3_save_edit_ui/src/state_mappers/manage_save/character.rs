@@ -1,10 +1,17 @@
 use anyhow::Result;
+use tracing::error;
 
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::ability_tree::SkillPreset;
 use bl3_save_edit_core::bl3_save::ammo::AmmoPool;
 use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
 use bl3_save_edit_core::bl3_save::sdu::SaveSduSlot;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::config::PlayerColorPresets;
+use crate::views::manage_save::character::colors::PlayerColorField;
+use crate::views::manage_save::character::emotes::EmoteSlot;
+use crate::views::manage_save::character::skills::SkillsEditor;
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
@@ -36,6 +43,14 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .character_state
         .ability_points_input = save.character_data.ability_points();
 
+    manage_save_state
+        .save_view_state
+        .character_state
+        .skills_editor = SkillsEditor::new(
+        save.character_data.player_class(),
+        save.character_data.ability_tree(),
+    );
+
     manage_save_state
         .save_view_state
         .character_state
@@ -157,6 +172,74 @@ pub fn map_save_to_character_state(manage_save_state: &mut ManageSaveState) {
         .save_view_state
         .character_state
         .sdu_unlocker = sdu_unlocker;
+
+    manage_save_state
+        .save_view_state
+        .character_state
+        .color_editor
+        .fields = save
+        .character_data
+        .player_colors()
+        .into_iter()
+        .map(PlayerColorField::new)
+        .collect();
+
+    manage_save_state
+        .save_view_state
+        .character_state
+        .emote_selectors
+        .slots = save
+        .character_data
+        .equipped_emotes()
+        .into_iter()
+        .map(EmoteSlot::new)
+        .collect();
+}
+
+/// Emotes can only be picked from the ones unlocked in the profile, without a profile the
+/// equipped emotes can't be changed.
+pub fn map_profile_to_character_emotes(
+    manage_save_state: &mut ManageSaveState,
+    profile: Option<&Bl3Profile>,
+) {
+    manage_save_state
+        .save_view_state
+        .character_state
+        .emote_selectors
+        .unlocked_emotes = profile
+        .map(|p| p.profile_data.unlocked_emotes())
+        .unwrap_or_default();
+}
+
+pub fn map_player_color_presets_to_character_state(
+    manage_save_state: &mut ManageSaveState,
+    player_color_presets: Result<PlayerColorPresets>,
+) {
+    let color_editor = &mut manage_save_state
+        .save_view_state
+        .character_state
+        .color_editor;
+
+    match player_color_presets {
+        Ok(player_color_presets) => {
+            color_editor.presets = player_color_presets.presets;
+            color_editor.presets_load_error = None;
+        }
+        Err(e) => {
+            error!("Failed to load player color presets: {}", e);
+
+            color_editor.presets = Vec::new();
+            color_editor.presets_load_error = Some(e.to_string());
+        }
+    }
+
+    if !color_editor
+        .presets
+        .iter()
+        .any(|p| Some(&p.name) == color_editor.preset_selected.as_ref())
+    {
+        color_editor.preset_selected = None;
+    }
 }
 
 pub fn map_character_state_to_save(
@@ -169,6 +252,8 @@ pub fn map_character_state_to_save(
         .name_input
         .clone();
 
+    let loaded_player_class = save.character_data.player_class();
+
     save.character_data.set_player_level(
         manage_save_state
             .save_view_state
@@ -183,6 +268,17 @@ pub fn map_character_state_to_save(
             .player_class_selected_class,
     )?;
 
+    let skills_editor = &manage_save_state
+        .save_view_state
+        .character_state
+        .skills_editor;
+
+    if skills_editor.changed && save.character_data.player_class() == loaded_player_class {
+        let preset = SkillPreset::from_ability_tree("技能", &skills_editor.to_ability_tree());
+
+        save.character_data.apply_skill_preset(&preset)?;
+    }
+
     save.character_data.set_ability_points(
         manage_save_state
             .save_view_state
@@ -281,5 +377,34 @@ pub fn map_character_state_to_save(
         save.character_data.set_sdu_slot(&s.sdu_slot, s.input);
     }
 
+    let player_colors = manage_save_state
+        .save_view_state
+        .character_state
+        .color_editor
+        .edited_player_colors()?;
+
+    for c in &player_colors {
+        save.character_data.set_player_color(c);
+    }
+
+    let emote_selectors = &manage_save_state
+        .save_view_state
+        .character_state
+        .emote_selectors;
+
+    let equipped_emotes = save.character_data.equipped_emotes();
+
+    for (i, slot) in emote_selectors.slots.iter().enumerate() {
+        if let Some(emote) = slot.selected {
+            if equipped_emotes.get(i) != Some(&Some(emote)) {
+                save.character_data.set_equipped_emote(
+                    i,
+                    &emote,
+                    &emote_selectors.unlocked_emotes,
+                )?;
+            }
+        }
+    }
+
     Ok(())
 }
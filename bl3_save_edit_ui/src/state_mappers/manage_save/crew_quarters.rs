@@ -0,0 +1,174 @@
+use anyhow::Result;
+use rayon::slice::ParallelSliceMut;
+use tracing::info;
+
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::crew_quarters::{
+    available_crew_quarters_rooms, CREW_QUARTERS_ROOMS,
+};
+use bl3_save_edit_core::bl3_save::Bl3Save;
+
+use crate::views::item_editor::item_editor_list_item::ItemEditorListItem;
+use crate::views::item_editor::{sort_items, ItemEditorStateExt};
+use crate::views::manage_save::crew_quarters::{
+    CrewQuartersDecorationField, CrewQuartersDecorationOption, CrewQuartersRoomOption,
+};
+use crate::views::manage_save::ManageSaveState;
+
+/// Rooms and decorations can only be picked from the ones unlocked in the profile, without a
+/// profile only the character's own room can be used and no decorations can be placed.
+pub fn map_profile_to_crew_quarters(
+    manage_save_state: &mut ManageSaveState,
+    profile: Option<&Bl3Profile>,
+) {
+    let crew_quarters_state = &mut manage_save_state.save_view_state.crew_quarters_state;
+
+    crew_quarters_state.unlocked_rooms = profile
+        .map(|p| p.profile_data.unlocked_crew_quarters_rooms())
+        .unwrap_or_default();
+
+    crew_quarters_state.unlocked_decorations = profile
+        .map(|p| p.profile_data.unlocked_crew_quarters_decorations())
+        .unwrap_or_default();
+}
+
+pub fn map_save_to_crew_quarters_room_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let crew_quarters_state = &mut manage_save_state.save_view_state.crew_quarters_state;
+
+    crew_quarters_state.rooms = available_crew_quarters_rooms(
+        character_data.player_class(),
+        &crew_quarters_state.unlocked_rooms,
+    )
+    .into_iter()
+    .map(|(room_data_path, name)| CrewQuartersRoomOption {
+        room_data_path,
+        name,
+    })
+    .collect();
+
+    crew_quarters_state.room_selected = character_data.crew_quarters_room().and_then(|room| {
+        CREW_QUARTERS_ROOMS
+            .iter()
+            .find(|(room_data_path, _)| room_data_path.eq_ignore_ascii_case(room))
+            .map(|(room_data_path, name)| CrewQuartersRoomOption {
+                room_data_path,
+                name,
+            })
+    });
+
+    crew_quarters_state.decoration_options = std::iter::once(CrewQuartersDecorationOption::empty())
+        .chain(
+            crew_quarters_state
+                .unlocked_decorations
+                .iter()
+                .map(CrewQuartersDecorationOption::from_decoration),
+        )
+        .collect();
+
+    crew_quarters_state.decorations = character_data
+        .crew_quarters_decorations()
+        .iter()
+        .map(CrewQuartersDecorationField::new)
+        .collect();
+}
+
+pub fn map_save_to_crew_quarters_state(manage_save_state: &mut ManageSaveState) -> Result<()> {
+    map_save_to_crew_quarters_room_state(manage_save_state);
+
+    let save = &mut manage_save_state.current_file;
+
+    manage_save_state
+        .save_view_state
+        .crew_quarters_state
+        .gun_rack_state
+        .selected_item_index = 0;
+
+    let mut gun_rack_items = save
+        .character_data
+        .gun_rack_items()
+        .iter()
+        .enumerate()
+        .map(|(i, rack_item)| ItemEditorListItem::new(i, rack_item.item.clone()))
+        .collect::<Vec<_>>();
+
+    gun_rack_items.par_sort_by(|a, b| {
+        let a_item = &a.item;
+        let b_item = &b.item;
+
+        sort_items(a_item, b_item)
+    });
+
+    *manage_save_state
+        .save_view_state
+        .crew_quarters_state
+        .gun_rack_state
+        .items_mut() = gun_rack_items;
+
+    manage_save_state
+        .save_view_state
+        .crew_quarters_state
+        .gun_rack_state
+        .item_list_scrollable_state
+        .snap_to(0.0);
+
+    manage_save_state
+        .save_view_state
+        .crew_quarters_state
+        .gun_rack_state
+        .map_current_item_if_exists(|i| {
+            i.editor.available_parts.scrollable_state.snap_to(0.0);
+            i.editor.current_parts.scrollable_state.snap_to(0.0);
+        })?;
+
+    manage_save_state
+        .save_view_state
+        .crew_quarters_state
+        .gun_rack_state
+        .search_items_input
+        .clear();
+
+    Ok(())
+}
+
+pub fn map_crew_quarters_state_to_save(
+    manage_save_state: &mut ManageSaveState,
+    save: &mut Bl3Save,
+) -> Result<()> {
+    let mut gun_rack_items = manage_save_state
+        .save_view_state
+        .crew_quarters_state
+        .gun_rack_state
+        .items()
+        .iter()
+        .map(|i| (i.index, &i.item))
+        .collect::<Vec<_>>();
+
+    gun_rack_items.par_sort_by_key(|(i, _)| *i);
+
+    for (i, edited_item) in gun_rack_items {
+        if let Some(original_item) = save.character_data.gun_rack_items().get(i) {
+            let original_serial_number = original_item.item.get_serial_number(true)?;
+
+            let edited_serial_number = edited_item.get_serial_number(true)?;
+
+            // If the item we have edited has different serial number
+            // Then we replace it
+            if original_serial_number != edited_serial_number {
+                info!("Replacing gun rack item at index: {}", i);
+
+                save.character_data.replace_gun_rack_item(i, edited_item)?;
+            } else {
+                info!("Keeping existing gun rack item at index: {}", i);
+            }
+        } else {
+            // New items go into the rack slot matching their type
+            info!("Adding gun rack item at index: {}", i);
+
+            save.character_data.add_gun_rack_item(edited_item)?;
+        }
+    }
+
+    Ok(())
+}
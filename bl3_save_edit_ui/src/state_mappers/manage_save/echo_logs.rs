@@ -0,0 +1,14 @@
+use crate::views::manage_save::echo_logs::EchoLogField;
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_echo_logs_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let echo_logs_state = &mut manage_save_state.save_view_state.echo_logs_state;
+
+    echo_logs_state.echo_logs = character_data
+        .echo_logs()
+        .into_iter()
+        .map(EchoLogField::new)
+        .collect();
+}
@@ -0,0 +1,32 @@
+use crate::views::manage_save::fast_travel::FastTravelStationField;
+use crate::views::manage_save::missions::PlaythroughOption;
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_fast_travel_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let fast_travel_state = &mut manage_save_state.save_view_state.fast_travel_state;
+
+    fast_travel_state.playthroughs = (0..character_data.playthroughs().len())
+        .map(PlaythroughOption)
+        .collect();
+
+    if !fast_travel_state
+        .playthroughs
+        .contains(&fast_travel_state.playthrough_selected)
+    {
+        fast_travel_state.playthrough_selected = PlaythroughOption::default();
+    }
+
+    let playthrough_index = fast_travel_state.playthrough_selected.0;
+
+    fast_travel_state.stations = character_data
+        .fast_travel_stations(playthrough_index)
+        .into_iter()
+        .map(FastTravelStationField::new)
+        .collect();
+
+    fast_travel_state.spawn_station = character_data
+        .spawn_station(playthrough_index)
+        .map(|s| s.to_owned());
+}
@@ -1,5 +1,7 @@
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
+use crate::views::manage_save::general::PlaythroughField;
+use crate::views::manage_save::missions::PlaythroughOption;
 use crate::views::manage_save::ManageSaveState;
 
 pub fn map_save_to_general_state(manage_save_state: &mut ManageSaveState) {
@@ -20,6 +22,21 @@ pub fn map_save_to_general_state(manage_save_state: &mut ManageSaveState) {
         .save_view_state
         .general_state
         .save_type_selected = save.header_type;
+
+    map_save_to_playthroughs_state(manage_save_state);
+}
+
+pub fn map_save_to_playthroughs_state(manage_save_state: &mut ManageSaveState) {
+    manage_save_state.save_view_state.general_state.playthroughs = manage_save_state
+        .current_file
+        .character_data
+        .playthroughs()
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            PlaythroughField::new(PlaythroughOption(i), p.mayhem_level, p.mayhem_random_seed)
+        })
+        .collect();
 }
 
 pub fn map_general_state_to_save(manage_save_state: &mut ManageSaveState, save: &mut Bl3Save) {
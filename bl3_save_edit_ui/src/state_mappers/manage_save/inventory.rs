@@ -2,6 +2,7 @@ use anyhow::Result;
 use rayon::slice::ParallelSliceMut;
 use tracing::info;
 
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
 use bl3_save_edit_core::bl3_save::Bl3Save;
 
 use crate::views::item_editor::item_editor_list_item::ItemEditorListItem;
@@ -39,6 +40,12 @@ pub fn map_save_to_inventory_state(manage_save_state: &mut ManageSaveState) -> R
         .item_editor_state
         .items_mut() = inventory_items;
 
+    manage_save_state
+        .save_view_state
+        .inventory_state
+        .item_editor_state
+        .update_equipped_slots(&manage_save_state.current_file.character_data);
+
     manage_save_state
         .save_view_state
         .inventory_state
@@ -65,6 +72,26 @@ pub fn map_save_to_inventory_state(manage_save_state: &mut ManageSaveState) -> R
     Ok(())
 }
 
+/// Weapon skins and trinkets can only be picked from the ones unlocked in the profile, without a
+/// profile the current ones can only be removed.
+pub fn map_profile_to_weapon_cosmetics(
+    manage_save_state: &mut ManageSaveState,
+    profile: Option<&Bl3Profile>,
+) {
+    let item_editor_state = &mut manage_save_state
+        .save_view_state
+        .inventory_state
+        .item_editor_state;
+
+    item_editor_state.unlocked_weapon_skins = profile
+        .map(|p| p.profile_data.unlocked_weapon_skins())
+        .unwrap_or_default();
+
+    item_editor_state.unlocked_weapon_trinkets = profile
+        .map(|p| p.profile_data.unlocked_weapon_trinkets())
+        .unwrap_or_default();
+}
+
 pub fn map_inventory_state_to_save(
     manage_save_state: &mut ManageSaveState,
     save: &mut Bl3Save,
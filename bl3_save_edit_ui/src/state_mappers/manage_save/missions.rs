@@ -0,0 +1,55 @@
+use bl3_save_edit_core::bl3_save::mission::{MissionData, MissionStatus, MAIN_STORY_MISSIONS};
+use bl3_save_edit_core::resources::MISSION;
+
+use crate::views::manage_save::missions::{MissionField, PlaythroughOption, StoryMission};
+use crate::views::manage_save::ManageSaveState;
+
+pub fn map_save_to_missions_state(manage_save_state: &mut ManageSaveState) {
+    let character_data = &manage_save_state.current_file.character_data;
+
+    let missions_state = &mut manage_save_state.save_view_state.missions_state;
+
+    missions_state.playthroughs = (0..character_data.playthroughs().len())
+        .map(PlaythroughOption)
+        .collect();
+
+    if !missions_state
+        .playthroughs
+        .contains(&missions_state.playthrough_selected)
+    {
+        missions_state.playthrough_selected = PlaythroughOption::default();
+    }
+
+    let playthrough_index = missions_state.playthrough_selected.0;
+
+    let mut missions = character_data.missions(playthrough_index);
+
+    // Missions which have never been picked up are not in the save so add them from the game data
+    for mission in MISSION.iter() {
+        if !missions
+            .iter()
+            .any(|m| m.mission_class_path.eq_ignore_ascii_case(mission.ident))
+        {
+            missions.push(MissionData {
+                mission_class_path: mission.ident.to_owned(),
+                name: mission.name.to_owned(),
+                status: MissionStatus::NotStarted,
+                objectives_progress: Vec::new(),
+            });
+        }
+    }
+
+    missions.sort_by(|a, b| a.name.cmp(&b.name));
+
+    missions_state.missions = missions.into_iter().map(MissionField::new).collect();
+
+    missions_state.tracked_mission = character_data
+        .tracked_mission(playthrough_index)
+        .map(|t| t.to_owned());
+
+    missions_state.story_missions = MAIN_STORY_MISSIONS
+        .iter()
+        .copied()
+        .map(StoryMission)
+        .collect();
+}
@@ -6,9 +6,13 @@ use crate::state_mappers::manage_save;
 use crate::views::manage_save::ManageSaveState;
 
 pub mod character;
+pub mod crew_quarters;
 pub mod currency;
+pub mod echo_logs;
+pub mod fast_travel;
 pub mod general;
 pub mod inventory;
+pub mod missions;
 pub mod vehicle;
 
 pub fn map_all_states_to_save(
@@ -25,5 +29,7 @@ pub fn map_all_states_to_save(
 
     manage_save::vehicle::map_vehicle_state_to_save(manage_save_state, current_file);
 
+    manage_save::crew_quarters::map_crew_quarters_state_to_save(manage_save_state, current_file)?;
+
     Ok(())
 }
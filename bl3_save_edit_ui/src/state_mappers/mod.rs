@@ -4,12 +4,17 @@ use std::path::PathBuf;
 use anyhow::Result;
 
 use bl3_save_edit_core::bl3_profile::guardian_reward::GuardianRewardData;
+use bl3_save_edit_core::bl3_profile::Bl3Profile;
+use bl3_save_edit_core::bl3_save::Bl3Save;
 use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::parser::HeaderType;
 
 use crate::bl3_ui::Bl3Application;
 use crate::bl3_ui::ViewState;
 use crate::commands::interaction;
 use crate::commands::interaction::choose_save_directory;
+use crate::config::PlayerColorPresets;
+use crate::views::diff::DiffState;
 use crate::views::manage_profile::main::ProfileTabBarView;
 use crate::views::manage_profile::ManageProfileView;
 use crate::views::manage_save::main::SaveTabBarView;
@@ -17,8 +22,24 @@ use crate::views::manage_save::ManageSaveView;
 
 pub mod manage_profile;
 pub mod manage_save;
+pub mod transfer;
+
+/// The profile the save belongs to, `loaded_files` only holds the files of the current saves folder
+/// so this is the profile for the same platform as the save. Without one there is nothing unlocked
+/// to choose from.
+fn profile_for_save<'a>(loaded_files: &'a [Bl3FileType], save: &Bl3Save) -> Option<&'a Bl3Profile> {
+    loaded_files
+        .iter()
+        .find_map(|f| match (save.header_type, f) {
+            (HeaderType::PcSave, Bl3FileType::PcProfile(p))
+            | (HeaderType::Ps4Save, Bl3FileType::Ps4Profile(p)) => Some(p),
+            _ => None,
+        })
+}
 
 pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
+    main_state.diff_state = DiffState::default();
+
     match &*main_state.loaded_files_selected {
         Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => {
             //This file will be the one that gets modified when we press save.
@@ -28,12 +49,46 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
 
             manage_save::character::map_save_to_character_state(&mut main_state.manage_save_state);
 
+            manage_save::character::map_player_color_presets_to_character_state(
+                &mut main_state.manage_save_state,
+                PlayerColorPresets::load(main_state.config.config_dir()),
+            );
+
             manage_save::inventory::map_save_to_inventory_state(&mut main_state.manage_save_state)?;
 
+            let profile = profile_for_save(&main_state.loaded_files, save);
+
+            manage_save::inventory::map_profile_to_weapon_cosmetics(
+                &mut main_state.manage_save_state,
+                profile,
+            );
+
+            manage_save::character::map_profile_to_character_emotes(
+                &mut main_state.manage_save_state,
+                profile,
+            );
+
             manage_save::currency::map_save_to_currency_state(&mut main_state.manage_save_state);
 
             manage_save::vehicle::map_save_to_vehicle_state(&mut main_state.manage_save_state);
 
+            manage_save::missions::map_save_to_missions_state(&mut main_state.manage_save_state);
+
+            manage_save::fast_travel::map_save_to_fast_travel_state(
+                &mut main_state.manage_save_state,
+            );
+
+            manage_save::echo_logs::map_save_to_echo_logs_state(&mut main_state.manage_save_state);
+
+            manage_save::crew_quarters::map_profile_to_crew_quarters(
+                &mut main_state.manage_save_state,
+                profile,
+            );
+
+            manage_save::crew_quarters::map_save_to_crew_quarters_state(
+                &mut main_state.manage_save_state,
+            )?;
+
             if mem::discriminant(&main_state.view_state)
                 != mem::discriminant(&ViewState::ManageSave(ManageSaveView::TabBar(
                 SaveTabBarView::基础,
@@ -56,8 +111,22 @@ pub fn map_loaded_file_to_state(main_state: &mut Bl3Application) -> Result<()> {
 
             manage_profile::keys::map_profile_to_keys_state(&mut main_state.manage_profile_state);
 
+            manage_profile::vault_card::map_profile_to_vault_card_state(
+                &mut main_state.manage_profile_state,
+            );
+
             manage_profile::bank::map_profile_to_bank_state(&mut main_state.manage_profile_state)?;
 
+            manage_profile::lost_loot::map_profile_to_lost_loot_state(
+                &mut main_state.manage_profile_state,
+            )?;
+
+            manage_profile::mail::map_profile_to_mail_state(&mut main_state.manage_profile_state);
+
+            manage_profile::game_settings::map_profile_to_game_settings_state(
+                &mut main_state.manage_profile_state,
+            );
+
             if mem::discriminant(&main_state.view_state)
                 != mem::discriminant(&ViewState::ManageProfile(ManageProfileView::TabBar(
                     ProfileTabBarView::General,
@@ -0,0 +1,80 @@
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::transfer::{container_items, ItemContainer};
+
+use crate::views::transfer::{TransferItemField, TransferState};
+
+fn find_file(files: &[Bl3FileType], file: Option<&Bl3FileType>) -> Option<Bl3FileType> {
+    let file = file?;
+
+    files
+        .iter()
+        .find(|f| f.filename() == file.filename())
+        .cloned()
+}
+
+fn valid_container(
+    file: Option<&Bl3FileType>,
+    container: Option<ItemContainer>,
+) -> Option<ItemContainer> {
+    let containers = ItemContainer::for_file(file?);
+
+    container
+        .filter(|c| containers.contains(c))
+        .or_else(|| containers.first().copied())
+}
+
+/// Refreshes the files which can be transferred between, keeping the chosen files if they are still loaded.
+pub fn map_files_to_transfer_state(transfer_state: &mut TransferState, files: &[Bl3FileType]) {
+    transfer_state.files = files.to_vec();
+
+    transfer_state.source_file = find_file(files, transfer_state.source_file.as_ref());
+    transfer_state.target_file = find_file(files, transfer_state.target_file.as_ref());
+
+    map_transfer_containers(transfer_state);
+}
+
+pub fn map_transfer_containers(transfer_state: &mut TransferState) {
+    transfer_state.source_container = valid_container(
+        transfer_state.source_file.as_ref(),
+        transfer_state.source_container,
+    );
+
+    transfer_state.target_container = valid_container(
+        transfer_state.target_file.as_ref(),
+        transfer_state.target_container,
+    );
+
+    map_transfer_items(transfer_state);
+}
+
+pub fn map_transfer_items(transfer_state: &mut TransferState) {
+    let items = match (&transfer_state.source_file, transfer_state.source_container) {
+        (Some(file), Some(container)) => container_items(file, container).ok(),
+        _ => None,
+    };
+
+    transfer_state.items = items
+        .map(|items| {
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let balance = item.balance_part();
+
+                    TransferItemField {
+                        index,
+                        name: balance
+                            .name
+                            .as_ref()
+                            .or(balance.short_ident.as_ref())
+                            .unwrap_or(&balance.ident)
+                            .to_owned(),
+                        item_type: item.item_type.to_string(),
+                        level: item.level(),
+                        selected: false,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+}
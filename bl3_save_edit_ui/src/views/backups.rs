@@ -0,0 +1,271 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Color, Column, Container, Length, Row,
+    Scrollable, Text,
+};
+
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::commands::interaction::backups::BackupFile;
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default)]
+pub struct BackupsState {
+    pub backups: Vec<BackupRow>,
+    pub is_loading: bool,
+    pub is_restoring: bool,
+    pub keep_per_character_input: usize,
+    keep_per_character_input_state: text_input::State,
+    pub max_age_days_input: u32,
+    max_age_days_input_state: text_input::State,
+    refresh_button_state: button::State,
+    apply_retention_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug)]
+pub struct BackupRow {
+    pub backup: BackupFile,
+    restore_button_state: button::State,
+}
+
+impl BackupRow {
+    pub fn new(backup: BackupFile) -> Self {
+        BackupRow {
+            backup,
+            restore_button_state: button::State::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BackupsInteractionMessage {
+    Refresh,
+    RefreshCompleted(MessageResult<Vec<BackupFile>>),
+    Restore(usize),
+    RestoreCompleted(MessageResult<(Bl3FileType, Vec<Bl3FileType>)>),
+    KeepPerCharacter(usize),
+    MaxAgeDays(u32),
+    ApplyRetention,
+    ApplyRetentionCompleted(MessageResult<usize>),
+}
+
+fn cell<'a>(text: String, color: Color, portion: u16) -> Container<'a, Bl3Message> {
+    Container::new(Text::new(text).font(ST_HEI_TI_LIGHT).size(16).color(color))
+        .width(Length::FillPortion(portion))
+        .align_y(Vertical::Center)
+}
+
+fn describe(file: &Bl3FileType) -> (String, String) {
+    match file {
+        Bl3FileType::PcSave(save) | Bl3FileType::Ps4Save(save) => (
+            format!(
+                "[{}] {} ({})",
+                save.header_type,
+                save.character_data.character.preferred_character_name,
+                save.character_data.player_class()
+            ),
+            save.character_data.player_level().to_string(),
+        ),
+        Bl3FileType::PcProfile(profile) | Bl3FileType::Ps4Profile(profile) => (
+            format!("[{}] {}", profile.header_type, profile.file_name),
+            "-".to_owned(),
+        ),
+    }
+}
+
+pub fn view(backups_state: &mut BackupsState) -> Container<Bl3Message> {
+    let text_color = Color::from_rgb8(220, 220, 220);
+
+    let mut refresh_button = Button::new(
+        &mut backups_state.refresh_button_state,
+        Text::new("刷新").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !backups_state.is_loading {
+        refresh_button = refresh_button.on_press(InteractionMessage::BackupsInteraction(
+            BackupsInteractionMessage::Refresh,
+        ));
+    }
+
+    let retention = Container::new(
+        Row::new()
+            .push(
+                LabelledElement::create(
+                    "每个存档保留",
+                    Length::Units(130),
+                    NumberInput::new(
+                        &mut backups_state.keep_per_character_input_state,
+                        backups_state.keep_per_character_input,
+                        0,
+                        None,
+                        |v| {
+                            InteractionMessage::BackupsInteraction(
+                                BackupsInteractionMessage::KeepPerCharacter(v),
+                            )
+                        },
+                    )
+                    .0
+                    .font(ST_HEI_TI_LIGHT)
+                    .padding(10)
+                    .size(17)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(15)
+                .width(Length::FillPortion(4))
+                .align_items(Alignment::Center),
+            )
+            .push(
+                LabelledElement::create(
+                    "最长保留天数",
+                    Length::Units(130),
+                    NumberInput::new(
+                        &mut backups_state.max_age_days_input_state,
+                        backups_state.max_age_days_input,
+                        0,
+                        None,
+                        |v| {
+                            InteractionMessage::BackupsInteraction(
+                                BackupsInteractionMessage::MaxAgeDays(v),
+                            )
+                        },
+                    )
+                    .0
+                    .font(ST_HEI_TI_LIGHT)
+                    .padding(10)
+                    .size(17)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(15)
+                .width(Length::FillPortion(4))
+                .align_items(Alignment::Center),
+            )
+            .push(
+                Button::new(
+                    &mut backups_state.apply_retention_button_state,
+                    Text::new("清理旧备份").font(ST_HEI_TI_LIGHT).size(17),
+                )
+                .on_press(InteractionMessage::BackupsInteraction(
+                    BackupsInteractionMessage::ApplyRetention,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(refresh_button.into_element())
+            .spacing(10)
+            .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
+    let retention_hint = Text::new("0 表示不限制，每次保存文件后都会自动清理旧备份")
+        .font(ST_HEI_TI_LIGHT)
+        .size(15)
+        .color(Color::from_rgb8(180, 180, 180));
+
+    let mut all_contents = Column::new()
+        .push(retention)
+        .push(retention_hint)
+        .spacing(15);
+
+    if backups_state.backups.is_empty() {
+        let message = if backups_state.is_loading {
+            "正在加载备份..."
+        } else {
+            "备份文件夹中没有找到备份"
+        };
+
+        all_contents = all_contents.push(
+            Container::new(
+                Text::new(message)
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(text_color),
+            )
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center),
+        );
+    } else {
+        let header_color = Color::from_rgb8(242, 203, 5);
+
+        let header = Container::new(
+            Row::new()
+                .push(cell("角色".to_owned(), header_color, 4))
+                .push(cell("等级".to_owned(), header_color, 1))
+                .push(cell("时间".to_owned(), header_color, 3))
+                .push(Container::new(Text::new("")).width(Length::Units(80)))
+                .spacing(10),
+        )
+        .padding(10)
+        .style(Bl3UiStyleNoBorder);
+
+        let is_restoring = backups_state.is_restoring;
+
+        let rows = backups_state.backups.iter_mut().enumerate().fold(
+            Column::new().spacing(5),
+            |curr, (i, row)| {
+                let (name, level) = describe(&row.backup.file);
+
+                let mut restore_button = Button::new(
+                    &mut row.restore_button_state,
+                    Text::new("恢复").font(ST_HEI_TI_LIGHT).size(16),
+                )
+                .width(Length::Units(80))
+                .padding(5)
+                .style(Bl3UiStyle);
+
+                if !is_restoring {
+                    restore_button =
+                        restore_button.on_press(InteractionMessage::BackupsInteraction(
+                            BackupsInteractionMessage::Restore(i),
+                        ));
+                }
+
+                curr.push(
+                    Container::new(
+                        Row::new()
+                            .push(cell(name, text_color, 4))
+                            .push(cell(level, text_color, 1))
+                            .push(cell(
+                                row.backup.created.format("%Y-%m-%d %H:%M:%S").to_string(),
+                                text_color,
+                                3,
+                            ))
+                            .push(restore_button.into_element())
+                            .spacing(10)
+                            .align_items(Alignment::Center),
+                    )
+                    .padding(10)
+                    .style(Bl3UiStyle),
+                )
+            },
+        );
+
+        all_contents = all_contents.push(
+            Container::new(
+                Column::new().push(header).push(
+                    Scrollable::new(&mut backups_state.scrollable_state)
+                        .push(rows)
+                        .height(Length::Fill)
+                        .width(Length::Fill),
+                ),
+            )
+            .height(Length::Fill),
+        );
+    }
+
+    Container::new(all_contents).padding(30)
+}
@@ -1,12 +1,17 @@
 use std::path::PathBuf;
 
 use iced::alignment::{Horizontal, Vertical};
-use iced::{button, Alignment, Button, Color, Column, Container, Length, Text};
+use iced::{
+    button, pick_list, scrollable, Alignment, Button, Color, Column, Container, Length, PickList,
+    Scrollable, Text,
+};
 
 use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::load_warning::LoadWarning;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::Bl3UiStyle;
+use crate::commands::interaction::choose_save_directory::{LoadReport, SaveDirLocation};
 use crate::resources::fonts::{ST_HEI_TI_LIGHT};
 use crate::views::InteractionExt;
 
@@ -14,17 +19,26 @@ use crate::views::InteractionExt;
 pub struct ChooseSaveDirectoryState {
     choose_dir_button_state: button::State,
     pub choose_dir_window_open: bool,
+    pub save_dirs: Vec<SaveDirLocation>,
+    save_dir_selector: pick_list::State<SaveDirLocation>,
+    continue_button_state: button::State,
+    pub can_continue: bool,
+    pub load_report: LoadReport,
+    load_report_scrollable_state: scrollable::State,
 }
 
 #[derive(Debug, Clone)]
 pub enum ChooseSaveMessage {
     ChooseDirCompleted(MessageResult<PathBuf>),
-    FilesLoaded(MessageResult<(PathBuf, Vec<Bl3FileType>)>),
+    SaveDirsDiscovered(Vec<SaveDirLocation>),
+    FilesLoaded(MessageResult<(PathBuf, Vec<Bl3FileType>, LoadReport)>),
 }
 
 #[derive(Debug, Clone)]
 pub enum ChooseSaveInteractionMessage {
     ChooseDirPressed,
+    SaveDirSelected(SaveDirLocation),
+    ContinuePressed,
 }
 
 pub fn view(choose_save_directory_state: &mut ChooseSaveDirectoryState) -> Container<Bl3Message> {
@@ -49,15 +63,114 @@ pub fn view(choose_save_directory_state: &mut ChooseSaveDirectoryState) -> Conta
         ));
     }
 
-    let contents = Column::new()
+    let mut contents = Column::new()
         .push(dir_button_text)
         .push(dir_button.into_element())
         .spacing(20)
         .align_items(Alignment::Center);
 
+    if !choose_save_directory_state.save_dirs.is_empty() {
+        let save_dirs_text = Text::new("或选择找到的存档文件夹：")
+            .font(ST_HEI_TI_LIGHT)
+            .size(18)
+            .color(Color::from_rgb8(220, 220, 220));
+
+        let save_dir_selector = PickList::new(
+            &mut choose_save_directory_state.save_dir_selector,
+            choose_save_directory_state.save_dirs.clone(),
+            None,
+            |d| {
+                InteractionMessage::ChooseSaveInteraction(
+                    ChooseSaveInteractionMessage::SaveDirSelected(d),
+                )
+            },
+        )
+        .font(ST_HEI_TI_LIGHT)
+        .text_size(17)
+        .width(Length::Units(700))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        contents = contents.push(save_dirs_text).push(save_dir_selector);
+    }
+
+    let load_report = &choose_save_directory_state.load_report;
+
+    if !load_report.is_empty() {
+        let report_entry = |w: &LoadWarning, color: Color| {
+            Text::new(format!("{} - {}: {}", w.file, w.field, w.detail))
+                .font(ST_HEI_TI_LIGHT)
+                .size(16)
+                .color(color)
+        };
+
+        let mut report = Column::new().spacing(10);
+
+        if !load_report.failed.is_empty() {
+            report = report.push(
+                Text::new(format!("{} 个文件无法读取:", load_report.failed.len()))
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(18)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            );
+
+            for failed in &load_report.failed {
+                report = report.push(report_entry(failed, Color::from_rgb8(230, 80, 80)));
+            }
+        }
+
+        if !load_report.warnings.is_empty() {
+            report = report.push(
+                Text::new(format!(
+                    "{} 个问题已跳过, 文件的其余部分已读取:",
+                    load_report.warnings.len()
+                ))
+                .font(ST_HEI_TI_LIGHT)
+                .size(18)
+                .color(Color::from_rgb8(220, 220, 220)),
+            );
+
+            for warning in &load_report.warnings {
+                report = report.push(report_entry(warning, Color::from_rgb8(242, 203, 5)));
+            }
+        }
+
+        contents = contents.push(
+            Container::new(
+                Scrollable::new(&mut choose_save_directory_state.load_report_scrollable_state)
+                    .push(report)
+                    .width(Length::Fill)
+                    .height(Length::Units(300)),
+            )
+            .padding(20)
+            .width(Length::Fill)
+            .style(Bl3UiStyle),
+        );
+
+        if choose_save_directory_state.can_continue {
+            contents = contents.push(
+                Button::new(
+                    &mut choose_save_directory_state.continue_button_state,
+                    Text::new("继续")
+                        .horizontal_alignment(Horizontal::Center)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(18),
+                )
+                .on_press(InteractionMessage::ChooseSaveInteraction(
+                    ChooseSaveInteractionMessage::ContinuePressed,
+                ))
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            );
+        }
+    }
+
     Container::new(contents)
         .width(Length::Fill)
         .height(Length::Fill)
+        .padding(30)
         .align_x(Horizontal::Center)
         .align_y(Vertical::Center)
 }
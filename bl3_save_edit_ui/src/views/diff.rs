@@ -0,0 +1,143 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, Alignment, Button, Color, Column, Container, Length, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::diff::DiffEntry;
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Default)]
+pub struct DiffState {
+    pub compare_file_name: Option<String>,
+    pub entries: Vec<DiffEntry>,
+    pub choose_file_window_open: bool,
+    choose_file_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum DiffInteractionMessage {
+    ChooseFilePressed,
+    ChooseFileCompleted(MessageResult<Bl3FileType>),
+}
+
+fn cell<'a>(text: &str, color: Color, portion: u16) -> Container<'a, Bl3Message> {
+    Container::new(Text::new(text).font(ST_HEI_TI_LIGHT).size(16).color(color))
+        .width(Length::FillPortion(portion))
+}
+
+pub fn view(diff_state: &mut DiffState) -> Container<Bl3Message> {
+    let mut choose_file_button = Button::new(
+        &mut diff_state.choose_file_button_state,
+        Text::new("选择要对比的文件").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !diff_state.choose_file_window_open {
+        choose_file_button = choose_file_button.on_press(InteractionMessage::DiffInteraction(
+            DiffInteractionMessage::ChooseFilePressed,
+        ));
+    }
+
+    let summary = match &diff_state.compare_file_name {
+        Some(file_name) => format!(
+            "左侧: {}    右侧: 当前文件    差异: {}",
+            file_name,
+            diff_state.entries.len()
+        ),
+        None => "选择一个文件（例如备份）与当前文件进行对比".to_owned(),
+    };
+
+    let summary_row = Container::new(
+        Row::new()
+            .push(
+                Container::new(
+                    Text::new(summary)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(17)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .width(Length::Fill),
+            )
+            .push(choose_file_button.into_element())
+            .align_items(Alignment::Center),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut all_contents = Column::new().push(summary_row).spacing(20);
+
+    if diff_state.compare_file_name.is_some() && diff_state.entries.is_empty() {
+        all_contents = all_contents.push(
+            Container::new(
+                Text::new("两个文件没有差异")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center),
+        );
+    } else if !diff_state.entries.is_empty() {
+        let header_color = Color::from_rgb8(242, 203, 5);
+
+        let header = Container::new(
+            Row::new()
+                .push(cell("分类", header_color, 1))
+                .push(cell("项目", header_color, 3))
+                .push(cell("左侧", header_color, 3))
+                .push(cell("右侧", header_color, 3))
+                .spacing(10),
+        )
+        .padding(10)
+        .style(Bl3UiStyleNoBorder);
+
+        let rows = diff_state
+            .entries
+            .iter()
+            .fold(Column::new().spacing(5), |curr, entry| {
+                let text_color = Color::from_rgb8(220, 220, 220);
+                let missing_color = Color::from_rgb8(240, 149, 149);
+
+                let side = |value: &Option<String>| match value {
+                    Some(v) => cell(v, text_color, 3),
+                    None => cell("-", missing_color, 3),
+                };
+
+                curr.push(
+                    Container::new(
+                        Row::new()
+                            .push(cell(&entry.section.to_string(), text_color, 1))
+                            .push(cell(&entry.field, text_color, 3))
+                            .push(side(&entry.left))
+                            .push(side(&entry.right))
+                            .spacing(10),
+                    )
+                    .padding(10)
+                    .style(Bl3UiStyle),
+                )
+            });
+
+        all_contents = all_contents.push(
+            Container::new(
+                Column::new().push(header).push(
+                    Scrollable::new(&mut diff_state.scrollable_state)
+                        .push(rows)
+                        .height(Length::Fill)
+                        .width(Length::Fill),
+                ),
+            )
+            .height(Length::Fill),
+        );
+    }
+
+    Container::new(all_contents).padding(30)
+}
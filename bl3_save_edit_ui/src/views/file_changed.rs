@@ -0,0 +1,78 @@
+use iced::{button, Alignment, Button, Color, Container, Element, Length, Row, Text};
+
+use bl3_save_edit_core::file_helper::Bl3FileType;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Default)]
+pub struct FileChangedState {
+    pub changed_files: Vec<String>,
+    pub is_checking: bool,
+    pub is_saving: bool,
+    reload_button_state: button::State,
+    merge_button_state: button::State,
+    overwrite_button_state: button::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum FileChangedInteractionMessage {
+    ReloadPressed,
+    ReloadCompleted(MessageResult<Bl3FileType>),
+    MergePressed,
+    MergeCompleted(MessageResult<Bl3FileType>),
+    OverwritePressed,
+}
+
+fn file_changed_button<'a>(
+    state: &'a mut button::State,
+    text: &str,
+    message: FileChangedInteractionMessage,
+) -> Element<'a, Bl3Message> {
+    Button::new(state, Text::new(text).font(ST_HEI_TI_LIGHT).size(17))
+        .on_press(InteractionMessage::FileChangedInteraction(message))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element()
+}
+
+pub fn view<'a>(
+    file_changed_state: &'a mut FileChangedState,
+    file_name: &str,
+) -> Container<'a, Bl3Message> {
+    let message = Text::new(format!(
+        "{} 已在编辑器外被修改 (例如游戏或 Steam 云存档)。重新加载会放弃未写入的修改, 合并会把未写入的修改应用到新文件上, 覆盖会用编辑器中的内容替换新文件。",
+        file_name
+    ))
+    .font(ST_HEI_TI_LIGHT)
+    .size(17)
+    .color(Color::from_rgb8(242, 203, 5))
+    .width(Length::Fill);
+
+    let contents = Row::new()
+        .push(message)
+        .push(file_changed_button(
+            &mut file_changed_state.reload_button_state,
+            "重新加载",
+            FileChangedInteractionMessage::ReloadPressed,
+        ))
+        .push(file_changed_button(
+            &mut file_changed_state.merge_button_state,
+            "合并",
+            FileChangedInteractionMessage::MergePressed,
+        ))
+        .push(file_changed_button(
+            &mut file_changed_state.overwrite_button_state,
+            "覆盖",
+            FileChangedInteractionMessage::OverwritePressed,
+        ))
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+    Container::new(contents)
+        .width(Length::Fill)
+        .padding(20)
+        .style(Bl3UiStyle)
+}
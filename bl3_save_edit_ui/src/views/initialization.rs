@@ -1,12 +1,14 @@
 use iced::alignment::{Horizontal, Vertical};
 use iced::{Color, Container, Length, Text};
 
-use crate::bl3_ui::Bl3Message;
+use bl3_save_edit_core::resources::data_pack::DataPackInfo;
+
+use crate::bl3_ui::{Bl3Message, MessageResult};
 use crate::resources::fonts::{ST_HEI_TI_LIGHT};
 
 #[derive(Debug, Clone)]
 pub enum InitializationMessage {
-    LoadSaves,
+    LazyDataLoaded(MessageResult<Option<DataPackInfo>>),
 }
 
 pub fn view<'a>() -> Container<'a, Bl3Message> {
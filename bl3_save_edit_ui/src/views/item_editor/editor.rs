@@ -1,6 +1,6 @@
 use iced::{
-    button, searchable_pick_list, text_input, tooltip, Alignment, Column, Container, Length, Row,
-    SearchablePickList, TextInput, Tooltip,
+    button, searchable_pick_list, text_input, tooltip, Alignment, Color, Column, Container, Length,
+    Row, SearchablePickList, Text, TextInput, Tooltip,
 };
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
@@ -8,7 +8,7 @@ use bl3_save_edit_core::bl3_item::{BalancePart, Bl3Item, InvDataPart, Manufactur
 use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
 use bl3_save_edit_core::resources::{
     INVENTORY_BALANCE_PARTS, INVENTORY_INV_DATA_PARTS, INVENTORY_MANUFACTURER_PARTS,
-    INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
+    INVENTORY_PARTS_ALL_CATEGORIZED, INVENTORY_SERIAL_DB, INVENTORY_SERIAL_DB_PARTS_CATEGORIZED,
 };
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
@@ -51,6 +51,10 @@ impl Editor {
     where
         F: Fn(ItemEditorInteractionMessage) -> InteractionMessage + 'static + Copy,
     {
+        if item.is_opaque() {
+            return self.read_only_view(item);
+        }
+
         let inventory_serial_db_parts_categorized = &*INVENTORY_SERIAL_DB_PARTS_CATEGORIZED;
         let inventory_parts_all_categorized = &INVENTORY_PARTS_ALL_CATEGORIZED;
 
@@ -350,4 +354,60 @@ impl Editor {
 
         Container::new(item_editor_contents)
     }
+
+    /// Items which couldn't be fully read (usually from a newer game version) are kept as they are,
+    /// so only their serial is shown.
+    fn read_only_view(&mut self, item: &Bl3Item) -> Container<Bl3Message> {
+        let serial_row = Container::new(
+            LabelledElement::create(
+                "序列号",
+                Length::Units(85),
+                TextInput::new(
+                    &mut self.serial_input_state,
+                    "BL3(AwAAAABmboC7I9xAEzwShMJVX8nPYwsAAA==)",
+                    &self.serial_input,
+                    |_| InteractionMessage::Ignore,
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .select_all_on_click(true)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle);
+
+        let opaque_data = item.opaque_data();
+
+        let unparsed_bits = opaque_data
+            .map(|o| o.unparsed_bits.len())
+            .unwrap_or_default();
+
+        let explanation = match opaque_data.and_then(|o| o.error.as_ref()) {
+            Some(error) => format!(
+                "无法读取此物品 ({}), 它将按原样保存且无法编辑。",
+                error
+            ),
+            None => format!(
+                "无法完整读取此物品 (版本 {}, 最高支持版本 {}, 未解析 {} 位), 它将按原样保存且无法编辑。",
+                item.data_version, INVENTORY_SERIAL_DB.max_version, unparsed_bits
+            ),
+        };
+
+        let contents = Column::new()
+            .push(serial_row)
+            .push(
+                Text::new(explanation)
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .spacing(20);
+
+        Container::new(contents)
+    }
 }
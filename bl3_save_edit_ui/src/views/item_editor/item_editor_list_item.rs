@@ -1,12 +1,19 @@
 use std::convert::TryInto;
+use std::iter;
 
 use anyhow::{bail, Result};
 use iced::alignment::Horizontal;
-use iced::{button, Button, Container, Element, Length, Row, Text};
+use iced::{
+    button, pick_list, Alignment, Button, Color, Container, Element, Length, PickList, Row, Text,
+};
 
-use bl3_save_edit_core::bl3_item::Bl3Item;
+use bl3_save_edit_core::bl3_item::{Bl3Item, ItemType};
+use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
+use bl3_save_edit_core::game_data::GameDataKv;
+use bl3_save_edit_core::resources::{PROFILE_WEAPON_SKINS, PROFILE_WEAPON_TRINKETS};
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
 use crate::resources::fonts::ST_HEI_TI_LIGHT;
 use crate::views::item_editor::editor::Editor;
 use crate::views::item_editor::item_button_style::{
@@ -15,6 +22,12 @@ use crate::views::item_editor::item_button_style::{
 use crate::views::item_editor::{list_item_contents, ItemEditorInteractionMessage};
 use crate::views::InteractionExt;
 
+/// Shown first in the weapon skin and trinket lists to remove the current one.
+const NO_WEAPON_COSMETIC: GameDataKv = GameDataKv {
+    ident: "",
+    name: "无",
+};
+
 #[derive(Debug, Default)]
 pub struct ItemEditorListItem {
     pub index: usize,
@@ -23,6 +36,13 @@ pub struct ItemEditorListItem {
     duplicate_button_state: button::State,
     share_button_state: button::State,
     delete_button_state: button::State,
+    pub equipped_slot: Option<InventorySlot>,
+    pub can_equip: bool,
+    equip_slot_selector: pick_list::State<InventorySlot>,
+    unequip_button_state: button::State,
+    pub equipped_trinket: Option<String>,
+    weapon_skin_selector: pick_list::State<GameDataKv>,
+    weapon_trinket_selector: pick_list::State<GameDataKv>,
     pub editor: Editor,
 }
 
@@ -54,6 +74,8 @@ impl ItemEditorListItem {
         &mut self,
         id: usize,
         is_active: bool,
+        weapon_skins: &[GameDataKv],
+        weapon_trinkets: &[GameDataKv],
         interaction_message: F,
     ) -> (Element<Bl3Message>, Option<Container<Bl3Message>>)
     where
@@ -108,7 +130,144 @@ impl ItemEditorListItem {
             .width(Length::Fill)
             .spacing(10);
 
-        let item_content = list_item_contents::view(&self.item).push(action_row);
+        let mut item_content = list_item_contents::view(&self.item);
+
+        if let Some(equipped_slot) = &self.equipped_slot {
+            item_content = item_content.push(
+                Text::new(format!("已装备: {}", equipped_slot))
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(16)
+                    .color(Color::from_rgb8(242, 203, 5)),
+            );
+        }
+
+        let equip_slots = InventorySlot::ALL
+            .iter()
+            .filter(|s| s.accepts(&self.item.item_type))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut action_row = action_row;
+
+        if self.can_equip && !equip_slots.is_empty() {
+            action_row = action_row.push(
+                PickList::new(
+                    &mut self.equip_slot_selector,
+                    equip_slots,
+                    self.equipped_slot.clone(),
+                    move |s| interaction_message(ItemEditorInteractionMessage::EquipItem(id, s)),
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .text_size(16)
+                .padding(5)
+                .width(Length::Units(120))
+                .style(Bl3UiStyle),
+            );
+
+            if self.equipped_slot.is_some() {
+                action_row = action_row.push(
+                    Button::new(
+                        &mut self.unequip_button_state,
+                        Text::new("卸下")
+                            .font(ST_HEI_TI_LIGHT)
+                            .size(16)
+                            .horizontal_alignment(Horizontal::Center),
+                    )
+                    .on_press(interaction_message(
+                        ItemEditorInteractionMessage::UnequipItem(id),
+                    ))
+                    .padding(5)
+                    .width(Length::Units(85))
+                    .style(ItemEditorListButtonStyle),
+                );
+            }
+        }
+
+        let mut item_content = item_content.push(action_row);
+
+        // Cosmetics are stored in the save next to the item, so only items in the save can use them
+        if self.can_equip && self.item.item_type == ItemType::Weapon {
+            let weapon_skin_selected = match self.item.weapon_skin_path() {
+                Some(path) => PROFILE_WEAPON_SKINS
+                    .iter()
+                    .find(|s| s.ident == path)
+                    .copied(),
+                None => Some(NO_WEAPON_COSMETIC),
+            };
+
+            let mut cosmetics_row = Row::new()
+                .push(
+                    Text::new("武器皮肤")
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(Color::from_rgb8(220, 220, 220)),
+                )
+                .push(
+                    PickList::new(
+                        &mut self.weapon_skin_selector,
+                        iter::once(NO_WEAPON_COSMETIC)
+                            .chain(weapon_skins.iter().copied())
+                            .collect::<Vec<_>>(),
+                        weapon_skin_selected,
+                        move |s| {
+                            interaction_message(ItemEditorInteractionMessage::WeaponSkinSelected(
+                                id, s,
+                            ))
+                        },
+                    )
+                    .font(ST_HEI_TI_LIGHT)
+                    .text_size(16)
+                    .padding(5)
+                    .width(Length::Units(180))
+                    .style(Bl3UiStyle),
+                )
+                .spacing(10)
+                .align_items(Alignment::Center);
+
+            if self
+                .equipped_slot
+                .as_ref()
+                .map(|s| s.is_weapon())
+                .unwrap_or(false)
+            {
+                let weapon_trinket_selected = match &self.equipped_trinket {
+                    Some(path) => PROFILE_WEAPON_TRINKETS
+                        .iter()
+                        .find(|t| t.ident == *path)
+                        .copied(),
+                    None => Some(NO_WEAPON_COSMETIC),
+                };
+
+                cosmetics_row = cosmetics_row
+                    .push(
+                        Text::new("饰品")
+                            .font(ST_HEI_TI_LIGHT)
+                            .size(16)
+                            .color(Color::from_rgb8(220, 220, 220)),
+                    )
+                    .push(
+                        PickList::new(
+                            &mut self.weapon_trinket_selector,
+                            iter::once(NO_WEAPON_COSMETIC)
+                                .chain(weapon_trinkets.iter().copied())
+                                .collect::<Vec<_>>(),
+                            weapon_trinket_selected,
+                            move |t| {
+                                interaction_message(
+                                    ItemEditorInteractionMessage::WeaponTrinketSelected(id, t),
+                                )
+                            },
+                        )
+                        .font(ST_HEI_TI_LIGHT)
+                        .text_size(16)
+                        .padding(5)
+                        .width(Length::Units(180))
+                        .style(Bl3UiStyle),
+                    );
+            }
+
+            item_content = item_content.push(cosmetics_row);
+        }
 
         let item_editor = if is_active {
             Some(self.editor.view(&self.item, interaction_message))
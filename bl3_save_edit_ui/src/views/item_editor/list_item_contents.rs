@@ -10,22 +10,30 @@ use crate::resources::svgs::{FAVORITE, JUNK};
 pub fn view(item: &Bl3Item) -> Column<InteractionMessage> {
     let balance_part = item.balance_part();
 
-    let label = balance_part.name.clone().unwrap_or_else(|| {
-        balance_part
-            .short_ident
-            .clone()
-            .unwrap_or_else(|| balance_part.ident.clone())
-    });
+    let label = if item.opaque_data().and_then(|o| o.error.as_ref()).is_some() {
+        "无法读取的物品".to_owned()
+    } else if item.is_opaque() {
+        format!("未知物品 (版本 {})", item.data_version)
+    } else {
+        balance_part.name.clone().unwrap_or_else(|| {
+            balance_part
+                .short_ident
+                .clone()
+                .unwrap_or_else(|| balance_part.ident.clone())
+        })
+    };
+
+    let tag = if item.is_opaque() {
+        "只读".to_owned()
+    } else {
+        format!("等级 {}", item.level())
+    };
 
     let mut tags_row = Row::new()
         .push(
-            Container::new(
-                Text::new(format!("等级 {}", item.level()))
-                    .font(ST_HEI_TI_LIGHT)
-                    .size(15),
-            )
-            .padding(5)
-            .style(ItemInfoStyle),
+            Container::new(Text::new(tag).font(ST_HEI_TI_LIGHT).size(15))
+                .padding(5)
+                .style(ItemInfoStyle),
         )
         .width(Length::Fill)
         .spacing(10);
@@ -6,8 +6,8 @@ use derivative::Derivative;
 use heck::TitleCase;
 use iced::alignment::{Horizontal, Vertical};
 use iced::{
-    button, scrollable, text_input, tooltip, Alignment, Button, Color, Column, Command, Container,
-    Length, Row, Scrollable, Text, Tooltip,
+    button, scrollable, text_input, tooltip, Alignment, Button, Checkbox, Color, Column, Command,
+    Container, Length, Row, Scrollable, Text, Tooltip,
 };
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rayon::slice::ParallelSliceMut;
@@ -19,8 +19,10 @@ use bl3_save_edit_core::bl3_item::{
     MAX_BL3_ITEM_PARTS,
 };
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
-use bl3_save_edit_core::bl3_save::character_data::MAX_CHARACTER_LEVEL;
+use bl3_save_edit_core::bl3_save::character_data::{CharacterData, MAX_CHARACTER_LEVEL};
+use bl3_save_edit_core::bl3_save::inventory_slot::InventorySlot;
 use bl3_save_edit_core::bl3_save::Bl3Save;
+use bl3_save_edit_core::game_data::GameDataKv;
 use bl3_save_edit_core::resources::{INVENTORY_SERIAL_DB, LOOTLEMON_ITEMS};
 use parts::available_parts;
 use parts::available_parts::AvailablePartTypeIndex;
@@ -62,6 +64,7 @@ pub struct ItemEditorState {
     pub all_item_levels_input: i32,
     pub all_item_levels_input_state: text_input::State,
     pub all_item_levels_button_state: button::State,
+    pub legit_only: bool,
     pub import_serial_button_state: button::State,
     items: Vec<ItemEditorListItem>,
     lootlemon_items: ItemEditorLootlemonItems,
@@ -74,6 +77,8 @@ pub struct ItemEditorState {
     pub item_list_tab_type: ItemListTabType,
     pub item_list_items_tab_button_state: button::State,
     pub item_list_lootlemon_tab_button_state: button::State,
+    pub unlocked_weapon_skins: Vec<GameDataKv>,
+    pub unlocked_weapon_trinkets: Vec<GameDataKv>,
 }
 
 #[derive(Debug)]
@@ -155,6 +160,24 @@ impl ItemEditorState {
         self.sort_items();
     }
 
+    /// Marks the items which are equipped in the save, new items can't be equipped until the
+    /// save has been written.
+    pub fn update_equipped_slots(&mut self, character_data: &CharacterData) {
+        let number_of_save_items = character_data.inventory_items().len();
+
+        let equipped_items = character_data.equipped_items();
+
+        self.items.iter_mut().for_each(|i| {
+            i.equipped_slot = character_data.equipped_slot(i.index);
+            i.equipped_trinket = equipped_items
+                .iter()
+                .find(|e| e.inventory_index == i.index)
+                .map(|e| e.trinket_data_path.clone())
+                .filter(|t| !t.is_empty());
+            i.can_equip = i.index < number_of_save_items;
+        });
+    }
+
     pub fn previously_selected_index(&mut self) -> usize {
         let previous_item = self
             .items
@@ -256,8 +279,9 @@ impl std::default::Default for ItemListTabType {
 #[derive(Debug)]
 pub enum ItemEditorFileType<'a> {
     Save(&'a mut Bl3Save),
+    SaveGunRack(&'a mut Bl3Save),
     ProfileBank(&'a mut Bl3Profile),
-    // ProfileLostLoot(Bl3Profile),
+    ProfileLostLoot(&'a mut Bl3Profile),
 }
 
 #[derive(Debug, Clone)]
@@ -291,8 +315,13 @@ pub enum ItemEditorInteractionMessage {
     ImportItemFromSerialPressed,
     AllItemLevel(i32),
     SetAllItemLevelsPressed,
+    LegitOnlySelected(bool),
     ItemLevel(i32),
     DeleteItem(usize),
+    EquipItem(usize, InventorySlot),
+    UnequipItem(usize),
+    WeaponSkinSelected(usize, GameDataKv),
+    WeaponTrinketSelected(usize, GameDataKv),
     DuplicateItem(usize),
     ShareItem(usize),
     BalanceInputSelected(BalancePart),
@@ -353,18 +382,25 @@ impl ItemEditorInteractionMessage {
                 if let Some(lootlemon_item) = item_editor_state.lootlemon_items.items.get(id) {
                     let item = lootlemon_item.item.clone();
 
-                    let item_pos = item_editor_state.add_item(item);
-
-                    item_editor_state.selected_item_index = item_pos;
-
-                    item_editor_state
-                        .map_current_item_if_exists_to_editor_state()
-                        .handle_ui_error(
-                            "Failed to map Lootlemon item to editor",
+                    if let Err(e) = check_new_item(&item, item_editor_state.legit_only) {
+                        e.handle_ui_error(
+                            "Failed to import item from Lootlemon",
                             &mut notification,
                         );
+                    } else {
+                        let item_pos = item_editor_state.add_item(item);
 
-                    item_editor_state.search_lootlemon_items_input_state.focus();
+                        item_editor_state.selected_item_index = item_pos;
+
+                        item_editor_state
+                            .map_current_item_if_exists_to_editor_state()
+                            .handle_ui_error(
+                                "Failed to map Lootlemon item to editor",
+                                &mut notification,
+                            );
+
+                        item_editor_state.search_lootlemon_items_input_state.focus();
+                    }
                 } else {
                     let msg = format!("Failed to import item from Lootlemon: couldn't find an item with index {}.", id);
 
@@ -453,6 +489,7 @@ impl ItemEditorInteractionMessage {
             }
             ItemEditorInteractionMessage::AvailablePartPressed(available_part_type_index) => {
                 let selected_item_index = item_editor_state.selected_item_index;
+                let legit_only = item_editor_state.legit_only;
 
                 if let Some(current_item) =
                     item_editor_state.items_mut().get_mut(selected_item_index)
@@ -472,7 +509,11 @@ impl ItemEditorInteractionMessage {
                                 if let Ok(bl3_part) = INVENTORY_SERIAL_DB
                                     .get_part_by_short_name(part_inv_key, &part_selected.part.name)
                                 {
-                                    if let Err(e) = current_item.item.add_part(bl3_part) {
+                                    if let Err(e) =
+                                        edit_item(&mut current_item.item, legit_only, |item| {
+                                            item.add_part(bl3_part)
+                                        })
+                                    {
                                         e.handle_ui_error(
                                             "Failed to add part to item",
                                             &mut notification,
@@ -503,6 +544,7 @@ impl ItemEditorInteractionMessage {
             }
             ItemEditorInteractionMessage::AvailableAnointmentPressed(available_part_type_index) => {
                 let selected_item_index = item_editor_state.selected_item_index;
+                let legit_only = item_editor_state.legit_only;
 
                 if let Some(current_item) =
                     item_editor_state.items_mut().get_mut(selected_item_index)
@@ -521,7 +563,11 @@ impl ItemEditorInteractionMessage {
                                     "InventoryGenericPartData",
                                     &anointment_selected.part.name,
                                 ) {
-                                    if let Err(e) = current_item.item.add_generic_part(bl3_part) {
+                                    if let Err(e) =
+                                        edit_item(&mut current_item.item, legit_only, |item| {
+                                            item.add_generic_part(bl3_part)
+                                        })
+                                    {
                                         e.handle_ui_error(
                                             "Failed to add anointment to item",
                                             &mut notification,
@@ -643,6 +689,7 @@ impl ItemEditorInteractionMessage {
                 current_part_type_index,
             ) => {
                 let selected_item_index = item_editor_state.selected_item_index;
+                let legit_only = item_editor_state.legit_only;
 
                 if !reorder_parts {
                     if let Some(current_item) =
@@ -656,8 +703,9 @@ impl ItemEditorInteractionMessage {
                             .and_then(|p| p.parts.get(current_part_type_index.part_index));
 
                         if let Some(part_selected) = part_selected {
-                            if let Err(e) = current_item.item.remove_part(&part_selected.part.part)
-                            {
+                            if let Err(e) = edit_item(&mut current_item.item, legit_only, |item| {
+                                item.remove_part(&part_selected.part.part)
+                            }) {
                                 e.handle_ui_error(
                                     "Failed to remove part from item",
                                     &mut notification,
@@ -682,6 +730,7 @@ impl ItemEditorInteractionMessage {
             }
             ItemEditorInteractionMessage::CurrentAnointmentPressed(current_part_type_index) => {
                 let selected_item_index = item_editor_state.selected_item_index;
+                let legit_only = item_editor_state.legit_only;
 
                 if let Some(current_item) =
                     item_editor_state.items_mut().get_mut(selected_item_index)
@@ -694,10 +743,9 @@ impl ItemEditorInteractionMessage {
                         .and_then(|p| p.parts.get(current_part_type_index.part_index));
 
                     if let Some(part_selected) = part_selected {
-                        if let Err(e) = current_item
-                            .item
-                            .remove_generic_part(&part_selected.part.part)
-                        {
+                        if let Err(e) = edit_item(&mut current_item.item, legit_only, |item| {
+                            item.remove_generic_part(&part_selected.part.part)
+                        }) {
                             e.handle_ui_error(
                                 "Failed to remove anointment from item",
                                 &mut notification,
@@ -719,24 +767,32 @@ impl ItemEditorInteractionMessage {
             ItemEditorInteractionMessage::CreateItemPressed => {
                 let item = Bl3Item::from_serial_base64("BL3(BAAAAAD2aoA+P1vAEgA=)").unwrap();
 
-                let item_pos = item_editor_state.add_item(item);
+                if let Err(e) = check_new_item(&item, item_editor_state.legit_only) {
+                    e.handle_ui_error("Failed to create item", &mut notification);
+                } else {
+                    let item_pos = item_editor_state.add_item(item);
 
-                item_editor_state.selected_item_index = item_pos;
+                    item_editor_state.selected_item_index = item_pos;
 
-                item_editor_state.item_list_scrollable_state.snap_to(1.0);
+                    item_editor_state.item_list_scrollable_state.snap_to(1.0);
 
-                item_editor_state.search_items_input_state.focus();
+                    item_editor_state.search_items_input_state.focus();
 
-                item_editor_state.item_list_tab_type = ItemListTabType::Items;
+                    item_editor_state.item_list_tab_type = ItemListTabType::Items;
 
-                item_editor_state
-                    .map_current_item_if_exists_to_editor_state()
-                    .handle_ui_error("Failed map created item to editor", &mut notification);
+                    item_editor_state
+                        .map_current_item_if_exists_to_editor_state()
+                        .handle_ui_error("Failed map created item to editor", &mut notification);
+                }
             }
             ItemEditorInteractionMessage::ImportItemFromSerialPressed => {
                 let item_serial = item_editor_state.import_serial_input.trim();
 
-                match Bl3Item::from_serial_base64(item_serial) {
+                match Bl3Item::from_serial_base64(item_serial).and_then(|item| {
+                    check_new_item(&item, item_editor_state.legit_only)?;
+
+                    Ok(item)
+                }) {
                     Ok(item) => {
                         let item_pos = item_editor_state.add_item(item);
 
@@ -763,11 +819,19 @@ impl ItemEditorInteractionMessage {
             }
             ItemEditorInteractionMessage::SetAllItemLevelsPressed => {
                 let item_level = item_editor_state.all_item_levels_input as usize;
+                let legit_only = item_editor_state.legit_only;
 
                 let mut failed = false;
 
                 for (i, item) in item_editor_state.items_mut().iter_mut().enumerate() {
-                    if let Err(e) = item.item.set_level(item_level) {
+                    // Items which couldn't be fully read are kept as they are
+                    if item.item.is_opaque() {
+                        continue;
+                    }
+
+                    if let Err(e) = edit_item(&mut item.item, legit_only, |item| {
+                        item.set_level(item_level)
+                    }) {
                         let msg = format!("Failed to set level for item number: {} - {}", i, e);
 
                         e.handle_ui_error(&msg, &mut notification);
@@ -787,10 +851,17 @@ impl ItemEditorInteractionMessage {
                         );
                 }
             }
+            ItemEditorInteractionMessage::LegitOnlySelected(selected) => {
+                item_editor_state.legit_only = selected;
+            }
             ItemEditorInteractionMessage::ItemLevel(item_level_input) => {
+                let legit_only = item_editor_state.legit_only;
+
                 item_editor_state
                     .map_current_item_if_exists_result(|i| {
-                        i.item.set_level(item_level_input as usize)
+                        edit_item(&mut i.item, legit_only, |item| {
+                            item.set_level(item_level_input as usize)
+                        })
                     })
                     .handle_ui_error("Failed to set level for item", &mut notification);
 
@@ -803,14 +874,28 @@ impl ItemEditorInteractionMessage {
 
                     match item_editor_file_type {
                         ItemEditorFileType::Save(s) => {
-                            s.character_data.remove_inventory_item(original_index)
+                            s.character_data.remove_inventory_item(original_index);
+
+                            item_editor_state.remove_item(id);
+
+                            item_editor_state.update_equipped_slots(&s.character_data);
+                        }
+                        ItemEditorFileType::SaveGunRack(s) => {
+                            s.character_data.remove_gun_rack_item(original_index);
+
+                            item_editor_state.remove_item(id);
                         }
                         ItemEditorFileType::ProfileBank(p) => {
-                            p.profile_data.remove_bank_item(original_index)
+                            p.profile_data.remove_bank_item(original_index);
+
+                            item_editor_state.remove_item(id);
                         }
-                    }
+                        ItemEditorFileType::ProfileLostLoot(p) => {
+                            p.profile_data.remove_lost_loot_item(original_index);
 
-                    item_editor_state.remove_item(id);
+                            item_editor_state.remove_item(id);
+                        }
+                    }
 
                     let selected_item_index = item_editor_state.selected_item_index;
 
@@ -837,6 +922,67 @@ impl ItemEditorInteractionMessage {
                     notification = Some(Notification::new(msg, NotificationSentiment::Negative));
                 }
             }
+            ItemEditorInteractionMessage::EquipItem(id, inventory_slot) => {
+                if let (Some(item), ItemEditorFileType::Save(s)) =
+                    (item_editor_state.items.get(id), item_editor_file_type)
+                {
+                    match s.character_data.equip_item(&inventory_slot, item.index) {
+                        Ok(()) => item_editor_state.update_equipped_slots(&s.character_data),
+                        Err(e) => e.handle_ui_error("Failed to equip item", &mut notification),
+                    }
+                }
+            }
+            ItemEditorInteractionMessage::UnequipItem(id) => {
+                if let (Some(item), ItemEditorFileType::Save(s)) =
+                    (item_editor_state.items.get(id), item_editor_file_type)
+                {
+                    if let Some(equipped_slot) = &item.equipped_slot {
+                        match s.character_data.unequip(equipped_slot) {
+                            Ok(()) => item_editor_state.update_equipped_slots(&s.character_data),
+                            Err(e) => {
+                                e.handle_ui_error("Failed to unequip item", &mut notification)
+                            }
+                        }
+                    }
+                }
+            }
+            ItemEditorInteractionMessage::WeaponSkinSelected(id, weapon_skin) => {
+                if let (Some(item), ItemEditorFileType::Save(s)) =
+                    (item_editor_state.items.get_mut(id), item_editor_file_type)
+                {
+                    let weapon_skin_path = Some(weapon_skin.ident).filter(|p| !p.is_empty());
+
+                    match s.character_data.set_inventory_item_weapon_skin(
+                        item.index,
+                        weapon_skin_path,
+                        &item_editor_state.unlocked_weapon_skins,
+                    ) {
+                        Ok(()) => item
+                            .item
+                            .set_weapon_skin_path(weapon_skin_path)
+                            .handle_ui_error("Failed to set weapon skin", &mut notification),
+                        Err(e) => e.handle_ui_error("Failed to set weapon skin", &mut notification),
+                    }
+                }
+            }
+            ItemEditorInteractionMessage::WeaponTrinketSelected(id, trinket) => {
+                if let (Some(item), ItemEditorFileType::Save(s)) =
+                    (item_editor_state.items.get(id), item_editor_file_type)
+                {
+                    if let Some(equipped_slot) = &item.equipped_slot {
+                        let trinket_path = Some(trinket.ident).filter(|p| !p.is_empty());
+
+                        match s.character_data.set_equipped_trinket(
+                            equipped_slot,
+                            trinket_path,
+                            &item_editor_state.unlocked_weapon_trinkets,
+                        ) {
+                            Ok(()) => item_editor_state.update_equipped_slots(&s.character_data),
+                            Err(e) => e.handle_ui_error("Failed to set trinket", &mut notification),
+                        }
+                    }
+                }
+            }
             ItemEditorInteractionMessage::DuplicateItem(id) => {
                 if let Some(item) = item_editor_state.items.get(id) {
                     let item = item.item.clone();
@@ -888,8 +1034,14 @@ impl ItemEditorInteractionMessage {
                 }
             }
             ItemEditorInteractionMessage::BalanceInputSelected(balance_selected) => {
+                let legit_only = item_editor_state.legit_only;
+
                 item_editor_state
-                    .map_current_item_if_exists_result(|i| i.item.set_balance(balance_selected))
+                    .map_current_item_if_exists_result(|i| {
+                        edit_item(&mut i.item, legit_only, |item| {
+                            item.set_balance(balance_selected)
+                        })
+                    })
                     .handle_ui_error("Failed to set balance for item", &mut notification);
 
                 let index = item_editor_state.previously_selected_index();
@@ -908,8 +1060,14 @@ impl ItemEditorInteractionMessage {
                 }
             }
             ItemEditorInteractionMessage::InvDataInputSelected(inv_data_selected) => {
+                let legit_only = item_editor_state.legit_only;
+
                 item_editor_state
-                    .map_current_item_if_exists_result(|i| i.item.set_inv_data(inv_data_selected))
+                    .map_current_item_if_exists_result(|i| {
+                        edit_item(&mut i.item, legit_only, |item| {
+                            item.set_inv_data(inv_data_selected)
+                        })
+                    })
                     .handle_ui_error("Failed to set inventory data for item", &mut notification);
 
                 let index = item_editor_state.previously_selected_index();
@@ -928,9 +1086,13 @@ impl ItemEditorInteractionMessage {
                 }
             }
             ItemEditorInteractionMessage::ManufacturerInputSelected(manufacturer_selected) => {
+                let legit_only = item_editor_state.legit_only;
+
                 item_editor_state
                     .map_current_item_if_exists_result(|i| {
-                        i.item.set_manufacturer(manufacturer_selected)
+                        edit_item(&mut i.item, legit_only, |item| {
+                            item.set_manufacturer(manufacturer_selected)
+                        })
                     })
                     .handle_ui_error("Failed to set manufacturer for item", &mut notification);
 
@@ -961,6 +1123,55 @@ impl ItemEditorInteractionMessage {
     }
 }
 
+/// Applies an edit to the item, when `legit_only` is set the edit is refused if it would
+/// introduce an issue that the item didn't already have.
+fn edit_item<F>(item: &mut Bl3Item, legit_only: bool, edit: F) -> Result<()>
+where
+    F: FnOnce(&mut Bl3Item) -> Result<()>,
+{
+    if !legit_only {
+        return edit(item);
+    }
+
+    let existing_issues = item.validate();
+
+    let mut edited_item = item.clone();
+
+    edit(&mut edited_item)?;
+
+    if let Some(issue) = edited_item
+        .validate()
+        .into_iter()
+        .find(|i| !existing_issues.contains(i))
+    {
+        bail!(
+            "legit only mode is enabled and this would make the item invalid - {}",
+            issue
+        );
+    }
+
+    *item = edited_item;
+
+    Ok(())
+}
+
+/// Checks an item before it is added to the list, when `legit_only` is set the item is refused
+/// if it has any issues.
+fn check_new_item(item: &Bl3Item, legit_only: bool) -> Result<()> {
+    if !legit_only {
+        return Ok(());
+    }
+
+    if let Some(issue) = item.validate().into_iter().next() {
+        bail!(
+            "legit only mode is enabled and this item is invalid - {}",
+            issue
+        );
+    }
+
+    Ok(())
+}
+
 pub fn view<F>(
     item_editor_state: &mut ItemEditorState,
     interaction_message: F,
@@ -1094,6 +1305,30 @@ where
     .width(Length::Fill)
     .style(Bl3UiStyle);
 
+    let legit_only_checkbox = Container::new(
+        Tooltip::new(
+            Checkbox::new(item_editor_state.legit_only, "仅合法编辑", move |c| {
+                interaction_message(ItemEditorInteractionMessage::LegitOnlySelected(c))
+            })
+            .size(17)
+            .font(ST_HEI_TI_LIGHT)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+            "Refuse part, anointment, balance and level changes that would make the item invalid.",
+            tooltip::Position::Top,
+        )
+        .gap(10)
+        .padding(10)
+        .font(ST_HEI_TI_LIGHT)
+        .size(17)
+        .style(Bl3UiTooltipStyle),
+    )
+    .padding(8)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
     let general_options_row = Row::new()
         .push(create_item_button)
         .push(
@@ -1108,6 +1343,7 @@ where
                 .height(Length::Units(36))
                 .style(Bl3UiStyle),
         )
+        .push(legit_only_checkbox)
         .spacing(20);
 
     let search_items_query = match item_list_tab_type {
@@ -1223,7 +1459,13 @@ where
 
             let is_active = i == selected_item_index;
 
-            let (list_item_button, curr_item_editor) = item.view(i, is_active, interaction_message);
+            let (list_item_button, curr_item_editor) = item.view(
+                i,
+                is_active,
+                &item_editor_state.unlocked_weapon_skins,
+                &item_editor_state.unlocked_weapon_trinkets,
+                interaction_message,
+            );
 
             // Check if the curr item index is in our filtered_items to decide whether to show the
             // list item button or not.
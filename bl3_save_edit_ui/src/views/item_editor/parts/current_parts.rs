@@ -214,6 +214,32 @@ impl CurrentParts {
 
         let mut current_parts_content = Column::new().push(Container::new(title_row));
 
+        let item_issues = item.validate();
+
+        if !item_issues.is_empty() {
+            let item_issues_column =
+                item_issues
+                    .iter()
+                    .fold(Column::new().spacing(5), |curr, issue| {
+                        curr.push(
+                            Text::new(issue.to_string())
+                                .font(ST_HEI_TI_LIGHT)
+                                .size(16)
+                                .color(Color::from_rgb8(240, 149, 149)),
+                        )
+                    });
+
+            current_parts_content = current_parts_content.push(
+                Container::new(
+                    Container::new(item_issues_column)
+                        .padding(15)
+                        .width(Length::Fill)
+                        .style(Bl3UiStyleNoBorder),
+                )
+                .padding(1),
+            );
+        }
+
         let parts;
 
         match self.parts_tab_type {
@@ -0,0 +1,186 @@
+use std::path::PathBuf;
+
+use heck::TitleCase;
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Checkbox, Color, Column, Container, Element,
+    Length, Row, Scrollable, Text, TextInput,
+};
+
+use bl3_save_edit_core::bl3_profile::game_settings::{
+    GameSetting, GameSettingSection, GameSettingValue, GameSettingsPreset,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug)]
+pub struct GameSettingField {
+    pub setting: GameSetting,
+    pub input: String,
+    input_state: text_input::State,
+}
+
+impl GameSettingField {
+    pub fn new(setting: GameSetting) -> Self {
+        Self {
+            input: setting.value.to_string(),
+            setting,
+            input_state: text_input::State::default(),
+        }
+    }
+
+    pub fn view(&mut self, index: usize) -> Container<Bl3Message> {
+        let label = self.setting.name.to_title_case();
+
+        let element: Element<Bl3Message> = match self.setting.value {
+            GameSettingValue::Bool(checked) => Checkbox::new(checked, label, move |c| {
+                InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::GameSettings(
+                        ProfileGameSettingsInteractionMessage::BoolChanged(index, c),
+                    ),
+                )
+            })
+            .size(20)
+            .font(ST_HEI_TI_LIGHT)
+            .text_color(Color::from_rgb8(220, 220, 220))
+            .text_size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+            _ => LabelledElement::create(
+                label,
+                Length::Units(300),
+                TextInput::new(&mut self.input_state, "", &self.input, move |v| {
+                    InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::GameSettings(
+                            ProfileGameSettingsInteractionMessage::ValueChanged(index, v),
+                        ),
+                    )
+                })
+                .font(ST_HEI_TI_LIGHT)
+                .padding(10)
+                .size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .into(),
+        };
+
+        Container::new(element)
+            .padding(5)
+            .width(Length::Fill)
+            .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GameSettingsState {
+    pub settings: Vec<GameSettingField>,
+    pub key_binding_categories: usize,
+    pub key_bindings: usize,
+    pub preset_window_open: bool,
+    export_button_state: button::State,
+    import_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum ProfileGameSettingsInteractionMessage {
+    BoolChanged(usize, bool),
+    ValueChanged(usize, String),
+    ExportPressed,
+    ExportCompleted(MessageResult<PathBuf>),
+    ImportPressed,
+    ImportCompleted(MessageResult<GameSettingsPreset>),
+}
+
+pub fn view(game_settings_state: &mut GameSettingsState) -> Container<Bl3Message> {
+    let mut export_button = Button::new(
+        &mut game_settings_state.export_button_state,
+        Text::new("Export Preset").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut import_button = Button::new(
+        &mut game_settings_state.import_button_state,
+        Text::new("Import Preset").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !game_settings_state.preset_window_open {
+        export_button = export_button.on_press(InteractionMessage::ManageProfileInteraction(
+            ManageProfileInteractionMessage::GameSettings(
+                ProfileGameSettingsInteractionMessage::ExportPressed,
+            ),
+        ));
+
+        import_button = import_button.on_press(InteractionMessage::ManageProfileInteraction(
+            ManageProfileInteractionMessage::GameSettings(
+                ProfileGameSettingsInteractionMessage::ImportPressed,
+            ),
+        ));
+    }
+
+    let presets = Container::new(
+        Row::new()
+            .push(
+                Text::new(format!(
+                    "Presets hold the settings below and {} key bindings in {} categories, progression is never included.",
+                    game_settings_state.key_bindings, game_settings_state.key_binding_categories
+                ))
+                .font(ST_HEI_TI_LIGHT)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220))
+                .width(Length::Fill),
+            )
+            .push(export_button.into_element())
+            .push(import_button.into_element())
+            .spacing(20)
+            .align_items(Alignment::Center),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut settings = Column::new().spacing(5);
+
+    let mut current_section: Option<GameSettingSection> = None;
+
+    for (i, setting) in game_settings_state.settings.iter_mut().enumerate() {
+        if current_section != Some(setting.setting.section) {
+            current_section = Some(setting.setting.section);
+
+            settings = settings.push(
+                Container::new(
+                    Text::new(setting.setting.section.to_string())
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(17)
+                        .color(Color::from_rgb8(242, 203, 5)),
+                )
+                .padding(10)
+                .width(Length::Fill)
+                .style(Bl3UiStyleNoBorder),
+            );
+        }
+
+        settings = settings.push(setting.view(i));
+    }
+
+    let all_contents = Column::new()
+        .push(presets)
+        .push(
+            Scrollable::new(&mut game_settings_state.scrollable_state)
+                .push(settings)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        )
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
@@ -0,0 +1,24 @@
+use iced::Container;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::views::item_editor;
+use crate::views::item_editor::{ItemEditorInteractionMessage, ItemEditorState};
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+
+#[derive(Debug, Default)]
+pub struct LostLootState {
+    pub item_editor_state: ItemEditorState,
+}
+
+#[derive(Debug, Clone)]
+pub enum ProfileLostLootInteractionMessage {
+    Editor(ItemEditorInteractionMessage),
+}
+
+pub fn view(lost_loot_state: &mut LostLootState) -> Container<Bl3Message> {
+    item_editor::view(&mut lost_loot_state.item_editor_state, |i| {
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::LostLoot(
+            ProfileLostLootInteractionMessage::Editor(i),
+        ))
+    })
+}
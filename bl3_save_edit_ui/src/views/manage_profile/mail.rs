@@ -0,0 +1,293 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, scrollable, text_input, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    Row, Scrollable, Text, TextInput,
+};
+
+use bl3_save_edit_core::bl3_profile::mail::MailData;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default)]
+pub struct MailField {
+    pub mail: MailData,
+    pub item_name: String,
+    pub date_sent: String,
+    delete_button_state: button::State,
+}
+
+impl MailField {
+    pub fn new(mail: MailData, item_name: String, date_sent: String) -> Self {
+        Self {
+            mail,
+            item_name,
+            date_sent,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let text_color = Color::from_rgb8(220, 220, 220);
+        let unread_color = Color::from_rgb8(242, 203, 5);
+
+        let mail_guid = self.mail.mail_guid.clone();
+
+        let read_checkbox = Checkbox::new(self.mail.has_been_read, "Read", move |c| {
+            InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::Mail(
+                ProfileMailInteractionMessage::MailRead(mail_guid.clone(), c),
+            ))
+        })
+        .size(20)
+        .font(ST_HEI_TI_LIGHT)
+        .text_color(text_color)
+        .text_size(16)
+        .style(Bl3UiStyle);
+
+        let delete_button = Button::new(
+            &mut self.delete_button_state,
+            Text::new("Delete").font(ST_HEI_TI_LIGHT).size(16),
+        )
+        .on_press(InteractionMessage::ManageProfileInteraction(
+            ManageProfileInteractionMessage::Mail(ProfileMailInteractionMessage::DeleteMail(
+                self.mail.mail_guid.clone(),
+            )),
+        ))
+        .width(Length::Units(80))
+        .padding(8)
+        .style(Bl3UiStyle);
+
+        Container::new(
+            Row::new()
+                .push(
+                    Text::new(&self.mail.sender_display_name)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(2)),
+                )
+                .push(
+                    Text::new(&self.mail.subject)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(if self.mail.has_been_read {
+                            text_color
+                        } else {
+                            unread_color
+                        })
+                        .width(Length::FillPortion(4)),
+                )
+                .push(
+                    Text::new(&self.item_name)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(3)),
+                )
+                .push(
+                    Text::new(&self.date_sent)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(2)),
+                )
+                .push(Container::new(read_checkbox.into_element()).width(Length::Units(80)))
+                .push(delete_button.into_element())
+                .spacing(10)
+                .align_items(Alignment::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MailState {
+    pub mail: Vec<MailField>,
+    pub sender_input: String,
+    sender_input_state: text_input::State,
+    pub subject_input: String,
+    subject_input_state: text_input::State,
+    pub body_input: String,
+    body_input_state: text_input::State,
+    pub item_serial_input: String,
+    item_serial_input_state: text_input::State,
+    send_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum ProfileMailInteractionMessage {
+    SenderInputChanged(String),
+    SubjectInputChanged(String),
+    BodyInputChanged(String),
+    ItemSerialInputChanged(String),
+    SendPressed,
+    MailRead(String, bool),
+    DeleteMail(String),
+}
+
+fn mail_input<'a>(
+    label: &str,
+    input_state: &'a mut text_input::State,
+    placeholder: &str,
+    value: &str,
+    on_change: fn(String) -> ProfileMailInteractionMessage,
+) -> Container<'a, Bl3Message> {
+    Container::new(
+        LabelledElement::create(
+            label,
+            Length::Units(120),
+            TextInput::new(input_state, placeholder, value, move |s| {
+                InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::Mail(
+                    on_change(s),
+                ))
+            })
+            .font(ST_HEI_TI_LIGHT)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .style(Bl3UiStyle)
+}
+
+pub fn view(mail_state: &mut MailState) -> Container<Bl3Message> {
+    let mut send_button = Button::new(
+        &mut mail_state.send_button_state,
+        Text::new("Send Mail").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if !mail_state.item_serial_input.trim().is_empty() {
+        send_button = send_button.on_press(InteractionMessage::ManageProfileInteraction(
+            ManageProfileInteractionMessage::Mail(ProfileMailInteractionMessage::SendPressed),
+        ));
+    }
+
+    let attachment_input = mail_input(
+        "Item Serial",
+        &mut mail_state.item_serial_input_state,
+        "BL3(...)",
+        &mail_state.item_serial_input,
+        ProfileMailInteractionMessage::ItemSerialInputChanged,
+    );
+
+    let composer = Column::new()
+        .push(
+            Row::new()
+                .push(mail_input(
+                    "Sender",
+                    &mut mail_state.sender_input_state,
+                    "Marcus",
+                    &mail_state.sender_input,
+                    ProfileMailInteractionMessage::SenderInputChanged,
+                ))
+                .push(mail_input(
+                    "Subject",
+                    &mut mail_state.subject_input_state,
+                    "Special Delivery",
+                    &mail_state.subject_input,
+                    ProfileMailInteractionMessage::SubjectInputChanged,
+                ))
+                .spacing(20),
+        )
+        .push(mail_input(
+            "Message",
+            &mut mail_state.body_input_state,
+            "",
+            &mail_state.body_input,
+            ProfileMailInteractionMessage::BodyInputChanged,
+        ))
+        .push(
+            Row::new()
+                .push(attachment_input)
+                .push(send_button.into_element())
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .spacing(15);
+
+    let header_color = Color::from_rgb8(242, 203, 5);
+
+    let header = Container::new(
+        Row::new()
+            .push(
+                Text::new("Sender")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(
+                Text::new("Subject")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new("Attachment")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(3)),
+            )
+            .push(
+                Text::new("Sent")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(Container::new(Text::new("")).width(Length::Units(80)))
+            .push(Container::new(Text::new("")).width(Length::Units(80)))
+            .spacing(10),
+    )
+    .padding(10)
+    .style(Bl3UiStyleNoBorder);
+
+    let mut all_contents = Column::new().push(composer).spacing(20);
+
+    if mail_state.mail.is_empty() {
+        all_contents = all_contents.push(
+            Container::new(
+                Text::new("No mail in this profile")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center),
+        );
+    } else {
+        let mail = mail_state
+            .mail
+            .iter_mut()
+            .fold(Column::new().spacing(5), |curr, mail| {
+                curr.push(mail.view())
+            });
+
+        all_contents = all_contents.push(
+            Column::new().push(header).push(
+                Scrollable::new(&mut mail_state.scrollable_state)
+                    .push(mail)
+                    .height(Length::Fill)
+                    .width(Length::Fill),
+            ),
+        );
+    }
+
+    Container::new(all_contents).padding(30)
+}
@@ -2,16 +2,24 @@ use iced::{button, svg, Column, Container, Length, Row};
 use strum::Display;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::svgs::{BANK, GENERAL, KEYS, PROFILE, SETTINGS};
+use crate::resources::svgs::{BANK, GENERAL, INVENTORY, KEYS, PROFILE, REFRESH, SETTINGS};
 use crate::views;
+use crate::views::backups::BackupsState;
+use crate::views::diff::DiffState;
 use crate::views::manage_profile::bank::BankState;
+use crate::views::manage_profile::game_settings::GameSettingsState;
 use crate::views::manage_profile::general::GeneralState;
 use crate::views::manage_profile::keys::KeysState;
+use crate::views::manage_profile::lost_loot::LostLootState;
+use crate::views::manage_profile::mail::MailState;
 use crate::views::manage_profile::profile::ProfileState;
+use crate::views::manage_profile::vault_card::VaultCardState;
 use crate::views::manage_profile::{
-    bank, general, keys, profile, ManageProfileInteractionMessage, ManageProfileState,
+    bank, game_settings, general, keys, lost_loot, mail, profile, vault_card,
+    ManageProfileInteractionMessage, ManageProfileState,
 };
 use crate::views::settings::SettingsState;
+use crate::views::transfer::TransferState;
 use crate::views::{tab_bar_button, ManageTabBarStyle};
 
 #[derive(Debug, Default)]
@@ -20,7 +28,11 @@ pub struct ProfileViewState {
     pub general_state: GeneralState,
     pub profile_state: ProfileState,
     pub keys_state: KeysState,
+    pub vault_card_state: VaultCardState,
     pub bank_state: BankState,
+    pub lost_loot_state: LostLootState,
+    pub mail_state: MailState,
+    pub game_settings_state: GameSettingsState,
 }
 
 #[derive(Debug, Default)]
@@ -28,7 +40,14 @@ pub struct ProfileTabBarState {
     general_button_state: button::State,
     profile_button_state: button::State,
     keys_button_state: button::State,
+    vault_card_button_state: button::State,
     bank_button_state: button::State,
+    lost_loot_button_state: button::State,
+    mail_button_state: button::State,
+    game_settings_button_state: button::State,
+    transfer_button_state: button::State,
+    compare_button_state: button::State,
+    backups_button_state: button::State,
     settings_button_state: button::State,
 }
 
@@ -37,7 +56,14 @@ pub enum ProfileTabBarInteractionMessage {
     General,
     Profile,
     Keys,
+    VaultCard,
     Bank,
+    LostLoot,
+    Mail,
+    GameSettings,
+    Transfer,
+    Compare,
+    Backups,
     Settings,
 }
 
@@ -47,12 +73,22 @@ pub enum ProfileTabBarView {
     General,
     Profile,
     Keys,
+    VaultCard,
     Bank,
+    LostLoot,
+    Mail,
+    GameSettings,
+    Transfer,
+    Compare,
+    Backups,
     Settings,
 }
 
 pub fn view<'a>(
     settings_state: &'a mut SettingsState,
+    diff_state: &'a mut DiffState,
+    transfer_state: &'a mut TransferState,
+    backups_state: &'a mut BackupsState,
     manage_profile_state: &'a mut ManageProfileState,
     tab_bar_view: &ProfileTabBarView,
 ) -> Container<'a, Bl3Message> {
@@ -98,6 +134,20 @@ pub fn view<'a>(
         75,
     );
 
+    let vault_card_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .vault_card_button_state,
+        ProfileTabBarView::VaultCard,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::VaultCard,
+        )),
+        svg::Handle::from_memory(KEYS),
+        115,
+    );
+
     let bank_button = tab_bar_button(
         &mut manage_profile_state
             .profile_view_state
@@ -112,6 +162,90 @@ pub fn view<'a>(
         75,
     );
 
+    let lost_loot_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .lost_loot_button_state,
+        ProfileTabBarView::LostLoot,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::LostLoot,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        105,
+    );
+
+    let mail_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .mail_button_state,
+        ProfileTabBarView::Mail,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Mail,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        75,
+    );
+
+    let game_settings_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .game_settings_button_state,
+        ProfileTabBarView::GameSettings,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::GameSettings,
+        )),
+        svg::Handle::from_memory(SETTINGS),
+        145,
+    );
+
+    let transfer_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .transfer_button_state,
+        ProfileTabBarView::Transfer,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Transfer,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        105,
+    );
+
+    let compare_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .compare_button_state,
+        ProfileTabBarView::Compare,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Compare,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        105,
+    );
+
+    let backups_button = tab_bar_button(
+        &mut manage_profile_state
+            .profile_view_state
+            .tab_bar_state
+            .backups_button_state,
+        ProfileTabBarView::Backups,
+        tab_bar_view,
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::TabBar(
+            ProfileTabBarInteractionMessage::Backups,
+        )),
+        svg::Handle::from_memory(REFRESH),
+        105,
+    );
+
     let settings_button = tab_bar_button(
         &mut manage_profile_state
             .profile_view_state
@@ -131,7 +265,14 @@ pub fn view<'a>(
             .push(general_button)
             .push(profile_button)
             .push(keys_button)
+            .push(vault_card_button)
             .push(bank_button)
+            .push(lost_loot_button)
+            .push(mail_button)
+            .push(game_settings_button)
+            .push(transfer_button)
+            .push(compare_button)
+            .push(backups_button)
             .push(settings_button),
     )
     .width(Length::Fill)
@@ -147,9 +288,24 @@ pub fn view<'a>(
         ProfileTabBarView::Keys => {
             keys::view(&mut manage_profile_state.profile_view_state.keys_state)
         }
+        ProfileTabBarView::VaultCard => {
+            vault_card::view(&mut manage_profile_state.profile_view_state.vault_card_state)
+        }
         ProfileTabBarView::Bank => {
             bank::view(&mut manage_profile_state.profile_view_state.bank_state)
         }
+        ProfileTabBarView::LostLoot => {
+            lost_loot::view(&mut manage_profile_state.profile_view_state.lost_loot_state)
+        }
+        ProfileTabBarView::Mail => {
+            mail::view(&mut manage_profile_state.profile_view_state.mail_state)
+        }
+        ProfileTabBarView::GameSettings => {
+            game_settings::view(&mut manage_profile_state.profile_view_state.game_settings_state)
+        }
+        ProfileTabBarView::Transfer => views::transfer::view(transfer_state),
+        ProfileTabBarView::Compare => views::diff::view(diff_state),
+        ProfileTabBarView::Backups => views::backups::view(backups_state),
         ProfileTabBarView::Settings => views::settings::view(settings_state),
     };
 
@@ -1,18 +1,26 @@
 use bl3_save_edit_core::bl3_profile::Bl3Profile;
 
 use crate::views::manage_profile::bank::ProfileBankInteractionMessage;
+use crate::views::manage_profile::game_settings::ProfileGameSettingsInteractionMessage;
 use crate::views::manage_profile::general::ProfileGeneralInteractionMessage;
 use crate::views::manage_profile::keys::ProfileKeysInteractionMessage;
+use crate::views::manage_profile::lost_loot::ProfileLostLootInteractionMessage;
+use crate::views::manage_profile::mail::ProfileMailInteractionMessage;
 use crate::views::manage_profile::main::{
     ProfileTabBarInteractionMessage, ProfileTabBarView, ProfileViewState,
 };
 use crate::views::manage_profile::profile::ProfileInteractionMessage;
+use crate::views::manage_profile::vault_card::ProfileVaultCardInteractionMessage;
 
 pub mod bank;
+pub mod game_settings;
 pub mod general;
 pub mod keys;
+pub mod lost_loot;
+pub mod mail;
 pub mod main;
 pub mod profile;
+pub mod vault_card;
 
 #[derive(Debug, Default)]
 pub struct ManageProfileState {
@@ -26,7 +34,11 @@ pub enum ManageProfileInteractionMessage {
     General(ProfileGeneralInteractionMessage),
     Profile(ProfileInteractionMessage),
     Keys(ProfileKeysInteractionMessage),
+    VaultCard(ProfileVaultCardInteractionMessage),
     Bank(ProfileBankInteractionMessage),
+    LostLoot(ProfileLostLootInteractionMessage),
+    Mail(ProfileMailInteractionMessage),
+    GameSettings(ProfileGameSettingsInteractionMessage),
     SaveProfilePressed,
 }
 
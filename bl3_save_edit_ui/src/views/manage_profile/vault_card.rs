@@ -0,0 +1,541 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Checkbox, Color, Column,
+    Container, Length, PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::bl3_profile::vault_card::{
+    VaultCardChallengeSeeds, VaultCardData, VaultCardGearRewardData,
+    VaultCardPreviousChallengeData, VaultCardRewardData,
+};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_profile::ManageProfileInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default)]
+pub struct VaultCardGearRewardField {
+    pub gear_reward: VaultCardGearRewardData,
+    repurchase_count_input_state: text_input::State,
+    remove_button_state: button::State,
+}
+
+impl VaultCardGearRewardField {
+    pub fn new(gear_reward: VaultCardGearRewardData) -> Self {
+        Self {
+            gear_reward,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self, index: usize) -> Container<Bl3Message> {
+        Container::new(
+            Row::new()
+                .push(
+                    Text::new(format!("Gear Reward {}", self.gear_reward.gear_index))
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(Color::from_rgb8(220, 220, 220))
+                        .width(Length::FillPortion(2)),
+                )
+                .push(
+                    vault_card_number_input(
+                        "Repurchase Count",
+                        &mut self.repurchase_count_input_state,
+                        self.gear_reward.repurchase_count,
+                        move |v| {
+                            ProfileVaultCardInteractionMessage::GearRewardRepurchaseCount(index, v)
+                        },
+                    )
+                    .width(Length::FillPortion(3)),
+                )
+                .push(
+                    Button::new(
+                        &mut self.remove_button_state,
+                        Text::new("Remove").font(ST_HEI_TI_LIGHT).size(16),
+                    )
+                    .on_press(InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::VaultCard(
+                            ProfileVaultCardInteractionMessage::RemoveGearReward(index),
+                        ),
+                    ))
+                    .padding(8)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(10)
+                .align_items(Alignment::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VaultCardChallengeField {
+    pub challenge: VaultCardPreviousChallengeData,
+    remove_button_state: button::State,
+}
+
+impl VaultCardChallengeField {
+    pub fn new(challenge: VaultCardPreviousChallengeData) -> Self {
+        Self {
+            challenge,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self, index: usize) -> Container<Bl3Message> {
+        let text_color = Color::from_rgb8(220, 220, 220);
+
+        Container::new(
+            Row::new()
+                .push(
+                    Text::new(format!("Challenge {}", self.challenge.challenge_id))
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(2)),
+                )
+                .push(
+                    Text::new(format!("Seed {}", self.challenge.challenge_seed))
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(3)),
+                )
+                .push(
+                    Button::new(
+                        &mut self.remove_button_state,
+                        Text::new("Remove").font(ST_HEI_TI_LIGHT).size(16),
+                    )
+                    .on_press(InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::VaultCard(
+                            ProfileVaultCardInteractionMessage::RemoveChallenge(index),
+                        ),
+                    ))
+                    .padding(8)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(10)
+                .align_items(Alignment::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VaultCardState {
+    pub vault_cards: Vec<VaultCardData>,
+    pub selected_vault_card_id: u32,
+    vault_card_selector: pick_list::State<u32>,
+    pub last_active_vault_card_input: u32,
+    last_active_vault_card_input_state: text_input::State,
+    experience_input_state: text_input::State,
+    chests_opened_input_state: text_input::State,
+    keys_spent_input_state: text_input::State,
+    pub gear_reward_fields: Vec<VaultCardGearRewardField>,
+    pub challenge_fields: Vec<VaultCardChallengeField>,
+    pub challenge_seeds: VaultCardChallengeSeeds,
+    day_seed_input_state: text_input::State,
+    week_seed_input_state: text_input::State,
+    pub new_reward_column_input: i32,
+    new_reward_column_input_state: text_input::State,
+    pub new_reward_row_input: i32,
+    new_reward_row_input_state: text_input::State,
+    add_reward_button_state: button::State,
+    pub new_gear_reward_index_input: i32,
+    new_gear_reward_index_input_state: text_input::State,
+    add_gear_reward_button_state: button::State,
+    pub new_challenge_id_input: u32,
+    new_challenge_id_input_state: text_input::State,
+    pub new_challenge_seed_input: i32,
+    new_challenge_seed_input_state: text_input::State,
+    add_challenge_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl VaultCardState {
+    pub fn selected_vault_card_mut(&mut self) -> Option<&mut VaultCardData> {
+        let selected_vault_card_id = self.selected_vault_card_id;
+
+        self.vault_cards
+            .iter_mut()
+            .find(|v| v.vault_card_id == selected_vault_card_id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ProfileVaultCardInteractionMessage {
+    VaultCardSelected(u32),
+    LastActiveVaultCard(u32),
+    Experience(i64),
+    ChestsOpened(u32),
+    KeysSpent(u32),
+    RewardUnlocked(usize, bool),
+    RewardRedeemed(usize, bool),
+    NewRewardColumn(i32),
+    NewRewardRow(i32),
+    AddRewardPressed,
+    GearRewardRepurchaseCount(usize, u32),
+    RemoveGearReward(usize),
+    NewGearRewardIndex(i32),
+    AddGearRewardPressed,
+    DaySeed(i32),
+    WeekSeed(i32),
+    NewChallengeId(u32),
+    NewChallengeSeed(i32),
+    AddChallengePressed,
+    RemoveChallenge(usize),
+}
+
+fn vault_card_number_input<'a, V, F>(
+    label: &str,
+    input_state: &'a mut text_input::State,
+    value: V,
+    on_change: F,
+) -> Container<'a, Bl3Message>
+where
+    V: 'static + Copy + Default + Display + FromStr + PartialOrd,
+    F: 'static + Fn(V) -> ProfileVaultCardInteractionMessage,
+{
+    Container::new(
+        LabelledElement::create(
+            label,
+            Length::Units(160),
+            NumberInput::new(input_state, value, V::default(), None, move |v| {
+                InteractionMessage::ManageProfileInteraction(
+                    ManageProfileInteractionMessage::VaultCard(on_change(v)),
+                )
+            })
+            .0
+            .font(ST_HEI_TI_LIGHT)
+            .padding(10)
+            .size(17)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle)
+}
+
+fn add_button<'a>(
+    button_state: &'a mut button::State,
+    label: &str,
+    message: ProfileVaultCardInteractionMessage,
+) -> Button<'a, InteractionMessage> {
+    Button::new(
+        button_state,
+        Text::new(label).font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .on_press(InteractionMessage::ManageProfileInteraction(
+        ManageProfileInteractionMessage::VaultCard(message),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+}
+
+fn section_header<'a>(title: &str) -> Container<'a, Bl3Message> {
+    Container::new(
+        Text::new(title)
+            .font(ST_HEI_TI_LIGHT)
+            .size(17)
+            .color(Color::from_rgb8(242, 203, 5)),
+    )
+    .padding(10)
+    .width(Length::Fill)
+    .style(Bl3UiStyleNoBorder)
+}
+
+fn reward_row<'a>(index: usize, reward: &VaultCardRewardData) -> Container<'a, Bl3Message> {
+    let text_color = Color::from_rgb8(220, 220, 220);
+
+    let unlocked_checkbox = Checkbox::new(reward.unlocked, "Unlocked", move |c| {
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::VaultCard(
+            ProfileVaultCardInteractionMessage::RewardUnlocked(index, c),
+        ))
+    })
+    .size(20)
+    .font(ST_HEI_TI_LIGHT)
+    .text_color(text_color)
+    .text_size(16)
+    .style(Bl3UiStyle);
+
+    let redeemed_checkbox = Checkbox::new(reward.redeemed, "Redeemed", move |c| {
+        InteractionMessage::ManageProfileInteraction(ManageProfileInteractionMessage::VaultCard(
+            ProfileVaultCardInteractionMessage::RewardRedeemed(index, c),
+        ))
+    })
+    .size(20)
+    .font(ST_HEI_TI_LIGHT)
+    .text_color(text_color)
+    .text_size(16)
+    .style(Bl3UiStyle);
+
+    Container::new(
+        Row::new()
+            .push(
+                Text::new(format!(
+                    "Column {}, Row {}",
+                    reward.column_index, reward.row_index
+                ))
+                .font(ST_HEI_TI_LIGHT)
+                .size(16)
+                .color(text_color)
+                .width(Length::FillPortion(2)),
+            )
+            .push(Container::new(unlocked_checkbox.into_element()).width(Length::FillPortion(1)))
+            .push(Container::new(redeemed_checkbox.into_element()).width(Length::FillPortion(1)))
+            .spacing(10)
+            .align_items(Alignment::Center),
+    )
+    .padding(10)
+    .style(Bl3UiStyle)
+}
+
+pub fn view(vault_card_state: &mut VaultCardState) -> Container<Bl3Message> {
+    let vault_card_ids = vault_card_state
+        .vault_cards
+        .iter()
+        .map(|v| v.vault_card_id)
+        .collect::<Vec<_>>();
+
+    let selected_vault_card_id = vault_card_state.selected_vault_card_id;
+
+    let selected_vault_card = vault_card_state
+        .vault_cards
+        .iter()
+        .find(|v| v.vault_card_id == selected_vault_card_id)
+        .cloned()
+        .unwrap_or_default();
+
+    let vault_card_selector = Container::new(
+        LabelledElement::create(
+            "Vault Card",
+            Length::Units(160),
+            PickList::new(
+                &mut vault_card_state.vault_card_selector,
+                vault_card_ids,
+                Some(selected_vault_card_id),
+                |id| {
+                    InteractionMessage::ManageProfileInteraction(
+                        ManageProfileInteractionMessage::VaultCard(
+                            ProfileVaultCardInteractionMessage::VaultCardSelected(id),
+                        ),
+                    )
+                },
+            )
+            .font(ST_HEI_TI_LIGHT)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .style(Bl3UiStyle);
+
+    let progress = Column::new()
+        .push(
+            Row::new()
+                .push(vault_card_selector)
+                .push(vault_card_number_input(
+                    "Last Active Card",
+                    &mut vault_card_state.last_active_vault_card_input_state,
+                    vault_card_state.last_active_vault_card_input,
+                    ProfileVaultCardInteractionMessage::LastActiveVaultCard,
+                ))
+                .spacing(20),
+        )
+        .push(
+            Row::new()
+                .push(vault_card_number_input(
+                    "Experience",
+                    &mut vault_card_state.experience_input_state,
+                    selected_vault_card.experience,
+                    ProfileVaultCardInteractionMessage::Experience,
+                ))
+                .push(
+                    Container::new(
+                        LabelledElement::create(
+                            "Level (Estimated)",
+                            Length::Units(160),
+                            Text::new(selected_vault_card.level().to_string())
+                                .color(Color::from_rgb8(220, 220, 220))
+                                .font(ST_HEI_TI_LIGHT)
+                                .size(17),
+                        )
+                        .spacing(15)
+                        .align_items(Alignment::Center),
+                    )
+                    .width(Length::Fill)
+                    .height(Length::Units(36))
+                    .style(Bl3UiStyle),
+                )
+                .push(vault_card_number_input(
+                    "Chests Opened",
+                    &mut vault_card_state.chests_opened_input_state,
+                    selected_vault_card.chests_opened,
+                    ProfileVaultCardInteractionMessage::ChestsOpened,
+                ))
+                .push(vault_card_number_input(
+                    "Keys Spent",
+                    &mut vault_card_state.keys_spent_input_state,
+                    selected_vault_card.keys_spent,
+                    ProfileVaultCardInteractionMessage::KeysSpent,
+                ))
+                .spacing(20),
+        )
+        .push(
+            Text::new("Vault card keys and unopened chests are edited in the Keys tab.")
+                .font(ST_HEI_TI_LIGHT)
+                .size(16)
+                .color(Color::from_rgb8(220, 220, 220)),
+        )
+        .spacing(15);
+
+    let rewards = selected_vault_card
+        .rewards
+        .iter()
+        .enumerate()
+        .fold(Column::new().spacing(5), |curr, (i, reward)| {
+            curr.push(reward_row(i, reward))
+        });
+
+    let add_reward = Row::new()
+        .push(vault_card_number_input(
+            "Column",
+            &mut vault_card_state.new_reward_column_input_state,
+            vault_card_state.new_reward_column_input,
+            ProfileVaultCardInteractionMessage::NewRewardColumn,
+        ))
+        .push(vault_card_number_input(
+            "Row",
+            &mut vault_card_state.new_reward_row_input_state,
+            vault_card_state.new_reward_row_input,
+            ProfileVaultCardInteractionMessage::NewRewardRow,
+        ))
+        .push(
+            add_button(
+                &mut vault_card_state.add_reward_button_state,
+                "Add Reward",
+                ProfileVaultCardInteractionMessage::AddRewardPressed,
+            )
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let gear_rewards = vault_card_state
+        .gear_reward_fields
+        .iter_mut()
+        .enumerate()
+        .fold(Column::new().spacing(5), |curr, (i, gear_reward)| {
+            curr.push(gear_reward.view(i))
+        });
+
+    let add_gear_reward = Row::new()
+        .push(vault_card_number_input(
+            "Gear Index",
+            &mut vault_card_state.new_gear_reward_index_input_state,
+            vault_card_state.new_gear_reward_index_input,
+            ProfileVaultCardInteractionMessage::NewGearRewardIndex,
+        ))
+        .push(
+            add_button(
+                &mut vault_card_state.add_gear_reward_button_state,
+                "Add Gear Reward",
+                ProfileVaultCardInteractionMessage::AddGearRewardPressed,
+            )
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let challenge_seeds = Row::new()
+        .push(vault_card_number_input(
+            "Daily Seed",
+            &mut vault_card_state.day_seed_input_state,
+            vault_card_state.challenge_seeds.day_seed,
+            ProfileVaultCardInteractionMessage::DaySeed,
+        ))
+        .push(vault_card_number_input(
+            "Weekly Seed",
+            &mut vault_card_state.week_seed_input_state,
+            vault_card_state.challenge_seeds.week_seed,
+            ProfileVaultCardInteractionMessage::WeekSeed,
+        ))
+        .spacing(20);
+
+    let challenges = vault_card_state
+        .challenge_fields
+        .iter_mut()
+        .enumerate()
+        .fold(Column::new().spacing(5), |curr, (i, challenge)| {
+            curr.push(challenge.view(i))
+        });
+
+    let add_challenge = Row::new()
+        .push(vault_card_number_input(
+            "Challenge Id",
+            &mut vault_card_state.new_challenge_id_input_state,
+            vault_card_state.new_challenge_id_input,
+            ProfileVaultCardInteractionMessage::NewChallengeId,
+        ))
+        .push(vault_card_number_input(
+            "Seed",
+            &mut vault_card_state.new_challenge_seed_input_state,
+            vault_card_state.new_challenge_seed_input,
+            ProfileVaultCardInteractionMessage::NewChallengeSeed,
+        ))
+        .push(
+            add_button(
+                &mut vault_card_state.add_challenge_button_state,
+                "Add Challenge",
+                ProfileVaultCardInteractionMessage::AddChallengePressed,
+            )
+            .into_element(),
+        )
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let all_contents = Column::new()
+        .push(progress)
+        .push(section_header(
+            "Rewards (unchecking both removes the reward)",
+        ))
+        .push(rewards)
+        .push(add_reward)
+        .push(section_header("Gear Rewards"))
+        .push(gear_rewards)
+        .push(add_gear_reward)
+        .push(section_header("Current Challenges"))
+        .push(challenge_seeds)
+        .push(section_header("Completed Challenges"))
+        .push(challenges)
+        .push(add_challenge)
+        .spacing(15);
+
+    Container::new(
+        Scrollable::new(&mut vault_card_state.scrollable_state)
+            .push(all_contents)
+            .height(Length::Fill)
+            .width(Length::Fill),
+    )
+    .padding(30)
+}
@@ -0,0 +1,264 @@
+use anyhow::{Context, Result};
+use iced::alignment::Horizontal;
+use iced::{
+    button, pick_list, text_input, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    PickList, Row, Text, TextInput,
+};
+
+use bl3_save_edit_core::bl3_save::player_color::{PlayerColor, PlayerColorData, PlayerColorPreset};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleCustomNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_save::character::{
+    CharacterColorMessage, SaveCharacterInteractionMessage,
+};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+
+#[derive(Debug, Default)]
+pub struct PlayerColorField {
+    pub player_color: PlayerColorData,
+    pub color_input: String,
+    color_input_state: text_input::State,
+    pub split_color_input: String,
+    split_color_input_state: text_input::State,
+}
+
+impl PlayerColorField {
+    pub fn new(player_color: PlayerColorData) -> Self {
+        Self {
+            color_input: player_color.color.to_hex(),
+            split_color_input: player_color.split_color.to_hex(),
+            player_color,
+            ..Default::default()
+        }
+    }
+
+    /// The color with the edited inputs applied, channels which weren't edited keep their exact
+    /// value from the save.
+    pub fn edited_player_color(&self) -> Result<PlayerColorData> {
+        let edited_color = |input: &str, original: PlayerColor| -> Result<PlayerColor> {
+            if input.trim().eq_ignore_ascii_case(&original.to_hex()) {
+                Ok(original)
+            } else {
+                PlayerColor::from_hex(input)
+                    .with_context(|| format!("invalid {}", self.player_color.slot))
+            }
+        };
+
+        Ok(PlayerColorData {
+            color: edited_color(&self.color_input, self.player_color.color)?,
+            split_color: edited_color(&self.split_color_input, self.player_color.split_color)?,
+            ..self.player_color.clone()
+        })
+    }
+
+    pub fn view(&mut self, index: usize) -> Row<Bl3Message> {
+        let color = PlayerColor::from_hex(&self.color_input).unwrap_or(self.player_color.color);
+        let split_color =
+            PlayerColor::from_hex(&self.split_color_input).unwrap_or(self.player_color.split_color);
+
+        Row::new()
+            .push(
+                Text::new(self.player_color.slot.to_string())
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(Color::from_rgb8(242, 203, 5))
+                    .width(Length::Units(70)),
+            )
+            .push(color_swatch(color))
+            .push(
+                TextInput::new(
+                    &mut self.color_input_state,
+                    "#RRGGBB",
+                    &self.color_input,
+                    move |s| color_message(CharacterColorMessage::ColorInputChanged(index, s)),
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .padding(10)
+                .size(17)
+                .width(Length::Units(110))
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Checkbox::new(
+                    self.player_color.use_default_color,
+                    "默认颜色",
+                    move |c| color_message(CharacterColorMessage::UseDefaultColor(index, c)),
+                )
+                .size(20)
+                .font(ST_HEI_TI_LIGHT)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(color_swatch(split_color))
+            .push(
+                TextInput::new(
+                    &mut self.split_color_input_state,
+                    "#RRGGBB",
+                    &self.split_color_input,
+                    move |s| color_message(CharacterColorMessage::SplitColorInputChanged(index, s)),
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .padding(10)
+                .size(17)
+                .width(Length::Units(110))
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(
+                Checkbox::new(
+                    self.player_color.use_default_split_color,
+                    "默认分色",
+                    move |c| color_message(CharacterColorMessage::UseDefaultSplitColor(index, c)),
+                )
+                .size(20)
+                .font(ST_HEI_TI_LIGHT)
+                .text_color(Color::from_rgb8(220, 220, 220))
+                .text_size(17)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PlayerColorEditor {
+    pub fields: Vec<PlayerColorField>,
+    pub presets: Vec<PlayerColorPreset>,
+    /// Set when the presets file couldn't be loaded, presets are not saved so it isn't overwritten
+    pub presets_load_error: Option<String>,
+    preset_selector: pick_list::State<String>,
+    pub preset_selected: Option<String>,
+    pub preset_name_input: String,
+    preset_name_input_state: text_input::State,
+    save_preset_button_state: button::State,
+    delete_preset_button_state: button::State,
+}
+
+impl PlayerColorEditor {
+    pub fn edited_player_colors(&self) -> Result<Vec<PlayerColorData>> {
+        self.fields
+            .iter()
+            .map(|f| f.edited_player_color())
+            .collect()
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let preset_names = self
+            .presets
+            .iter()
+            .map(|p| p.name.clone())
+            .collect::<Vec<_>>();
+
+        let mut delete_preset_button = Button::new(
+            &mut self.delete_preset_button_state,
+            Text::new("删除预设")
+                .font(ST_HEI_TI_LIGHT)
+                .size(17)
+                .horizontal_alignment(Horizontal::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle);
+
+        let mut save_preset_button = Button::new(
+            &mut self.save_preset_button_state,
+            Text::new("保存预设")
+                .font(ST_HEI_TI_LIGHT)
+                .size(17)
+                .horizontal_alignment(Horizontal::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle);
+
+        if self.presets_load_error.is_none() {
+            if self.preset_selected.is_some() {
+                delete_preset_button = delete_preset_button
+                    .on_press(color_message(CharacterColorMessage::DeletePresetPressed));
+            }
+
+            save_preset_button = save_preset_button
+                .on_press(color_message(CharacterColorMessage::SavePresetPressed));
+        }
+
+        let presets_row = Row::new()
+            .push(
+                PickList::new(
+                    &mut self.preset_selector,
+                    preset_names,
+                    self.preset_selected.clone(),
+                    |p| color_message(CharacterColorMessage::PresetSelected(p)),
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .text_size(17)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(delete_preset_button.into_element())
+            .push(
+                TextInput::new(
+                    &mut self.preset_name_input_state,
+                    "预设名称",
+                    &self.preset_name_input,
+                    |s| color_message(CharacterColorMessage::PresetNameInputChanged(s)),
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .padding(10)
+                .size(17)
+                .width(Length::Fill)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .push(save_preset_button.into_element())
+            .spacing(15)
+            .align_items(Alignment::Center);
+
+        let mut presets_column = Column::new().push(presets_row).spacing(15);
+
+        if let Some(e) = &self.presets_load_error {
+            presets_column = presets_column.push(
+                Text::new(format!(
+                    "读取颜色预设失败, 修复或删除该文件之前无法保存预设: {}",
+                    e
+                ))
+                .font(ST_HEI_TI_LIGHT)
+                .size(16)
+                .color(Color::from_rgb8(230, 80, 80)),
+            );
+        }
+
+        let colors = self
+            .fields
+            .iter_mut()
+            .enumerate()
+            .fold(presets_column, |curr, (i, field)| curr.push(field.view(i)));
+
+        Container::new(colors)
+            .width(Length::Fill)
+            .padding(15)
+            .style(Bl3UiStyle)
+    }
+}
+
+fn color_swatch<'a>(color: PlayerColor) -> Container<'a, Bl3Message> {
+    Container::new(Text::new(""))
+        .width(Length::Units(36))
+        .height(Length::Units(36))
+        .style(Bl3UiStyleCustomNoBorder(Color::from_rgb(
+            color.r, color.g, color.b,
+        )))
+}
+
+fn color_message(color_message: CharacterColorMessage) -> InteractionMessage {
+    InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Character(
+        SaveCharacterInteractionMessage::ColorMessage(color_message),
+    ))
+}
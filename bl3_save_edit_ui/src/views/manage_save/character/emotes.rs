@@ -0,0 +1,100 @@
+use iced::{pick_list, Alignment, Column, Container, Length, PickList, Row};
+use rayon::prelude::ParallelSliceMut;
+
+use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
+use bl3_save_edit_core::game_data::GameDataKv;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_save::character::SaveCharacterInteractionMessage;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default)]
+pub struct EmoteSlot {
+    pub selected: Option<GameDataKv>,
+    pick_list: pick_list::State<GameDataKv>,
+}
+
+impl EmoteSlot {
+    pub fn new(selected: Option<GameDataKv>) -> Self {
+        Self {
+            selected,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(
+        &mut self,
+        index: usize,
+        available_emotes: Vec<GameDataKv>,
+    ) -> Container<Bl3Message> {
+        Container::new(
+            LabelledElement::create(
+                format!("表情 {}", index + 1),
+                Length::Units(70),
+                PickList::new(
+                    &mut self.pick_list,
+                    available_emotes,
+                    self.selected,
+                    move |e| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::Character(
+                                SaveCharacterInteractionMessage::EmoteSelected(index, e),
+                            ),
+                        )
+                    },
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .text_size(17)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Units(36))
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EmoteSelectors {
+    pub slots: Vec<EmoteSlot>,
+    pub unlocked_emotes: Vec<GameDataKv>,
+}
+
+impl EmoteSelectors {
+    pub fn view(&mut self, player_class: &PlayerClass) -> Container<Bl3Message> {
+        let player_class_s = player_class.to_string();
+
+        let mut available_emotes = self
+            .unlocked_emotes
+            .iter()
+            .filter(|e| e.ident.contains(&player_class_s))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        available_emotes.par_sort();
+
+        let slot_count = self.slots.len();
+
+        let mut emotes = Column::new().spacing(20);
+        let mut emotes_row = Row::new().spacing(20);
+
+        for (i, slot) in self.slots.iter_mut().enumerate() {
+            emotes_row = emotes_row.push(slot.view(i, available_emotes.clone()));
+
+            if i % 2 == 1 || i == slot_count - 1 {
+                emotes = emotes.push(emotes_row);
+                emotes_row = Row::new().spacing(20);
+            }
+        }
+
+        Container::new(emotes)
+    }
+}
@@ -7,12 +7,15 @@ use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
 use bl3_save_edit_core::bl3_save::util::REQUIRED_XP_LIST;
 use bl3_save_edit_core::game_data::GameDataKv;
 
-use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::ST_HEI_TI_LIGHT;
 use crate::views::manage_save::character::ammo::AmmoSetter;
+use crate::views::manage_save::character::colors::PlayerColorEditor;
+use crate::views::manage_save::character::emotes::EmoteSelectors;
 use crate::views::manage_save::character::gear::GearUnlocker;
 use crate::views::manage_save::character::sdu::SduUnlocker;
+use crate::views::manage_save::character::skills::SkillsEditor;
 use crate::views::manage_save::character::skins::SkinSelectors;
 use crate::views::manage_save::ManageSaveInteractionMessage;
 use crate::views::InteractionExt;
@@ -21,8 +24,11 @@ use crate::widgets::number_input::NumberInput;
 use crate::widgets::text_input_limited::TextInputLimited;
 
 mod ammo;
+pub mod colors;
+pub mod emotes;
 mod gear;
 mod sdu;
+pub mod skills;
 mod skins;
 
 #[derive(Debug, Default)]
@@ -38,9 +44,12 @@ pub struct CharacterState {
     pub ability_points_input: i32,
     pub ability_points_input_state: text_input::State,
     pub skin_selectors: SkinSelectors,
+    pub emote_selectors: EmoteSelectors,
+    pub color_editor: PlayerColorEditor,
     pub gear_unlocker: GearUnlocker,
     pub ammo_setter: AmmoSetter,
     pub sdu_unlocker: SduUnlocker,
+    pub skills_editor: SkillsEditor,
 }
 
 #[derive(Debug, Clone)]
@@ -51,9 +60,12 @@ pub enum SaveCharacterInteractionMessage {
     AbilityPoints(i32),
     PlayerClassSelected(PlayerClass),
     SkinMessage(CharacterSkinSelectedMessage),
+    EmoteSelected(usize, GameDataKv),
+    ColorMessage(CharacterColorMessage),
     GearMessage(CharacterGearUnlockedMessage),
     SduMessage(CharacterSduMessage),
     AmmoMessage(CharacterAmmoMessage),
+    SkillMessage(CharacterSkillMessage),
     MaxSduSlotsPressed,
     MaxAmmoAmountsPressed,
 }
@@ -77,6 +89,19 @@ pub enum CharacterSkinSelectedMessage {
     EchoTheme(GameDataKv),
 }
 
+#[derive(Debug, Clone)]
+pub enum CharacterColorMessage {
+    ColorInputChanged(usize, String),
+    SplitColorInputChanged(usize, String),
+    UseDefaultColor(usize, bool),
+    UseDefaultSplitColor(usize, bool),
+    PresetSelected(String),
+    PresetNameInputChanged(String),
+    SavePresetPressed,
+    DeletePresetPressed,
+    PresetsSaveCompleted(MessageResult<()>),
+}
+
 #[derive(Debug, Clone)]
 pub enum CharacterGearUnlockedMessage {
     Grenade(bool),
@@ -112,6 +137,13 @@ pub enum CharacterAmmoMessage {
     Heavy(i32),
 }
 
+#[derive(Debug, Clone)]
+pub enum CharacterSkillMessage {
+    ItemPoints(String, i32),
+    MaxTreePressed(i32),
+    RespecPressed,
+}
+
 pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
     let selected_class = character_state.player_class_selected_class;
 
@@ -296,7 +328,22 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
         .push(ability_points)
         .spacing(20);
 
-    let skin_unlocker = character_state.skin_selectors.view(&selected_class);
+    let skin_unlocker = character_state
+        .skin_selectors
+        .view(&selected_class)
+        .width(Length::FillPortion(3));
+
+    let emote_selectors = character_state
+        .emote_selectors
+        .view(&selected_class)
+        .width(Length::FillPortion(2));
+
+    let skin_emote_row = Row::new()
+        .push(skin_unlocker)
+        .push(emote_selectors)
+        .spacing(20);
+
+    let color_editor = character_state.color_editor.view();
 
     let gear_unlocker = character_state
         .gear_unlocker
@@ -322,7 +369,8 @@ pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
     let all_contents = Column::new()
         .push(name_class_row)
         .push(experience_and_level_row)
-        .push(skin_unlocker)
+        .push(skin_emote_row)
+        .push(color_editor)
         .push(slot_sdu_row)
         .spacing(20);
 
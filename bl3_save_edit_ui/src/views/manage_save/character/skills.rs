@@ -0,0 +1,392 @@
+use iced::alignment::Horizontal;
+use iced::{
+    button, scrollable, text_input, tooltip, Alignment, Button, Color, Column, Container, Length,
+    Row, Scrollable, Text, Tooltip,
+};
+
+use bl3_save_edit_core::bl3_save::ability_tree::{
+    available_skill_points, skill_tree_info, AbilityTree, SkillPreset,
+};
+use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_save::character::{
+    CharacterSkillMessage, CharacterState, SaveCharacterInteractionMessage,
+};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::number_input::NumberInput;
+
+#[derive(Debug, Default)]
+pub struct SkillItemField {
+    pub item_asset_path: String,
+    name: String,
+    pub max_points: i32,
+    pub input: i32,
+    input_state: text_input::State,
+}
+
+impl SkillItemField {
+    pub fn view(&mut self) -> Row<Bl3Message> {
+        let item_asset_path = self.item_asset_path.clone();
+
+        Row::new()
+            .push(
+                Text::new(&self.name)
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(16)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::FillPortion(3)),
+            )
+            .push(
+                Tooltip::new(
+                    NumberInput::new(
+                        &mut self.input_state,
+                        self.input,
+                        0,
+                        Some(self.max_points),
+                        move |v| {
+                            InteractionMessage::ManageSaveInteraction(
+                                ManageSaveInteractionMessage::Character(
+                                    SaveCharacterInteractionMessage::SkillMessage(
+                                        CharacterSkillMessage::ItemPoints(
+                                            item_asset_path.clone(),
+                                            v,
+                                        ),
+                                    ),
+                                ),
+                            )
+                        },
+                    )
+                    .0
+                    .width(Length::FillPortion(1))
+                    .font(ST_HEI_TI_LIGHT)
+                    .padding(8)
+                    .size(16)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                    format!("技能点必须在 0 到 {} 之间", self.max_points),
+                    tooltip::Position::Top,
+                )
+                .gap(10)
+                .padding(10)
+                .font(ST_HEI_TI_LIGHT)
+                .size(17)
+                .style(Bl3UiTooltipStyle),
+            )
+            .spacing(10)
+            .align_items(Alignment::Center)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SkillTierField {
+    pub items: Vec<SkillItemField>,
+    pub points_required: i32,
+}
+
+#[derive(Debug, Default)]
+pub struct SkillTreeField {
+    name: String,
+    pub tree_identifier: i32,
+    pub tiers: Vec<SkillTierField>,
+    max_tree_button_state: button::State,
+}
+
+impl SkillTreeField {
+    pub fn points(&self) -> i32 {
+        self.tiers
+            .iter()
+            .flat_map(|t| t.items.iter())
+            .map(|i| i.input)
+            .sum()
+    }
+
+    pub fn max_points(&self) -> i32 {
+        self.tiers
+            .iter()
+            .flat_map(|t| t.items.iter())
+            .map(|i| i.max_points)
+            .sum()
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let header = Container::new(
+            Text::new(format!(
+                "{} ({}/{})",
+                self.name,
+                self.points(),
+                self.max_points()
+            ))
+            .font(ST_HEI_TI_LIGHT)
+            .size(17)
+            .color(Color::from_rgb8(242, 203, 5)),
+        )
+        .padding(10)
+        .align_x(Horizontal::Center)
+        .width(Length::Fill)
+        .style(Bl3UiStyle);
+
+        let mut tiers = Column::new().spacing(10);
+
+        for (i, tier) in self.tiers.iter_mut().enumerate() {
+            let tier_points = tier.items.iter().map(|i| i.input).sum::<i32>();
+            let tier_max_points = tier.items.iter().map(|i| i.max_points).sum::<i32>();
+
+            let mut tier_column = Column::new().push(
+                Text::new(format!(
+                    "层级 {} ({}/{}, 需要上层 {} 点)",
+                    i + 1,
+                    tier_points,
+                    tier_max_points,
+                    tier.points_required
+                ))
+                .font(ST_HEI_TI_LIGHT)
+                .size(16)
+                .color(Color::from_rgb8(210, 210, 210)),
+            );
+
+            for item in tier.items.iter_mut() {
+                tier_column = tier_column.push(item.view());
+            }
+
+            tiers = tiers.push(tier_column.spacing(5));
+        }
+
+        let max_tree_button = Button::new(
+            &mut self.max_tree_button_state,
+            Text::new("加满此技能树").font(ST_HEI_TI_LIGHT).size(17),
+        )
+        .on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::Character(SaveCharacterInteractionMessage::SkillMessage(
+                CharacterSkillMessage::MaxTreePressed(self.tree_identifier),
+            )),
+        ))
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        Container::new(
+            Column::new().push(header).push(
+                Container::new(
+                    Column::new()
+                        .push(tiers)
+                        .push(Container::new(max_tree_button).padding(5))
+                        .align_items(Alignment::Center)
+                        .spacing(15),
+                )
+                .padding(15)
+                .style(Bl3UiStyle),
+            ),
+        )
+        .width(Length::Fill)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SkillsEditor {
+    pub player_class: PlayerClass,
+    pub ability_tree: AbilityTree,
+    pub trees: Vec<SkillTreeField>,
+    pub changed: bool,
+    respec_button_state: button::State,
+    scrollable_state: scrollable::State,
+}
+
+impl SkillsEditor {
+    pub fn new(player_class: PlayerClass, ability_tree: &AbilityTree) -> Self {
+        let trees = ability_tree
+            .tree_identifiers()
+            .into_iter()
+            .map(|tree_identifier| {
+                let name = skill_tree_info(player_class, tree_identifier)
+                    .map(|t| t.name.to_owned())
+                    .unwrap_or_else(|| format!("技能树 {}", tree_identifier));
+
+                let tiers = ability_tree
+                    .tiers(tree_identifier)
+                    .into_iter()
+                    .map(|tier| SkillTierField {
+                        items: tier
+                            .items
+                            .iter()
+                            .filter(|i| i.max_points > 0)
+                            .map(|i| SkillItemField {
+                                item_asset_path: i.item_asset_path.clone(),
+                                name: i
+                                    .name()
+                                    .replace("WingItems_", "")
+                                    .replace("Items_", "")
+                                    .replace("OakPlayerAbilityTreeItemData_", ""),
+                                max_points: i.max_points,
+                                input: i.points,
+                                ..Default::default()
+                            })
+                            .collect(),
+                        points_required: tier.points_required,
+                    })
+                    .filter(|t| !t.items.is_empty())
+                    .collect();
+
+                SkillTreeField {
+                    name,
+                    tree_identifier,
+                    tiers,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        Self {
+            player_class,
+            ability_tree: ability_tree.clone(),
+            trees,
+            ..Default::default()
+        }
+    }
+
+    pub fn points_spent(&self) -> i32 {
+        self.trees.iter().map(|t| t.points()).sum()
+    }
+
+    pub fn set_item_points(&mut self, item_asset_path: &str, points: i32) {
+        if let Some(item) = self
+            .trees
+            .iter_mut()
+            .flat_map(|t| t.tiers.iter_mut())
+            .flat_map(|t| t.items.iter_mut())
+            .find(|i| i.item_asset_path == item_asset_path)
+        {
+            item.input = points;
+            self.changed = true;
+        }
+    }
+
+    pub fn respec(&mut self) {
+        self.trees
+            .iter_mut()
+            .flat_map(|t| t.tiers.iter_mut())
+            .flat_map(|t| t.items.iter_mut())
+            .for_each(|i| i.input = 0);
+
+        self.changed = true;
+    }
+
+    pub fn max_tree(&mut self, tree_identifier: i32, budget: i32) {
+        if let Some(tree) = skill_tree_info(self.player_class, tree_identifier) {
+            let preset = SkillPreset::max_tree(&self.ability_tree, &tree, budget);
+
+            self.respec();
+
+            for item in preset.items {
+                self.set_item_points(&item.item_asset_path, item.points);
+            }
+        }
+    }
+
+    /// The loaded ability tree with the points from the inputs applied.
+    pub fn to_ability_tree(&self) -> AbilityTree {
+        let mut ability_tree = self.ability_tree.clone();
+
+        for item in self
+            .trees
+            .iter()
+            .flat_map(|t| t.tiers.iter())
+            .flat_map(|t| t.items.iter())
+        {
+            if let Some(tree_item) = ability_tree
+                .items
+                .iter_mut()
+                .find(|i| i.item_asset_path == item.item_asset_path)
+            {
+                tree_item.points = item.input;
+            }
+        }
+
+        ability_tree
+    }
+}
+
+pub fn view(character_state: &mut CharacterState) -> Container<Bl3Message> {
+    let player_class = character_state.player_class_selected_class;
+    let player_level = character_state.level_input;
+    let ability_points = character_state.ability_points_input;
+
+    let skills_editor = &mut character_state.skills_editor;
+
+    let points_spent = skills_editor.points_spent();
+
+    let issues =
+        skills_editor
+            .to_ability_tree()
+            .validate(player_class, player_level, ability_points);
+
+    let summary = Text::new(format!(
+        "已用技能点: {}    剩余技能点: {}    当前等级可用: {}",
+        points_spent,
+        ability_points,
+        available_skill_points(player_level)
+    ))
+    .font(ST_HEI_TI_LIGHT)
+    .size(17)
+    .color(Color::from_rgb8(220, 220, 220));
+
+    let respec_button = Button::new(
+        &mut skills_editor.respec_button_state,
+        Text::new("重置技能点").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::Character(SaveCharacterInteractionMessage::SkillMessage(
+            CharacterSkillMessage::RespecPressed,
+        )),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let summary_row = Container::new(
+        Row::new()
+            .push(Container::new(summary).width(Length::Fill))
+            .push(respec_button)
+            .align_items(Alignment::Center),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut all_contents = Column::new().push(summary_row).spacing(20);
+
+    if player_class != skills_editor.player_class {
+        all_contents = all_contents.push(
+            Text::new("更改职业后技能树将被重置，请先保存存档")
+                .font(ST_HEI_TI_LIGHT)
+                .size(17)
+                .color(Color::from_rgb8(240, 149, 149)),
+        );
+    } else {
+        for issue in issues {
+            all_contents = all_contents.push(
+                Text::new(issue.to_string())
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(16)
+                    .color(Color::from_rgb8(240, 149, 149)),
+            );
+        }
+
+        let mut trees = Row::new().spacing(20);
+
+        for tree in skills_editor.trees.iter_mut() {
+            trees = trees.push(tree.view());
+        }
+
+        all_contents = all_contents.push(
+            Scrollable::new(&mut skills_editor.scrollable_state)
+                .push(trees)
+                .height(Length::Fill)
+                .width(Length::Fill),
+        );
+    }
+
+    Container::new(all_contents).padding(30)
+}
@@ -5,9 +5,10 @@ use iced::{pick_list, Alignment, Column, Container, Length, PickList, Row};
 use rayon::prelude::ParallelSliceMut;
 
 use bl3_save_edit_core::bl3_save::player_class::PlayerClass;
-use bl3_save_edit_core::game_data::{
-    GameDataKv, PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS,
-    PROFILE_HEADS_DEFAULTS, PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
+use bl3_save_edit_core::game_data::GameDataKv;
+use bl3_save_edit_core::resources::{
+    PROFILE_ECHO_THEMES, PROFILE_ECHO_THEMES_DEFAULTS, PROFILE_HEADS, PROFILE_HEADS_DEFAULTS,
+    PROFILE_SKINS, PROFILE_SKINS_DEFAULTS,
 };
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
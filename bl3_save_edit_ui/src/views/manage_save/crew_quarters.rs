@@ -0,0 +1,194 @@
+use std::fmt::Formatter;
+
+use iced::{pick_list, Alignment, Color, Column, Container, Length, PickList, Row, Text};
+
+use bl3_save_edit_core::bl3_save::crew_quarters::CrewQuartersDecorationData;
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::Bl3UiStyle;
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::item_editor;
+use crate::views::item_editor::{ItemEditorInteractionMessage, ItemEditorState};
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CrewQuartersRoomOption {
+    pub room_data_path: &'static str,
+    pub name: &'static str,
+}
+
+impl std::fmt::Display for CrewQuartersRoomOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct CrewQuartersDecorationOption {
+    pub decoration_data_path: Option<String>,
+    pub name: String,
+}
+
+impl CrewQuartersDecorationOption {
+    pub fn empty() -> Self {
+        Self {
+            decoration_data_path: None,
+            name: "无".to_owned(),
+        }
+    }
+
+    pub fn from_decoration(decoration: &CrewQuartersDecorationData) -> Self {
+        if decoration.is_empty() {
+            return Self::empty();
+        }
+
+        Self {
+            decoration_data_path: Some(decoration.decoration_data_path.clone()),
+            name: decoration.name.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for CrewQuartersDecorationOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CrewQuartersDecorationField {
+    pub decoration_index: i32,
+    pub decoration_selected: CrewQuartersDecorationOption,
+    decoration_selector: pick_list::State<CrewQuartersDecorationOption>,
+}
+
+impl CrewQuartersDecorationField {
+    pub fn new(decoration: &CrewQuartersDecorationData) -> Self {
+        Self {
+            decoration_index: decoration.decoration_index,
+            decoration_selected: CrewQuartersDecorationOption::from_decoration(decoration),
+            ..Default::default()
+        }
+    }
+
+    pub fn view(
+        &mut self,
+        decoration_options: Vec<CrewQuartersDecorationOption>,
+    ) -> Container<Bl3Message> {
+        let decoration_index = self.decoration_index;
+
+        Container::new(
+            LabelledElement::create(
+                format!("装饰 {}", decoration_index),
+                Length::Units(70),
+                PickList::new(
+                    &mut self.decoration_selector,
+                    decoration_options,
+                    Some(self.decoration_selected.clone()),
+                    move |d| {
+                        InteractionMessage::ManageSaveInteraction(
+                            ManageSaveInteractionMessage::CrewQuarters(
+                                SaveCrewQuartersInteractionMessage::DecorationSelected(
+                                    decoration_index,
+                                    d,
+                                ),
+                            ),
+                        )
+                    },
+                )
+                .font(ST_HEI_TI_LIGHT)
+                .text_size(17)
+                .width(Length::Fill)
+                .padding(10)
+                .style(Bl3UiStyle)
+                .into_element(),
+            )
+            .spacing(15)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::FillPortion(2))
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CrewQuartersState {
+    pub rooms: Vec<CrewQuartersRoomOption>,
+    pub room_selected: Option<CrewQuartersRoomOption>,
+    room_selector: pick_list::State<CrewQuartersRoomOption>,
+    pub decoration_options: Vec<CrewQuartersDecorationOption>,
+    pub decorations: Vec<CrewQuartersDecorationField>,
+    pub unlocked_rooms: Vec<String>,
+    pub unlocked_decorations: Vec<CrewQuartersDecorationData>,
+    pub gun_rack_state: ItemEditorState,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveCrewQuartersInteractionMessage {
+    RoomSelected(CrewQuartersRoomOption),
+    DecorationSelected(i32, CrewQuartersDecorationOption),
+    GunRack(ItemEditorInteractionMessage),
+}
+
+pub fn view(crew_quarters_state: &mut CrewQuartersState) -> Container<Bl3Message> {
+    let room_selector = Container::new(
+        LabelledElement::create(
+            "房间",
+            Length::Units(70),
+            PickList::new(
+                &mut crew_quarters_state.room_selector,
+                crew_quarters_state.rooms.clone(),
+                crew_quarters_state.room_selected,
+                |r| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::CrewQuarters(
+                            SaveCrewQuartersInteractionMessage::RoomSelected(r),
+                        ),
+                    )
+                },
+            )
+            .font(ST_HEI_TI_LIGHT)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .style(Bl3UiStyle);
+
+    let decoration_options = crew_quarters_state.decoration_options.clone();
+
+    let room = crew_quarters_state
+        .decorations
+        .iter_mut()
+        .fold(Row::new().push(room_selector), |curr, decoration| {
+            curr.push(decoration.view(decoration_options.clone()))
+        })
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let gun_rack_header = Text::new("枪架 (每个类型只能放一件装备, 保存时写入)")
+        .font(ST_HEI_TI_LIGHT)
+        .size(17)
+        .color(Color::from_rgb8(242, 203, 5));
+
+    let gun_rack = item_editor::view(&mut crew_quarters_state.gun_rack_state, |i| {
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::CrewQuarters(
+            SaveCrewQuartersInteractionMessage::GunRack(i),
+        ))
+    });
+
+    let all_contents = Column::new()
+        .push(
+            Container::new(Column::new().push(room).push(gun_rack_header).spacing(20)).padding(30),
+        )
+        .push(gun_rack);
+
+    Container::new(all_contents)
+}
@@ -0,0 +1,306 @@
+use std::fmt::Formatter;
+
+use iced::{
+    button, pick_list, scrollable, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text,
+};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_save::echo_log::{EchoLogContent, EchoLogData};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct EchoLogContentOption(pub Option<EchoLogContent>);
+
+impl EchoLogContentOption {
+    pub fn all() -> Vec<Self> {
+        std::iter::once(Self(None))
+            .chain(EchoLogContent::iter().map(|c| Self(Some(c))))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for EchoLogContentOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(content) => write!(f, "{}", content),
+            None => write!(f, "全部"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EchoLogField {
+    pub echo_log: EchoLogData,
+    unlock_button_state: button::State,
+}
+
+impl EchoLogField {
+    pub fn new(echo_log: EchoLogData) -> Self {
+        Self {
+            echo_log,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let text_color = Color::from_rgb8(220, 220, 220);
+        let locked_color = Color::from_rgb8(240, 149, 149);
+
+        let echo_log_path = self.echo_log.echo_log_path.clone();
+
+        let mut unlock_button = Button::new(
+            &mut self.unlock_button_state,
+            Text::new(if self.echo_log.unlocked {
+                "已解锁"
+            } else {
+                "解锁"
+            })
+            .font(ST_HEI_TI_LIGHT)
+            .size(16),
+        )
+        .width(Length::Units(80))
+        .padding(8)
+        .style(Bl3UiStyle);
+
+        if !self.echo_log.unlocked {
+            unlock_button = unlock_button.on_press(InteractionMessage::ManageSaveInteraction(
+                ManageSaveInteractionMessage::EchoLogs(
+                    SaveEchoLogsInteractionMessage::UnlockEchoLog(echo_log_path.clone()),
+                ),
+            ));
+        }
+
+        let mut row = Row::new()
+            .push(
+                Text::new(&self.echo_log.name)
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(16)
+                    .color(if self.echo_log.unlocked {
+                        text_color
+                    } else {
+                        locked_color
+                    })
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new(self.echo_log.content.to_string())
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(16)
+                    .color(text_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(unlock_button.into_element());
+
+        // Only logs which have been unlocked are stored in the save, so only they can be marked as seen
+        if self.echo_log.unlocked {
+            let seen_checkbox = Checkbox::new(self.echo_log.seen, "已读", move |c| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::EchoLogs(
+                    SaveEchoLogsInteractionMessage::EchoLogSeen(echo_log_path.clone(), c),
+                ))
+            })
+            .size(20)
+            .font(ST_HEI_TI_LIGHT)
+            .text_color(text_color)
+            .text_size(16)
+            .style(Bl3UiStyle);
+
+            row = row.push(Container::new(seen_checkbox.into_element()).width(Length::Units(80)));
+        } else {
+            row = row.push(Container::new(Text::new("")).width(Length::Units(80)));
+        }
+
+        Container::new(row.spacing(10).align_items(Alignment::Center))
+            .padding(10)
+            .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EchoLogsState {
+    pub content_selected: EchoLogContentOption,
+    content_selector: pick_list::State<EchoLogContentOption>,
+    unlock_content_button_state: button::State,
+    unlock_all_button_state: button::State,
+    mark_all_seen_button_state: button::State,
+    pub echo_logs: Vec<EchoLogField>,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveEchoLogsInteractionMessage {
+    ContentSelected(EchoLogContentOption),
+    UnlockContentPressed,
+    UnlockAllPressed,
+    MarkAllSeenPressed,
+    UnlockEchoLog(String),
+    EchoLogSeen(String, bool),
+}
+
+pub fn view(echo_logs_state: &mut EchoLogsState) -> Container<Bl3Message> {
+    let mut unlock_content_button = Button::new(
+        &mut echo_logs_state.unlock_content_button_state,
+        Text::new("解锁分类").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if echo_logs_state.content_selected.0.is_some() {
+        unlock_content_button = unlock_content_button.on_press(
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::EchoLogs(
+                SaveEchoLogsInteractionMessage::UnlockContentPressed,
+            )),
+        );
+    }
+
+    let content_selector = Container::new(
+        Row::new()
+            .push(
+                LabelledElement::create(
+                    "分类",
+                    Length::Units(90),
+                    PickList::new(
+                        &mut echo_logs_state.content_selector,
+                        EchoLogContentOption::all(),
+                        Some(echo_logs_state.content_selected),
+                        |c| {
+                            InteractionMessage::ManageSaveInteraction(
+                                ManageSaveInteractionMessage::EchoLogs(
+                                    SaveEchoLogsInteractionMessage::ContentSelected(c),
+                                ),
+                            )
+                        },
+                    )
+                    .font(ST_HEI_TI_LIGHT)
+                    .text_size(17)
+                    .width(Length::Fill)
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(15)
+                .width(Length::Fill)
+                .align_items(Alignment::Center),
+            )
+            .push(unlock_content_button.into_element())
+            .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .style(Bl3UiStyle);
+
+    let unlock_all_button = Button::new(
+        &mut echo_logs_state.unlock_all_button_state,
+        Text::new("全部解锁").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::EchoLogs(SaveEchoLogsInteractionMessage::UnlockAllPressed),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let mark_all_seen_button = Button::new(
+        &mut echo_logs_state.mark_all_seen_button_state,
+        Text::new("全部标为已读").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::EchoLogs(SaveEchoLogsInteractionMessage::MarkAllSeenPressed),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let unlocked_count = echo_logs_state
+        .echo_logs
+        .iter()
+        .filter(|e| e.echo_log.unlocked)
+        .count();
+
+    let unseen_count = echo_logs_state
+        .echo_logs
+        .iter()
+        .filter(|e| e.echo_log.unlocked && !e.echo_log.seen)
+        .count();
+
+    let summary = Container::new(
+        Text::new(format!(
+            "已解锁: {}/{}    未读: {}",
+            unlocked_count,
+            echo_logs_state.echo_logs.len(),
+            unseen_count
+        ))
+        .font(ST_HEI_TI_LIGHT)
+        .size(17)
+        .color(Color::from_rgb8(220, 220, 220))
+        .width(Length::Fill),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let header_color = Color::from_rgb8(242, 203, 5);
+
+    let header = Container::new(
+        Row::new()
+            .push(
+                Text::new("回声日志")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new("分类")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(Container::new(Text::new("")).width(Length::Units(80)))
+            .push(Container::new(Text::new("")).width(Length::Units(80)))
+            .spacing(10),
+    )
+    .padding(10)
+    .style(Bl3UiStyleNoBorder);
+
+    let content_selected = echo_logs_state.content_selected.0;
+
+    let echo_logs = echo_logs_state
+        .echo_logs
+        .iter_mut()
+        .filter(|e| {
+            content_selected
+                .map(|c| e.echo_log.content == c)
+                .unwrap_or(true)
+        })
+        .fold(Column::new().spacing(5), |curr, echo_log| {
+            curr.push(echo_log.view())
+        });
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(content_selector)
+                .push(unlock_all_button)
+                .push(mark_all_seen_button)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .push(summary)
+        .push(
+            Column::new().push(header).push(
+                Scrollable::new(&mut echo_logs_state.scrollable_state)
+                    .push(echo_logs)
+                    .height(Length::Fill)
+                    .width(Length::Fill),
+            ),
+        )
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
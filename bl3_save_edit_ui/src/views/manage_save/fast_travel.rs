@@ -0,0 +1,380 @@
+use std::fmt::Formatter;
+
+use iced::{
+    button, pick_list, scrollable, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text,
+};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_save::fast_travel::{FastTravelGroup, FastTravelStationData};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_save::missions::PlaythroughOption;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct FastTravelGroupOption(pub Option<FastTravelGroup>);
+
+impl FastTravelGroupOption {
+    pub fn all() -> Vec<Self> {
+        std::iter::once(Self(None))
+            .chain(FastTravelGroup::iter().map(|g| Self(Some(g))))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for FastTravelGroupOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(group) => write!(f, "{}", group),
+            None => write!(f, "全部"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FastTravelStationField {
+    pub station: FastTravelStationData,
+    unlock_button_state: button::State,
+    spawn_button_state: button::State,
+}
+
+impl FastTravelStationField {
+    pub fn new(station: FastTravelStationData) -> Self {
+        Self {
+            station,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self, is_spawn_station: bool) -> Container<Bl3Message> {
+        let text_color = Color::from_rgb8(220, 220, 220);
+        let locked_color = Color::from_rgb8(240, 149, 149);
+
+        let station_name = self.station.active_travel_station_name.clone();
+
+        let mut unlock_button = Button::new(
+            &mut self.unlock_button_state,
+            Text::new(if self.station.unlocked {
+                "已解锁"
+            } else {
+                "解锁"
+            })
+            .font(ST_HEI_TI_LIGHT)
+            .size(16),
+        )
+        .width(Length::Units(80))
+        .padding(8)
+        .style(Bl3UiStyle);
+
+        if !self.station.unlocked {
+            unlock_button = unlock_button.on_press(InteractionMessage::ManageSaveInteraction(
+                ManageSaveInteractionMessage::FastTravel(
+                    SaveFastTravelInteractionMessage::UnlockStation(station_name.clone()),
+                ),
+            ));
+        }
+
+        let blacklisted_checkbox = Checkbox::new(self.station.blacklisted, "隐藏", {
+            let station_name = station_name.clone();
+
+            move |c| {
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::FastTravel(
+                    SaveFastTravelInteractionMessage::BlacklistStation(station_name.clone(), c),
+                ))
+            }
+        })
+        .size(20)
+        .font(ST_HEI_TI_LIGHT)
+        .text_color(if self.station.unlocked {
+            text_color
+        } else {
+            locked_color
+        })
+        .text_size(16)
+        .style(Bl3UiStyle);
+
+        let mut spawn_button = Button::new(
+            &mut self.spawn_button_state,
+            Text::new(if is_spawn_station {
+                "出生点"
+            } else {
+                "设为出生点"
+            })
+            .font(ST_HEI_TI_LIGHT)
+            .size(16),
+        )
+        .width(Length::Units(110))
+        .padding(8)
+        .style(Bl3UiStyle);
+
+        if self.station.unlocked && !is_spawn_station {
+            spawn_button = spawn_button.on_press(InteractionMessage::ManageSaveInteraction(
+                ManageSaveInteractionMessage::FastTravel(
+                    SaveFastTravelInteractionMessage::SetSpawnStation(station_name),
+                ),
+            ));
+        }
+
+        let mut row = Row::new()
+            .push(
+                Text::new(&self.station.name)
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(16)
+                    .color(if self.station.unlocked {
+                        text_color
+                    } else {
+                        locked_color
+                    })
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new(self.station.group.to_string())
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(16)
+                    .color(text_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(unlock_button.into_element());
+
+        // Only stations which have been unlocked can be hidden from the fast travel menu
+        if self.station.unlocked {
+            row = row
+                .push(Container::new(blacklisted_checkbox.into_element()).width(Length::Units(80)));
+        } else {
+            row = row.push(Container::new(Text::new("")).width(Length::Units(80)));
+        }
+
+        Container::new(
+            row.push(spawn_button.into_element())
+                .spacing(10)
+                .align_items(Alignment::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FastTravelState {
+    pub playthroughs: Vec<PlaythroughOption>,
+    pub playthrough_selected: PlaythroughOption,
+    playthrough_selector: pick_list::State<PlaythroughOption>,
+    pub group_selected: FastTravelGroupOption,
+    group_selector: pick_list::State<FastTravelGroupOption>,
+    unlock_group_button_state: button::State,
+    unlock_all_button_state: button::State,
+    pub spawn_station: Option<String>,
+    pub stations: Vec<FastTravelStationField>,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveFastTravelInteractionMessage {
+    PlaythroughSelected(PlaythroughOption),
+    GroupSelected(FastTravelGroupOption),
+    UnlockGroupPressed,
+    UnlockAllPressed,
+    UnlockStation(String),
+    BlacklistStation(String, bool),
+    SetSpawnStation(String),
+}
+
+pub fn view(fast_travel_state: &mut FastTravelState) -> Container<Bl3Message> {
+    let playthrough_selector = Container::new(
+        LabelledElement::create(
+            "周目",
+            Length::Units(90),
+            PickList::new(
+                &mut fast_travel_state.playthrough_selector,
+                fast_travel_state.playthroughs.clone(),
+                Some(fast_travel_state.playthrough_selected),
+                |p| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::FastTravel(
+                            SaveFastTravelInteractionMessage::PlaythroughSelected(p),
+                        ),
+                    )
+                },
+            )
+            .font(ST_HEI_TI_LIGHT)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .style(Bl3UiStyle);
+
+    let mut unlock_group_button = Button::new(
+        &mut fast_travel_state.unlock_group_button_state,
+        Text::new("解锁分组").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if fast_travel_state.group_selected.0.is_some() {
+        unlock_group_button = unlock_group_button.on_press(
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::FastTravel(
+                SaveFastTravelInteractionMessage::UnlockGroupPressed,
+            )),
+        );
+    }
+
+    let group_selector = Container::new(
+        Row::new()
+            .push(
+                LabelledElement::create(
+                    "地区",
+                    Length::Units(90),
+                    PickList::new(
+                        &mut fast_travel_state.group_selector,
+                        FastTravelGroupOption::all(),
+                        Some(fast_travel_state.group_selected),
+                        |g| {
+                            InteractionMessage::ManageSaveInteraction(
+                                ManageSaveInteractionMessage::FastTravel(
+                                    SaveFastTravelInteractionMessage::GroupSelected(g),
+                                ),
+                            )
+                        },
+                    )
+                    .font(ST_HEI_TI_LIGHT)
+                    .text_size(17)
+                    .width(Length::Fill)
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(15)
+                .width(Length::Fill)
+                .align_items(Alignment::Center),
+            )
+            .push(unlock_group_button.into_element())
+            .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .style(Bl3UiStyle);
+
+    let unlock_all_button = Button::new(
+        &mut fast_travel_state.unlock_all_button_state,
+        Text::new("全部解锁").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .on_press(InteractionMessage::ManageSaveInteraction(
+        ManageSaveInteractionMessage::FastTravel(
+            SaveFastTravelInteractionMessage::UnlockAllPressed,
+        ),
+    ))
+    .padding(10)
+    .style(Bl3UiStyle)
+    .into_element();
+
+    let spawn_station_name = fast_travel_state
+        .spawn_station
+        .as_deref()
+        .and_then(|spawn_station| {
+            fast_travel_state
+                .stations
+                .iter()
+                .find(|s| {
+                    s.station
+                        .active_travel_station_name
+                        .eq_ignore_ascii_case(spawn_station)
+                })
+                .map(|s| s.station.name.clone())
+        })
+        .unwrap_or_else(|| "无".to_owned());
+
+    let unlocked_count = fast_travel_state
+        .stations
+        .iter()
+        .filter(|s| s.station.unlocked)
+        .count();
+
+    let summary = Container::new(
+        Text::new(format!(
+            "出生点: {}    已解锁: {}/{}",
+            spawn_station_name,
+            unlocked_count,
+            fast_travel_state.stations.len()
+        ))
+        .font(ST_HEI_TI_LIGHT)
+        .size(17)
+        .color(Color::from_rgb8(220, 220, 220))
+        .width(Length::Fill),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let header_color = Color::from_rgb8(242, 203, 5);
+
+    let header = Container::new(
+        Row::new()
+            .push(
+                Text::new("传送站")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new("地区")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(Container::new(Text::new("")).width(Length::Units(80)))
+            .push(Container::new(Text::new("")).width(Length::Units(80)))
+            .push(Container::new(Text::new("")).width(Length::Units(110)))
+            .spacing(10),
+    )
+    .padding(10)
+    .style(Bl3UiStyleNoBorder);
+
+    let group_selected = fast_travel_state.group_selected.0;
+    let spawn_station = fast_travel_state.spawn_station.clone();
+
+    let stations = fast_travel_state
+        .stations
+        .iter_mut()
+        .filter(|s| group_selected.map(|g| s.station.group == g).unwrap_or(true))
+        .fold(Column::new().spacing(5), |curr, station| {
+            let is_spawn_station = spawn_station
+                .as_deref()
+                .map(|s| s.eq_ignore_ascii_case(&station.station.active_travel_station_name))
+                .unwrap_or(false);
+
+            curr.push(station.view(is_spawn_station))
+        });
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(playthrough_selector)
+                .push(group_selector)
+                .push(unlock_all_button)
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .push(summary)
+        .push(
+            Column::new().push(header).push(
+                Scrollable::new(&mut fast_travel_state.scrollable_state)
+                    .push(stations)
+                    .height(Length::Fill)
+                    .width(Length::Fill),
+            ),
+        )
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
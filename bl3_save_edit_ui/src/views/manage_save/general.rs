@@ -3,11 +3,13 @@ use iced::{
     Row, Text, TextInput, Tooltip,
 };
 
+use bl3_save_edit_core::bl3_save::character_data::MAX_MAYHEM_LEVEL;
 use bl3_save_edit_core::parser::HeaderType;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiTooltipStyle};
 use crate::resources::fonts::{ST_HEI_TI_LIGHT};
+use crate::views::manage_save::missions::PlaythroughOption;
 use crate::views::manage_save::ManageSaveInteractionMessage;
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
@@ -25,6 +27,106 @@ pub struct GeneralState {
     pub generate_guid_button_state: button::State,
     pub save_type_selector: pick_list::State<HeaderType>,
     pub save_type_selected: HeaderType,
+    pub playthroughs: Vec<PlaythroughField>,
+    pub add_tvhm_button_state: button::State,
+    pub remove_tvhm_button_state: button::State,
+}
+
+#[derive(Debug, Default)]
+pub struct PlaythroughField {
+    pub playthrough: PlaythroughOption,
+    pub mayhem_level: i32,
+    pub mayhem_random_seed: i32,
+    mayhem_level_input_state: text_input::State,
+    mayhem_random_seed_input_state: text_input::State,
+}
+
+impl PlaythroughField {
+    pub fn new(playthrough: PlaythroughOption, mayhem_level: i32, mayhem_random_seed: i32) -> Self {
+        Self {
+            playthrough,
+            mayhem_level,
+            mayhem_random_seed,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self) -> Row<Bl3Message> {
+        let playthrough_index = self.playthrough.0;
+
+        Row::new()
+            .push(
+                Container::new(
+                    LabelledElement::create(
+                        format!("{} 混乱等级", self.playthrough),
+                        Length::Units(160),
+                        NumberInput::new(
+                            &mut self.mayhem_level_input_state,
+                            self.mayhem_level,
+                            0,
+                            Some(MAX_MAYHEM_LEVEL),
+                            move |v| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::General(
+                                        SaveGeneralInteractionMessage::MayhemLevel(
+                                            playthrough_index,
+                                            v,
+                                        ),
+                                    ),
+                                )
+                            },
+                        )
+                        .0
+                        .font(ST_HEI_TI_LIGHT)
+                        .padding(10)
+                        .size(17)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+                )
+                .width(Length::FillPortion(1))
+                .height(Length::Units(36))
+                .style(Bl3UiStyle),
+            )
+            .push(
+                Container::new(
+                    LabelledElement::create(
+                        "混乱种子",
+                        Length::Units(90),
+                        NumberInput::new(
+                            &mut self.mayhem_random_seed_input_state,
+                            self.mayhem_random_seed,
+                            i32::MIN,
+                            None,
+                            move |v| {
+                                InteractionMessage::ManageSaveInteraction(
+                                    ManageSaveInteractionMessage::General(
+                                        SaveGeneralInteractionMessage::MayhemRandomSeed(
+                                            playthrough_index,
+                                            v,
+                                        ),
+                                    ),
+                                )
+                            },
+                        )
+                        .0
+                        .font(ST_HEI_TI_LIGHT)
+                        .padding(10)
+                        .size(17)
+                        .style(Bl3UiStyle)
+                        .into_element(),
+                    )
+                    .spacing(15)
+                    .align_items(Alignment::Center),
+                )
+                .width(Length::FillPortion(1))
+                .height(Length::Units(36))
+                .style(Bl3UiStyle),
+            )
+            .spacing(20)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +135,10 @@ pub enum SaveGeneralInteractionMessage {
     Slot(u32),
     GenerateGuidPressed,
     SaveTypeSelected(HeaderType),
+    MayhemLevel(usize, i32),
+    MayhemRandomSeed(usize, i32),
+    AddTvhmPressed,
+    RemoveTvhmPressed,
 }
 
 pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
@@ -193,11 +299,55 @@ pub fn view(general_state: &mut GeneralState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
+    let number_of_playthroughs = general_state.playthroughs.len();
+
+    let mut add_tvhm_button = Button::new(
+        &mut general_state.add_tvhm_button_state,
+        Text::new("添加真实模式").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if number_of_playthroughs == 1 {
+        add_tvhm_button = add_tvhm_button.on_press(InteractionMessage::ManageSaveInteraction(
+            ManageSaveInteractionMessage::General(SaveGeneralInteractionMessage::AddTvhmPressed),
+        ));
+    }
+
+    let mut remove_tvhm_button = Button::new(
+        &mut general_state.remove_tvhm_button_state,
+        Text::new("移除真实模式").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if number_of_playthroughs > 1 {
+        remove_tvhm_button = remove_tvhm_button.on_press(
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::General(
+                SaveGeneralInteractionMessage::RemoveTvhmPressed,
+            )),
+        );
+    }
+
+    let playthroughs = general_state
+        .playthroughs
+        .iter_mut()
+        .fold(Column::new().spacing(20), |curr, playthrough| {
+            curr.push(playthrough.view())
+        });
+
     let all_contents = Column::new()
         .push(file)
         .push(save_guid)
         .push(save_slot)
         .push(save_type)
+        .push(playthroughs)
+        .push(
+            Row::new()
+                .push(add_tvhm_button.into_element())
+                .push(remove_tvhm_button.into_element())
+                .spacing(20),
+        )
         .spacing(20);
 
     Container::new(all_contents).padding(30)
@@ -2,17 +2,25 @@ use iced::{button, svg, Column, Container, Length, Row};
 use strum::Display;
 
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
-use crate::resources::svgs::{CHARACTER, CURRENCY, GENERAL, INVENTORY, SETTINGS, VEHICLE};
+use crate::resources::svgs::{CHARACTER, CURRENCY, GENERAL, INVENTORY, REFRESH, SETTINGS, VEHICLE};
 use crate::views;
+use crate::views::backups::BackupsState;
+use crate::views::diff::DiffState;
 use crate::views::manage_save::character::CharacterState;
+use crate::views::manage_save::crew_quarters::CrewQuartersState;
 use crate::views::manage_save::currency::CurrencyState;
+use crate::views::manage_save::echo_logs::EchoLogsState;
+use crate::views::manage_save::fast_travel::FastTravelState;
 use crate::views::manage_save::general::GeneralState;
 use crate::views::manage_save::inventory::InventoryState;
+use crate::views::manage_save::missions::MissionsState;
 use crate::views::manage_save::vehicle::VehicleState;
 use crate::views::manage_save::{
-    character, currency, general, inventory, vehicle, ManageSaveInteractionMessage, ManageSaveState,
+    character, crew_quarters, currency, echo_logs, fast_travel, general, inventory, missions,
+    vehicle, ManageSaveInteractionMessage, ManageSaveState,
 };
 use crate::views::settings::SettingsState;
+use crate::views::transfer::TransferState;
 use crate::views::{tab_bar_button, ManageTabBarStyle};
 
 #[derive(Debug, Default)]
@@ -23,15 +31,27 @@ pub struct SaveViewState {
     pub inventory_state: InventoryState,
     pub currency_state: CurrencyState,
     pub vehicle_state: VehicleState,
+    pub missions_state: MissionsState,
+    pub fast_travel_state: FastTravelState,
+    pub echo_logs_state: EchoLogsState,
+    pub crew_quarters_state: CrewQuartersState,
 }
 
 #[derive(Debug, Default)]
 pub struct SaveTabBarState {
     general_button_state: button::State,
     character_button_state: button::State,
+    skills_button_state: button::State,
     inventory_button_state: button::State,
     currency_button_state: button::State,
     vehicle_button_state: button::State,
+    missions_button_state: button::State,
+    fast_travel_button_state: button::State,
+    echo_logs_button_state: button::State,
+    crew_quarters_button_state: button::State,
+    transfer_button_state: button::State,
+    compare_button_state: button::State,
+    backups_button_state: button::State,
     settings_button_state: button::State,
 }
 
@@ -40,9 +60,17 @@ pub enum SaveTabBarInteractionMessage {
     // fixme: 我知道这样写非常蠢，但是真的很快不是吗
     基础,
     角色,
+    技能,
     背包,
     金钱,
     车辆,
+    任务,
+    传送,
+    日志,
+    宿舍,
+    转移,
+    对比,
+    备份,
     设置,
 }
 
@@ -52,14 +80,25 @@ pub enum SaveTabBarView {
     // fixme: 我知道这样写非常蠢，但是真的很快不是吗
     基础,
     角色,
+    技能,
     背包,
     金钱,
     车辆,
+    任务,
+    传送,
+    日志,
+    宿舍,
+    转移,
+    对比,
+    备份,
     设置,
 }
 
 pub fn view<'a>(
     settings_state: &'a mut SettingsState,
+    diff_state: &'a mut DiffState,
+    transfer_state: &'a mut TransferState,
+    backups_state: &'a mut BackupsState,
     manage_save_state: &'a mut ManageSaveState,
     tab_bar_view: &SaveTabBarView,
 ) -> Container<'a, Bl3Message> {
@@ -91,6 +130,20 @@ pub fn view<'a>(
         115,
     );
 
+    let skills_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .skills_button_state,
+        SaveTabBarView::技能,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::技能,
+        )),
+        svg::Handle::from_memory(CHARACTER),
+        115,
+    );
+
     let inventory_button = tab_bar_button(
         &mut manage_save_state
             .save_view_state
@@ -133,6 +186,104 @@ pub fn view<'a>(
         100,
     );
 
+    let missions_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .missions_button_state,
+        SaveTabBarView::任务,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::任务,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        100,
+    );
+
+    let fast_travel_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .fast_travel_button_state,
+        SaveTabBarView::传送,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::传送,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        100,
+    );
+
+    let echo_logs_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .echo_logs_button_state,
+        SaveTabBarView::日志,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::日志,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        100,
+    );
+
+    let crew_quarters_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .crew_quarters_button_state,
+        SaveTabBarView::宿舍,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::宿舍,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        100,
+    );
+
+    let transfer_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .transfer_button_state,
+        SaveTabBarView::转移,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::转移,
+        )),
+        svg::Handle::from_memory(INVENTORY),
+        100,
+    );
+
+    let compare_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .compare_button_state,
+        SaveTabBarView::对比,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::对比,
+        )),
+        svg::Handle::from_memory(GENERAL),
+        100,
+    );
+
+    let backups_button = tab_bar_button(
+        &mut manage_save_state
+            .save_view_state
+            .tab_bar_state
+            .backups_button_state,
+        SaveTabBarView::备份,
+        tab_bar_view,
+        InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::TabBar(
+            SaveTabBarInteractionMessage::备份,
+        )),
+        svg::Handle::from_memory(REFRESH),
+        100,
+    );
+
     let settings_button = tab_bar_button(
         &mut manage_save_state
             .save_view_state
@@ -151,9 +302,17 @@ pub fn view<'a>(
         Row::new()
             .push(general_button)
             .push(character_button)
+            .push(skills_button)
             .push(inventory_button)
             .push(currency_button)
             .push(vehicle_button)
+            .push(missions_button)
+            .push(fast_travel_button)
+            .push(echo_logs_button)
+            .push(crew_quarters_button)
+            .push(transfer_button)
+            .push(compare_button)
+            .push(backups_button)
             .push(settings_button),
     )
     .width(Length::Fill)
@@ -166,6 +325,9 @@ pub fn view<'a>(
         SaveTabBarView::角色 => {
             character::view(&mut manage_save_state.save_view_state.character_state)
         }
+        SaveTabBarView::技能 => {
+            character::skills::view(&mut manage_save_state.save_view_state.character_state)
+        }
         SaveTabBarView::背包 => {
             inventory::view(&mut manage_save_state.save_view_state.inventory_state)
         }
@@ -175,6 +337,21 @@ pub fn view<'a>(
         SaveTabBarView::车辆 => {
             vehicle::view(&mut manage_save_state.save_view_state.vehicle_state)
         }
+        SaveTabBarView::任务 => {
+            missions::view(&mut manage_save_state.save_view_state.missions_state)
+        }
+        SaveTabBarView::传送 => {
+            fast_travel::view(&mut manage_save_state.save_view_state.fast_travel_state)
+        }
+        SaveTabBarView::日志 => {
+            echo_logs::view(&mut manage_save_state.save_view_state.echo_logs_state)
+        }
+        SaveTabBarView::宿舍 => {
+            crew_quarters::view(&mut manage_save_state.save_view_state.crew_quarters_state)
+        }
+        SaveTabBarView::转移 => views::transfer::view(transfer_state),
+        SaveTabBarView::对比 => views::diff::view(diff_state),
+        SaveTabBarView::备份 => views::backups::view(backups_state),
         SaveTabBarView::设置 => views::settings::view(settings_state),
     };
 
@@ -0,0 +1,377 @@
+use std::fmt::Formatter;
+
+use iced::{
+    button, pick_list, scrollable, text_input, Alignment, Button, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text, TextInput,
+};
+use strum::IntoEnumIterator;
+
+use bl3_save_edit_core::bl3_save::mission::{mission_name, MissionData, MissionStatus};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::manage_save::ManageSaveInteractionMessage;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct PlaythroughOption(pub usize);
+
+impl std::fmt::Display for PlaythroughOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            0 => write!(f, "普通模式"),
+            1 => write!(f, "真实模式"),
+            i => write!(f, "周目 {}", i + 1),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StoryMission(pub &'static str);
+
+impl std::fmt::Display for StoryMission {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", mission_name(self.0))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MissionField {
+    pub mission: MissionData,
+    pub objectives_input: String,
+    status_selector: pick_list::State<MissionStatus>,
+    objectives_input_state: text_input::State,
+    track_button_state: button::State,
+}
+
+impl MissionField {
+    pub fn new(mission: MissionData) -> Self {
+        let objectives_input = mission
+            .objectives_progress
+            .iter()
+            .map(|o| o.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Self {
+            mission,
+            objectives_input,
+            ..Default::default()
+        }
+    }
+
+    pub fn view(&mut self, tracked: bool) -> Container<Bl3Message> {
+        let text_color = Color::from_rgb8(220, 220, 220);
+
+        let mission_class_path = self.mission.mission_class_path.clone();
+
+        let status_selector = PickList::new(
+            &mut self.status_selector,
+            MissionStatus::iter().collect::<Vec<_>>(),
+            Some(self.mission.status),
+            {
+                let mission_class_path = mission_class_path.clone();
+
+                move |s| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Missions(
+                            SaveMissionsInteractionMessage::StatusSelected(
+                                mission_class_path.clone(),
+                                s,
+                            ),
+                        ),
+                    )
+                }
+            },
+        )
+        .font(ST_HEI_TI_LIGHT)
+        .text_size(16)
+        .width(Length::FillPortion(2))
+        .padding(8)
+        .style(Bl3UiStyle)
+        .into_element();
+
+        let mut objectives_input = TextInput::new(
+            &mut self.objectives_input_state,
+            "目标进度, 例如 1,0,1",
+            &self.objectives_input,
+            {
+                let mission_class_path = mission_class_path.clone();
+
+                move |s| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Missions(
+                            SaveMissionsInteractionMessage::ObjectivesProgressInput(
+                                mission_class_path.clone(),
+                                s,
+                            ),
+                        ),
+                    )
+                }
+            },
+        )
+        .font(ST_HEI_TI_LIGHT)
+        .padding(8)
+        .size(16)
+        .width(Length::FillPortion(2))
+        .style(Bl3UiStyle);
+
+        if self.mission.status != MissionStatus::NotStarted {
+            objectives_input = objectives_input.on_submit(
+                InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Missions(
+                    SaveMissionsInteractionMessage::ObjectivesProgressSubmitted(
+                        mission_class_path.clone(),
+                    ),
+                )),
+            );
+        }
+
+        let mut track_button = Button::new(
+            &mut self.track_button_state,
+            Text::new(if tracked { "追踪中" } else { "追踪" })
+                .font(ST_HEI_TI_LIGHT)
+                .size(16),
+        )
+        .width(Length::Units(80))
+        .padding(8)
+        .style(Bl3UiStyle);
+
+        if self.mission.status == MissionStatus::Active && !tracked {
+            track_button = track_button.on_press(InteractionMessage::ManageSaveInteraction(
+                ManageSaveInteractionMessage::Missions(
+                    SaveMissionsInteractionMessage::TrackMission(mission_class_path),
+                ),
+            ));
+        }
+
+        Container::new(
+            Row::new()
+                .push(
+                    Text::new(&self.mission.name)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(4)),
+                )
+                .push(status_selector)
+                .push(objectives_input.into_element())
+                .push(track_button.into_element())
+                .spacing(10)
+                .align_items(Alignment::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MissionsState {
+    pub playthroughs: Vec<PlaythroughOption>,
+    pub playthrough_selected: PlaythroughOption,
+    playthrough_selector: pick_list::State<PlaythroughOption>,
+    pub story_missions: Vec<StoryMission>,
+    pub story_mission_selected: Option<StoryMission>,
+    story_mission_selector: pick_list::State<StoryMission>,
+    complete_story_button_state: button::State,
+    pub tracked_mission: Option<String>,
+    stop_tracking_button_state: button::State,
+    pub missions: Vec<MissionField>,
+    scrollable_state: scrollable::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum SaveMissionsInteractionMessage {
+    PlaythroughSelected(PlaythroughOption),
+    StatusSelected(String, MissionStatus),
+    ObjectivesProgressInput(String, String),
+    ObjectivesProgressSubmitted(String),
+    TrackMission(String),
+    StopTrackingPressed,
+    StoryMissionSelected(StoryMission),
+    CompleteStoryPressed,
+}
+
+pub fn view(missions_state: &mut MissionsState) -> Container<Bl3Message> {
+    let playthrough_selector = Container::new(
+        LabelledElement::create(
+            "周目",
+            Length::Units(90),
+            PickList::new(
+                &mut missions_state.playthrough_selector,
+                missions_state.playthroughs.clone(),
+                Some(missions_state.playthrough_selected),
+                |p| {
+                    InteractionMessage::ManageSaveInteraction(
+                        ManageSaveInteractionMessage::Missions(
+                            SaveMissionsInteractionMessage::PlaythroughSelected(p),
+                        ),
+                    )
+                },
+            )
+            .font(ST_HEI_TI_LIGHT)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(2))
+    .style(Bl3UiStyle);
+
+    let mut complete_story_button = Button::new(
+        &mut missions_state.complete_story_button_state,
+        Text::new("完成至此").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if missions_state.story_mission_selected.is_some() {
+        complete_story_button = complete_story_button.on_press(
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Missions(
+                SaveMissionsInteractionMessage::CompleteStoryPressed,
+            )),
+        );
+    }
+
+    let complete_story = Container::new(
+        Row::new()
+            .push(
+                LabelledElement::create(
+                    "主线任务",
+                    Length::Units(90),
+                    PickList::new(
+                        &mut missions_state.story_mission_selector,
+                        missions_state.story_missions.clone(),
+                        missions_state.story_mission_selected,
+                        |m| {
+                            InteractionMessage::ManageSaveInteraction(
+                                ManageSaveInteractionMessage::Missions(
+                                    SaveMissionsInteractionMessage::StoryMissionSelected(m),
+                                ),
+                            )
+                        },
+                    )
+                    .font(ST_HEI_TI_LIGHT)
+                    .text_size(17)
+                    .width(Length::Fill)
+                    .padding(10)
+                    .style(Bl3UiStyle)
+                    .into_element(),
+                )
+                .spacing(15)
+                .width(Length::Fill)
+                .align_items(Alignment::Center),
+            )
+            .push(complete_story_button.into_element())
+            .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .style(Bl3UiStyle);
+
+    let tracked_mission_name = missions_state
+        .tracked_mission
+        .as_deref()
+        .map(mission_name)
+        .unwrap_or_else(|| "无".to_owned());
+
+    let mut stop_tracking_button = Button::new(
+        &mut missions_state.stop_tracking_button_state,
+        Text::new("取消追踪").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if missions_state.tracked_mission.is_some() {
+        stop_tracking_button = stop_tracking_button.on_press(
+            InteractionMessage::ManageSaveInteraction(ManageSaveInteractionMessage::Missions(
+                SaveMissionsInteractionMessage::StopTrackingPressed,
+            )),
+        );
+    }
+
+    let tracked_mission = Container::new(
+        Row::new()
+            .push(
+                Text::new(format!("当前追踪任务: {}", tracked_mission_name))
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220))
+                    .width(Length::Fill),
+            )
+            .push(stop_tracking_button.into_element())
+            .align_items(Alignment::Center),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let header_color = Color::from_rgb8(242, 203, 5);
+
+    let header = Container::new(
+        Row::new()
+            .push(
+                Text::new("任务")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new("状态")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(
+                Text::new("目标进度")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(2)),
+            )
+            .push(Container::new(Text::new("")).width(Length::Units(80)))
+            .spacing(10),
+    )
+    .padding(10)
+    .style(Bl3UiStyleNoBorder);
+
+    let tracked = missions_state.tracked_mission.clone();
+
+    let missions =
+        missions_state
+            .missions
+            .iter_mut()
+            .fold(Column::new().spacing(5), |curr, mission| {
+                let is_tracked = tracked
+                    .as_deref()
+                    .map(|t| t.eq_ignore_ascii_case(&mission.mission.mission_class_path))
+                    .unwrap_or(false);
+
+                curr.push(mission.view(is_tracked))
+            });
+
+    let all_contents = Column::new()
+        .push(
+            Row::new()
+                .push(playthrough_selector)
+                .push(complete_story)
+                .spacing(20),
+        )
+        .push(tracked_mission)
+        .push(
+            Column::new().push(header).push(
+                Scrollable::new(&mut missions_state.scrollable_state)
+                    .push(missions)
+                    .height(Length::Fill)
+                    .width(Length::Fill),
+            ),
+        )
+        .spacing(20);
+
+    Container::new(all_contents).padding(30)
+}
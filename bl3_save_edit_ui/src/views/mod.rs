@@ -6,7 +6,10 @@ use iced::{button, container, svg, Alignment, Button, Color, Element, Length, Ro
 use crate::bl3_ui::{Bl3Message, InteractionMessage};
 use crate::resources::fonts::{ST_HEI_TI_LIGHT};
 
+pub mod backups;
 pub mod choose_save_directory;
+pub mod diff;
+pub mod file_changed;
 pub mod initialization;
 pub mod item_editor;
 pub mod loading;
@@ -14,6 +17,7 @@ pub mod manage_profile;
 pub mod manage_save;
 pub mod settings;
 pub mod tab_bar_button;
+pub mod transfer;
 
 pub const NO_SEARCH_RESULTS_FOUND_MESSAGE: &str = "No results found.";
 
@@ -1,11 +1,15 @@
 use std::path::PathBuf;
 
 use iced::{
-    button, text_input, Alignment, Button, Color, Column, Container, Length, Row, Text, TextInput,
+    button, pick_list, text_input, Alignment, Button, Color, Column, Container, Length, PickList,
+    Row, Text, TextInput,
 };
 
+use bl3_save_edit_core::resources::data_pack::DataPackInfo;
+
 use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
 use crate::bl3_ui_style::Bl3UiStyle;
+use crate::commands::interaction::choose_save_directory::SaveDirLocation;
 use crate::resources::fonts::{ST_HEI_TI_LIGHT};
 use crate::views::InteractionExt;
 use crate::widgets::labelled_element::LabelledElement;
@@ -25,9 +29,13 @@ pub struct SettingsState {
     pub open_saves_dir_button_state: button::State,
     pub change_saves_dir_button_state: button::State,
     pub choose_saves_dir_window_open: bool,
+    pub save_dirs: Vec<SaveDirLocation>,
+    pub save_dir_selector: pick_list::State<SaveDirLocation>,
     pub decrease_ui_scale_button_state: button::State,
     pub increase_ui_scale_button_state: button::State,
     pub ui_scale_factor: f64,
+    pub embedded_max_version: usize,
+    pub data_pack: Option<DataPackInfo>,
 }
 
 #[derive(Debug, Clone)]
@@ -42,6 +50,7 @@ pub enum SettingsInteractionMessage {
     OpenSavesDirCompleted(MessageResult<()>),
     ChangeSavesDir,
     ChangeSavesDirCompleted(MessageResult<PathBuf>),
+    SaveDirSelected(SaveDirLocation),
     DecreaseUIScale,
     IncreaseUIScale,
 }
@@ -199,6 +208,40 @@ pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
     .height(Length::Units(36))
     .style(Bl3UiStyle);
 
+    let selected_save_dir = settings_state
+        .save_dirs
+        .iter()
+        .find(|d| d.path == PathBuf::from(&settings_state.saves_dir_input))
+        .cloned();
+
+    let found_saves_dirs = Container::new(
+        LabelledElement::create(
+            "找到的存档",
+            Length::Units(140),
+            PickList::new(
+                &mut settings_state.save_dir_selector,
+                settings_state.save_dirs.clone(),
+                selected_save_dir,
+                |d| {
+                    InteractionMessage::SettingsInteraction(
+                        SettingsInteractionMessage::SaveDirSelected(d),
+                    )
+                },
+            )
+            .font(ST_HEI_TI_LIGHT)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
     let ui_scale = Container::new(
         LabelledElement::create(
             "UI 调整",
@@ -243,11 +286,40 @@ pub fn view(settings_state: &mut SettingsState) -> Container<Bl3Message> {
     )
     .style(Bl3UiStyle);
 
+    let data_version_text = match &settings_state.data_pack {
+        Some(data_pack) => format!(
+            "数据包 {}: {}  (内置: {})",
+            data_pack.manifest.name,
+            data_pack.manifest.max_version,
+            settings_state.embedded_max_version
+        ),
+        None => format!("内置: {}", settings_state.embedded_max_version),
+    };
+
+    let data_version = Container::new(
+        LabelledElement::create(
+            "数据版本",
+            Length::Units(140),
+            Text::new(data_version_text)
+                .color(Color::from_rgb8(220, 220, 220))
+                .font(ST_HEI_TI_LIGHT)
+                .size(17),
+        )
+        .spacing(15)
+        .width(Length::Fill)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::Fill)
+    .height(Length::Units(36))
+    .style(Bl3UiStyle);
+
     let all_contents = Column::new()
         .push(config_dir)
         .push(backup_dir)
         .push(saves_dir)
+        .push(found_saves_dirs)
         .push(ui_scale)
+        .push(data_version)
         .spacing(20);
 
     Container::new(all_contents).padding(30)
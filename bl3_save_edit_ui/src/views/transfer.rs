@@ -0,0 +1,319 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::{
+    button, pick_list, scrollable, Alignment, Button, Checkbox, Color, Column, Container, Length,
+    PickList, Row, Scrollable, Text,
+};
+
+use bl3_save_edit_core::file_helper::Bl3FileType;
+use bl3_save_edit_core::transfer::{ItemContainer, TransferMode};
+
+use crate::bl3_ui::{Bl3Message, InteractionMessage, MessageResult};
+use crate::bl3_ui_style::{Bl3UiStyle, Bl3UiStyleNoBorder};
+use crate::resources::fonts::ST_HEI_TI_LIGHT;
+use crate::views::InteractionExt;
+use crate::widgets::labelled_element::LabelledElement;
+
+#[derive(Debug, Default)]
+pub struct TransferItemField {
+    pub index: usize,
+    pub name: String,
+    pub item_type: String,
+    pub level: usize,
+    pub selected: bool,
+}
+
+impl TransferItemField {
+    pub fn view(&mut self) -> Container<Bl3Message> {
+        let text_color = Color::from_rgb8(220, 220, 220);
+
+        let index = self.index;
+
+        let selected_checkbox = Checkbox::new(self.selected, "", move |c| {
+            InteractionMessage::TransferInteraction(TransferInteractionMessage::ItemSelected(
+                index, c,
+            ))
+        })
+        .size(20)
+        .style(Bl3UiStyle);
+
+        Container::new(
+            Row::new()
+                .push(Container::new(selected_checkbox.into_element()).width(Length::Units(40)))
+                .push(
+                    Text::new(&self.name)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(4)),
+                )
+                .push(
+                    Text::new(&self.item_type)
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(1)),
+                )
+                .push(
+                    Text::new(self.level.to_string())
+                        .font(ST_HEI_TI_LIGHT)
+                        .size(16)
+                        .color(text_color)
+                        .width(Length::FillPortion(1)),
+                )
+                .spacing(10)
+                .align_items(Alignment::Center),
+        )
+        .padding(10)
+        .style(Bl3UiStyle)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TransferState {
+    pub files: Vec<Bl3FileType>,
+    pub source_file: Option<Bl3FileType>,
+    source_file_selector: pick_list::State<Bl3FileType>,
+    pub source_container: Option<ItemContainer>,
+    source_container_selector: pick_list::State<ItemContainer>,
+    pub target_file: Option<Bl3FileType>,
+    target_file_selector: pick_list::State<Bl3FileType>,
+    pub target_container: Option<ItemContainer>,
+    target_container_selector: pick_list::State<ItemContainer>,
+    pub items: Vec<TransferItemField>,
+    move_button_state: button::State,
+    copy_button_state: button::State,
+    scrollable_state: scrollable::State,
+    pub is_transferring: bool,
+    pub items_losing_cosmetics: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum TransferInteractionMessage {
+    SourceFileSelected(Box<Bl3FileType>),
+    SourceContainerSelected(ItemContainer),
+    TargetFileSelected(Box<Bl3FileType>),
+    TargetContainerSelected(ItemContainer),
+    ItemSelected(usize, bool),
+    SelectAll(bool),
+    TransferPressed(TransferMode),
+    TransferCompleted(MessageResult<Vec<Bl3FileType>>),
+}
+
+fn file_selector<'a>(
+    label: &str,
+    selector_state: &'a mut pick_list::State<Bl3FileType>,
+    files: &'a [Bl3FileType],
+    selected: Option<Bl3FileType>,
+    on_selected: fn(Box<Bl3FileType>) -> TransferInteractionMessage,
+) -> Container<'a, Bl3Message> {
+    Container::new(
+        LabelledElement::create(
+            label,
+            Length::Units(90),
+            PickList::new(selector_state, files, selected, move |f| {
+                InteractionMessage::TransferInteraction(on_selected(Box::new(f)))
+            })
+            .font(ST_HEI_TI_LIGHT)
+            .text_size(17)
+            .width(Length::Fill)
+            .padding(10)
+            .style(Bl3UiStyle)
+            .into_element(),
+        )
+        .spacing(15)
+        .align_items(Alignment::Center),
+    )
+    .width(Length::FillPortion(3))
+    .style(Bl3UiStyle)
+}
+
+fn container_selector<'a>(
+    selector_state: &'a mut pick_list::State<ItemContainer>,
+    file: Option<&Bl3FileType>,
+    selected: Option<ItemContainer>,
+    on_selected: fn(ItemContainer) -> TransferInteractionMessage,
+) -> Container<'a, Bl3Message> {
+    let containers = file.map(ItemContainer::for_file).unwrap_or_default();
+
+    Container::new(
+        PickList::new(selector_state, containers, selected, move |c| {
+            InteractionMessage::TransferInteraction(on_selected(c))
+        })
+        .font(ST_HEI_TI_LIGHT)
+        .text_size(17)
+        .width(Length::Fill)
+        .padding(10)
+        .style(Bl3UiStyle)
+        .into_element(),
+    )
+    .width(Length::FillPortion(1))
+    .style(Bl3UiStyle)
+}
+
+pub fn view(transfer_state: &mut TransferState) -> Container<Bl3Message> {
+    let source_row = Row::new()
+        .push(file_selector(
+            "来源",
+            &mut transfer_state.source_file_selector,
+            &transfer_state.files,
+            transfer_state.source_file.clone(),
+            TransferInteractionMessage::SourceFileSelected,
+        ))
+        .push(container_selector(
+            &mut transfer_state.source_container_selector,
+            transfer_state.source_file.as_ref(),
+            transfer_state.source_container,
+            TransferInteractionMessage::SourceContainerSelected,
+        ))
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let target_row = Row::new()
+        .push(file_selector(
+            "目标",
+            &mut transfer_state.target_file_selector,
+            &transfer_state.files,
+            transfer_state.target_file.clone(),
+            TransferInteractionMessage::TargetFileSelected,
+        ))
+        .push(container_selector(
+            &mut transfer_state.target_container_selector,
+            transfer_state.target_file.as_ref(),
+            transfer_state.target_container,
+            TransferInteractionMessage::TargetContainerSelected,
+        ))
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+    let selected_count = transfer_state.items.iter().filter(|i| i.selected).count();
+
+    let can_transfer = selected_count > 0
+        && transfer_state.target_file.is_some()
+        && transfer_state.target_container.is_some()
+        && !transfer_state.is_transferring;
+
+    let mut move_button = Button::new(
+        &mut transfer_state.move_button_state,
+        Text::new("移动所选").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let mut copy_button = Button::new(
+        &mut transfer_state.copy_button_state,
+        Text::new("复制所选").font(ST_HEI_TI_LIGHT).size(17),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    if can_transfer {
+        move_button = move_button.on_press(InteractionMessage::TransferInteraction(
+            TransferInteractionMessage::TransferPressed(TransferMode::Move),
+        ));
+
+        copy_button = copy_button.on_press(InteractionMessage::TransferInteraction(
+            TransferInteractionMessage::TransferPressed(TransferMode::Copy),
+        ));
+    }
+
+    let summary = Container::new(
+        Row::new()
+            .push(
+                Text::new(format!(
+                    "已选择: {}/{}    转移后两个文件会立即保存并各自备份, 当前打开文件未保存的修改会丢失",
+                    selected_count,
+                    transfer_state.items.len()
+                ))
+                .font(ST_HEI_TI_LIGHT)
+                .size(17)
+                .color(Color::from_rgb8(220, 220, 220))
+                .width(Length::Fill),
+            )
+            .push(move_button.into_element())
+            .push(copy_button.into_element())
+            .spacing(20)
+            .align_items(Alignment::Center),
+    )
+    .padding(10)
+    .style(Bl3UiStyle);
+
+    let header_color = Color::from_rgb8(242, 203, 5);
+
+    let all_selected =
+        !transfer_state.items.is_empty() && selected_count == transfer_state.items.len();
+
+    let select_all_checkbox = Checkbox::new(all_selected, "", |c| {
+        InteractionMessage::TransferInteraction(TransferInteractionMessage::SelectAll(c))
+    })
+    .size(20)
+    .style(Bl3UiStyle);
+
+    let header = Container::new(
+        Row::new()
+            .push(Container::new(select_all_checkbox.into_element()).width(Length::Units(40)))
+            .push(
+                Text::new("物品")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(4)),
+            )
+            .push(
+                Text::new("类型")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(1)),
+            )
+            .push(
+                Text::new("等级")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(header_color)
+                    .width(Length::FillPortion(1)),
+            )
+            .spacing(10)
+            .align_items(Alignment::Center),
+    )
+    .padding(10)
+    .style(Bl3UiStyleNoBorder);
+
+    let mut all_contents = Column::new()
+        .push(source_row)
+        .push(target_row)
+        .push(summary)
+        .spacing(20);
+
+    if transfer_state.items.is_empty() {
+        all_contents = all_contents.push(
+            Container::new(
+                Text::new("来源中没有物品")
+                    .font(ST_HEI_TI_LIGHT)
+                    .size(17)
+                    .color(Color::from_rgb8(220, 220, 220)),
+            )
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center),
+        );
+    } else {
+        let items = transfer_state
+            .items
+            .iter_mut()
+            .fold(Column::new().spacing(5), |curr, item| {
+                curr.push(item.view())
+            });
+
+        all_contents = all_contents.push(
+            Column::new().push(header).push(
+                Scrollable::new(&mut transfer_state.scrollable_state)
+                    .push(items)
+                    .height(Length::Fill)
+                    .width(Length::Fill),
+            ),
+        );
+    }
+
+    Container::new(all_contents).padding(30)
+}